@@ -1,12 +1,15 @@
 #![deny(clippy::undocumented_unsafe_blocks)]
 //TODO: Add documentation and fix naming
 
+pub mod session;
+
 use {
     async_fs as fs,
     async_trait::async_trait,
     futures_io::{AsyncRead, AsyncWrite},
     futures_util::{AsyncReadExt, AsyncWriteExt},
     http::StatusCode,
+    miners_profile::GameProfile,
     serde_derive::{Deserialize, Serialize},
     serde_json::json,
     std::{fmt::Display, path::Path, string::FromUtf8Error},
@@ -119,12 +122,6 @@ pub struct Auth {
     pub token: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-struct McProfile {
-    id: String,
-    name: String,
-}
-
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct McAuth {
     pub access_token: String,
@@ -134,7 +131,7 @@ struct McAuth {
 }
 
 impl McAuth {
-    async fn mc_profile(&self, client: &impl HttpClient) -> Result<McProfile, Error> {
+    async fn mc_profile(&self, client: &impl HttpClient) -> Result<GameProfile, Error> {
         let pr_resp = client
             .execute_request(
                 http::request::Builder::new()
@@ -567,7 +564,7 @@ impl DeviceCode {
 
         let auth = Auth {
             name: profile.name,
-            uuid: profile.id,
+            uuid: profile.uuid.simple().to_string(),
             token: mca.access_token,
         };
         Ok(auth)
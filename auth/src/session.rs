@@ -0,0 +1,392 @@
+//! Requests to the session server, which is used to check that a player
+//! joining an online-mode server owns the account they're logging in with.
+//!
+//! The client calls [`SessionServer::join`] after receiving the encryption
+//! request and before answering it, the server then calls
+//! [`SessionServer::has_joined`] after receiving the encryption response.
+//! Both use the same [`server_hash`].
+
+use {
+    crate::{Auth, HttpClient, HttpStatusError},
+    http::StatusCode,
+    serde_derive::{Deserialize, Serialize},
+    serde_json::json,
+    std::{fmt::Write, net::IpAddr},
+};
+
+pub use miners_profile::{GameProfile, Property};
+pub use miners_util::server_hash::server_hash;
+
+pub const SESSION_SERVER: &str = "https://sessionserver.mojang.com";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    /// The session server doesn't know about the player joining this server,
+    /// it's likely not logged in with the account it claims to be.
+    #[error("the player is not authenticated with the session server")]
+    NotAuthenticated,
+    /// The access token is invalid or expired and has to be refreshed.
+    #[error("the access token is invalid or expired")]
+    InvalidToken,
+    /// The account is banned from joining online-mode servers.
+    #[error("the account is banned from multiplayer")]
+    Banned,
+    /// Multiplayer is disabled for the account, e.g. by parental controls.
+    #[error("multiplayer is disabled for the account")]
+    MultiplayerDisabled,
+    /// The session server refused the request for another reason.
+    #[error("the session server refused the request: {error}: {message}")]
+    Forbidden { error: String, message: String },
+    /// The request couldn't be sent or the response couldn't be received.
+    #[error(transparent)]
+    Transport(anyhow::Error),
+    #[error(transparent)]
+    HttpStatus(#[from] HttpStatusError),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error(transparent)]
+    Http(#[from] http::Error),
+}
+
+/// The body of an error response of the session server.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorResponse {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_message: String,
+}
+
+impl From<ErrorResponse> for SessionError {
+    fn from(resp: ErrorResponse) -> Self {
+        match &resp.error as &str {
+            "InsufficientPrivilegesException" => Self::MultiplayerDisabled,
+            "UserBannedException" => Self::Banned,
+            "ForbiddenOperationException" if resp.error_message.contains("token") => {
+                Self::InvalidToken
+            }
+            _ => Self::Forbidden {
+                error: resp.error,
+                message: resp.error_message,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionServer {
+    base_url: String,
+}
+
+impl Default for SessionServer {
+    fn default() -> Self {
+        Self::new(SESSION_SERVER)
+    }
+}
+
+impl SessionServer {
+    /// Uses another session server, like a local one for testing.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        Self { base_url }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Checks whether the player has joined the server with the server hash
+    /// computed during the encryption handshake and returns its profile.
+    ///
+    /// If `ip` is given, the session server also checks that the player
+    /// joined from that address.
+    pub async fn has_joined(
+        &self,
+        client: &impl HttpClient,
+        username: &str,
+        server_hash: &str,
+        ip: Option<IpAddr>,
+    ) -> Result<GameProfile, SessionError> {
+        let mut uri = format!(
+            "{}/session/minecraft/hasJoined?username={}&serverId={}",
+            self.base_url,
+            percent_encode(username),
+            percent_encode(server_hash),
+        );
+        if let Some(ip) = ip {
+            // infallible, writing to a string
+            let _ = write!(uri, "&ip={}", percent_encode(&ip.to_string()));
+        }
+
+        let resp = client
+            .execute_request(
+                http::request::Builder::new()
+                    .uri(uri)
+                    .method(http::Method::GET)
+                    .body(Vec::new())?,
+            )
+            .await
+            .map_err(SessionError::Transport)?;
+
+        match resp.status() {
+            // the session server answers with no content if the player
+            // hasn't joined
+            StatusCode::NO_CONTENT => Err(SessionError::NotAuthenticated),
+            StatusCode::OK if resp.body().as_ref().is_empty() => {
+                Err(SessionError::NotAuthenticated)
+            }
+            StatusCode::OK => Ok(serde_json::from_slice(resp.body().as_ref())?),
+            status => Err(HttpStatusError::from(status).into()),
+        }
+    }
+
+    /// Tells the session server that the player is joining the server with
+    /// the server hash computed after receiving the encryption request.
+    ///
+    /// This has to be done before sending the encryption response, otherwise
+    /// the server won't be able to verify the player.
+    pub async fn join(
+        &self,
+        client: &impl HttpClient,
+        access_token: &str,
+        profile_id: &str,
+        server_hash: &str,
+    ) -> Result<(), SessionError> {
+        let json = json!({
+            "accessToken": access_token,
+            "selectedProfile": profile_id.replace('-', ""),
+            "serverId": server_hash,
+        });
+
+        let resp = client
+            .execute_request(
+                http::request::Builder::new()
+                    .uri(format!("{}/session/minecraft/join", self.base_url))
+                    .method(http::Method::POST)
+                    .header("content-type", "application/json")
+                    .body(serde_json::to_vec(&json)?)?,
+            )
+            .await
+            .map_err(SessionError::Transport)?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(SessionError::InvalidToken),
+            status @ (StatusCode::FORBIDDEN | StatusCode::BAD_REQUEST) => {
+                match serde_json::from_slice::<ErrorResponse>(resp.body().as_ref()) {
+                    Ok(error) => Err(error.into()),
+                    Err(_) => Err(HttpStatusError::from(status).into()),
+                }
+            }
+            status => Err(HttpStatusError::from(status).into()),
+        }
+    }
+}
+
+impl Auth {
+    /// Joins a server on the default session server, see
+    /// [`SessionServer::join`].
+    pub async fn join_server(
+        &self,
+        client: &impl HttpClient,
+        server_hash: &str,
+    ) -> Result<(), SessionError> {
+        SessionServer::default()
+            .join(client, &self.token, &self.uuid, server_hash)
+            .await
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => {
+                // infallible, writing to a string
+                let _ = write!(encoded, "%{byte:02X}");
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, async_trait::async_trait, std::sync::Mutex};
+
+    /// Stands in for the session server, answering every request with the
+    /// same response and remembering the requested uris.
+    struct StandIn {
+        status: StatusCode,
+        body: &'static str,
+        requests: Mutex<Vec<(http::Method, String, Vec<u8>)>>,
+    }
+
+    impl StandIn {
+        fn new(status: StatusCode, body: &'static str) -> Self {
+            Self {
+                status,
+                body,
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for StandIn {
+        type Body = &'static str;
+
+        async fn execute_request(
+            &self,
+            req: http::Request<Vec<u8>>,
+        ) -> anyhow::Result<http::response::Response<Self::Body>> {
+            self.requests
+                .lock()
+                .map_err(|_| anyhow::anyhow!("poisoned"))?
+                .push((
+                    req.method().clone(),
+                    req.uri().to_string(),
+                    req.body().clone(),
+                ));
+            let mut resp = http::Response::new(self.body);
+            *resp.status_mut() = self.status;
+            Ok(resp)
+        }
+    }
+
+    const PROFILE: &str = r#"{
+        "id": "069a79f444e94726a5befca90e38aaf5",
+        "name": "Notch",
+        "properties": [{ "name": "textures", "value": "e30=", "signature": "c2ln" }]
+    }"#;
+
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn has_joined() {
+        let client = StandIn::new(StatusCode::OK, PROFILE);
+        let server = SessionServer::new("http://127.0.0.1:8080/");
+        let profile = server
+            .has_joined(
+                &client,
+                "Notch",
+                "-7c9d5b0044c130109a5d7b5fb5c317c02b4e28c1",
+                Some([127, 0, 0, 1].into()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(profile.name, "Notch");
+        assert_eq!(
+            profile.uuid.simple().to_string(),
+            "069a79f444e94726a5befca90e38aaf5"
+        );
+        assert_eq!(
+            profile.properties,
+            [Property {
+                name: "textures".into(),
+                value: "e30=".into(),
+                signature: Some("c2ln".into()),
+            }]
+        );
+        let requests = client.requests.lock().unwrap();
+        assert_eq!(requests[0].0, http::Method::GET);
+        assert_eq!(
+            requests[0].1,
+            "http://127.0.0.1:8080/session/minecraft/hasJoined?username=Notch&serverId=-7c9d5b0044c130109a5d7b5fb5c317c02b4e28c1&ip=127.0.0.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn not_authenticated() {
+        let server = SessionServer::new("http://127.0.0.1:8080");
+        for client in [
+            StandIn::new(StatusCode::NO_CONTENT, ""),
+            StandIn::new(StatusCode::OK, ""),
+        ] {
+            let res = server.has_joined(&client, "Notch", "0", None).await;
+            assert!(matches!(res, Err(SessionError::NotAuthenticated)));
+        }
+        let client = StandIn::new(StatusCode::SERVICE_UNAVAILABLE, "");
+        let res = server.has_joined(&client, "Notch", "0", None).await;
+        assert!(matches!(res, Err(SessionError::HttpStatus(_))));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn join() {
+        let client = StandIn::new(StatusCode::NO_CONTENT, "");
+        let server = SessionServer::new("http://127.0.0.1:8080");
+        server
+            .join(
+                &client,
+                "token",
+                "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+                &server_hash("", &[], &[]),
+            )
+            .await
+            .unwrap();
+
+        let requests = client.requests.lock().unwrap();
+        assert_eq!(requests[0].0, http::Method::POST);
+        assert_eq!(
+            requests[0].1,
+            "http://127.0.0.1:8080/session/minecraft/join"
+        );
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].2).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "accessToken": "token",
+                "selectedProfile": "069a79f444e94726a5befca90e38aaf5",
+                "serverId": "-25c65c11a194b4f2cdaa40106a9fe76f5027f8f7",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn join_errors() {
+        let server = &SessionServer::new("http://127.0.0.1:8080");
+        let join = |status, body| async move {
+            let client = StandIn::new(status, body);
+            server.join(&client, "token", "id", "0").await
+        };
+        assert!(matches!(
+            join(
+                StatusCode::FORBIDDEN,
+                r#"{"error":"ForbiddenOperationException","errorMessage":"Invalid token."}"#
+            )
+            .await,
+            Err(SessionError::InvalidToken)
+        ));
+        assert!(matches!(
+            join(StatusCode::UNAUTHORIZED, "").await,
+            Err(SessionError::InvalidToken)
+        ));
+        assert!(matches!(
+            join(
+                StatusCode::FORBIDDEN,
+                r#"{"error":"InsufficientPrivilegesException","path":"/session/minecraft/join"}"#
+            )
+            .await,
+            Err(SessionError::MultiplayerDisabled)
+        ));
+        assert!(matches!(
+            join(
+                StatusCode::FORBIDDEN,
+                r#"{"error":"UserBannedException","path":"/session/minecraft/join"}"#
+            )
+            .await,
+            Err(SessionError::Banned)
+        ));
+        assert!(matches!(
+            join(StatusCode::FORBIDDEN, "").await,
+            Err(SessionError::HttpStatus(_))
+        ));
+    }
+}
@@ -0,0 +1,534 @@
+use serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{ClickEvent, Color, HoverEvent, Style};
+
+/// A chat component as sent in json by the server.
+///
+/// Every component has some content, a style and any amount of children in
+/// `extra`, which inherit the style of their parent.
+///
+/// Deserialization accepts everything vanilla does: bare strings, numbers and
+/// booleans become text components and arrays are turned into the first
+/// element with the rest appended to its `extra`. Serialization always
+/// produces the object form.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Component {
+    pub content: Content,
+    pub style: Style,
+    pub extra: Vec<Component>,
+}
+
+/// The content of a [`Component`], determined by which key is present in its
+/// json object.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    Text(String),
+    Translate {
+        key: String,
+        /// used if the key is unknown to the client, since 1.19.4
+        fallback: Option<String>,
+        with: Vec<Component>,
+    },
+    /// since 1.8
+    Score {
+        name: String,
+        objective: String,
+        /// the resolved score, removed in 1.20.3
+        value: Option<String>,
+    },
+    /// since 1.8
+    Selector {
+        selector: String,
+        /// since 1.17
+        separator: Option<Box<Component>>,
+    },
+    /// since 1.12
+    Keybind(String),
+    /// since 1.14
+    Nbt {
+        path: String,
+        interpret: Option<bool>,
+        /// since 1.17
+        separator: Option<Box<Component>>,
+        source: NbtSource,
+    },
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content::Text(String::new())
+    }
+}
+
+/// Where an nbt component takes its nbt from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NbtSource {
+    /// coordinates of a block entity
+    Block(String),
+    /// an entity selector
+    Entity(String),
+    /// a command storage resource location, since 1.15
+    Storage(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Score {
+    name: String,
+    objective: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+impl Component {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::from_content(Content::Text(text.into()))
+    }
+    pub fn translate(key: impl Into<String>, with: impl IntoIterator<Item = Component>) -> Self {
+        Self::from_content(Content::Translate {
+            key: key.into(),
+            fallback: None,
+            with: with.into_iter().collect(),
+        })
+    }
+    pub fn score(name: impl Into<String>, objective: impl Into<String>) -> Self {
+        Self::from_content(Content::Score {
+            name: name.into(),
+            objective: objective.into(),
+            value: None,
+        })
+    }
+    pub fn selector(selector: impl Into<String>) -> Self {
+        Self::from_content(Content::Selector {
+            selector: selector.into(),
+            separator: None,
+        })
+    }
+    pub fn keybind(keybind: impl Into<String>) -> Self {
+        Self::from_content(Content::Keybind(keybind.into()))
+    }
+    pub fn from_content(content: Content) -> Self {
+        Self {
+            content,
+            style: Style::default(),
+            extra: vec![],
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.style.bold = Some(bold);
+        self
+    }
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.style.italic = Some(italic);
+        self
+    }
+    pub fn underlined(mut self, underlined: bool) -> Self {
+        self.style.underlined = Some(underlined);
+        self
+    }
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.style.strikethrough = Some(strikethrough);
+        self
+    }
+    pub fn obfuscated(mut self, obfuscated: bool) -> Self {
+        self.style.obfuscated = Some(obfuscated);
+        self
+    }
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.style.font = Some(font.into());
+        self
+    }
+    pub fn insertion(mut self, insertion: impl Into<String>) -> Self {
+        self.style.insertion = Some(insertion.into());
+        self
+    }
+    pub fn click_event(mut self, event: ClickEvent) -> Self {
+        self.style.click_event = Some(event);
+        self
+    }
+    pub fn hover_event(mut self, event: HoverEvent) -> Self {
+        self.style.hover_event = Some(event);
+        self
+    }
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+    /// Appends a child to `extra`.
+    pub fn append(mut self, child: impl Into<Component>) -> Self {
+        self.extra.push(child.into());
+        self
+    }
+
+    /// Whether this is a text component without style or children, which
+    /// vanilla serializes as a bare string in translation arguments.
+    pub fn is_plain_text(&self) -> bool {
+        matches!(self.content, Content::Text(_)) && self.style.is_empty() && self.extra.is_empty()
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+    pub fn to_json(&self) -> String {
+        // the serialize impl never fails and only produces string keys
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl From<&str> for Component {
+    fn from(text: &str) -> Self {
+        Component::text(text)
+    }
+}
+impl From<String> for Component {
+    fn from(text: String) -> Self {
+        Component::text(text)
+    }
+}
+
+/// Serializes translation arguments, writing plain text as bare strings.
+struct TranslationArgs<'a>(&'a [Component]);
+
+impl Serialize for TranslationArgs<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for arg in self.0 {
+            match &arg.content {
+                Content::Text(text) if arg.is_plain_text() => seq.serialize_element(text)?,
+                _ => seq.serialize_element(arg)?,
+            }
+        }
+        seq.end()
+    }
+}
+
+#[derive(Serialize)]
+struct ComponentRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translate: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fallback: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    with: Option<TranslationArgs<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<Score>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selector: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keybind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nbt: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interpret: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    separator: Option<&'a Component>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage: Option<&'a str>,
+    #[serde(flatten)]
+    style: &'a Style,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    extra: &'a [Component],
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut repr = ComponentRef {
+            text: None,
+            translate: None,
+            fallback: None,
+            with: None,
+            score: None,
+            selector: None,
+            keybind: None,
+            nbt: None,
+            interpret: None,
+            separator: None,
+            block: None,
+            entity: None,
+            storage: None,
+            style: &self.style,
+            extra: &self.extra,
+        };
+        match &self.content {
+            Content::Text(text) => repr.text = Some(text),
+            Content::Translate {
+                key,
+                fallback,
+                with,
+            } => {
+                repr.translate = Some(key);
+                repr.fallback = fallback.as_deref();
+                if !with.is_empty() {
+                    repr.with = Some(TranslationArgs(with));
+                }
+            }
+            Content::Score {
+                name,
+                objective,
+                value,
+            } => {
+                repr.score = Some(Score {
+                    name: name.clone(),
+                    objective: objective.clone(),
+                    value: value.clone(),
+                })
+            }
+            Content::Selector {
+                selector,
+                separator,
+            } => {
+                repr.selector = Some(selector);
+                repr.separator = separator.as_deref();
+            }
+            Content::Keybind(keybind) => repr.keybind = Some(keybind),
+            Content::Nbt {
+                path,
+                interpret,
+                separator,
+                source,
+            } => {
+                repr.nbt = Some(path);
+                repr.interpret = *interpret;
+                repr.separator = separator.as_deref();
+                match source {
+                    NbtSource::Block(block) => repr.block = Some(block),
+                    NbtSource::Entity(entity) => repr.entity = Some(entity),
+                    NbtSource::Storage(storage) => repr.storage = Some(storage),
+                }
+            }
+        }
+        repr.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+struct ComponentObject {
+    /// content type hint, since 1.20.3
+    #[serde(default, rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    text: Option<Primitive>,
+    #[serde(default)]
+    translate: Option<String>,
+    #[serde(default)]
+    fallback: Option<String>,
+    #[serde(default)]
+    with: Option<Vec<Component>>,
+    #[serde(default)]
+    score: Option<Score>,
+    #[serde(default)]
+    selector: Option<String>,
+    #[serde(default)]
+    keybind: Option<String>,
+    #[serde(default)]
+    nbt: Option<String>,
+    #[serde(default)]
+    interpret: Option<bool>,
+    #[serde(default)]
+    separator: Option<Component>,
+    #[serde(default)]
+    block: Option<String>,
+    #[serde(default)]
+    entity: Option<String>,
+    #[serde(default)]
+    storage: Option<String>,
+    #[serde(flatten)]
+    style: Style,
+    #[serde(default)]
+    extra: Vec<Component>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Primitive {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+}
+
+impl Primitive {
+    fn into_string(self) -> String {
+        match self {
+            Primitive::String(s) => s,
+            Primitive::Bool(b) => b.to_string(),
+            Primitive::Integer(i) => i.to_string(),
+            Primitive::Float(f) => f.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ComponentRepr {
+    Primitive(Primitive),
+    Array(Vec<Component>),
+    Object(Box<ComponentObject>),
+}
+
+impl ComponentObject {
+    fn into_component<E: de::Error>(self) -> Result<Component, E> {
+        let ComponentObject {
+            kind,
+            text,
+            translate,
+            fallback,
+            with,
+            score,
+            selector,
+            keybind,
+            nbt,
+            interpret,
+            separator,
+            block,
+            entity,
+            storage,
+            style,
+            extra,
+        } = self;
+        let separator = separator.map(Box::new);
+
+        // without a type hint the first present key in vanilla's order wins
+        let content = match (
+            kind.as_deref(),
+            text,
+            translate,
+            score,
+            selector,
+            keybind,
+            nbt,
+        ) {
+            (None | Some("text"), Some(text), ..) => Content::Text(text.into_string()),
+            (None | Some("translatable"), _, Some(key), ..) => Content::Translate {
+                key,
+                fallback,
+                with: with.unwrap_or_default(),
+            },
+            (None | Some("score"), _, _, Some(score), ..) => Content::Score {
+                name: score.name,
+                objective: score.objective,
+                value: score.value,
+            },
+            (None | Some("selector"), _, _, _, Some(selector), ..) => Content::Selector {
+                selector,
+                separator,
+            },
+            (None | Some("keybind"), _, _, _, _, Some(keybind), _) => Content::Keybind(keybind),
+            (None | Some("nbt"), _, _, _, _, _, Some(path)) => {
+                let source = match (block, entity, storage) {
+                    (Some(block), ..) => NbtSource::Block(block),
+                    (_, Some(entity), _) => NbtSource::Entity(entity),
+                    (_, _, Some(storage)) => NbtSource::Storage(storage),
+                    _ => return Err(E::custom("nbt component without a source")),
+                };
+                Content::Nbt {
+                    path,
+                    interpret,
+                    separator,
+                    source,
+                }
+            }
+            _ => return Err(E::custom("unknown chat component type")),
+        };
+        Ok(Component {
+            content,
+            style,
+            extra,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ComponentRepr::deserialize(deserializer)? {
+            ComponentRepr::Primitive(primitive) => Ok(Component::text(primitive.into_string())),
+            ComponentRepr::Array(components) => {
+                let mut components = components.into_iter();
+                let mut first = components
+                    .next()
+                    .ok_or_else(|| de::Error::invalid_length(0, &"a non-empty array"))?;
+                first.extra.extend(components);
+                Ok(first)
+            }
+            ComponentRepr::Object(object) => object.into_component(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[allow(clippy::unwrap_used)]
+    fn roundtrip(value: Value) {
+        let component: Component = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&component).unwrap(), value);
+    }
+
+    #[test]
+    fn vanilla_roundtrip() {
+        roundtrip(json!({
+            "translate": "chat.type.text",
+            "with": [
+                {
+                    "text": "Notch",
+                    "insertion": "Notch",
+                    "clickEvent": { "action": "suggest_command", "value": "/msg Notch " },
+                    "hoverEvent": {
+                        "action": "show_entity",
+                        "contents": {
+                            "type": "minecraft:player",
+                            "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+                            "name": { "text": "Notch" }
+                        }
+                    }
+                },
+                "hello"
+            ]
+        }));
+        roundtrip(json!({
+            "text": "",
+            "extra": [
+                { "text": "red", "color": "red", "bold": true },
+                { "text": "rgb", "color": "#12AB3F", "font": "minecraft:uniform" },
+                { "keybind": "key.jump" },
+                { "score": { "name": "@p", "objective": "kills" } },
+                { "selector": "@e", "separator": { "text": "|" } },
+                { "nbt": "Items", "interpret": false, "block": "~ ~ ~" }
+            ]
+        }));
+        roundtrip(json!({
+            "text": "legacy",
+            "hoverEvent": { "action": "show_text", "value": { "text": "tooltip" } }
+        }));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn lenient() {
+        let component = Component::from_json(
+            r#"["a", {"text": "b", "color": "nope"}, 3, {"text": "c", "color": 12, "bold": "yes"}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            component,
+            Component::text("a")
+                .append("b")
+                .append(Component::text("3"))
+                .append("c")
+        );
+        assert!(Component::from_json(r#"{"color": "red"}"#).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Component;
+
+/// Action performed when a component is clicked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", content = "value", rename_all = "snake_case")]
+pub enum ClickEvent {
+    OpenUrl(String),
+    /// only usable by the client itself, ignored when sent by a server
+    OpenFile(String),
+    RunCommand(String),
+    SuggestCommand(String),
+    /// only usable in books, the page is sent as a string by vanilla
+    ChangePage(#[serde(deserialize_with = "string_or_number")] String),
+    /// since 1.15
+    CopyToClipboard(String),
+}
+
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(i64),
+    }
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s,
+        StringOrNumber::Number(n) => n.to_string(),
+    })
+}
+
+/// Tooltip shown when hovering over a component.
+///
+/// Since 1.16 the tooltip is sent in `contents` with a structure depending on
+/// the action, before that it was always a component in `value`, which for
+/// items and entities contained their data as SNBT text. The latter is kept as
+/// [`HoverEvent::Legacy`] so it round-trips unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoverEvent {
+    ShowText(Box<Component>),
+    ShowItem(HoverItem),
+    ShowEntity(HoverEntity),
+    Legacy {
+        action: HoverAction,
+        value: Box<Component>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoverAction {
+    ShowText,
+    ShowItem,
+    ShowEntity,
+    /// removed in 1.12
+    ShowAchievement,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoverItem {
+    /// resource location of the item
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    /// the item's nbt as SNBT
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoverEntity {
+    /// resource location of the entity type
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// hyphenated uuid of the entity
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<Component>>,
+}
+
+impl HoverEvent {
+    pub fn action(&self) -> HoverAction {
+        match self {
+            HoverEvent::ShowText(_) => HoverAction::ShowText,
+            HoverEvent::ShowItem(_) => HoverAction::ShowItem,
+            HoverEvent::ShowEntity(_) => HoverAction::ShowEntity,
+            HoverEvent::Legacy { action, .. } => *action,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct HoverEventRef<'a, T: Serialize> {
+    action: HoverAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<&'a T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<&'a Component>,
+}
+
+impl Serialize for HoverEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        fn contents<'a, T: Serialize>(
+            action: HoverAction,
+            contents: &'a T,
+        ) -> HoverEventRef<'a, T> {
+            HoverEventRef {
+                action,
+                contents: Some(contents),
+                value: None,
+            }
+        }
+        match self {
+            HoverEvent::ShowText(text) => {
+                contents(HoverAction::ShowText, &**text).serialize(serializer)
+            }
+            HoverEvent::ShowItem(item) => {
+                contents(HoverAction::ShowItem, item).serialize(serializer)
+            }
+            HoverEvent::ShowEntity(entity) => {
+                contents(HoverAction::ShowEntity, entity).serialize(serializer)
+            }
+            HoverEvent::Legacy { action, value } => HoverEventRef::<()> {
+                action: *action,
+                contents: None,
+                value: Some(value),
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HoverEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Repr {
+            action: HoverAction,
+            #[serde(default)]
+            contents: Option<serde_json::Value>,
+            #[serde(default)]
+            value: Option<Component>,
+        }
+        let Repr {
+            action,
+            contents,
+            value,
+        } = Repr::deserialize(deserializer)?;
+
+        let Some(contents) = contents else {
+            let value = value.ok_or_else(|| de::Error::missing_field("contents"))?;
+            return Ok(HoverEvent::Legacy {
+                action,
+                value: Box::new(value),
+            });
+        };
+        match action {
+            HoverAction::ShowText => {
+                serde_json::from_value(contents).map(|text| HoverEvent::ShowText(Box::new(text)))
+            }
+            HoverAction::ShowItem => match contents {
+                // 1.16 also accepts a bare item id
+                serde_json::Value::String(id) => Ok(HoverEvent::ShowItem(HoverItem {
+                    id,
+                    count: None,
+                    tag: None,
+                })),
+                contents => serde_json::from_value(contents).map(HoverEvent::ShowItem),
+            },
+            HoverAction::ShowEntity => serde_json::from_value(contents).map(HoverEvent::ShowEntity),
+            HoverAction::ShowAchievement => {
+                return Err(de::Error::custom(
+                    "show_achievement has no contents representation",
+                ))
+            }
+        }
+        .map_err(de::Error::custom)
+    }
+}
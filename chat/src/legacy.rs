@@ -0,0 +1,382 @@
+//! Conversion between components and legacy text using formatting codes like
+//! `§c` as used before 1.8 and still in some places like sign text or the
+//! server list description of old servers.
+//!
+//! A color code also resets all formatting, `§r` resets everything.
+
+use crate::{Color, Component, Content, Style};
+
+/// The prefix used by the game.
+pub const SECTION: char = '§';
+/// The prefix commonly used in configuration files and plugins.
+pub const AMPERSAND: char = '&';
+
+/// The english formats of the translation keys commonly sent by servers, used
+/// when rendering translations without a fallback.
+const FORMATS: &[(&str, &str)] = &[
+    ("chat.type.text", "<%s> %s"),
+    ("chat.type.text.narrate", "%s says %s"),
+    ("chat.type.announcement", "[%s] %s"),
+    ("chat.type.emote", "* %s %s"),
+    ("chat.type.admin", "[%s: %s]"),
+    ("chat.type.team.text", "%s <%s> %s"),
+    ("chat.type.team.sent", "-> %s <%s> %s"),
+    (
+        "chat.type.advancement.task",
+        "%s has made the advancement %s",
+    ),
+    ("chat.type.advancement.goal", "%s has reached the goal %s"),
+    (
+        "chat.type.advancement.challenge",
+        "%s has completed the challenge %s",
+    ),
+    (
+        "commands.message.display.incoming",
+        "%s whispers to you: %s",
+    ),
+    ("commands.message.display.outgoing", "You whisper to %s: %s"),
+    ("multiplayer.player.joined", "%s joined the game"),
+    (
+        "multiplayer.player.joined.renamed",
+        "%s (formerly known as %s) joined the game",
+    ),
+    ("multiplayer.player.left", "%s left the game"),
+];
+
+impl Color {
+    /// The legacy formatting code of a named color.
+    pub fn legacy_code(&self) -> Option<char> {
+        Color::NAMED
+            .iter()
+            .position(|color| color == self)
+            .and_then(|i| char::from_digit(i as u32, 16))
+    }
+
+    /// The named color for a legacy formatting code.
+    pub fn from_legacy_code(code: char) -> Option<Color> {
+        code.to_digit(16)
+            .map(|i| Color::NAMED[i as usize])
+            .filter(|_| !code.is_ascii_uppercase())
+    }
+
+    /// The named color closest to this one, for when only those are
+    /// available. [`Color::Reset`] has none.
+    pub fn nearest_named(&self) -> Option<Color> {
+        let (r, g, b) = self.rgb()?;
+        Color::NAMED.into_iter().min_by_key(|named| {
+            let (nr, ng, nb) = named.rgb().unwrap_or_default();
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(r, nr) + d(g, ng) + d(b, nb)
+        })
+    }
+}
+
+/// The effective formatting of a piece of legacy text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct LegacyStyle {
+    color: Option<Color>,
+    obfuscated: bool,
+    bold: bool,
+    strikethrough: bool,
+    underlined: bool,
+    italic: bool,
+}
+
+impl LegacyStyle {
+    fn formats(&self) -> [(bool, char); 5] {
+        [
+            (self.obfuscated, 'k'),
+            (self.bold, 'l'),
+            (self.strikethrough, 'm'),
+            (self.underlined, 'n'),
+            (self.italic, 'o'),
+        ]
+    }
+
+    /// Applies a formatting code, returns false if it is unknown.
+    fn apply(&mut self, code: char) -> bool {
+        if let Some(color) = Color::from_legacy_code(code) {
+            *self = LegacyStyle {
+                color: Some(color),
+                ..Default::default()
+            };
+            return true;
+        }
+        match code {
+            'k' => self.obfuscated = true,
+            'l' => self.bold = true,
+            'm' => self.strikethrough = true,
+            'n' => self.underlined = true,
+            'o' => self.italic = true,
+            'r' => *self = LegacyStyle::default(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn to_style(self) -> Style {
+        let flag = |on: bool| on.then_some(true);
+        Style {
+            color: self.color,
+            obfuscated: flag(self.obfuscated),
+            bold: flag(self.bold),
+            strikethrough: flag(self.strikethrough),
+            underlined: flag(self.underlined),
+            italic: flag(self.italic),
+            ..Default::default()
+        }
+    }
+
+    fn inherit(&self, style: &Style) -> LegacyStyle {
+        LegacyStyle {
+            color: match style.color {
+                Some(Color::Reset) => None,
+                Some(color) => color.nearest_named(),
+                None => self.color,
+            },
+            obfuscated: style.obfuscated.unwrap_or(self.obfuscated),
+            bold: style.bold.unwrap_or(self.bold),
+            strikethrough: style.strikethrough.unwrap_or(self.strikethrough),
+            underlined: style.underlined.unwrap_or(self.underlined),
+            italic: style.italic.unwrap_or(self.italic),
+        }
+    }
+}
+
+impl Component {
+    /// Parses `§`-coded legacy text.
+    pub fn from_legacy(text: &str) -> Component {
+        Self::from_legacy_with_prefix(text, SECTION)
+    }
+
+    /// Parses legacy text using a different prefix such as [`AMPERSAND`].
+    ///
+    /// Unknown codes are kept as literal text.
+    pub fn from_legacy_with_prefix(text: &str, prefix: char) -> Component {
+        let mut parts: Vec<Component> = vec![];
+        let mut style = LegacyStyle::default();
+        let mut current = String::new();
+
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == prefix {
+                if let Some(&code) = chars.peek() {
+                    let mut next = style;
+                    if next.apply(code.to_ascii_lowercase()) {
+                        chars.next();
+                        if next != style && !current.is_empty() {
+                            parts.push(
+                                Component::text(std::mem::take(&mut current))
+                                    .with_style(style.to_style()),
+                            );
+                        }
+                        style = next;
+                        continue;
+                    }
+                }
+            }
+            current.push(c);
+        }
+        if !current.is_empty() || parts.is_empty() {
+            parts.push(Component::text(current).with_style(style.to_style()));
+        }
+
+        if parts.len() == 1 {
+            parts.pop().unwrap_or_default()
+        } else {
+            Component {
+                extra: parts,
+                ..Default::default()
+            }
+        }
+    }
+
+    /// Renders the component as `§`-coded legacy text.
+    ///
+    /// Rgb colors are approximated by the nearest named color and fonts,
+    /// insertions and events are dropped. Translations are rendered using
+    /// their fallback or the english format of common keys like
+    /// `chat.type.text`. Other keys are used as the format if they contain
+    /// placeholders, or written followed by their arguments.
+    pub fn to_legacy(&self) -> String {
+        self.to_legacy_with_prefix(SECTION)
+    }
+
+    /// Renders the component as legacy text using a different prefix.
+    pub fn to_legacy_with_prefix(&self, prefix: char) -> String {
+        let mut writer = LegacyWriter {
+            out: String::new(),
+            prefix,
+            current: LegacyStyle::default(),
+        };
+        writer.component(self, LegacyStyle::default());
+        writer.out
+    }
+}
+
+struct LegacyWriter {
+    out: String,
+    prefix: char,
+    current: LegacyStyle,
+}
+
+impl LegacyWriter {
+    fn component(&mut self, component: &Component, parent: LegacyStyle) {
+        let style = parent.inherit(&component.style);
+        match &component.content {
+            Content::Text(text) => self.text(text, style),
+            Content::Translate {
+                key,
+                fallback,
+                with,
+            } => {
+                let format = fallback.as_deref().or_else(|| {
+                    FORMATS
+                        .iter()
+                        .find(|(known, _)| known == key)
+                        .map(|(_, format)| *format)
+                });
+                match format {
+                    Some(format) => self.translate(format, with, style),
+                    // like vanilla, an unknown key is its own format
+                    None if key.contains('%') => self.translate(key, with, style),
+                    None => {
+                        self.text(key, style);
+                        for arg in with {
+                            self.text(" ", style);
+                            self.component(arg, style);
+                        }
+                    }
+                }
+            }
+            Content::Score { value, .. } => self.text(value.as_deref().unwrap_or_default(), style),
+            Content::Selector { selector, .. } => self.text(selector, style),
+            Content::Keybind(keybind) => self.text(keybind, style),
+            Content::Nbt { path, .. } => self.text(path, style),
+        }
+        for child in &component.extra {
+            self.component(child, style);
+        }
+    }
+
+    /// Writes a translation format, substituting `%s` and `%1$s` with the
+    /// arguments.
+    fn translate(&mut self, format: &str, with: &[Component], style: LegacyStyle) {
+        let mut next_arg = 0;
+        let mut rest = format;
+        while let Some(i) = rest.find('%') {
+            self.text(&rest[..i], style);
+            rest = &rest[i + 1..];
+            if let Some(after) = rest.strip_prefix('%') {
+                self.text("%", style);
+                rest = after;
+                continue;
+            }
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let index = if digits > 0 && rest[digits..].starts_with("$s") {
+                let index = rest[..digits].parse::<usize>().unwrap_or(0).checked_sub(1);
+                rest = &rest[digits + 2..];
+                index
+            } else if let Some(after) = rest.strip_prefix('s') {
+                rest = after;
+                next_arg += 1;
+                Some(next_arg - 1)
+            } else {
+                self.text("%", style);
+                continue;
+            };
+            if let Some(arg) = index.and_then(|i| with.get(i)) {
+                self.component(arg, style);
+            }
+        }
+        self.text(rest, style);
+    }
+
+    fn text(&mut self, text: &str, style: LegacyStyle) {
+        if text.is_empty() {
+            return;
+        }
+        if style != self.current {
+            let only_adds_formats = style.color == self.current.color
+                && style
+                    .formats()
+                    .iter()
+                    .zip(self.current.formats())
+                    .all(|(&(new, _), (old, _))| new || !old);
+            if !only_adds_formats {
+                let code = style
+                    .color
+                    .and_then(|color| color.legacy_code())
+                    .unwrap_or('r');
+                self.code(code);
+                self.current = LegacyStyle {
+                    color: style.color,
+                    ..Default::default()
+                };
+            }
+            for ((new, code), (old, _)) in style.formats().into_iter().zip(self.current.formats()) {
+                if new && !old {
+                    self.code(code);
+                }
+            }
+            self.current = style;
+        }
+        self.out.push_str(text);
+    }
+
+    fn code(&mut self, code: char) {
+        self.out.push(self.prefix);
+        self.out.push(code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_roundtrip() {
+        for text in [
+            "plain",
+            "§cred §lbold red§r plain §aby color",
+            "§9§lA§9B",
+            "100% §zunknown code§",
+        ] {
+            assert_eq!(Component::from_legacy(text).to_legacy(), text);
+        }
+        // redundant codes are dropped
+        assert_eq!(Component::from_legacy("§o§aa§r§r§ab").to_legacy(), "§aab");
+        assert_eq!(
+            Component::from_legacy_with_prefix("&6gold &Lbold", AMPERSAND).to_legacy(),
+            "§6gold §lbold"
+        );
+    }
+
+    #[test]
+    fn inheritance() {
+        let component = Component::text("a")
+            .color(Color::Red)
+            .bold(true)
+            .append(Component::text("b").bold(false))
+            .append(Component::text("c").color(Color::Rgb(0xfe, 0xfe, 0x50)))
+            .append(Component::translate(
+                "chat.type.text",
+                [Component::text("x"), Component::text("y").italic(true)],
+            ))
+            .append(Component::translate(
+                "%2$s %1$s%%",
+                ["1".into(), "2".into()],
+            ));
+        assert_eq!(component.to_legacy(), "§c§la§cb§e§lc§c§l<x> §oy§c§l2 1%");
+    }
+
+    #[test]
+    fn translations() {
+        let args = || [Component::text("Notch"), Component::text("hi")];
+        let announcement = Component::translate("chat.type.announcement", args());
+        assert_eq!(announcement.to_legacy(), "[Notch] hi");
+        // unknown keys keep their arguments
+        let unknown = Component::translate("chat.type.unknown", args());
+        assert_eq!(unknown.to_legacy(), "chat.type.unknown Notch hi");
+    }
+}
@@ -1 +1,14 @@
+//! The chat component (a.k.a. text component) type used by minecraft for
+//! chat messages, disconnect reasons, titles, the server list description
+//! and more.
+
 #![deny(clippy::undocumented_unsafe_blocks)]
+
+pub mod component;
+pub mod event;
+pub mod legacy;
+pub mod style;
+
+pub use component::*;
+pub use event::*;
+pub use style::*;
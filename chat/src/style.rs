@@ -0,0 +1,254 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{ClickEvent, HoverEvent};
+
+/// Formatting of a component, inherited by its children unless overridden.
+///
+/// Every field is optional, an absent field means "inherit from the parent".
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Style {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub color: Option<Color>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub bold: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub italic: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub underlined: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub strikethrough: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub obfuscated: Option<bool>,
+    /// resource location of the font, since 1.16
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub font: Option<String>,
+    /// text inserted into the chat box when shift-clicking
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub insertion: Option<String>,
+    #[serde(
+        rename = "clickEvent",
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub click_event: Option<ClickEvent>,
+    #[serde(
+        rename = "hoverEvent",
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "lenient"
+    )]
+    pub hover_event: Option<HoverEvent>,
+}
+
+impl Style {
+    /// Whether no field of this style is set.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Vanilla ignores style fields it does not understand, like unknown or
+/// numeric colors, instead of failing to parse the whole component, so do we.
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Lenient<T> {
+        Valid(T),
+        Invalid(serde::de::IgnoredAny),
+    }
+    Ok(match Lenient::deserialize(deserializer)? {
+        Lenient::Valid(value) => Some(value),
+        Lenient::Invalid(_) => None,
+    })
+}
+
+/// A text color, either one of the 16 named colors or, since 1.16, any rgb
+/// color in the form `#RRGGBB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    /// resets the color to the default, only valid before 1.16
+    Reset,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// The 16 named colors in the order of their legacy formatting code
+    /// (`0`-`9`, `a`-`f`).
+    pub const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::DarkBlue,
+        Color::DarkGreen,
+        Color::DarkAqua,
+        Color::DarkRed,
+        Color::DarkPurple,
+        Color::Gold,
+        Color::Gray,
+        Color::DarkGray,
+        Color::Blue,
+        Color::Green,
+        Color::Aqua,
+        Color::Red,
+        Color::LightPurple,
+        Color::Yellow,
+        Color::White,
+    ];
+
+    /// The name used in json, `None` for rgb colors.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Color::Black => "black",
+            Color::DarkBlue => "dark_blue",
+            Color::DarkGreen => "dark_green",
+            Color::DarkAqua => "dark_aqua",
+            Color::DarkRed => "dark_red",
+            Color::DarkPurple => "dark_purple",
+            Color::Gold => "gold",
+            Color::Gray => "gray",
+            Color::DarkGray => "dark_gray",
+            Color::Blue => "blue",
+            Color::Green => "green",
+            Color::Aqua => "aqua",
+            Color::Red => "red",
+            Color::LightPurple => "light_purple",
+            Color::Yellow => "yellow",
+            Color::White => "white",
+            Color::Reset => "reset",
+            Color::Rgb(..) => return None,
+        })
+    }
+
+    /// The rgb value of the color, `None` for [`Color::Reset`].
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        Some(match self {
+            Color::Black => (0x00, 0x00, 0x00),
+            Color::DarkBlue => (0x00, 0x00, 0xaa),
+            Color::DarkGreen => (0x00, 0xaa, 0x00),
+            Color::DarkAqua => (0x00, 0xaa, 0xaa),
+            Color::DarkRed => (0xaa, 0x00, 0x00),
+            Color::DarkPurple => (0xaa, 0x00, 0xaa),
+            Color::Gold => (0xff, 0xaa, 0x00),
+            Color::Gray => (0xaa, 0xaa, 0xaa),
+            Color::DarkGray => (0x55, 0x55, 0x55),
+            Color::Blue => (0x55, 0x55, 0xff),
+            Color::Green => (0x55, 0xff, 0x55),
+            Color::Aqua => (0x55, 0xff, 0xff),
+            Color::Red => (0xff, 0x55, 0x55),
+            Color::LightPurple => (0xff, 0x55, 0xff),
+            Color::Yellow => (0xff, 0xff, 0x55),
+            Color::White => (0xff, 0xff, 0xff),
+            Color::Reset => return None,
+            Color::Rgb(r, g, b) => (*r, *g, *b),
+        })
+    }
+}
+
+/// The error returned when parsing an unknown color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidColor;
+
+impl fmt::Display for InvalidColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid color")
+    }
+}
+
+impl std::error::Error for InvalidColor {}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return Err(InvalidColor);
+            }
+            let channel = |i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| InvalidColor);
+            return Ok(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+        }
+        if s == "reset" {
+            return Ok(Color::Reset);
+        }
+        Color::NAMED
+            .into_iter()
+            .find(|color| color.name() == Some(s))
+            .ok_or(InvalidColor)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Rgb(r, g, b) => write!(f, "#{r:02X}{g:02X}{b:02X}"),
+            named => f.write_str(named.name().unwrap_or_default()),
+        }
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -13,32 +13,23 @@ use isahc::http::StatusCode;
 use miners::encoding::attrs::StringUuid;
 use miners::encoding::{Decode, Encode};
 use miners::nbt;
-use miners::protocol::netty::handshaking::serverbound::NextState0;
-use miners::protocol::netty::handshaking::SbHandshaking;
+use miners::net::encryption::{self, KeyPair};
+use miners::net::typed::{Handshaked, Login, Play, Server};
 use miners::protocol::netty::login::clientbound::{
-    EncryptionRequest19, SetCompression27, Success0,
+    Disconnect0, EncryptionRequest19, SetCompression27, Success0,
 };
-use miners::protocol::netty::login::serverbound::{EncryptionResponse19, LoginStart0};
-use miners::protocol::netty::login::SbLogin;
+use miners::protocol::netty::login::{CbLogin, SbLogin};
 use miners::protocol::netty::play::clientbound::{
     ChunkData27, Dimension0, GameMode0, JoinGame29, KeepAlive32, PlayerAbilities0,
     PositionAndLook6, SpawnPosition6,
 };
 use miners::protocol::netty::play::serverbound::KeepAlive7;
 use miners::protocol::netty::play::SbPlay;
-use miners::{protocol::netty::handshaking::serverbound::Handshake0, version::ProtocolVersion};
+use miners::version::ProtocolVersion;
 use miners_level::chunk::ChunkColumn47;
-use num_bigint::BigInt;
-use rsa::pkcs8::Document;
-use rsa::rand_core::OsRng;
-use rsa::Pkcs1v15Encrypt;
-use rsa::{pkcs8::EncodePublicKey, RsaPrivateKey};
-use sha1::Digest;
-use sha1::Sha1;
 use uuid::Uuid;
 
 const VERSION: i32 = 47;
-const RSA_BIT_SIZE: usize = 2048;
 
 #[async_std::main]
 async fn main() {
@@ -46,8 +37,7 @@ async fn main() {
     let offline = args.any(|v| v == "--offline-mode");
     let compression = !args.any(|v| v == "--no-compression");
 
-    let priv_key = Arc::new(RsaPrivateKey::new(&mut OsRng, RSA_BIT_SIZE).unwrap());
-    let pub_key = Arc::new(priv_key.to_public_key().to_public_key_der().unwrap());
+    let key_pair = Arc::new(KeyPair::generate().unwrap());
 
     let version = ProtocolVersion::new(VERSION).unwrap();
 
@@ -70,12 +60,14 @@ async fn main() {
     println!("Now listening on localhost:25565");
     loop {
         let (stream, _addr) = listener.accept().await.unwrap();
-        let conn = miners::net::conn::Connection::new(stream.clone(), stream);
+        let conn = miners::net::typed::Conn::server(miners::net::conn::Connection::new(
+            stream.clone(),
+            stream,
+        ));
         spawn(accept(
             conn,
             version,
-            priv_key.clone(),
-            pub_key.clone(),
+            key_pair.clone(),
             chunk.clone(),
             offline,
             compression,
@@ -84,29 +76,29 @@ async fn main() {
 }
 
 async fn accept(
-    mut conn: Conn,
+    conn: HandshakeConn,
     version: ProtocolVersion,
-    priv_key: Arc<RsaPrivateKey>,
-    pub_key: Arc<Document>,
+    key_pair: Arc<KeyPair>,
     chunk: Arc<ChunkColumn47>,
     offline: bool,
     compression: bool,
 ) {
-    match handshake(&mut conn, version).await.unwrap() {
-        NextState0::Status => (),
-        NextState0::Login => {
-            let (read, write) = login(
-                conn,
-                version,
-                priv_key,
-                pub_key,
-                chunk,
-                offline,
-                compression,
-            )
+    match conn.read_handshake().await.unwrap().1 {
+        Handshaked::Status(_) => (),
+        Handshaked::Login(conn) => {
+            let (read, write) = login(conn, version, key_pair, chunk, offline, compression)
+                .await
+                .unwrap();
+            play(read, write, version).await.unwrap()
+        }
+        Handshaked::UnsupportedLogin(mut conn, e) => {
+            let reason = format!("{{\"text\":\"{e}\"}}");
+            conn.write(&CbLogin::Disconnect0(Disconnect0 {
+                reason: reason.into(),
+            }))
             .await
             .unwrap();
-            play(read, write, version).await.unwrap()
+            conn.flush().await.unwrap();
         }
     };
 }
@@ -154,29 +146,15 @@ async fn send_keepalive(
     }
 }
 
-async fn handshake(conn: &mut Conn, version: ProtocolVersion) -> anyhow::Result<NextState0> {
-    match SbHandshaking::parse(conn.read_half.read_encoded().await?.to_packet()?, version)? {
-        SbHandshaking::Handshake0(packet) => {
-            // This is so rust-analyzer recognises the type.
-            let packet: Handshake0 = packet;
-            Ok(packet.next_state)
-        }
-    }
-}
-
 async fn login(
-    mut conn: Conn,
+    mut conn: LoginConn,
     version: ProtocolVersion,
-    priv_key: Arc<RsaPrivateKey>,
-    pub_key: Arc<Document>,
+    key_pair: Arc<KeyPair>,
     chunk: Arc<ChunkColumn47>,
     offline: bool,
     compression: bool,
 ) -> anyhow::Result<(Reader, Writer)> {
-    let username = if let SbLogin::LoginStart0(packet) =
-        SbLogin::parse(conn.read_half.read_encoded().await?.to_packet()?, version)?
-    {
-        let packet: LoginStart0 = packet;
+    let username = if let SbLogin::LoginStart0(packet) = conn.read().await? {
         packet.username.to_string()
     } else {
         bail!("incorrect packet order")
@@ -184,38 +162,25 @@ async fn login(
 
     let uuid = if !offline {
         // TODO: Fix client side decoding errors.
-        let verify_token: [u8; 32] = rand::random();
+        let verify_token = encryption::verify_token();
 
-        conn.write_half
-            .write_packet(
-                version,
-                EncryptionRequest19 {
-                    server_id: "".into(),
-                    public_key: pub_key.as_bytes().into(),
-                    verify_token: (&verify_token[..]).into(),
-                },
-            )
-            .await?;
+        conn.write(&CbLogin::EncryptionRequest19(EncryptionRequest19 {
+            server_id: "".into(),
+            public_key: key_pair.public_key_der().into(),
+            verify_token: (&verify_token[..]).into(),
+        }))
+        .await?;
 
-        conn.write_half.flush().await?;
+        conn.flush().await?;
 
-        let secret = if let SbLogin::EncryptionResponse19(packet) =
-            SbLogin::parse(conn.read_half.read_encoded().await?.to_packet()?, version)?
-        {
-            let packet: EncryptionResponse19 = packet;
-            if &priv_key.decrypt(Pkcs1v15Encrypt, &packet.verify_token)? != &verify_token {
-                bail!("verify token corrupted!")
-            }
-            priv_key.decrypt(Pkcs1v15Encrypt, &packet.secret)?
+        let secret = if let SbLogin::EncryptionResponse19(packet) = conn.read().await? {
+            key_pair.check_verify_token(&verify_token, &packet.verify_token)?;
+            key_pair.decrypt_secret(&packet.secret)?
         } else {
             bail!("incorrect packet order")
         };
 
-        let mut hash: Sha1 = Sha1::new();
-        hash.update(b"");
-        hash.update(&secret);
-        hash.update(pub_key.as_bytes());
-        let hash = BigInt::from_signed_bytes_be(hash.finalize().as_ref()).to_str_radix(16);
+        let hash = secret.server_hash("", key_pair.public_key_der());
         let resp = isahc::get_async(format!(
             "https://sessionserver.mojang.com/session/minecraft/hasJoined?username={username}&serverId={hash}",
         ))
@@ -255,29 +220,28 @@ async fn login(
     };
 
     if compression {
-        conn.write_half
-            .write_packet(version, SetCompression27 { threshold: 512 })
-            .await?;
-        conn.write_half.flush().await?;
-        conn.enable_compression(512);
+        // compression is enabled right after the packet is written
+        conn.write(&CbLogin::SetCompression27(SetCompression27 {
+            threshold: 512,
+        }))
+        .await?;
+        conn.flush().await?;
     }
 
-    conn.write_half
-        .write_packet(
-            version,
-            Success0 {
-                username: (&username).into(),
-                uuid,
-            },
-        )
-        .await
-        .unwrap();
+    conn.write(&CbLogin::Success0(Success0 {
+        username: (&username).into(),
+        uuid,
+    }))
+    .await?;
 
-    conn.write_half.flush().await?;
+    conn.flush().await?;
     println!("{username} logged in!");
     println!("success0");
 
-    let (read, mut write) = conn.split();
+    let conn = conn
+        .into_state::<Play>()
+        .map_err(|_| anyhow!("the connection didn't switch to play"))?;
+    let (read, mut write) = conn.into_inner().split();
 
     write
         .write_packet(
@@ -363,6 +327,13 @@ async fn login(
     Ok((read, write))
 }
 
-type Conn = miners::net::conn::Connection<BufReader<TcpStream>, BufWriter<TcpStream>>;
+type HandshakeConn = miners::net::typed::Conn<
+    Server,
+    miners::net::typed::Handshaking,
+    BufReader<TcpStream>,
+    BufWriter<TcpStream>,
+>;
+type LoginConn =
+    miners::net::typed::Conn<Server, Login, BufReader<TcpStream>, BufWriter<TcpStream>>;
 type Reader = miners::net::conn::ReadHalf<BufReader<TcpStream>>;
 type Writer = miners::net::conn::WriteHalf<BufWriter<TcpStream>>;
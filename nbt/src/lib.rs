@@ -66,11 +66,80 @@ impl<'dec> Decode<'dec> for Nbt<'dec> {
     }
 }
 
+#[test]
+#[allow(clippy::unwrap_used)]
+fn nbt() {
+    let nbt = Nbt {
+        name: "hello world".into(),
+        data: nbt!({ "name": "Bananrama" }),
+    };
+    let mut encoded = vec![];
+    // encoding used to panic after writing the compound
+    nbt.encode(&mut encoded).unwrap();
+    let decoded = Nbt::decode(&mut std::io::Cursor::new(&encoded[..])).unwrap();
+    assert_eq!(decoded.name, nbt.name);
+    assert_eq!(decoded.data, nbt.data);
+}
+
 impl<'a> Encode for Nbt<'a> {
     fn encode(&self, writer: &mut impl std::io::Write) -> miners_encoding::encode::Result<()> {
         NbtTag::Compound.encode(writer)?;
         miners_encoding::attrs::Mutf8::from(&self.name).encode(writer)?;
-        self.data.encode(writer)?;
-        todo!()
+        self.data.encode(writer)
+    }
+}
+
+/// Nbt with a nameless root compound, used by the network protocol since
+/// 1.20.2 (pv764).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkNbt<'a>(pub Compound<'a>);
+
+impl<'a> Deref for NetworkNbt<'a> {
+    type Target = Compound<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> DerefMut for NetworkNbt<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "to_static")]
+impl<'a> ToStatic for NetworkNbt<'a> {
+    type Static = NetworkNbt<'static>;
+
+    fn to_static(&self) -> Self::Static {
+        NetworkNbt(self.0.to_static())
+    }
+
+    fn into_static(self) -> Self::Static {
+        NetworkNbt(self.0.into_static())
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for NetworkNbt<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let tag = NbtTag::decode(cursor)?;
+        if !matches!(tag, NbtTag::Compound) {
+            return Err(miners_encoding::decode::Error::InvalidId);
+        }
+        Ok(Self(Compound::decode(cursor)?))
+    }
+}
+
+impl<'a> Encode for NetworkNbt<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> miners_encoding::encode::Result<()> {
+        NbtTag::Compound.encode(writer)?;
+        self.0.encode(writer)
+    }
+}
+
+impl<'a> From<Nbt<'a>> for NetworkNbt<'a> {
+    fn from(nbt: Nbt<'a>) -> Self {
+        Self(nbt.data)
     }
 }
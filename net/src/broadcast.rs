@@ -0,0 +1,199 @@
+//! Sending one packet to many connections.
+//!
+//! Writing a packet to every connection separately encodes and compresses it
+//! once per connection. A [`Broadcast`] instead encodes it once per protocol
+//! version and compresses it once per compression threshold, the resulting
+//! [`Frame`]s are reference counted and can be sent to the tasks of the
+//! connections. Only the encryption, which has its own state for every
+//! connection, is done by [`WriteHalf::write_frame`].
+//!
+//! ```no_run
+//! # use miners_net::{broadcast::Broadcast, conn::WriteHalf};
+//! # use miners_version::ProtocolVersion;
+//! # async fn run(
+//! #     packet: impl miners_packet::Packet,
+//! #     players: &mut [(ProtocolVersion, WriteHalf<futures_lite::io::Sink>)],
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut broadcast = Broadcast::new(packet);
+//! for (version, write_half) in players {
+//!     let threshold = write_half.compression_threshold();
+//!     if let Some(frame) = broadcast.frame(*version, threshold) {
+//!         write_half.write_frame(&frame?).await?;
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`WriteHalf::write_frame`]: crate::conn::WriteHalf::write_frame
+
+use std::sync::Arc;
+
+use miners_encoding::encode;
+use miners_packet::Packet;
+use miners_version::ProtocolVersion;
+
+use crate::encoding::{EncodedData, PacketEncodeExt};
+use crate::helpers::varint_vec;
+use crate::packing::Compression;
+
+/// A complete, unencrypted frame including its length prefix, shared between
+/// all connections it is sent to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame(Arc<[u8]>);
+
+impl Frame {
+    /// Prefixes `data` with its length.
+    fn new(data: &[u8]) -> Self {
+        let mut frame = Vec::with_capacity(data.len() + 5);
+        varint_vec(data.len() as u32, &mut frame);
+        frame.extend_from_slice(data);
+        Self(frame.into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A packet that is sent to many connections, see the [module docs](self).
+pub struct Broadcast<P> {
+    packet: P,
+    zlib: Option<Compression>,
+    /// The encoded packet for every protocol version, `None` if the packet
+    /// doesn't exist in it
+    encoded: Vec<(ProtocolVersion, Option<EncodedData>)>,
+    frames: Vec<(ProtocolVersion, Option<u32>, Frame)>,
+}
+
+impl<P: Packet> Broadcast<P> {
+    pub fn new(packet: P) -> Self {
+        Self {
+            packet,
+            zlib: None,
+            encoded: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    /// Returns the frame for connections with the protocol `version` and
+    /// compression `threshold`, encoding and compressing the packet only the
+    /// first time it is requested.
+    ///
+    /// Returns `None` if the packet doesn't exist in the protocol version.
+    pub fn frame(
+        &mut self,
+        version: ProtocolVersion,
+        threshold: Option<u32>,
+    ) -> Option<encode::Result<Frame>> {
+        if let Some((_, _, frame)) = self
+            .frames
+            .iter()
+            .find(|(v, t, _)| *v == version && *t == threshold)
+        {
+            return Some(Ok(frame.clone()));
+        }
+
+        let encoded = match self.encoded.iter().position(|(v, _)| *v == version) {
+            Some(i) => &self.encoded[i].1,
+            None => {
+                let encoded = match self.packet.encode_packet(version) {
+                    Some(Ok(encoded)) => Some(encoded),
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => None,
+                };
+                self.encoded.push((version, encoded));
+                &self.encoded[self.encoded.len() - 1].1
+            }
+        };
+        let encoded = encoded.as_ref()?;
+
+        let frame = match threshold {
+            // no compression, the marker isn't sent
+            None => Frame::new(&encoded.0[1..]),
+            // the marker doubles as the data length of 0 of an uncompressed
+            // packet
+            Some(threshold) if encoded.uncompressed_len() < threshold => Frame::new(&encoded.0),
+            Some(_) => {
+                let compression = self.zlib.get_or_insert_with(|| {
+                    Compression::new(0, flate2::Compress::new(flate2::Compression::fast(), true))
+                });
+                Frame::new(compression.do_compress(encoded).get())
+            }
+        };
+        self.frames.push((version, threshold, frame.clone()));
+        Some(Ok(frame))
+    }
+
+    pub fn packet(&self) -> &P {
+        &self.packet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conn::Connection;
+
+    /// A chat message that only exists since 1.8 (pv47).
+    struct Chat(String);
+
+    impl Packet for Chat {
+        fn id_for_version(&self, version: ProtocolVersion) -> Option<i32> {
+            (version >= ProtocolVersion::new(47).ok()?).then_some(0x02)
+        }
+
+        fn encode_for_version(
+            &self,
+            version: ProtocolVersion,
+            writer: &mut impl std::io::Write,
+        ) -> Option<encode::Result<()>> {
+            let id = self.id_for_version(version)?;
+            let mut encode = || -> encode::Result<()> {
+                miners_encoding::Encode::encode(&miners_encoding::attrs::Var::from(id), writer)?;
+                miners_encoding::Encode::encode(&self.0.as_str(), writer)
+            };
+            Some(encode())
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn frames() {
+        let mut broadcast = Broadcast::new(Chat("hello".repeat(1000)));
+        let version = ProtocolVersion::new(47).unwrap();
+        assert!(broadcast
+            .frame(ProtocolVersion::new(5).unwrap(), None)
+            .is_none());
+
+        for threshold in [None, Some(0), Some(64), Some(1024)] {
+            let frame = broadcast.frame(version, threshold).unwrap().unwrap();
+            let again = broadcast.frame(version, threshold).unwrap().unwrap();
+            assert!(Arc::ptr_eq(&frame.0, &again.0));
+
+            // the frame is written the same way as the packet itself
+            let (mut sent, mut expected) = (vec![], vec![]);
+            let mut broadcasted = Connection::unbuffered(&[][..], &mut sent);
+            let mut written = Connection::unbuffered(&[][..], &mut expected);
+            for conn in [&mut broadcasted, &mut written] {
+                if let Some(threshold) = threshold {
+                    conn.enable_compression(threshold as i32);
+                }
+                conn.enable_encryption(&[7; 16]).unwrap();
+            }
+            futures_lite::future::block_on(async {
+                broadcasted.write_half.write_frame(&frame).await.unwrap();
+                let encoded = broadcast.packet().encode_packet(version).unwrap().unwrap();
+                written.write_half.write(encoded).await.unwrap();
+            });
+            broadcasted.write_half.swrite_frame(&frame).unwrap();
+            written
+                .write_half
+                .swrite_packet(version, broadcast.packet())
+                .unwrap();
+            drop((broadcasted, written));
+            assert_eq!(sent, expected);
+        }
+        assert_eq!(broadcast.encoded.len(), 2);
+    }
+}
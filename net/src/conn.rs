@@ -3,7 +3,7 @@ use futures_lite::io::{AsyncRead, AsyncWrite};
 use futures_lite::io::{BufReader, BufWriter};
 mod readhalf;
 mod writehalf;
-pub use readhalf::ReadHalf;
+pub use readhalf::{FrameError, Limits, ReadHalf};
 // use writehalf::Compression;
 pub use writehalf::WriteHalf;
 
@@ -30,10 +30,32 @@ impl<R: AsyncRead + Unpin, W: AsyncWrite + Unpin> Connection<R, W> {
     pub fn split(self) -> (ReadHalf<R>, WriteHalf<W>) {
         (self.read_half, self.write_half)
     }
+}
+
+impl<R: std::io::Read, W: std::io::Write> Connection<R, W> {
+    /// Creates a connection for blocking I/O, use the `s`-prefixed methods
+    /// like [`ReadHalf::sread_encoded`] and [`WriteHalf::swrite`] with it.
+    pub fn blocking(
+        reader: R,
+        writer: W,
+    ) -> Connection<std::io::BufReader<R>, std::io::BufWriter<W>> {
+        Connection::blocking_unbuffered(
+            std::io::BufReader::new(reader),
+            std::io::BufWriter::new(writer),
+        )
+    }
+    pub fn blocking_unbuffered(reader: R, writer: W) -> Self {
+        Connection {
+            read_half: ReadHalf::new(reader),
+            write_half: WriteHalf::new(writer),
+        }
+    }
+}
 
+impl<R, W> Connection<R, W> {
     pub fn enable_compression(&mut self, threshold: i32) {
         self.write_half.enable_compression(threshold);
-        self.read_half.enable_compression();
+        self.read_half.enable_compression(threshold);
     }
 
     // pub fn set_compression(&mut self, threshold: i32, compression: flate2::Compression) {
@@ -70,3 +92,168 @@ impl<R: AsyncRead + Unpin, W: AsyncWrite + Unpin> Connection<R, W> {
 //         Connection::new(v.0, v.1)
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::EncodedData;
+
+    fn packets() -> [EncodedData; 2] {
+        ["small".to_string(), "large".repeat(100)]
+            .map(|data| EncodedData::try_from((0x05, data.as_str())).unwrap())
+    }
+
+    fn enable(conn: &mut Connection<impl Sized, impl Sized>) {
+        conn.enable_compression(64);
+        conn.enable_encryption(&[7; 16]).unwrap();
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn blocking() {
+        let mut sent = vec![];
+        let mut client = Connection::blocking_unbuffered(&[][..], &mut sent);
+        enable(&mut client);
+        for encoded in packets() {
+            client.write_half.swrite(encoded).unwrap();
+        }
+        client.write_half.sflush().unwrap();
+        drop(client);
+
+        // the blocking writer sends the same bytes as the async one
+        let mut sent_async = vec![];
+        let mut client = Connection::unbuffered(&[][..], &mut sent_async);
+        enable(&mut client);
+        futures_lite::future::block_on(async {
+            for encoded in packets() {
+                client.write_half.write(encoded).await.unwrap();
+            }
+            client.write_half.flush().await.unwrap();
+        });
+        drop(client);
+        assert_eq!(sent, sent_async);
+
+        let mut server = Connection::blocking(&sent[..], std::io::sink());
+        enable(&mut server);
+        for expected in packets() {
+            let encoded = server.read_half.sread_encoded().unwrap();
+            assert_eq!(encoded.0[..], expected.0[..]);
+        }
+    }
+
+    /// Returns one byte at a time and is pending before every byte.
+    struct Trickle<'a>(&'a [u8], bool);
+
+    impl AsyncRead for Trickle<'_> {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> std::task::Poll<std::io::Result<usize>> {
+            self.1 = !self.1;
+            if self.1 {
+                cx.waker().wake_by_ref();
+                return std::task::Poll::Pending;
+            }
+            let n = buf.len().min(self.0.len()).min(1);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            std::task::Poll::Ready(Ok(n))
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn cancel_safe() {
+        let mut sent = vec![];
+        let mut client = Connection::unbuffered(&[][..], &mut sent);
+        enable(&mut client);
+        futures_lite::future::block_on(async {
+            for encoded in packets() {
+                client.write_half.write(encoded).await.unwrap();
+            }
+            client.write_half.flush().await.unwrap();
+        });
+        drop(client);
+
+        let mut server = Connection::unbuffered(Trickle(&sent, false), futures_lite::io::sink());
+        enable(&mut server);
+        #[cfg(feature = "workpool")]
+        server.read_half.set_blocking_threshold(0);
+        futures_lite::future::block_on(async {
+            for expected in packets() {
+                // every read future is dropped after being polled once
+                let encoded = loop {
+                    let read = server.read_half.read_encoded();
+                    if let Some(encoded) = futures_lite::future::poll_once(read).await {
+                        break encoded.unwrap();
+                    }
+                };
+                assert_eq!(encoded.0[..], expected.0[..]);
+            }
+        });
+    }
+
+    fn write_all(client: &mut Connection<impl AsyncRead, &mut Vec<u8>>) {
+        futures_lite::future::block_on(async {
+            for encoded in packets() {
+                client.write_half.write(encoded).await.unwrap();
+            }
+            client.write_half.flush().await.unwrap();
+        });
+    }
+
+    /// Reads a frame from `sent` that is expected to fail.
+    fn read_err(sent: &[u8], setup: impl FnOnce(&mut ReadHalf<&[u8]>)) -> std::io::Error {
+        let mut server = Connection::unbuffered(sent, futures_lite::io::sink());
+        setup(&mut server.read_half);
+        match futures_lite::future::block_on(server.read_half.read_encoded()) {
+            Ok(_) => panic!("the frame should have been rejected"),
+            Err(e) => e,
+        }
+    }
+
+    fn frame_error(e: &std::io::Error) -> &FrameError {
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+        e.get_ref().unwrap().downcast_ref().unwrap()
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn limits() {
+        let mut sent = vec![];
+        write_all(&mut Connection::unbuffered(&[][..], &mut sent));
+        let e = read_err(&sent, |read| read.set_limits(Limits::new(4, 1024)));
+        assert!(matches!(frame_error(&e), FrameError::FrameTooLarge { .. }));
+        let e = read_err(&sent, |read| read.set_limits(Limits::new(1024, 4)));
+        assert!(matches!(frame_error(&e), FrameError::DataTooLarge { .. }));
+
+        // every packet is compressed
+        let mut sent = vec![];
+        let mut client = Connection::unbuffered(&[][..], &mut sent);
+        client.enable_compression(0);
+        write_all(&mut client);
+        let e = read_err(&sent, |read| read.enable_compression(64));
+        assert!(matches!(frame_error(&e), FrameError::BelowThreshold { .. }));
+        let e = read_err(&sent, |read| {
+            read.enable_compression(0);
+            read.set_limits(Limits::new(1024, 4));
+        });
+        assert!(matches!(frame_error(&e), FrameError::DataTooLarge { .. }));
+
+        // the first frame claims to decompress to one byte more, then one less
+        assert_eq!(sent[1], 7);
+        sent[1] = 8;
+        let e = read_err(&sent, |read| read.enable_compression(0));
+        assert!(matches!(
+            frame_error(&e),
+            FrameError::DataLengthMismatch { .. }
+        ));
+        sent[1] = 6;
+        let e = read_err(&sent, |read| read.enable_compression(0));
+        assert!(matches!(
+            frame_error(&e),
+            FrameError::DataLengthMismatch { .. }
+        ));
+    }
+}
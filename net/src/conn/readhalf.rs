@@ -1,31 +1,79 @@
+#[cfg(feature = "workpool")]
+use std::future::Future;
+use std::io;
 use std::pin::Pin;
-use std::task::Poll;
-use std::{fmt::Display, io};
+use std::task::{Context, Poll};
 
 use crate::encoding::EncodedData;
 use crate::helpers::{decrypt, AsyncCancelled};
+use crate::legacy::{self, LegacyPing};
+use crate::poll::{self, Futures, PollRead};
 #[cfg(feature = "workpool")]
 use crate::DEFAULT_UNBLOCK_THRESHOLD;
 
 use aes::cipher::{InvalidLength, KeyIvInit};
 use futures_lite::ready;
-use futures_lite::{AsyncRead, AsyncReadExt};
-use miners_util::bufpool::request_buf;
+use futures_lite::AsyncRead;
+use miners_util::bufpool::{request_buf, BufGuard};
 
 /// The maximum packet length, 8 MiB
 const MAX_PACKET_LENGTH: u32 = 1024 * 1024 * 8;
 
-#[inline]
-fn verify_len(len: u32) -> std::io::Result<()> {
-    if len > MAX_PACKET_LENGTH {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "The data length exceeds the maximum packet length! {len} > {MAX_PACKET_LENGTH}"
-            ),
-        ))
-    } else {
-        Ok(())
+/// Limits on the size of the frames read from a connection.
+///
+/// They are checked before any buffer is allocated, so a peer can't make the
+/// connection allocate more than the limits by sending a large length prefix
+/// or a small compressed packet claiming a large size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// The maximum length of a frame as sent, after compression
+    pub frame_length: u32,
+    /// The maximum length of the packet id and data, before compression
+    pub data_length: u32,
+}
+
+impl Limits {
+    /// The maximum packet length, 8 MiB for both.
+    pub const MAX: Limits = Limits::new(MAX_PACKET_LENGTH, MAX_PACKET_LENGTH);
+
+    pub const fn new(frame_length: u32, data_length: u32) -> Self {
+        Self {
+            frame_length,
+            data_length,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::MAX
+    }
+}
+
+/// A frame that broke the [`Limits`] or the compression rules.
+///
+/// Returned as the inner error of an `io::Error` with the kind
+/// `InvalidData`, it can be retrieved with `io::Error::get_ref` and
+/// `downcast_ref`. Typed connections return it as `ConnError::Frame`.
+#[derive(Debug, thiserror::Error)]
+pub enum FrameError {
+    #[error("the frame length exceeds the limit, {len} > {max}")]
+    FrameTooLarge { len: u32, max: u32 },
+    #[error("the data length exceeds the limit, {len} > {max}")]
+    DataTooLarge { len: u32, max: u32 },
+    /// A compressed packet that should have been sent uncompressed.
+    #[error("the data length is below the compression threshold, {len} < {threshold}")]
+    BelowThreshold { len: u32, threshold: u32 },
+    /// The packet didn't decompress to the length declared in front of it.
+    #[error("the decompressed packet doesn't have the declared length of {declared}")]
+    DataLengthMismatch { declared: u32 },
+    #[error(transparent)]
+    Decompress(#[from] flate2::DecompressError),
+}
+
+impl From<FrameError> for io::Error {
+    fn from(e: FrameError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
     }
 }
 
@@ -34,93 +82,135 @@ fn verify_len(len: u32) -> std::io::Result<()> {
 /// The reading half of a connection.
 /// Returned from `Connection::split()`
 pub struct ReadHalf<R> {
-    pub(super) compression: bool,
+    /// The compression threshold, set once compression is enabled
+    pub(super) threshold: Option<u32>,
+    limits: Limits,
     zlib: flate2::Decompress,
     reader: Reader<R>,
+    partial: Partial,
+}
+
+#[cfg(feature = "workpool")]
+type DecryptionResult = (Vec<u8>, Box<cfb8::Decryptor<aes::Aes128>>);
+
+/// The state of the frame that is being read, kept in the read half so
+/// dropping a [`ReadHalf::read_encoded`] future doesn't lose any bytes.
+enum Partial {
+    /// Reading the length prefix, `read` bytes of it were read so far.
+    Length { len: u32, read: u8 },
+    /// Reading the frame into `buf[start..]`, `filled` bytes of it were read
+    /// so far. The frame is still encrypted.
+    Frame {
+        buf: BufGuard,
+        start: usize,
+        filled: usize,
+    },
+    /// The frame is decrypted by the workpool, the decryptor is sent back
+    /// with the decrypted buffer.
+    #[cfg(feature = "workpool")]
+    Decrypting {
+        buf: BufGuard,
+        recv: futures_channel::oneshot::Receiver<DecryptionResult>,
+    },
+}
+
+impl Default for Partial {
+    fn default() -> Self {
+        Self::Length { len: 0, read: 0 }
+    }
 }
 
 pub struct Reader<R> {
     reader: R,
     decryptor: Option<Option<Box<cfb8::Decryptor<aes::Aes128>>>>,
+    /// the bytes read by `ReadHalf::sniff_legacy_ping` that weren't a legacy
+    /// ping, always read before encryption is enabled
+    peeked: Vec<u8>,
     #[cfg(feature = "workpool")]
     unblock_threshold: u32,
 }
 
+impl<R> Reader<R> {
+    /// Moves as many peeked bytes as fit to the start of `buf` and returns
+    /// how many were moved.
+    fn read_peeked(&mut self, buf: &mut [u8]) -> usize {
+        let n = buf.len().min(self.peeked.len());
+        buf[..n].copy_from_slice(&self.peeked[..n]);
+        self.peeked.drain(..n);
+        n
+    }
+}
+
 impl<R> Reader<R>
 where
-    R: AsyncRead + Unpin,
+    R: io::Read,
 {
-    async fn read(&mut self, buf: &mut Vec<u8>, len: u32) -> io::Result<()> {
-        // TODO: replace with readbuf or similar api once that is stabilized
+    fn sread(&mut self, buf: &mut Vec<u8>, len: u32) -> io::Result<()> {
         buf.resize(buf.len() + len as usize, 0);
         let slice_start = buf.len() - len as usize;
-        self.reader.read_exact(&mut buf[slice_start..]).await?;
+        let peeked = self.read_peeked(&mut buf[slice_start..]);
+        self.reader.read_exact(&mut buf[slice_start + peeked..])?;
         if let Some(decryptor) = &mut self.decryptor {
-            let mut decryptor = decryptor.take().ok_or(AsyncCancelled)?;
-            #[cfg(feature = "workpool")]
-            let decryptor = if len > self.unblock_threshold {
-                let taken_buf = std::mem::take(buf);
-                // SAFETY: this is safe as we are specifying a length that was just written
-                let (taken_buf, mutated_decryptor) = unsafe {
-                    crate::workpool::request_partial_decryption(taken_buf, len as usize, decryptor)
-                        .await
-                        .await
-                        .unwrap()
-                };
-                *buf = taken_buf;
-                mutated_decryptor
-            } else {
-                decrypt(&mut buf[slice_start..], &mut decryptor);
-                decryptor
-            };
-            #[cfg(not(feature = "workpool"))]
-            decrypt(&mut buf[slice_start..], &mut decryptor);
-            self.decryptor = Some(Some(decryptor));
+            let decryptor = decryptor.as_mut().ok_or(AsyncCancelled)?;
+            decrypt(&mut buf[slice_start..], decryptor);
         }
         Ok(())
     }
 }
 
-impl<R: AsyncRead + Unpin> AsyncRead for Reader<R> {
-    fn poll_read(
-        self: Pin<&mut Self>,
-        cx: &mut std::task::Context<'_>,
+impl<R: io::Read> io::Read for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let peeked = self.read_peeked(buf);
+        if peeked > 0 {
+            return Ok(peeked);
+        }
+        let n = self.reader.read(buf)?;
+        if let Some(decryptor) = &mut self.decryptor {
+            let decryptor = decryptor.as_mut().ok_or(AsyncCancelled)?;
+            decrypt(&mut buf[..n], decryptor);
+        }
+        Ok(n)
+    }
+}
+
+impl<R: Unpin> Reader<R> {
+    fn poll_read<I: PollRead<R>>(
+        &mut self,
+        cx: &mut Context<'_>,
         buf: &mut [u8],
-    ) -> std::task::Poll<io::Result<usize>> {
-        let this = self.get_mut();
-        match &mut this.decryptor {
-            None => Pin::new(&mut this.reader).poll_read(cx, buf),
+    ) -> Poll<io::Result<usize>> {
+        let peeked = self.read_peeked(buf);
+        if peeked > 0 {
+            return Poll::Ready(Ok(peeked));
+        }
+        match &mut self.decryptor {
+            None => I::poll_read(Pin::new(&mut self.reader), cx, buf),
             Some(decryptor) => {
-                let mut decryptor = decryptor.take().ok_or(AsyncCancelled)?;
-                let n = ready!(Pin::new(&mut this.reader).poll_read(cx, buf))?;
-                decrypt(buf, &mut decryptor);
-                this.decryptor = Some(Some(decryptor));
+                // only touch the decryptor once the read is done, it would
+                // be lost if it was taken out before returning pending
+                let n = ready!(I::poll_read(Pin::new(&mut self.reader), cx, buf))?;
+                let decryptor = decryptor.as_mut().ok_or(AsyncCancelled)?;
+                decrypt(&mut buf[..n], decryptor);
                 Poll::Ready(Ok(n))
             }
         }
     }
 }
 
-#[derive(Debug)]
-struct PacketLengthTooLarge;
-impl std::error::Error for PacketLengthTooLarge {}
-impl Display for PacketLengthTooLarge {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("packet length too large")
-    }
-}
-
 impl<R> ReadHalf<R> {
-    pub(super) fn new(reader: R) -> Self {
+    pub(crate) fn new(reader: R) -> Self {
         Self {
-            compression: false,
+            threshold: None,
+            limits: Limits::default(),
             zlib: flate2::Decompress::new(true),
             reader: Reader {
                 reader,
                 decryptor: None,
+                peeked: Vec::new(),
                 #[cfg(feature = "workpool")]
                 unblock_threshold: DEFAULT_UNBLOCK_THRESHOLD,
             },
+            partial: Partial::default(),
         }
     }
 
@@ -129,8 +219,18 @@ impl<R> ReadHalf<R> {
         Ok(())
     }
 
-    pub(super) fn enable_compression(&mut self) {
-        self.compression = true
+    pub(super) fn enable_compression(&mut self, threshold: i32) {
+        self.threshold = Some(threshold.max(0) as u32)
+    }
+
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Sets the limits for the following frames, e.g. when the connection
+    /// switches to a state with larger packets.
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
     }
 
     #[cfg(feature = "workpool")]
@@ -156,75 +256,294 @@ impl<R> ReadHalf<R>
 where
     R: AsyncRead + Unpin,
 {
+    /// Checks whether the connection starts with a legacy server list ping.
+    ///
+    /// Must be called before anything else is read. If the bytes read aren't
+    /// a legacy ping they are kept and `Ok(None)` is returned, so the
+    /// connection can be used as usual. Like vanilla's `LegacyQueryHandler`,
+    /// this falls back to the normal framing on any mismatch, as a modern
+    /// frame with a length of e.g. 254 (`FE 01`) also starts with `0xFE`.
+    ///
+    /// Like vanilla, the ping version is determined by which bytes are
+    /// already available after the `0xFE`, old clients send nothing more and
+    /// wait for a response.
+    pub async fn sniff_legacy_ping(&mut self) -> io::Result<Option<LegacyPing>> {
+        self.sniff_legacy_ping_with::<Futures>().await
+    }
+
+    /// Reads the next frame.
+    ///
+    /// This is cancel safe, a partially read frame is kept in the read half
+    /// and the next call continues reading it. It can be used as a branch of
+    /// `select!` without losing packets or the state of the decryptor.
     pub async fn read_encoded(&mut self) -> io::Result<EncodedData> {
-        let len = read_varint_async(&mut self.reader).await?;
-        let mut buf = miners_util::bufpool::request_buf(len as usize);
-        buf.clear();
-        if !self.compression {
-            // push a zero-byte so we adhere to the encoding buffer structure
-            buf.push(0);
-        }
+        futures_lite::future::poll_fn(|cx| self.poll_read_encoded(cx)).await
+    }
 
-        self.reader.read(&mut buf, len).await?;
-        match &mut self.compression {
-            false => Ok(EncodedData(buf)),
-            true if buf[0] == 0 => {
-                // compression enabled, prefixed with zero-byte
-                Ok(EncodedData(buf))
-            }
-            true => {
-                let mut compression_buf = request_buf(buf.len());
-                compression_buf.clear();
-                compression_buf.push(0);
-                let mut reader = std::io::Cursor::new(&buf[..]);
+    /// Polls for the next frame, see [`ReadHalf::read_encoded`].
+    pub fn poll_read_encoded(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<EncodedData>> {
+        self.poll_read_encoded_with::<Futures>(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R> ReadHalf<R>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    /// Tokio version of [`ReadHalf::sniff_legacy_ping`].
+    pub async fn tsniff_legacy_ping(&mut self) -> io::Result<Option<LegacyPing>> {
+        self.sniff_legacy_ping_with::<poll::Tokio>().await
+    }
 
-                let uncompressed_len = read_varint(&mut reader)?;
+    /// Tokio version of [`ReadHalf::read_encoded`], also cancel safe.
+    pub async fn tread_encoded(&mut self) -> io::Result<EncodedData> {
+        futures_lite::future::poll_fn(|cx| self.tpoll_read_encoded(cx)).await
+    }
 
-                verify_len(uncompressed_len)?;
+    /// Tokio version of [`ReadHalf::poll_read_encoded`].
+    pub fn tpoll_read_encoded(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<EncodedData>> {
+        self.poll_read_encoded_with::<poll::Tokio>(cx)
+    }
+}
 
-                compression_buf.reserve_exact(uncompressed_len as usize);
+impl<R: Unpin> ReadHalf<R> {
+    async fn sniff_legacy_ping_with<I: PollRead<R>>(&mut self) -> io::Result<Option<LegacyPing>> {
+        let reader = &mut self.reader.reader;
+        let mut consumed = vec![0];
+        poll::read_exact::<I, _>(reader, &mut consumed).await?;
+        if consumed[0] != 0xFE {
+            self.reader.peeked = consumed;
+            return Ok(None);
+        }
 
-                // error check?
-                self.zlib
-                    .decompress_vec(
-                        &reader.get_ref()[reader.get_ref().len()..],
-                        &mut compression_buf,
-                        flate2::FlushDecompress::Finish,
-                    )
-                    .ok();
+        async fn read_available<I: PollRead<R>, R: Unpin>(
+            reader: &mut R,
+        ) -> io::Result<Option<u8>> {
+            let mut byte = [0];
+            match futures_lite::future::poll_once(poll::read::<I, _>(reader, &mut byte)).await {
+                None | Some(Ok(0)) => Ok(None),
+                Some(Ok(_)) => Ok(Some(byte[0])),
+                Some(Err(e)) => Err(e),
+            }
+        }
 
-                self.zlib.reset(true);
+        match read_available::<I, _>(reader).await? {
+            None => return Ok(Some(LegacyPing::V1_3)),
+            Some(byte) => consumed.push(byte),
+        }
+        if consumed[1] == 0x01 {
+            match read_available::<I, _>(reader).await? {
+                None => return Ok(Some(LegacyPing::V1_4)),
+                Some(byte) => consumed.push(byte),
+            }
+        }
+        if consumed[1..] != [0x01, 0xFA] {
+            self.reader.peeked = consumed;
+            return Ok(None);
+        }
 
-                Ok(EncodedData(compression_buf))
+        // MC|PingHost plugin message, channel as utf-16 string, then the
+        // length of the data. A modern frame is at least 254 bytes long here,
+        // so reading the channel doesn't wait for more than it sent.
+        let channel = legacy::PING_HOST_CHANNEL;
+        let start = consumed.len();
+        consumed.resize(start + channel.len(), 0);
+        poll::read_exact::<I, _>(reader, &mut consumed[start..]).await?;
+        if consumed[start..] != *channel {
+            self.reader.peeked = consumed;
+            return Ok(None);
+        }
+        let mut len = [0; 2];
+        poll::read_exact::<I, _>(reader, &mut len).await?;
+        let mut data = vec![0; u16::from_be_bytes(len) as usize];
+        poll::read_exact::<I, _>(reader, &mut data).await?;
+        legacy::parse_ping_host(&data).map(Some)
+    }
+
+    fn poll_read_encoded_with<I: PollRead<R>>(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<EncodedData>> {
+        loop {
+            match &mut self.partial {
+                Partial::Length { len, read } => {
+                    // the length prefix is read through the decrypting reader,
+                    // which also returns the bytes peeked by `sniff_legacy_ping`
+                    let mut byte = [0];
+                    if ready!(self.reader.poll_read::<I>(cx, &mut byte))? == 0 {
+                        return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                    }
+                    *len += ((byte[0] & 0x7f) as u32) << (*read * 7);
+                    *read += 1;
+                    if byte[0] & 0x80 == 0 || *read == 5 {
+                        let len = *len;
+                        self.partial = Partial::default();
+                        let mut buf = self.frame_buf(len)?;
+                        let start = buf.len();
+                        buf.resize(start + len as usize, 0);
+                        self.partial = Partial::Frame {
+                            buf,
+                            start,
+                            filled: 0,
+                        };
+                    }
+                }
+                Partial::Frame { buf, start, filled } => {
+                    while *start + *filled < buf.len() {
+                        let rest = &mut buf[*start + *filled..];
+                        // the frame is decrypted once it is complete, so only
+                        // the peeked bytes, read before encryption, are taken
+                        // from the reader
+                        let n = match self.reader.read_peeked(rest) {
+                            0 => ready!(I::poll_read(Pin::new(&mut self.reader.reader), cx, rest))?,
+                            peeked => peeked,
+                        };
+                        if n == 0 {
+                            return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                        }
+                        *filled += n;
+                    }
+                    let start = *start;
+                    let Partial::Frame { mut buf, .. } = std::mem::take(&mut self.partial) else {
+                        unreachable!()
+                    };
+                    if let Some(decryptor) = &mut self.reader.decryptor {
+                        #[cfg(feature = "workpool")]
+                        if buf.len() - start > self.reader.unblock_threshold as usize {
+                            let decryptor = decryptor.take().ok_or(AsyncCancelled)?;
+                            let taken_buf = std::mem::take(&mut *buf);
+                            let len = taken_buf.len() - start;
+                            // SAFETY: len is at most the length of the buffer
+                            let recv = unsafe {
+                                crate::workpool::request_partial_decryption(
+                                    taken_buf, len, decryptor,
+                                )
+                            };
+                            self.partial = Partial::Decrypting { buf, recv };
+                            continue;
+                        }
+                        let decryptor = decryptor.as_mut().ok_or(AsyncCancelled)?;
+                        decrypt(&mut buf[start..], decryptor);
+                    }
+                    return Poll::Ready(self.unpack(buf));
+                }
+                #[cfg(feature = "workpool")]
+                Partial::Decrypting { recv, .. } => {
+                    let (decrypted, decryptor) = ready!(Pin::new(recv).poll(cx))
+                        .map_err(|_| io::Error::other("the decryption task was dropped"))?;
+                    self.reader.decryptor = Some(Some(decryptor));
+                    let Partial::Decrypting { mut buf, .. } = std::mem::take(&mut self.partial)
+                    else {
+                        unreachable!()
+                    };
+                    *buf = decrypted;
+                    return Poll::Ready(self.unpack(buf));
+                }
             }
         }
     }
 }
 
-fn read_varint<R>(reader: &mut R) -> io::Result<u32>
+impl<R> ReadHalf<R>
 where
     R: io::Read,
 {
-    let mut val = 0;
-    let mut cur_val = [0];
-    for i in 0..5 {
-        reader.read_exact(&mut cur_val)?;
-        val += ((cur_val[0] & 0x7f) as u32) << (i * 7);
-        if (cur_val[0] & 0x80) == 0x00 {
-            break;
+    /// Blocking version of [`ReadHalf::read_encoded`].
+    pub fn sread_encoded(&mut self) -> io::Result<EncodedData> {
+        let len = read_varint(&mut self.reader)?;
+        let mut buf = self.frame_buf(len)?;
+        self.reader.sread(&mut buf, len)?;
+        self.unpack(buf)
+    }
+}
+
+impl<R> ReadHalf<R> {
+    /// Requests a buffer for a frame of `len` bytes.
+    fn frame_buf(&self, len: u32) -> io::Result<BufGuard> {
+        if len > self.limits.frame_length {
+            return Err(FrameError::FrameTooLarge {
+                len,
+                max: self.limits.frame_length,
+            }
+            .into());
+        }
+        let mut buf = request_buf(len as usize);
+        buf.clear();
+        if self.threshold.is_none() {
+            // the whole frame is the packet
+            self.verify_data_len(len)?;
+            // push a zero-byte so we adhere to the encoding buffer structure
+            buf.push(0);
+        }
+        Ok(buf)
+    }
+
+    fn verify_data_len(&self, len: u32) -> Result<(), FrameError> {
+        if len > self.limits.data_length {
+            Err(FrameError::DataTooLarge {
+                len,
+                max: self.limits.data_length,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Decompresses a frame if necessary.
+    fn unpack(&mut self, buf: BufGuard) -> io::Result<EncodedData> {
+        let Some(threshold) = self.threshold else {
+            return Ok(EncodedData(buf));
+        };
+        if buf.first() == Some(&0) {
+            // compression enabled, prefixed with zero-byte
+            self.verify_data_len(buf.len() as u32 - 1)?;
+            return Ok(EncodedData(buf));
+        }
+
+        let mut reader = std::io::Cursor::new(&buf[..]);
+        let data_len = read_varint(&mut reader)?;
+        if data_len < threshold {
+            return Err(FrameError::BelowThreshold {
+                len: data_len,
+                threshold,
+            }
+            .into());
+        }
+        self.verify_data_len(data_len)?;
+
+        let mut compression_buf = request_buf(data_len as usize + 1);
+        compression_buf.clear();
+        compression_buf.push(0);
+        compression_buf.reserve_exact(data_len as usize);
+
+        // inflates at most up to the capacity of the buffer, so a packet
+        // decompressing to more than it declared can't grow it
+        let status = self.zlib.decompress_vec(
+            &buf[reader.position() as usize..],
+            &mut compression_buf,
+            flate2::FlushDecompress::Finish,
+        );
+        let total_out = self.zlib.total_out();
+        self.zlib.reset(true);
+
+        match status.map_err(FrameError::from)? {
+            flate2::Status::StreamEnd if total_out == data_len as u64 => {
+                Ok(EncodedData(compression_buf))
+            }
+            _ => Err(FrameError::DataLengthMismatch { declared: data_len }.into()),
         }
     }
-    Ok(val)
 }
 
-async fn read_varint_async<R>(reader: &mut R) -> io::Result<u32>
+fn read_varint<R>(reader: &mut R) -> io::Result<u32>
 where
-    R: AsyncRead + Unpin,
+    R: io::Read,
 {
     let mut val = 0;
     let mut cur_val = [0];
     for i in 0..5 {
-        reader.read_exact(&mut cur_val).await?;
+        reader.read_exact(&mut cur_val)?;
         val += ((cur_val[0] & 0x7f) as u32) << (i * 7);
         if (cur_val[0] & 0x80) == 0x00 {
             break;
@@ -1,5 +1,7 @@
 use crate::{
+    broadcast::Frame,
     encoding::{EncodedData, PacketEncodeExt},
+    legacy::{LegacyPing, LegacyPingResponse},
     packing::Compression,
     writer::Writer,
 };
@@ -24,6 +26,14 @@ impl<W> WriteHalf<W> {
         self.writer.enable_encryption(encryptor)
     }
 
+    /// The threshold from which on packets are compressed, `None` if
+    /// compression isn't enabled.
+    pub fn compression_threshold(&self) -> Option<u32> {
+        self.compression
+            .as_ref()
+            .map(|compression| compression.threshold)
+    }
+
     pub(super) fn enable_compression(&mut self, threshold: i32) {
         self.compression = Some(Compression {
             threshold: threshold as u32,
@@ -40,9 +50,65 @@ where
         let packed = encoded.split_pack(self.compression.as_mut());
         self.writer.write(packed).await
     }
+    /// Writes a frame of a [`Broadcast`](crate::broadcast::Broadcast), it has
+    /// to be built for the protocol version and
+    /// [`WriteHalf::compression_threshold`] of this connection.
+    pub async fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.writer.write_frame(frame.as_bytes()).await
+    }
     pub async fn flush(&mut self) -> io::Result<()> {
         self.writer.flush().await
     }
+    /// Answers a legacy server list ping, the connection should be closed
+    /// afterwards.
+    pub async fn write_legacy_ping_response(
+        &mut self,
+        ping: &LegacyPing,
+        response: &LegacyPingResponse,
+    ) -> io::Result<()> {
+        self.writer.write_raw(&mut response.encode(ping)).await?;
+        self.writer.flush().await
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W> WriteHalf<W>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    /// Tokio version of [`WriteHalf::write`].
+    pub async fn twrite(&mut self, encoded: EncodedData) -> io::Result<()> {
+        let packed = encoded.split_pack(self.compression.as_mut());
+        self.writer.twrite(packed).await
+    }
+    /// Tokio version of [`WriteHalf::write_frame`].
+    pub async fn twrite_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.writer.twrite_frame(frame.as_bytes()).await
+    }
+    pub async fn tflush(&mut self) -> io::Result<()> {
+        self.writer.tflush().await
+    }
+    /// Tokio version of [`WriteHalf::write_legacy_ping_response`].
+    pub async fn twrite_legacy_ping_response(
+        &mut self,
+        ping: &LegacyPing,
+        response: &LegacyPingResponse,
+    ) -> io::Result<()> {
+        self.writer.twrite_raw(&mut response.encode(ping)).await?;
+        self.writer.tflush().await
+    }
+    /// Tokio version of [`WriteHalf::write_packet`].
+    pub async fn twrite_packet<P>(
+        &mut self,
+        version: miners_version::ProtocolVersion,
+        packet: P,
+    ) -> miners_encoding::encode::Result<()>
+    where
+        P: miners_packet::Packet,
+    {
+        let encoded = encode_packet(version, packet)?;
+        Ok(self.twrite(encoded).await?)
+    }
 }
 
 impl<W> WriteHalf<W>
@@ -52,22 +118,65 @@ where
     pub async fn write_packet<P>(
         &mut self,
         version: miners_version::ProtocolVersion,
-        mut packet: P,
+        packet: P,
+    ) -> miners_encoding::encode::Result<()>
+    where
+        P: miners_packet::Packet,
+    {
+        let encoded = encode_packet(version, packet)?;
+        Ok(self.write(encoded).await?)
+    }
+}
+
+impl<W> WriteHalf<W>
+where
+    W: io::Write + Unpin,
+{
+    /// Blocking version of [`WriteHalf::write`].
+    pub fn swrite(&mut self, encoded: EncodedData) -> io::Result<()> {
+        let packed = encoded.split_pack(self.compression.as_mut());
+        self.writer.swrite(packed)
+    }
+    /// Blocking version of [`WriteHalf::write_frame`].
+    pub fn swrite_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.writer.swrite_frame(frame.as_bytes())
+    }
+    pub fn sflush(&mut self) -> io::Result<()> {
+        self.writer.sflush()
+    }
+    /// Blocking version of [`WriteHalf::write_packet`].
+    pub fn swrite_packet<P>(
+        &mut self,
+        version: miners_version::ProtocolVersion,
+        packet: P,
     ) -> miners_encoding::encode::Result<()>
     where
         P: miners_packet::Packet,
     {
-        if let Some(res) = packet.encode_packet(version) {
-            match res {
-                Ok(encoded) => Ok(self.write(encoded).await?),
-                Err(e) => Err(e),
-            }
-        } else {
+        let encoded = encode_packet(version, packet)?;
+        Ok(self.swrite(encoded)?)
+    }
+}
+
+fn encode_packet<P>(
+    version: miners_version::ProtocolVersion,
+    mut packet: P,
+) -> miners_encoding::encode::Result<EncodedData>
+where
+    P: miners_packet::Packet,
+{
+    match packet.encode_packet(version) {
+        Some(res) => res,
+        None => {
             #[cfg(debug_assertions)]
             panic!(
                 "tried to write packet of type {0} in mismatching protocol version {version}",
                 std::any::type_name::<P>(),
             );
+            #[cfg(not(debug_assertions))]
+            Err(miners_encoding::encode::Error::Custom(
+                "packet doesn't exist in this protocol version",
+            ))
         }
     }
 }
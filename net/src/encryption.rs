@@ -0,0 +1,190 @@
+//! Helpers for the encryption handshake of the login state.
+//!
+//! The server sends its [`KeyPair::public_key_der`] and a verify token in an
+//! encryption request, the client answers with a [`SharedSecret`] and the
+//! verify token, both encrypted with that public key. After that both sides
+//! pass the secret to [`Connection::enable_encryption`] and compute the
+//! [`server_hash`] used to authenticate with the session server.
+//!
+//! [`Connection::enable_encryption`]: crate::conn::Connection::enable_encryption
+
+use rsa::pkcs8::{DecodePublicKey, EncodePublicKey};
+use rsa::rand_core::{OsRng, RngCore};
+use rsa::{Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey};
+use subtle::ConstantTimeEq;
+
+pub use miners_util::server_hash::server_hash;
+
+/// The key size used by the vanilla server.
+pub const KEY_BITS: usize = 1024;
+
+/// The length of the verify token sent by the vanilla server.
+pub const VERIFY_TOKEN_LEN: usize = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Rsa(#[from] rsa::Error),
+    #[error("invalid public key: {0}")]
+    PublicKey(#[from] rsa::pkcs8::spki::Error),
+    #[error("the verify token didn't match")]
+    VerifyTokenMismatch,
+    #[error("expected a 16 byte shared secret, got {0} bytes")]
+    SecretLength(usize),
+}
+
+/// The RSA key pair of a server.
+///
+/// Generating a key pair is expensive, so it should be created once and
+/// shared between connections.
+#[derive(Debug, Clone)]
+pub struct KeyPair {
+    private_key: RsaPrivateKey,
+    public_key_der: Box<[u8]>,
+}
+
+impl KeyPair {
+    /// Generates a new key pair with [`KEY_BITS`] bits.
+    pub fn generate() -> Result<Self, Error> {
+        Self::from_private_key(RsaPrivateKey::new(&mut OsRng, KEY_BITS)?)
+    }
+
+    pub fn from_private_key(private_key: RsaPrivateKey) -> Result<Self, Error> {
+        let public_key_der = private_key
+            .to_public_key()
+            .to_public_key_der()?
+            .into_vec()
+            .into_boxed_slice();
+        Ok(Self {
+            private_key,
+            public_key_der,
+        })
+    }
+
+    /// The public key in the DER format sent in the encryption request.
+    pub fn public_key_der(&self) -> &[u8] {
+        &self.public_key_der
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(self.private_key.decrypt(Pkcs1v15Encrypt, data)?)
+    }
+
+    /// Decrypts the verify token sent back by the client and compares it to
+    /// the one that was sent, in constant time.
+    pub fn check_verify_token(&self, expected: &[u8], encrypted: &[u8]) -> Result<(), Error> {
+        let token = self.decrypt(encrypted)?;
+        if bool::from(token.ct_eq(expected)) {
+            Ok(())
+        } else {
+            Err(Error::VerifyTokenMismatch)
+        }
+    }
+
+    /// Decrypts the shared secret sent by the client.
+    pub fn decrypt_secret(&self, encrypted: &[u8]) -> Result<SharedSecret, Error> {
+        SharedSecret::try_from(&self.decrypt(encrypted)?[..])
+    }
+}
+
+/// Generates a random verify token for an encryption request.
+pub fn verify_token() -> [u8; VERIFY_TOKEN_LEN] {
+    let mut token = [0; VERIFY_TOKEN_LEN];
+    OsRng.fill_bytes(&mut token);
+    token
+}
+
+/// Encrypts `data` with a public key in the DER format, like the one sent in
+/// the encryption request.
+pub fn encrypt(public_key_der: &[u8], data: &[u8]) -> Result<Vec<u8>, Error> {
+    let public_key = RsaPublicKey::from_public_key_der(public_key_der)?;
+    Ok(public_key.encrypt(&mut OsRng, Pkcs1v15Encrypt, data)?)
+}
+
+/// The 16 byte secret used as both the key and iv of the AES/CFB8 stream
+/// cipher.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SharedSecret([u8; 16]);
+
+impl SharedSecret {
+    /// Generates a new random secret, done by the client.
+    pub fn generate() -> Self {
+        let mut secret = [0; 16];
+        OsRng.fill_bytes(&mut secret);
+        Self(secret)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Encrypts the secret with the public key of the server.
+    pub fn encrypt(&self, public_key_der: &[u8]) -> Result<Vec<u8>, Error> {
+        encrypt(public_key_der, &self.0)
+    }
+
+    /// Shorthand for [`server_hash`] with this secret.
+    pub fn server_hash(&self, server_id: &str, public_key_der: &[u8]) -> String {
+        server_hash(server_id, &self.0, public_key_der)
+    }
+}
+
+impl std::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedSecret(..)")
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for SharedSecret {
+    fn from(secret: [u8; 16]) -> Self {
+        Self(secret)
+    }
+}
+
+impl TryFrom<&[u8]> for SharedSecret {
+    type Error = Error;
+
+    fn try_from(secret: &[u8]) -> Result<Self, Error> {
+        secret
+            .try_into()
+            .map(Self)
+            .map_err(|_| Error::SecretLength(secret.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn handshake() {
+        let key_pair = KeyPair::generate().unwrap();
+        let token = verify_token();
+
+        // client side
+        let secret = SharedSecret::generate();
+        let encrypted_secret = secret.encrypt(key_pair.public_key_der()).unwrap();
+        let encrypted_token = encrypt(key_pair.public_key_der(), &token).unwrap();
+
+        key_pair
+            .check_verify_token(&token, &encrypted_token)
+            .unwrap();
+        let mut wrong_token = token;
+        wrong_token[0] ^= 1;
+        assert!(matches!(
+            key_pair.check_verify_token(&wrong_token, &encrypted_token),
+            Err(Error::VerifyTokenMismatch)
+        ));
+        assert_eq!(key_pair.decrypt_secret(&encrypted_secret).unwrap(), secret);
+
+        let mut connection = crate::conn::Connection::new(&[][..], Vec::new());
+        connection.enable_encryption(secret.as_ref()).unwrap();
+    }
+}
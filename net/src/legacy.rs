@@ -0,0 +1,187 @@
+//! The server list ping used by clients before the netty rewrite (1.7).
+//!
+//! These pings don't use the normal packet framing, they start with a `0xFE`
+//! byte. A modern frame only starts with it when its length is e.g. 254 or
+//! 382, so [`ReadHalf::sniff_legacy_ping`] checks the following bytes too and
+//! falls back to the normal framing when they don't match.
+//!
+//! [`ReadHalf::sniff_legacy_ping`]: crate::conn::ReadHalf::sniff_legacy_ping
+
+use std::io;
+
+/// A legacy server list ping, identified by what the client sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegacyPing {
+    /// beta 1.8 to 1.3, just `0xFE`
+    V1_3,
+    /// 1.4 to 1.5, `0xFE 0x01`
+    V1_4,
+    /// 1.6, `0xFE 0x01 0xFA` followed by a `MC|PingHost` plugin message
+    V1_6 {
+        protocol_version: u8,
+        hostname: String,
+        port: i32,
+    },
+}
+
+/// The kick message a server answers a legacy ping with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyPingResponse {
+    /// the protocol version shown to the client, modern servers usually send
+    /// 127 to have old clients show the server as incompatible
+    pub protocol: i32,
+    pub version: String,
+    pub motd: String,
+    pub online: i32,
+    pub max: i32,
+}
+
+impl LegacyPingResponse {
+    /// Encodes the `0xFF` kick packet answering the given ping.
+    ///
+    /// Clients before 1.4 only understand the motd and player counts, so the
+    /// motd has its `§` characters removed for them as they are used as the
+    /// delimiter.
+    pub fn encode(&self, ping: &LegacyPing) -> Vec<u8> {
+        let Self {
+            protocol,
+            version,
+            motd,
+            online,
+            max,
+        } = self;
+        let message = match ping {
+            LegacyPing::V1_3 => format!("{}§{online}§{max}", motd.replace('§', "")),
+            _ => format!("§1\0{protocol}\0{version}\0{motd}\0{online}\0{max}"),
+        };
+        let message: Vec<u16> = message.encode_utf16().collect();
+
+        let mut buf = Vec::with_capacity(3 + message.len() * 2);
+        buf.push(0xFF);
+        buf.extend_from_slice(&(message.len() as u16).to_be_bytes());
+        for unit in message {
+            buf.extend_from_slice(&unit.to_be_bytes());
+        }
+        buf
+    }
+}
+
+/// The channel of the plugin message sent by 1.6 clients, as a UTF-16BE
+/// string prefixed with its length in code units.
+pub(crate) const PING_HOST_CHANNEL: &[u8] = &[
+    0, 11, 0, b'M', 0, b'C', 0, b'|', 0, b'P', 0, b'i', 0, b'n', 0, b'g', 0, b'H', 0, b'o', 0,
+    b's', 0, b't',
+];
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Reads a UTF-16BE string prefixed with its length in code units.
+pub(crate) fn read_utf16(data: &mut &[u8]) -> io::Result<String> {
+    let len = read_u16(data)? as usize;
+    if data.len() < len * 2 {
+        return Err(invalid_data("legacy ping string exceeds the payload"));
+    }
+    let (string, rest) = data.split_at(len * 2);
+    *data = rest;
+    char::decode_utf16(
+        string
+            .chunks_exact(2)
+            .map(|unit| u16::from_be_bytes([unit[0], unit[1]])),
+    )
+    .collect::<Result<_, _>>()
+    .map_err(|_| invalid_data("invalid utf-16 in legacy ping"))
+}
+
+fn read_u16(data: &mut &[u8]) -> io::Result<u16> {
+    let Some((bytes, rest)) = data.split_first_chunk() else {
+        return Err(invalid_data("legacy ping payload too short"));
+    };
+    *data = rest;
+    Ok(u16::from_be_bytes(*bytes))
+}
+
+/// Parses the data of the `MC|PingHost` plugin message sent by 1.6 clients.
+pub(crate) fn parse_ping_host(mut data: &[u8]) -> io::Result<LegacyPing> {
+    let data = &mut data;
+    let Some((&protocol_version, rest)) = data.split_first() else {
+        return Err(invalid_data("legacy ping payload too short"));
+    };
+    *data = rest;
+    let hostname = read_utf16(data)?;
+    let Some(port) = data.first_chunk() else {
+        return Err(invalid_data("legacy ping payload too short"));
+    };
+    Ok(LegacyPing::V1_6 {
+        protocol_version,
+        hostname,
+        port: i32::from_be_bytes(*port),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conn::Connection;
+
+    fn sniff(data: &[u8]) -> io::Result<Option<LegacyPing>> {
+        let mut conn = Connection::unbuffered(data, Vec::<u8>::new());
+        futures_lite::future::block_on(conn.read_half.sniff_legacy_ping())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn sniffing() {
+        assert_eq!(sniff(&[0xFE]).unwrap(), Some(LegacyPing::V1_3));
+        assert_eq!(sniff(&[0xFE, 0x01]).unwrap(), Some(LegacyPing::V1_4));
+
+        let mut ping_host = vec![0xFE, 0x01, 0xFA];
+        let channel: Vec<u16> = "MC|PingHost".encode_utf16().collect();
+        ping_host.extend_from_slice(&(channel.len() as u16).to_be_bytes());
+        channel
+            .iter()
+            .for_each(|unit| ping_host.extend_from_slice(&unit.to_be_bytes()));
+        ping_host.extend_from_slice(&[0, 7 + 2 * 9, 74, 0, 9]);
+        "localhost"
+            .encode_utf16()
+            .for_each(|unit| ping_host.extend_from_slice(&unit.to_be_bytes()));
+        ping_host.extend_from_slice(&25565i32.to_be_bytes());
+        assert_eq!(
+            sniff(&ping_host).unwrap(),
+            Some(LegacyPing::V1_6 {
+                protocol_version: 74,
+                hostname: "localhost".into(),
+                port: 25565
+            })
+        );
+
+        // a modern handshake is left untouched
+        let mut conn = Connection::unbuffered(&[0x02, 0x00, 0x2A][..], Vec::<u8>::new());
+        futures_lite::future::block_on(async {
+            assert_eq!(conn.read_half.sniff_legacy_ping().await.unwrap(), None);
+            let packet = conn.read_half.read_encoded().await.unwrap();
+            assert_eq!(packet.0[..], [0x00, 0x00, 0x2A]);
+        });
+    }
+
+    #[test]
+    fn response() {
+        let response = LegacyPingResponse {
+            protocol: 127,
+            version: "1.19.2".into(),
+            motd: "A §cMinecraft§r Server".into(),
+            online: 1,
+            max: 20,
+        };
+        let encoded = response.encode(&LegacyPing::V1_3);
+        let decoded = read_utf16(&mut &encoded[1..]).ok();
+        assert_eq!(decoded.as_deref(), Some("A cMinecraftr Server§1§20"));
+        let encoded = response.encode(&LegacyPing::V1_4);
+        let decoded = read_utf16(&mut &encoded[1..]).ok();
+        assert_eq!(
+            decoded.as_deref(),
+            Some("§1\u{0}127\u{0}1.19.2\u{0}A §cMinecraft§r Server\u{0}1\u{0}20")
+        );
+    }
+}
@@ -1,7 +1,16 @@
 #![deny(clippy::undocumented_unsafe_blocks)]
+pub mod broadcast;
 pub mod conn;
 pub mod encoding;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod legacy;
 pub mod packing;
+pub(crate) mod poll;
+#[cfg(feature = "tokio")]
+pub mod tokio_io;
+#[cfg(feature = "protocol")]
+pub mod typed;
 
 #[cfg(feature = "workpool")]
 pub(crate) mod workpool;
@@ -16,7 +16,10 @@ impl Compression {
             zlib,
         }
     }
-    fn do_compress<'compressed, 'encoded, 'packed>(&mut self, encoded: EncodedData) -> PackedData
+    pub(crate) fn do_compress<'compressed, 'encoded, 'packed>(
+        &mut self,
+        encoded: &EncodedData,
+    ) -> PackedData
     where
         'encoded: 'packed,
         'compressed: 'packed,
@@ -53,7 +56,7 @@ impl Compression {
         'compressed: 'mutslice,
     {
         if encoded.uncompressed_len() >= self.threshold {
-            self.do_compress(encoded)
+            self.do_compress(&encoded)
         } else {
             encoded.zero_prefixed()
         }
@@ -0,0 +1,139 @@
+//! The async I/O traits the framing is implemented on.
+//!
+//! The read and write halves are generic over the marker types of this module
+//! instead of the I/O traits themselves, as a type can implement both the
+//! `futures-io` and the tokio traits. The futures methods of the halves use
+//! [`Futures`], the `t`-prefixed ones [`Tokio`].
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_lite::{future::poll_fn, ready};
+
+pub(crate) trait PollRead<R> {
+    fn poll_read(
+        reader: Pin<&mut R>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>>;
+}
+
+pub(crate) trait PollWrite<W> {
+    fn poll_write(writer: Pin<&mut W>, cx: &mut Context<'_>, buf: &[u8])
+        -> Poll<io::Result<usize>>;
+    fn poll_flush(writer: Pin<&mut W>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+}
+
+/// `futures_lite::AsyncRead` and `AsyncWrite`
+pub(crate) struct Futures;
+
+impl<R: futures_lite::AsyncRead> PollRead<R> for Futures {
+    fn poll_read(
+        reader: Pin<&mut R>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        futures_lite::AsyncRead::poll_read(reader, cx, buf)
+    }
+}
+
+impl<W: futures_lite::AsyncWrite> PollWrite<W> for Futures {
+    fn poll_write(
+        writer: Pin<&mut W>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        futures_lite::AsyncWrite::poll_write(writer, cx, buf)
+    }
+    fn poll_flush(writer: Pin<&mut W>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        futures_lite::AsyncWrite::poll_flush(writer, cx)
+    }
+}
+
+/// `tokio::io::AsyncRead` and `AsyncWrite`
+#[cfg(feature = "tokio")]
+pub(crate) struct Tokio;
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead> PollRead<R> for Tokio {
+    fn poll_read(
+        reader: Pin<&mut R>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        // the slice is read into directly, `ReadBuf` only tracks how much of
+        // it was filled
+        let mut buf = tokio::io::ReadBuf::new(buf);
+        ready!(tokio::io::AsyncRead::poll_read(reader, cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite> PollWrite<W> for Tokio {
+    fn poll_write(
+        writer: Pin<&mut W>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        tokio::io::AsyncWrite::poll_write(writer, cx, buf)
+    }
+    fn poll_flush(writer: Pin<&mut W>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        tokio::io::AsyncWrite::poll_flush(writer, cx)
+    }
+}
+
+pub(crate) async fn read<I, R>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize>
+where
+    I: PollRead<R>,
+    R: Unpin,
+{
+    poll_fn(|cx| I::poll_read(Pin::new(&mut *reader), cx, buf)).await
+}
+
+pub(crate) async fn read_exact<I, R>(reader: &mut R, buf: &mut [u8]) -> io::Result<()>
+where
+    I: PollRead<R>,
+    R: Unpin,
+{
+    let mut filled = 0;
+    poll_fn(|cx| {
+        while filled < buf.len() {
+            let n = ready!(I::poll_read(Pin::new(&mut *reader), cx, &mut buf[filled..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+            filled += n;
+        }
+        Poll::Ready(Ok(()))
+    })
+    .await
+}
+
+pub(crate) async fn write_all<I, W>(writer: &mut W, buf: &[u8]) -> io::Result<()>
+where
+    I: PollWrite<W>,
+    W: Unpin,
+{
+    let mut written = 0;
+    poll_fn(|cx| {
+        while written < buf.len() {
+            let n = ready!(I::poll_write(Pin::new(&mut *writer), cx, &buf[written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            written += n;
+        }
+        Poll::Ready(Ok(()))
+    })
+    .await
+}
+
+pub(crate) async fn flush<I, W>(writer: &mut W) -> io::Result<()>
+where
+    I: PollWrite<W>,
+    W: Unpin,
+{
+    poll_fn(|cx| I::poll_flush(Pin::new(&mut *writer), cx)).await
+}
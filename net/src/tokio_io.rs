@@ -0,0 +1,91 @@
+//! Running connections on tokio's I/O traits.
+//!
+//! With the `tokio` feature, [`ReadHalf`] and [`WriteHalf`] implement the same
+//! framing, compression and encryption directly on
+//! [`tokio::io::AsyncRead`]/[`tokio::io::AsyncWrite`], through the
+//! `t`-prefixed versions of their methods like [`ReadHalf::tread_encoded`]
+//! and [`WriteHalf::twrite`]. Reads go straight into the connection's
+//! buffers, without a compat wrapper in between.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use miners_net::conn::Connection;
+//!
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:25565").await?;
+//! let (stream, _) = listener.accept().await?;
+//! let (read, write) = stream.into_split();
+//! let mut connection = Connection::tokio(read, write);
+//! let encoded = connection.read_half.tread_encoded().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ReadHalf`]: crate::conn::ReadHalf
+//! [`WriteHalf`]: crate::conn::WriteHalf
+//! [`ReadHalf::tread_encoded`]: crate::conn::ReadHalf::tread_encoded
+//! [`WriteHalf::twrite`]: crate::conn::WriteHalf::twrite
+
+use tokio::io::{AsyncRead, AsyncWrite, BufReader, BufWriter};
+
+use crate::conn::{Connection, ReadHalf, WriteHalf};
+
+impl<R, W> Connection<R, W>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    /// Like [`Connection::new`], but buffered with tokio's `BufReader` and
+    /// `BufWriter`.
+    pub fn tokio(reader: R, writer: W) -> Connection<BufReader<R>, BufWriter<W>> {
+        Connection::tokio_unbuffered(BufReader::new(reader), BufWriter::new(writer))
+    }
+
+    pub fn tokio_unbuffered(reader: R, writer: W) -> Self {
+        Connection {
+            read_half: ReadHalf::new(reader),
+            write_half: WriteHalf::new(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::EncodedData;
+
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn roundtrip() {
+        let (client, server) = tokio::io::duplex(64);
+        let (client_read, client_write) = tokio::io::split(client);
+        let (server_read, server_write) = tokio::io::split(server);
+        let mut client = Connection::tokio(client_read, client_write);
+        let mut server = Connection::tokio(server_read, server_write);
+
+        let key = [7; 16];
+        for conn in [&mut client, &mut server] {
+            conn.enable_compression(64);
+            conn.enable_encryption(&key).unwrap();
+        }
+
+        let packets = ["small".to_string(), "large".repeat(100)];
+        let sent = packets.clone();
+        let writer = tokio::spawn(async move {
+            for data in &sent {
+                let encoded = EncodedData::try_from((0x05, data.as_str())).unwrap();
+                client.write_half.twrite(encoded).await.unwrap();
+            }
+            client.write_half.tflush().await.unwrap();
+        });
+
+        for expected in &packets {
+            let encoded = server.read_half.tread_encoded().await.unwrap();
+            let packet = encoded.to_packet().unwrap();
+            assert_eq!(packet.id, 0x05);
+            let mut expected_data = vec![];
+            miners_encoding::Encode::encode(&expected.as_str(), &mut expected_data).unwrap();
+            assert_eq!(packet.data, &expected_data[..]);
+        }
+        writer.await.unwrap();
+    }
+}
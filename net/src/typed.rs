@@ -0,0 +1,846 @@
+//! A connection that knows the state it is in.
+//!
+//! [`Conn`] wraps a [`Connection`] and parses the packets of its current state
+//! for the negotiated [`ProtocolVersion`], so a play packet can't be read
+//! during login by mistake. Compression is enabled as soon as the set
+//! compression packet passed, encryption has to be enabled during login using
+//! the shared secret.
+//!
+//! After the last packet of a state was read or written, the connection
+//! refuses to read or write anything else until it was switched to the next
+//! state using [`Conn::into_state`].
+
+use std::io;
+use std::marker::PhantomData;
+
+use aes::cipher::InvalidLength;
+use futures_lite::{AsyncRead, AsyncWrite};
+use miners_encoding::{decode, encode};
+use miners_packet::{Packet, RawPacket};
+use miners_protocol::netty::configuration::{CbConfiguration, SbConfiguration};
+use miners_protocol::netty::handshaking::serverbound::{Handshake0, NextState0};
+use miners_protocol::netty::handshaking::SbHandshaking;
+use miners_protocol::netty::login::{CbLogin, SbLogin};
+use miners_protocol::netty::play::{CbPlay, SbPlay};
+use miners_protocol::netty::status::{CbStatus, SbStatus};
+use miners_protocol::netty::State;
+use miners_protocol::ToStatic;
+use miners_version::{InvalidVersion, ProtocolVersion};
+
+use crate::conn::{Connection, FrameError, Limits};
+use crate::encoding::{EncodedData, PacketEncodeExt};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConnError {
+    #[error(transparent)]
+    Io(io::Error),
+    /// A frame broke the [`Limits`] of the current state or the compression
+    /// rules.
+    #[error(transparent)]
+    Frame(FrameError),
+    #[error(transparent)]
+    Decode(#[from] decode::Error),
+    #[error(transparent)]
+    Encode(#[from] encode::Error),
+    #[error(transparent)]
+    InvalidVersion(#[from] InvalidVersion),
+    #[error("the packet doesn't exist in protocol version {0}")]
+    UnsupportedPacket(ProtocolVersion),
+    /// The last packet of the current state was already read or written.
+    #[error("the connection is switching to the {0:?} state")]
+    Switching(State),
+}
+
+/// Frame errors are returned by the read half inside of an `io::Error`.
+impl From<io::Error> for ConnError {
+    fn from(e: io::Error) -> Self {
+        match e.downcast::<FrameError>() {
+            Ok(e) => Self::Frame(e),
+            Err(e) => Self::Io(e),
+        }
+    }
+}
+
+/// The server side of a connection, reads serverbound and writes clientbound
+/// packets.
+pub struct Server;
+/// The client side of a connection, reads clientbound and writes serverbound
+/// packets.
+pub struct Client;
+
+/// The first state of every connection, see [`Conn::read_handshake`] and
+/// [`Conn::handshake`].
+pub struct Handshaking;
+pub struct Status;
+pub struct Login;
+/// Only used since 1.20.2 (pv764)
+pub struct Configuration;
+pub struct Play;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Status {}
+    impl Sealed for super::Login {}
+    impl Sealed for super::Configuration {}
+    impl Sealed for super::Play {}
+}
+
+/// A state after the handshake, with the packets that can be sent in it.
+pub trait ConnState: sealed::Sealed {
+    const STATE: State;
+    type Serverbound<'a>: Packet;
+    type Clientbound<'a>: Packet;
+
+    fn parse_serverbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Serverbound<'_>>;
+    fn parse_clientbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Clientbound<'_>>;
+
+    /// The state the connection switches to after this packet
+    fn serverbound_switch(
+        _packet: &Self::Serverbound<'_>,
+        _version: ProtocolVersion,
+    ) -> Option<State> {
+        None
+    }
+    /// The state the connection switches to after this packet, if the switch
+    /// isn't acknowledged by a serverbound packet
+    fn clientbound_switch(
+        _packet: &Self::Clientbound<'_>,
+        _version: ProtocolVersion,
+    ) -> Option<State> {
+        None
+    }
+    /// The compression threshold set by this packet
+    fn compression(_packet: &Self::Clientbound<'_>) -> Option<i32> {
+        None
+    }
+    /// Whether the packets of this state are mapped in `version`
+    fn is_mapped(_version: ProtocolVersion) -> bool {
+        true
+    }
+}
+
+impl ConnState for Status {
+    const STATE: State = State::Status;
+    type Serverbound<'a> = SbStatus;
+    type Clientbound<'a> = CbStatus;
+
+    fn parse_serverbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Serverbound<'_>> {
+        SbStatus::parse(packet, version)
+    }
+    fn parse_clientbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Clientbound<'_>> {
+        CbStatus::parse(packet, version)
+    }
+}
+
+impl ConnState for Login {
+    const STATE: State = State::Login;
+    type Serverbound<'a> = SbLogin<'a>;
+    type Clientbound<'a> = CbLogin<'a>;
+
+    fn parse_serverbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Serverbound<'_>> {
+        SbLogin::parse(packet, version)
+    }
+    fn parse_clientbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Clientbound<'_>> {
+        CbLogin::parse(packet, version)
+    }
+
+    fn serverbound_switch(
+        packet: &Self::Serverbound<'_>,
+        _version: ProtocolVersion,
+    ) -> Option<State> {
+        packet.next_state()
+    }
+    fn clientbound_switch(
+        packet: &Self::Clientbound<'_>,
+        version: ProtocolVersion,
+    ) -> Option<State> {
+        // the switch to configuration is acknowledged by the client
+        packet
+            .next_state(version)
+            .filter(|state| *state == State::Play)
+    }
+    fn compression(packet: &Self::Clientbound<'_>) -> Option<i32> {
+        match packet {
+            CbLogin::SetCompression27(packet) => Some(packet.threshold),
+            _ => None,
+        }
+    }
+}
+
+impl ConnState for Configuration {
+    const STATE: State = State::Configuration;
+    type Serverbound<'a> = SbConfiguration<'a>;
+    type Clientbound<'a> = CbConfiguration<'a>;
+
+    fn parse_serverbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Serverbound<'_>> {
+        SbConfiguration::parse(packet, version)
+    }
+    fn parse_clientbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Clientbound<'_>> {
+        CbConfiguration::parse(packet, version)
+    }
+
+    fn serverbound_switch(
+        packet: &Self::Serverbound<'_>,
+        _version: ProtocolVersion,
+    ) -> Option<State> {
+        packet.next_state()
+    }
+}
+
+impl ConnState for Play {
+    const STATE: State = State::Play;
+    type Serverbound<'a> = SbPlay<'a>;
+    type Clientbound<'a> = CbPlay<'a>;
+
+    fn parse_serverbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Serverbound<'_>> {
+        SbPlay::parse(packet, version)
+    }
+    fn parse_clientbound(
+        packet: RawPacket<'_>,
+        version: ProtocolVersion,
+    ) -> decode::Result<Self::Clientbound<'_>> {
+        CbPlay::parse(packet, version)
+    }
+
+    fn is_mapped(version: ProtocolVersion) -> bool {
+        miners_protocol::netty::play::is_mapped(version)
+    }
+}
+
+/// A [`Connection`] in the state `St` seen from `Side`.
+pub struct Conn<Side, St, R, W> {
+    connection: Connection<R, W>,
+    version: ProtocolVersion,
+    /// Set after the last packet of the current state
+    switching: Option<State>,
+    /// The data of the last packet read, borrowed by it
+    buf: Option<EncodedData>,
+    state: State,
+    limits: StateLimits,
+    _state: PhantomData<(Side, St)>,
+}
+
+/// The frame [`Limits`] of each state, applied to the read half when the
+/// connection switches to that state.
+///
+/// Before the play state only a few small packets are expected, so the
+/// defaults keep a peer from making the connection allocate large buffers
+/// before it even logged in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StateLimits {
+    /// 8 KiB by default, proxies like BungeeCord forward the profile of the
+    /// player in the server address
+    pub handshaking: Limits,
+    /// 256 KiB by default, large enough for the favicon in a status response
+    pub status: Limits,
+    /// 64 KiB by default
+    pub login: Limits,
+    /// [`Limits::MAX`] by default, registries are sent in this state
+    pub configuration: Limits,
+    /// [`Limits::MAX`] by default
+    pub play: Limits,
+}
+
+impl StateLimits {
+    pub fn get(&self, state: State) -> Limits {
+        match state {
+            State::Handshaking => self.handshaking,
+            State::Status => self.status,
+            State::Login => self.login,
+            State::Configuration => self.configuration,
+            State::Play => self.play,
+        }
+    }
+}
+
+impl Default for StateLimits {
+    fn default() -> Self {
+        Self {
+            handshaking: Limits::new(8 * 1024, 8 * 1024),
+            status: Limits::new(256 * 1024, 256 * 1024),
+            login: Limits::new(64 * 1024, 64 * 1024),
+            configuration: Limits::MAX,
+            play: Limits::MAX,
+        }
+    }
+}
+
+/// Why [`Conn::into_state`] didn't switch, holds the unchanged connection.
+pub enum SwitchError<C> {
+    /// The connection isn't switching to the state.
+    NotSwitching(C),
+    /// The packets of the state aren't mapped in the version of the
+    /// connection, like play since 1.19.3. The client should be disconnected.
+    Unmapped(C),
+}
+
+impl<C> SwitchError<C> {
+    pub fn into_inner(self) -> C {
+        match self {
+            Self::NotSwitching(conn) | Self::Unmapped(conn) => conn,
+        }
+    }
+}
+
+/// The state chosen in the handshake.
+pub enum Handshaked<Side, R, W> {
+    Status(Conn<Side, Status, R, W>),
+    Login(Conn<Side, Login, R, W>),
+    /// The client asked to log in with a version that isn't supported. The
+    /// connection is in the latest version instead, the login disconnect is
+    /// the same in every version so the client can still be told why.
+    UnsupportedLogin(Conn<Side, Login, R, W>, InvalidVersion),
+}
+
+impl<Side, St, R, W> Conn<Side, St, R, W> {
+    fn with_state<T>(
+        mut connection: Connection<R, W>,
+        version: ProtocolVersion,
+        state: State,
+        limits: StateLimits,
+    ) -> Conn<Side, T, R, W> {
+        connection.read_half.set_limits(limits.get(state));
+        Conn {
+            connection,
+            version,
+            switching: None,
+            buf: None,
+            state,
+            limits,
+            _state: PhantomData,
+        }
+    }
+
+    pub fn limits(&self) -> StateLimits {
+        self.limits
+    }
+
+    /// Replaces the limits, they are applied to the current state right
+    /// away.
+    pub fn set_limits(&mut self, limits: StateLimits) {
+        self.connection.read_half.set_limits(limits.get(self.state));
+        self.limits = limits;
+    }
+
+    /// The protocol version the packets are parsed in.
+    pub fn version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// The state the connection is switching to if the last packet of the
+    /// current state already passed.
+    pub fn switching(&self) -> Option<State> {
+        self.switching
+    }
+
+    pub fn into_inner(self) -> Connection<R, W> {
+        self.connection
+    }
+}
+
+impl<R, W> Conn<Server, Handshaking, R, W> {
+    pub fn server(connection: Connection<R, W>) -> Self {
+        Self::with_state(
+            connection,
+            ProtocolVersion::latest(),
+            State::Handshaking,
+            StateLimits::default(),
+        )
+    }
+}
+
+impl<R, W> Conn<Client, Handshaking, R, W> {
+    pub fn client(connection: Connection<R, W>) -> Self {
+        Self::with_state(
+            connection,
+            ProtocolVersion::latest(),
+            State::Handshaking,
+            StateLimits::default(),
+        )
+    }
+}
+
+impl<R, W> Conn<Server, Handshaking, R, W>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Reads the handshake and switches to the state the client asked for.
+    ///
+    /// Clients ping the server with their own version, so status packets of an
+    /// unknown version are read in the latest version. A login with an
+    /// unknown version is returned as [`Handshaked::UnsupportedLogin`].
+    pub async fn read_handshake(
+        mut self,
+    ) -> Result<(Handshake0<'static>, Handshaked<Server, R, W>), ConnError> {
+        let encoded = self.connection.read_half.read_encoded().await?;
+        let SbHandshaking::Handshake0(handshake) =
+            SbHandshaking::parse(encoded.to_packet()?, self.version)?;
+        let handshake = handshake.into_static();
+        let next = match handshake.next_state {
+            NextState0::Status => Handshaked::Status(Self::with_state(
+                self.connection,
+                ProtocolVersion::new(handshake.protocol_version)
+                    .unwrap_or_else(|_| ProtocolVersion::latest()),
+                State::Status,
+                self.limits,
+            )),
+            NextState0::Login => match ProtocolVersion::new(handshake.protocol_version) {
+                Ok(version) => Handshaked::Login(Self::with_state(
+                    self.connection,
+                    version,
+                    State::Login,
+                    self.limits,
+                )),
+                Err(e) => Handshaked::UnsupportedLogin(
+                    Self::with_state(
+                        self.connection,
+                        ProtocolVersion::latest(),
+                        State::Login,
+                        self.limits,
+                    ),
+                    e,
+                ),
+            },
+        };
+        Ok((handshake, next))
+    }
+}
+
+impl<R, W> Conn<Client, Handshaking, R, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Sends the handshake and switches to the state it asks for.
+    pub async fn handshake(
+        mut self,
+        handshake: &Handshake0<'_>,
+    ) -> Result<Handshaked<Client, R, W>, ConnError> {
+        let version = ProtocolVersion::new(handshake.protocol_version)?;
+        let mut packet = handshake;
+        let encoded = match packet.encode_packet(version) {
+            Some(encoded) => encoded?,
+            None => return Err(ConnError::UnsupportedPacket(version)),
+        };
+        self.connection.write_half.write(encoded).await?;
+        Ok(match handshake.next_state {
+            NextState0::Status => Handshaked::Status(Self::with_state(
+                self.connection,
+                version,
+                State::Status,
+                self.limits,
+            )),
+            NextState0::Login => Handshaked::Login(Self::with_state(
+                self.connection,
+                version,
+                State::Login,
+                self.limits,
+            )),
+        })
+    }
+}
+
+impl<Side, St: ConnState, R, W> Conn<Side, St, R, W> {
+    /// Switches to the state `T` after the last packet of the current state
+    /// passed, returns the connection unchanged if it isn't switching to `T`
+    /// or the packets of `T` aren't mapped in its version.
+    #[allow(clippy::result_large_err)]
+    pub fn into_state<T: ConnState>(self) -> Result<Conn<Side, T, R, W>, SwitchError<Self>> {
+        if self.switching != Some(T::STATE) {
+            Err(SwitchError::NotSwitching(self))
+        } else if !T::is_mapped(self.version) {
+            Err(SwitchError::Unmapped(self))
+        } else {
+            Ok(Self::with_state(
+                self.connection,
+                self.version,
+                T::STATE,
+                self.limits,
+            ))
+        }
+    }
+
+    fn ensure_not_switching(&self) -> Result<(), ConnError> {
+        match self.switching {
+            Some(state) => Err(ConnError::Switching(state)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<Side, St: ConnState, R, W> Conn<Side, St, R, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    async fn write_packet(&mut self, mut packet: impl Packet) -> Result<(), ConnError> {
+        self.ensure_not_switching()?;
+        let encoded = match packet.encode_packet(self.version) {
+            Some(encoded) => encoded?,
+            None => return Err(ConnError::UnsupportedPacket(self.version)),
+        };
+        Ok(self.connection.write_half.write(encoded).await?)
+    }
+
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.connection.write_half.flush().await
+    }
+}
+
+impl<St: ConnState, R, W> Conn<Server, St, R, W>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Reads the next serverbound packet of the current state.
+    pub async fn read(&mut self) -> Result<St::Serverbound<'_>, ConnError> {
+        self.ensure_not_switching()?;
+        let encoded = self
+            .buf
+            .insert(self.connection.read_half.read_encoded().await?);
+        let packet = St::parse_serverbound(encoded.to_packet()?, self.version)?;
+        self.switching = St::serverbound_switch(&packet, self.version);
+        Ok(packet)
+    }
+}
+
+impl<St: ConnState, R, W> Conn<Server, St, R, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Writes a clientbound packet of the current state.
+    pub async fn write(&mut self, packet: &St::Clientbound<'_>) -> Result<(), ConnError> {
+        self.write_packet(packet).await?;
+        if let Some(threshold) = St::compression(packet) {
+            self.connection.enable_compression(threshold);
+        }
+        self.switching = St::clientbound_switch(packet, self.version);
+        Ok(())
+    }
+}
+
+impl<St: ConnState, R, W> Conn<Client, St, R, W>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Reads the next clientbound packet of the current state.
+    pub async fn read(&mut self) -> Result<St::Clientbound<'_>, ConnError> {
+        self.ensure_not_switching()?;
+        let encoded = self
+            .buf
+            .insert(self.connection.read_half.read_encoded().await?);
+        let packet = St::parse_clientbound(encoded.to_packet()?, self.version)?;
+        if let Some(threshold) = St::compression(&packet) {
+            self.connection.enable_compression(threshold);
+        }
+        self.switching = St::clientbound_switch(&packet, self.version);
+        Ok(packet)
+    }
+}
+
+impl<St: ConnState, R, W> Conn<Client, St, R, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Writes a serverbound packet of the current state.
+    pub async fn write(&mut self, packet: &St::Serverbound<'_>) -> Result<(), ConnError> {
+        self.write_packet(packet).await?;
+        self.switching = St::serverbound_switch(packet, self.version);
+        Ok(())
+    }
+}
+
+impl<Side, R, W> Conn<Side, Login, R, W> {
+    /// Enables encryption in both directions using the shared secret.
+    pub fn enable_encryption(&mut self, secret: &[u8]) -> Result<(), InvalidLength> {
+        self.connection.enable_encryption(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miners_protocol::netty::login::{clientbound, serverbound};
+
+    fn handshake(version: i32) -> Handshake0<'static> {
+        Handshake0 {
+            protocol_version: version,
+            server_address: "localhost".into(),
+            server_port: 25565,
+            next_state: NextState0::Login,
+        }
+    }
+
+    async fn client_login<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+        connection: Connection<R, W>,
+        version: i32,
+    ) -> Result<Conn<Client, Login, R, W>, ConnError> {
+        match Conn::client(connection)
+            .handshake(&handshake(version))
+            .await?
+        {
+            Handshaked::Login(conn) => Ok(conn),
+            _ => panic!("the handshake asked for login"),
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn login() {
+        futures_lite::future::block_on(async {
+            let mut to_server = vec![];
+            let mut conn = client_login(Connection::unbuffered(&[][..], &mut to_server), 47)
+                .await
+                .unwrap();
+            let login_start = SbLogin::LoginStart0(serverbound::LoginStart0 {
+                username: "Notch".into(),
+            });
+            conn.write(&login_start).await.unwrap();
+            conn.flush().await.unwrap();
+            drop(conn);
+
+            let mut to_client = vec![];
+            let conn = Conn::server(Connection::unbuffered(&to_server[..], &mut to_client));
+            let (handshake, conn) = conn.read_handshake().await.unwrap();
+            assert_eq!(handshake.server_address, "localhost");
+            let Handshaked::Login(mut conn) = conn else {
+                panic!("the handshake asked for login")
+            };
+            let SbLogin::LoginStart0(login_start) = conn.read().await.unwrap() else {
+                panic!("expected login start")
+            };
+            assert_eq!(login_start.username, "Notch");
+            conn.write(&CbLogin::SetCompression27(clientbound::SetCompression27 {
+                threshold: 256,
+            }))
+            .await
+            .unwrap();
+            let success = CbLogin::Success0(clientbound::Success0 {
+                uuid: uuid::Uuid::nil().into(),
+                username: "Notch".into(),
+            });
+            conn.write(&success).await.unwrap();
+            conn.flush().await.unwrap();
+            assert_eq!(conn.switching(), Some(State::Play));
+            assert!(matches!(
+                conn.write(&success).await,
+                Err(ConnError::Switching(State::Play))
+            ));
+            let Err(SwitchError::NotSwitching(conn)) = conn.into_state::<Configuration>() else {
+                panic!("the connection is switching to play")
+            };
+            assert!(conn.into_state::<Play>().is_ok());
+
+            let mut conn = client_login(Connection::unbuffered(&to_client[..], vec![]), 47)
+                .await
+                .unwrap();
+            assert!(matches!(
+                conn.read().await.unwrap(),
+                CbLogin::SetCompression27(_)
+            ));
+            // read with compression enabled
+            assert!(matches!(conn.read().await.unwrap(), CbLogin::Success0(_)));
+            assert!(conn.into_state::<Play>().is_ok());
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn state_limits() {
+        futures_lite::future::block_on(async {
+            let version = ProtocolVersion::new(340).unwrap();
+            // larger than the 64 KiB allowed during login
+            let message = CbPlay::PluginMessage32(
+                miners_protocol::netty::play::clientbound::PluginMessage32 {
+                    channel: "large".into(),
+                    data: vec![0; 100 * 1024].into(),
+                },
+            );
+            let success = CbLogin::Success0(clientbound::Success0 {
+                uuid: uuid::Uuid::nil().into(),
+                username: "Notch".into(),
+            });
+
+            let mut to_client = vec![];
+            let mut conn = Conn::<Server, Handshaking, _, _>::with_state::<Login>(
+                Connection::unbuffered(&[][..], &mut to_client),
+                version,
+                State::Login,
+                StateLimits::default(),
+            );
+            conn.write(&success).await.unwrap();
+            let Ok(mut conn) = conn.into_state::<Play>() else {
+                panic!("the connection is switching to play")
+            };
+            conn.write(&message).await.unwrap();
+            conn.flush().await.unwrap();
+            drop(conn);
+
+            let mut conn = client_login(Connection::unbuffered(&to_client[..], vec![]), 340)
+                .await
+                .unwrap();
+            assert!(matches!(conn.read().await.unwrap(), CbLogin::Success0(_)));
+            let Ok(mut conn) = conn.into_state::<Play>() else {
+                panic!("the connection is switching to play")
+            };
+            assert!(matches!(
+                conn.read().await.unwrap(),
+                CbPlay::PluginMessage32(_)
+            ));
+
+            // the same frame is rejected before the switch to play
+            let mut frame = vec![];
+            let mut play = Conn::<Server, Handshaking, _, _>::with_state::<Play>(
+                Connection::unbuffered(&[][..], &mut frame),
+                version,
+                State::Play,
+                StateLimits::default(),
+            );
+            play.write(&message).await.unwrap();
+            play.flush().await.unwrap();
+            drop(play);
+            let mut login = Conn::<Client, Handshaking, _, _>::with_state::<Login>(
+                Connection::unbuffered(&frame[..], vec![]),
+                version,
+                State::Login,
+                StateLimits::default(),
+            );
+            assert!(matches!(
+                login.read().await,
+                Err(ConnError::Frame(FrameError::FrameTooLarge { .. }))
+            ));
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn unsupported_login() {
+        futures_lite::future::block_on(async {
+            let mut to_server = vec![];
+            let mut conn = Conn::client(Connection::unbuffered(&[][..], &mut to_server));
+            let encoded = (&handshake(9999))
+                .encode_packet(ProtocolVersion::latest())
+                .unwrap()
+                .unwrap();
+            conn.connection.write_half.write(encoded).await.unwrap();
+            conn.connection.write_half.flush().await.unwrap();
+            drop(conn);
+
+            let mut to_client = vec![];
+            let conn = Conn::server(Connection::unbuffered(&to_server[..], &mut to_client));
+            let (_, conn) = conn.read_handshake().await.unwrap();
+            let Handshaked::UnsupportedLogin(mut conn, e) = conn else {
+                panic!("the version isn't supported")
+            };
+            assert_eq!(e.inner(), 9999);
+            let disconnect = CbLogin::Disconnect0(clientbound::Disconnect0 {
+                reason: "{\"text\":\"unsupported version\"}".into(),
+            });
+            conn.write(&disconnect).await.unwrap();
+            conn.flush().await.unwrap();
+
+            let mut conn = client_login(Connection::unbuffered(&to_client[..], vec![]), 760)
+                .await
+                .unwrap();
+            assert!(matches!(
+                conn.read().await.unwrap(),
+                CbLogin::Disconnect0(_)
+            ));
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn legacy_ping_fallback() {
+        futures_lite::future::block_on(async {
+            // id, pv763 and the port take 6 bytes, the address 2 + 246
+            let handshake = Handshake0 {
+                server_address: "a".repeat(246).into(),
+                ..handshake(763)
+            };
+            let mut to_server = vec![];
+            let mut conn = Conn::client(Connection::unbuffered(&[][..], &mut to_server));
+            let encoded = (&handshake)
+                .encode_packet(ProtocolVersion::latest())
+                .unwrap()
+                .unwrap();
+            conn.connection.write_half.write(encoded).await.unwrap();
+            conn.connection.write_half.flush().await.unwrap();
+            drop(conn);
+            // a frame length of 254 starts like a 1.4 legacy ping
+            assert_eq!(to_server[..2], [0xFE, 0x01]);
+
+            let mut connection = Connection::unbuffered(&to_server[..], vec![]);
+            assert_eq!(
+                connection.read_half.sniff_legacy_ping().await.unwrap(),
+                None
+            );
+            let (read, _) = Conn::server(connection).read_handshake().await.unwrap();
+            assert_eq!(read.protocol_version, 763);
+            assert_eq!(read.server_address, handshake.server_address);
+            assert_eq!(read.server_port, 25565);
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn configuration() {
+        futures_lite::future::block_on(async {
+            let mut conn = client_login(Connection::unbuffered(&[][..], vec![]), 764)
+                .await
+                .unwrap();
+            let ack = SbLogin::LoginAcknowledged764(serverbound::LoginAcknowledged764 {});
+            conn.write(&ack).await.unwrap();
+            assert_eq!(conn.switching(), Some(State::Configuration));
+            let Ok(mut conn) = conn.into_state::<Configuration>() else {
+                panic!("the connection is switching to configuration")
+            };
+            assert_eq!(conn.switching(), None);
+            let finish = SbConfiguration::FinishConfiguration764(
+                miners_protocol::netty::configuration::serverbound::FinishConfiguration764 {},
+            );
+            conn.write(&finish).await.unwrap();
+            assert_eq!(conn.switching(), Some(State::Play));
+            // the play packets of 1.20.2 aren't mapped
+            let Err(SwitchError::Unmapped(mut conn)) = conn.into_state::<Play>() else {
+                panic!("play isn't mapped in 764")
+            };
+            let login_start = SbLogin::LoginStart0(serverbound::LoginStart0 {
+                username: "Notch".into(),
+            });
+            let mut login = client_login(Connection::unbuffered(&[][..], vec![]), 764)
+                .await
+                .unwrap();
+            // login start changed in 764
+            assert!(matches!(
+                login.write(&login_start).await,
+                Err(ConnError::UnsupportedPacket(_))
+            ));
+            conn.flush().await.unwrap();
+        });
+    }
+}
@@ -1,3 +1,8 @@
+//! Offloads encryption and decryption of large packets to other threads.
+//!
+//! Within a tokio runtime and with the `tokio` feature enabled, the work is
+//! done using `spawn_blocking`, otherwise in threads owned by this module.
+
 // TODO: Integrate BufGuard into this module?
 use std::{
     collections::VecDeque,
@@ -55,6 +60,7 @@ static DECRYPTION_MAX_THREADCOUNT: once_cell::sync::Lazy<usize> =
 static DECRYPTION_THREADCOUNT: AtomicUsize = AtomicUsize::new(0);
 static DECRYPTION_WORKTHREADS_CONDVAR: parking_lot::Condvar = parking_lot::Condvar::new();
 
+/*
 pub async fn request_encryption(
     buf: Vec<u8>,
     enc: Box<cfb8::Encryptor<aes::Aes128>>,
@@ -64,6 +70,7 @@ pub async fn request_encryption(
     // buffers length
     unsafe { request_partial_encryption(buf, len_from_end, enc).await }
 }
+*/
 
 /// # Safety
 ///
@@ -75,6 +82,20 @@ pub(crate) async unsafe fn request_partial_encryption(
 ) -> futures_channel::oneshot::Receiver<(Vec<u8>, Box<cfb8::Encryptor<aes::Aes128>>)> {
     let (send, recv) = futures_channel::oneshot::channel();
 
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.spawn_blocking(move || {
+            let (mut buf, mut enc) = (buf, enc);
+            let start = buf.len() - len_from_end;
+            encrypt(&mut buf[start..], &mut enc);
+            // the receiver is gone if the connection was dropped, if this
+            // task panics or the runtime shuts down the sender is dropped and
+            // the receiver is canceled
+            let _ = send.send((buf, enc));
+        });
+        return recv;
+    }
+
     let mut lock = ENCRYPTION_WORKQUEUE.lock();
     lock.push_back(((buf, len_from_end, enc), send));
     let len = lock.len();
@@ -106,13 +127,27 @@ pub async fn request_decryption(
 /// # Safety
 ///
 /// this is safe as long as len_from_end is not longer than buf.len()
-pub(crate) async unsafe fn request_partial_decryption(
+pub(crate) unsafe fn request_partial_decryption(
     buf: Vec<u8>,
     len_from_end: usize,
     dec: Box<cfb8::Decryptor<aes::Aes128>>,
 ) -> futures_channel::oneshot::Receiver<(Vec<u8>, Box<cfb8::Decryptor<aes::Aes128>>)> {
     let (send, recv) = futures_channel::oneshot::channel();
 
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.spawn_blocking(move || {
+            let (mut buf, mut dec) = (buf, dec);
+            let start = buf.len() - len_from_end;
+            decrypt(&mut buf[start..], &mut dec);
+            // the receiver is gone if the connection was dropped, if this
+            // task panics or the runtime shuts down the sender is dropped and
+            // the receiver is canceled
+            let _ = send.send((buf, dec));
+        });
+        return recv;
+    }
+
     let mut lock = DECRYPTION_WORKQUEUE.lock();
     lock.push_back(((buf, len_from_end, dec), send));
     let len = lock.len();
@@ -1,10 +1,12 @@
 use std::io::{self, Write};
 
-use futures_lite::{AsyncWrite, AsyncWriteExt};
+use futures_lite::AsyncWrite;
+use miners_util::bufpool::request_buf;
 
 use crate::{
     helpers::{encrypt, varint_slice},
     packing::PackedData,
+    poll::{self, Futures, PollWrite},
 };
 
 pub struct Writer<W> {
@@ -36,7 +38,47 @@ impl<W> Writer<W>
 where
     W: AsyncWrite + Unpin,
 {
-    async fn write_varint(&mut self, int: u32) -> io::Result<()> {
+    pub async fn write(&mut self, data: PackedData) -> io::Result<()> {
+        self.write_with::<Futures>(data).await
+    }
+    /// Writes a complete frame, including its length prefix, see
+    /// [`Broadcast`](crate::broadcast::Broadcast).
+    pub async fn write_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        self.write_frame_with::<Futures>(frame).await
+    }
+    /// Writes data as is, without a length prefix.
+    pub async fn write_raw(&mut self, data: &mut [u8]) -> io::Result<()> {
+        self.write_raw_with::<Futures>(data).await
+    }
+    pub async fn flush(&mut self) -> io::Result<()> {
+        poll::flush::<Futures, _>(&mut self.inner).await
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W> Writer<W>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    /// Tokio version of [`Writer::write`].
+    pub async fn twrite(&mut self, data: PackedData) -> io::Result<()> {
+        self.write_with::<poll::Tokio>(data).await
+    }
+    /// Tokio version of [`Writer::write_frame`].
+    pub async fn twrite_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        self.write_frame_with::<poll::Tokio>(frame).await
+    }
+    /// Tokio version of [`Writer::write_raw`].
+    pub async fn twrite_raw(&mut self, data: &mut [u8]) -> io::Result<()> {
+        self.write_raw_with::<poll::Tokio>(data).await
+    }
+    pub async fn tflush(&mut self) -> io::Result<()> {
+        poll::flush::<poll::Tokio, _>(&mut self.inner).await
+    }
+}
+
+impl<W: Unpin> Writer<W> {
+    async fn write_varint<I: PollWrite<W>>(&mut self, int: u32) -> io::Result<()> {
         let mut var_buf = [0u8; 5];
         let var_slice = varint_slice(int, &mut var_buf);
         if let Some(encryptor) = &mut self.encryptor {
@@ -44,37 +86,63 @@ where
             encrypt(var_slice, &mut encryptor);
             self.encryptor = Some(Some(encryptor))
         }
-        self.inner.write_all(&*var_slice).await
+        poll::write_all::<I, _>(&mut self.inner, var_slice).await
+    }
+    async fn write_with<I: PollWrite<W>>(&mut self, mut data: PackedData) -> io::Result<()> {
+        self.write_varint::<I>(data.len()).await?;
+        let start = data.1 as usize;
+        self.encrypt_from(&mut data.0, start).await?;
+        poll::write_all::<I, _>(&mut self.inner, data.get()).await
     }
-    pub async fn write<'packed>(&mut self, mut data: PackedData) -> io::Result<()> {
-        self.write_varint(data.len()).await?;
+    async fn write_frame_with<I: PollWrite<W>>(&mut self, frame: &[u8]) -> io::Result<()> {
+        if self.encryptor.is_none() {
+            return poll::write_all::<I, _>(&mut self.inner, frame).await;
+        }
+        // the frame is shared, so it's encrypted in a copy
+        let mut buf = request_buf(frame.len());
+        buf.clear();
+        buf.extend_from_slice(frame);
+        self.encrypt_from(&mut buf, 0).await?;
+        poll::write_all::<I, _>(&mut self.inner, &buf).await
+    }
+    /// Encrypts `buf[start..]` if encryption is enabled.
+    // the vec is handed to the workpool with the `workpool` feature
+    #[allow(clippy::ptr_arg)]
+    async fn encrypt_from(&mut self, buf: &mut Vec<u8>, start: usize) -> io::Result<()> {
         if let Some(encryptor) = &mut self.encryptor {
             let mut encryptor = encryptor.take().ok_or(crate::helpers::AsyncCancelled)?;
             #[cfg(feature = "workpool")]
-            let encryptor = if data.len() >= self.unblock_threshold {
+            let encryptor = if (buf.len() - start) as u32 >= self.unblock_threshold {
                 // TODO: Remove std::mem::take shenanigans as it is no longer necessary with the bufpool.
-                let taken_buf = std::mem::take(&mut data.0 as &mut Vec<u8>);
+                let taken_buf = std::mem::take(buf);
+                let len = taken_buf.len() - start;
 
-                let (taken_buf, mutated_encryptor) =
-                    crate::workpool::request_encryption(taken_buf, encryptor)
+                // SAFETY: len is at most the length of the buffer
+                let (taken_buf, mutated_encryptor) = unsafe {
+                    crate::workpool::request_partial_encryption(taken_buf, len, encryptor)
                         .await
                         .await
-                        .expect("encryption task was terminated?");
-                *data.0 = taken_buf;
+                        .map_err(|_| io::Error::other("the encryption task was dropped"))?
+                };
+                *buf = taken_buf;
                 mutated_encryptor
             } else {
-                encrypt(data.get_mut(), &mut encryptor);
+                encrypt(&mut buf[start..], &mut encryptor);
                 encryptor
             };
             #[cfg(not(feature = "workpool"))]
-            encrypt(data.get_mut(), &mut encryptor);
+            encrypt(&mut buf[start..], &mut encryptor);
             self.encryptor = Some(Some(encryptor));
         }
-        self.inner.write_all(data.get()).await?;
         Ok(())
     }
-    pub async fn flush(&mut self) -> io::Result<()> {
-        self.inner.flush().await
+    async fn write_raw_with<I: PollWrite<W>>(&mut self, data: &mut [u8]) -> io::Result<()> {
+        if let Some(encryptor) = &mut self.encryptor {
+            let mut encryptor = encryptor.take().ok_or(crate::helpers::AsyncCancelled)?;
+            encrypt(data, &mut encryptor);
+            self.encryptor = Some(Some(encryptor));
+        }
+        poll::write_all::<I, _>(&mut self.inner, data).await
     }
 }
 
@@ -103,6 +171,18 @@ where
         self.inner.write_all(data.get())?;
         Ok(())
     }
+    /// Blocking version of [`Writer::write_frame`].
+    pub fn swrite_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        let Some(encryptor) = &mut self.encryptor else {
+            return self.inner.write_all(frame);
+        };
+        let encryptor = encryptor.as_mut().ok_or(crate::helpers::AsyncCancelled)?;
+        let mut buf = request_buf(frame.len());
+        buf.clear();
+        buf.extend_from_slice(frame);
+        encrypt(&mut buf, encryptor);
+        self.inner.write_all(&buf)
+    }
     pub fn sflush(&mut self) -> io::Result<()> {
         self.inner.flush()
     }
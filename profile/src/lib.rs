@@ -0,0 +1,231 @@
+//! The game profile of a player, as sent by the session server and in
+//! packets like login success and player list item.
+
+use {
+    md5::{Digest, Md5},
+    serde_derive::{Deserialize, Serialize},
+    uuid::Uuid,
+};
+
+/// The uuid, name and properties of a player.
+///
+/// Serializes to and from the json returned by the session server, where the
+/// uuid is written without hyphens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameProfile {
+    #[serde(rename = "id", with = "simple_uuid")]
+    pub uuid: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+}
+
+/// A property of a profile like `textures`. Properties returned by the
+/// session server are signed by Mojang.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Property {
+    pub name: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl GameProfile {
+    pub fn new(uuid: Uuid, name: impl Into<String>) -> Self {
+        Self {
+            uuid,
+            name: name.into(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// The profile vanilla servers in offline mode give to a player, with the
+    /// uuid generated by [`offline_uuid`] and no properties.
+    pub fn offline(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self::new(offline_uuid(&name), name)
+    }
+
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+
+    /// Decodes the `textures` property, returns `None` if it isn't present.
+    pub fn textures(&self) -> Result<Option<Textures>, TexturesError> {
+        self.property("textures")
+            .map(|property| Textures::decode(&property.value))
+            .transpose()
+    }
+}
+
+/// Generates the uuid of a player in offline mode, a name based (version 3)
+/// uuid of `OfflinePlayer:<name>`, the same way java's
+/// `UUID.nameUUIDFromBytes` does.
+pub fn offline_uuid(name: &str) -> Uuid {
+    let digest = Md5::digest(format!("OfflinePlayer:{name}").as_bytes());
+    uuid::Builder::from_md5_bytes(digest.into()).into_uuid()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TexturesError {
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// The skin and cape of a player.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Textures {
+    /// Milliseconds since the unix epoch when the property was created
+    pub timestamp: i64,
+    pub skin: Option<Skin>,
+    /// The url of the cape texture
+    pub cape: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Skin {
+    /// The url of the skin texture
+    pub url: String,
+    pub model: SkinModel,
+}
+
+/// The arm width of a skin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SkinModel {
+    /// 4 pixel wide arms, also known as Steve
+    #[default]
+    Classic,
+    /// 3 pixel wide arms, also known as Alex
+    Slim,
+}
+
+impl Textures {
+    /// Decodes the base64 encoded json value of the `textures` property.
+    pub fn decode(value: &str) -> Result<Self, TexturesError> {
+        let json = base64::decode(value)?;
+        let payload: TexturesPayload = serde_json::from_slice(&json)?;
+        Ok(Self {
+            timestamp: payload.timestamp,
+            skin: payload.textures.skin.map(|skin| Skin {
+                url: skin.url,
+                model: match skin.metadata {
+                    Some(TextureMetadata { model: Some(model) }) if model == "slim" => {
+                        SkinModel::Slim
+                    }
+                    _ => SkinModel::Classic,
+                },
+            }),
+            cape: payload.textures.cape.map(|cape| cape.url),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct TexturesPayload {
+    #[serde(default)]
+    timestamp: i64,
+    #[serde(default)]
+    textures: TextureUrls,
+}
+
+#[derive(Default, Deserialize)]
+struct TextureUrls {
+    #[serde(rename = "SKIN")]
+    skin: Option<Texture>,
+    #[serde(rename = "CAPE")]
+    cape: Option<Texture>,
+}
+
+#[derive(Deserialize)]
+struct Texture {
+    url: String,
+    metadata: Option<TextureMetadata>,
+}
+
+#[derive(Deserialize)]
+struct TextureMetadata {
+    model: Option<String>,
+}
+
+mod simple_uuid {
+    use {
+        serde::{de::Error, Deserialize, Deserializer, Serializer},
+        uuid::Uuid,
+    };
+
+    pub(super) fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&uuid.simple())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uuid, D::Error> {
+        // accepts both the simple and the hyphenated form
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Uuid::parse_str(&s).map_err(D::Error::custom)
+    }
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn offline() {
+    let profile = GameProfile::offline("Notch");
+    assert_eq!(
+        profile.uuid,
+        Uuid::parse_str("b50ad385-829d-3141-a216-7e7d7539ba7f").unwrap()
+    );
+    assert_eq!(profile.uuid.get_version_num(), 3);
+    assert_eq!(profile.name, "Notch");
+    assert!(profile.textures().unwrap().is_none());
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn textures() {
+    let json = r#"{
+        "id": "069a79f444e94726a5befca90e38aaf5",
+        "name": "Notch",
+        "properties": [{ "name": "textures", "value": "VALUE", "signature": "c2ln" }]
+    }"#;
+    let textures = r#"{
+        "timestamp": 1683500000000,
+        "profileId": "069a79f444e94726a5befca90e38aaf5",
+        "profileName": "Notch",
+        "textures": {
+            "SKIN": {
+                "url": "http://textures.minecraft.net/texture/skin",
+                "metadata": { "model": "slim" }
+            },
+            "CAPE": { "url": "http://textures.minecraft.net/texture/cape" }
+        }
+    }"#;
+    let json = json.replace("VALUE", &base64::encode(textures));
+
+    let profile: GameProfile = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        profile.uuid,
+        Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap()
+    );
+    assert_eq!(
+        profile.textures().unwrap().unwrap(),
+        Textures {
+            timestamp: 1683500000000,
+            skin: Some(Skin {
+                url: "http://textures.minecraft.net/texture/skin".into(),
+                model: SkinModel::Slim,
+            }),
+            cape: Some("http://textures.minecraft.net/texture/cape".into()),
+        }
+    );
+
+    let serialized = serde_json::to_value(&profile).unwrap();
+    assert_eq!(serialized["id"], "069a79f444e94726a5befca90e38aaf5");
+    assert_eq!(
+        serde_json::from_value::<GameProfile>(serialized).unwrap(),
+        profile
+    );
+}
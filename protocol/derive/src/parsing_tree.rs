@@ -160,9 +160,12 @@ pub fn parsing_tree(x: ParsingTreeInput) -> TokenStream {
                     })
                 })
         }
-        let ltsiter = ltiter(&path.path);
+        let mut lts: Vec<_> = ltiter(&path.path).collect();
+        // the same lifetime can be used by multiple generic arguments
+        lts.sort_by_key(|lt| lt.ident.to_string());
+        lts.dedup_by_key(|lt| lt.ident.to_string());
         let mut generics = quote! {};
-        for lts in ltsiter {
+        for lts in lts {
             quote! {#lts,}.to_tokens(&mut generics);
         }
         let mut match_body = quote! {};
@@ -1,6 +1,19 @@
 pub mod types;
 
+pub mod configuration;
 pub mod handshaking;
 pub mod login;
 pub mod play;
 pub mod status;
+pub mod translate;
+
+/// The state of a connection, which decides the packets that can be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Handshaking,
+    Status,
+    Login,
+    /// Only entered since 1.20.2 (pv764)
+    Configuration,
+    Play,
+}
@@ -0,0 +1,175 @@
+//! The configuration state between login and play, since 1.20.2 (pv764).
+//!
+//! The client enters it after acknowledging the login success and leaves it
+//! after acknowledging the finish configuration packet of the server. In the
+//! meantime the server sends the registries, feature flags and tags.
+
+use miners_version::ProtocolVersion;
+
+use super::State;
+use crate::*;
+
+pub mod clientbound;
+pub mod serverbound;
+
+parsing_tree! {
+    configuration_cb_custom configuration_cb_tree crate::netty::configuration::clientbound::;
+    0x00 => {
+        764 => PluginMessage764<'a>,
+    },
+    0x01 => {
+        764 => Disconnect764<'a>,
+    },
+    0x02 => {
+        764 => FinishConfiguration764,
+    },
+    0x03 => {
+        764 => KeepAlive764,
+    },
+    0x04 => {
+        764 => Ping764,
+    },
+    0x05 => {
+        764 => RegistryData764<'a>,
+    },
+    0x06 => {
+        764 => ResourcePack764<'a>,
+    },
+    0x07 => {
+        764 => FeatureFlags764<'a>,
+    },
+    0x08 => {
+        764 => UpdateTags764<'a>,
+    }
+}
+configuration_cb_custom! {
+    pub enum CbConfiguration<'a> {
+        #(#PacketName(#PacketTypeLt),)
+    }
+    impl<'a> Packet for CbConfiguration<'a> {
+        fn id_for_version(&self, version: miners_version::ProtocolVersion) -> Option<i32> {
+            match self {#(Self::#PacketName(#packet_name) => #packet_name.id_for_version(version),)}
+        }
+        fn encode_for_version(
+            &self,
+            version: miners_version::ProtocolVersion,
+            writer: &mut impl std::io::Write,
+        ) -> Option<encode::Result<()>> {
+            match self {#(Self::#PacketName(#packet_name) => #packet_name.encode_for_version(version, writer),)}
+        }
+    }
+}
+impl<'a> CbConfiguration<'a> {
+    pub fn parse(packet: RawPacket<'a>, version: ProtocolVersion) -> Result<Self, decode::Error> {
+        let (id, data): (i32, &[u8]) = packet.into();
+        let mut cursor = std::io::Cursor::new(data);
+        let pv = *version;
+        configuration_cb_tree! {
+            id, pv,
+            {<#PacketTypeLt as Decode>::decode(&mut cursor).map(CbConfiguration::#PacketName)},
+            {Err(decode::Error::InvalidId)}
+        }
+    }
+
+    /// The state of the following clientbound packets if it changes after
+    /// this one.
+    pub fn next_state(&self) -> Option<State> {
+        match self {
+            Self::FinishConfiguration764(_) => Some(State::Play),
+            _ => None,
+        }
+    }
+}
+
+parsing_tree! {
+    configuration_sb_custom configuration_sb_tree crate::netty::configuration::serverbound::;
+    0x00 => {
+        764 => ClientInformation764<'a>,
+    },
+    0x01 => {
+        764 => PluginMessage764<'a>,
+    },
+    0x02 => {
+        764 => FinishConfiguration764,
+    },
+    0x03 => {
+        764 => KeepAlive764,
+    },
+    0x04 => {
+        764 => Pong764,
+    },
+    0x05 => {
+        764 => ResourcePackResponse764,
+    }
+}
+configuration_sb_custom! {
+    pub enum SbConfiguration<'a> {
+        #(#PacketName(#PacketTypeLt),)
+    }
+    impl<'a> Packet for SbConfiguration<'a> {
+        fn id_for_version(&self, version: miners_version::ProtocolVersion) -> Option<i32> {
+            match self {#(Self::#PacketName(#packet_name) => #packet_name.id_for_version(version),)}
+        }
+        fn encode_for_version(
+            &self,
+            version: miners_version::ProtocolVersion,
+            writer: &mut impl std::io::Write,
+        ) -> Option<encode::Result<()>> {
+            match self {#(Self::#PacketName(#packet_name) => #packet_name.encode_for_version(version, writer),)}
+        }
+    }
+}
+impl<'a> SbConfiguration<'a> {
+    pub fn parse(packet: RawPacket<'a>, version: ProtocolVersion) -> Result<Self, decode::Error> {
+        let (id, data): (i32, &[u8]) = packet.into();
+        let mut cursor = std::io::Cursor::new(data);
+        let pv = *version;
+        configuration_sb_tree! {
+            id, pv,
+            {<#PacketTypeLt as Decode>::decode(&mut cursor).map(SbConfiguration::#PacketName)},
+            {Err(decode::Error::InvalidId)}
+        }
+    }
+
+    /// The state of the following serverbound packets if it changes after
+    /// this one.
+    pub fn next_state(&self) -> Option<State> {
+        match self {
+            Self::FinishConfiguration764(_) => Some(State::Play),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn configuration() {
+    let pv = ProtocolVersion::new(764).unwrap();
+    // a nameless compound with the byte "a" set to 1
+    let data = [0x0a, 0x01, 0x00, 0x01, b'a', 0x01, 0x00];
+    let packet = CbConfiguration::parse(RawPacket::from((0x05, &data[..])), pv).unwrap();
+    let CbConfiguration::RegistryData764(registry_data) = &packet else {
+        panic!("expected registry data");
+    };
+    assert_eq!(
+        registry_data.registry_codec.get("a"),
+        Some(&miners_nbt::Value::Byte(1))
+    );
+    let mut encoded = vec![];
+    packet
+        .encode_for_version(pv, &mut encoded)
+        .unwrap()
+        .unwrap();
+    // the packet id is encoded in front of the data
+    assert_eq!(encoded[0], 0x05);
+    assert_eq!(encoded[1..], data);
+    assert_eq!(packet.next_state(), None);
+
+    let packet = SbConfiguration::parse(RawPacket::from((0x02, &[][..])), pv).unwrap();
+    assert_eq!(packet.next_state(), Some(State::Play));
+    assert!(CbConfiguration::parse(
+        RawPacket::from((0x02, &[][..])),
+        ProtocolVersion::new(760).unwrap()
+    )
+    .is_err());
+}
@@ -0,0 +1,65 @@
+use std::borrow::Cow;
+
+use miners_nbt::NetworkNbt;
+
+use crate::netty::play::clientbound::RegistryTags755;
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PluginMessage764<'a> {
+    pub channel: Cow<'a, str>,
+    #[encoding(rest)]
+    pub data: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Disconnect764<'a> {
+    // chat
+    pub reason: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Ends the configuration, the client answers with a
+/// [`serverbound::FinishConfiguration764`][ack] and switches to play.
+///
+/// [ack]: super::serverbound::FinishConfiguration764
+pub struct FinishConfiguration764 {}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct KeepAlive764 {
+    pub id: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Ping764 {
+    pub id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// The registries the client has to know about, like the dimension types and
+/// biomes.
+pub struct RegistryData764<'a> {
+    pub registry_codec: NetworkNbt<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ResourcePack764<'a> {
+    pub url: Cow<'a, str>,
+    /// A 40 character hexadecimal and lowercase SHA-1 hash of the resource
+    /// pack file.
+    pub hash: Cow<'a, str>,
+    /// The client disconnects if it declines a forced resource pack
+    pub forced: bool,
+    // chat
+    pub prompt: Option<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct FeatureFlags764<'a> {
+    /// Identifiers of the enabled feature flags, like minecraft:vanilla
+    pub flags: Vec<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateTags764<'a> {
+    pub registries: Vec<RegistryTags755<'a>>,
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use crate::netty::play::serverbound::{
+    ChatMode80, DisplayedSkinParts6, MainHand80, ResourcePackStatusResult32,
+};
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ClientInformation764<'a> {
+    pub locale: Cow<'a, str>,
+    /// Client-side render distance, in chunks
+    pub view_distance: u8,
+    pub chat_mode: ChatMode80,
+    pub chat_colors: bool,
+    pub displayed_skin_parts: DisplayedSkinParts6,
+    pub main_hand: MainHand80,
+    pub disable_text_filtering: bool,
+    /// Whether the player is shown in the player sample of the server list
+    pub allow_server_listings: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PluginMessage764<'a> {
+    pub channel: Cow<'a, str>,
+    #[encoding(rest)]
+    pub data: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Acknowledges the
+/// [`clientbound::FinishConfiguration764`][finish], packets after this one are
+/// in the play state.
+///
+/// [finish]: super::clientbound::FinishConfiguration764
+pub struct FinishConfiguration764 {}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct KeepAlive764 {
+    pub id: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Pong764 {
+    pub id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ResourcePackResponse764 {
+    pub result: ResourcePackStatusResult32,
+}
@@ -1,12 +1,14 @@
 use miners_version::ProtocolVersion;
 
+use super::State;
 use crate::*;
+pub mod bungee;
 pub mod serverbound;
 
 parsing_tree! {
     handshaking_sb_custom handshaking_sb_tree crate::netty::handshaking::serverbound::;
     0x00 => {
-        0..=760 => Handshake0::<'a>,
+        0..=764 => Handshake0::<'a>,
     }
 }
 handshaking_sb_custom! {
@@ -38,4 +40,14 @@ impl<'a> SbHandshaking<'a> {
             {Err(decode::Error::InvalidId)}
         }
     }
+
+    /// The state of the following serverbound packets.
+    pub fn next_state(&self) -> State {
+        match self {
+            Self::Handshake0(handshake) => match handshake.next_state {
+                serverbound::NextState0::Status => State::Status,
+                serverbound::NextState0::Login => State::Login,
+            },
+        }
+    }
 }
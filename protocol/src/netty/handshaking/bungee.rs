@@ -0,0 +1,169 @@
+//! BungeeCord's legacy ip forwarding.
+//!
+//! Behind BungeeCord the `server_address` of [`Handshake0`] is replaced with
+//! `host\0ip\0uuid` followed by `\0properties` if the player has any, where
+//! the uuid has no dashes and the properties are a json array of the
+//! properties of the player's game profile.
+
+use std::borrow::Cow;
+use std::net::IpAddr;
+
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::serverbound::Handshake0;
+use crate::netty::play::clientbound::PlayerProperty19;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ForwardingError {
+    /// The server address doesn't contain any forwarded data, most likely the
+    /// player connected directly or forwarding is disabled on the proxy.
+    #[error("the proxy didn't forward the player info")]
+    NotForwarded,
+    #[error("the forwarded data has {0} parts instead of 3 or 4")]
+    InvalidPartCount(usize),
+    #[error("the forwarded address is invalid")]
+    InvalidAddress,
+    #[error("the forwarded uuid is invalid")]
+    InvalidUuid,
+    #[error("the forwarded properties are invalid")]
+    InvalidProperties,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct BungeeForwarding<'a> {
+    /// The address the player connected to the proxy with
+    pub host: Cow<'a, str>,
+    /// The address the player connected to the proxy from
+    pub address: IpAddr,
+    pub uuid: Uuid,
+    /// The properties of the player's game profile, like its textures
+    pub properties: Vec<PlayerProperty19<'a>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonProperty<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    value: Cow<'a, str>,
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    signature: Option<Cow<'a, str>>,
+}
+
+impl<'a> BungeeForwarding<'a> {
+    /// Parses the forwarded data out of a server address.
+    pub fn parse(server_address: &'a str) -> Result<Self, ForwardingError> {
+        if !server_address.contains('\0') {
+            return Err(ForwardingError::NotForwarded);
+        }
+        let parts: Vec<_> = server_address.split('\0').collect();
+        let (host, address, uuid, properties) = match parts[..] {
+            [host, address, uuid] => (host, address, uuid, None),
+            [host, address, uuid, properties] => (host, address, uuid, Some(properties)),
+            _ => return Err(ForwardingError::InvalidPartCount(parts.len())),
+        };
+        let address = address
+            .parse()
+            .map_err(|_| ForwardingError::InvalidAddress)?;
+        let uuid = Uuid::try_parse(uuid).map_err(|_| ForwardingError::InvalidUuid)?;
+        let properties = match properties {
+            Some(properties) => serde_json::from_str::<Vec<JsonProperty>>(properties)
+                .map_err(|_| ForwardingError::InvalidProperties)?
+                .into_iter()
+                .map(|property| PlayerProperty19 {
+                    name: property.name,
+                    value: property.value,
+                    signature: property.signature,
+                })
+                .collect(),
+            None => vec![],
+        };
+        Ok(Self {
+            host: Cow::Borrowed(host),
+            address,
+            uuid,
+            properties,
+        })
+    }
+
+    /// Creates the server address the proxy would send for this player.
+    pub fn to_server_address(&self) -> String {
+        let mut server_address =
+            format!("{}\0{}\0{}", self.host, self.address, self.uuid.as_simple());
+        if !self.properties.is_empty() {
+            let properties: Vec<_> = self
+                .properties
+                .iter()
+                .map(|property| JsonProperty {
+                    name: Cow::Borrowed(&property.name),
+                    value: Cow::Borrowed(&property.value),
+                    signature: property.signature.as_deref().map(Cow::Borrowed),
+                })
+                .collect();
+            server_address.push('\0');
+            // serializing strings to json can't fail
+            server_address.push_str(&serde_json::to_string(&properties).unwrap_or_default());
+        }
+        server_address
+    }
+}
+
+impl<'a> Handshake0<'a> {
+    /// Parses the player info BungeeCord forwarded in the server address.
+    pub fn bungee_forwarding(&self) -> Result<BungeeForwarding<'_>, ForwardingError> {
+        BungeeForwarding::parse(&self.server_address)
+    }
+
+    /// Replaces the server address with the forwarded player info the way
+    /// BungeeCord does.
+    pub fn set_bungee_forwarding(&mut self, forwarding: &BungeeForwarding) {
+        self.server_address = Cow::Owned(forwarding.to_server_address());
+    }
+}
+
+#[test]
+fn bungee_forwarding() {
+    let server_address = "mc.example.com\u{0}127.0.0.1\u{0}069a79f444e94726a5befca90e38aaf5\u{0}\
+        [{\"name\":\"textures\",\"value\":\"e30=\",\"signature\":\"c2ln\"}]";
+    #[allow(clippy::unwrap_used)]
+    let forwarding = BungeeForwarding::parse(server_address).unwrap();
+    assert_eq!(forwarding.host, "mc.example.com");
+    assert_eq!(forwarding.address, IpAddr::from([127, 0, 0, 1]));
+    assert_eq!(
+        forwarding.uuid,
+        Uuid::from_u128(0x069a79f444e94726a5befca90e38aaf5)
+    );
+    assert_eq!(forwarding.properties.len(), 1);
+    assert_eq!(forwarding.properties[0].name, "textures");
+    assert_eq!(forwarding.properties[0].signature.as_deref(), Some("c2ln"));
+    assert_eq!(forwarding.to_server_address(), server_address);
+
+    let server_address = "::1\u{0}::1\u{0}069a79f4-44e9-4726-a5be-fca90e38aaf5";
+    #[allow(clippy::unwrap_used)]
+    let forwarding = BungeeForwarding::parse(server_address).unwrap();
+    assert!(forwarding.properties.is_empty());
+
+    assert!(matches!(
+        BungeeForwarding::parse("mc.example.com"),
+        Err(ForwardingError::NotForwarded)
+    ));
+    assert!(matches!(
+        BungeeForwarding::parse("mc.example.com\u{0}FML\u{0}"),
+        Err(ForwardingError::InvalidAddress)
+    ));
+    assert!(matches!(
+        BungeeForwarding::parse("mc.example.com\u{0}127.0.0.1"),
+        Err(ForwardingError::InvalidPartCount(2))
+    ));
+    assert!(matches!(
+        BungeeForwarding::parse("mc.example.com\u{0}127.0.0.1\u{0}notch"),
+        Err(ForwardingError::InvalidUuid)
+    ));
+    assert!(matches!(
+        BungeeForwarding::parse(
+            "mc.example.com\u{0}127.0.0.1\u{0}069a79f444e94726a5befca90e38aaf5\u{0}{}"
+        ),
+        Err(ForwardingError::InvalidProperties)
+    ));
+}
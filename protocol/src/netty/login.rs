@@ -1,21 +1,23 @@
-use miners_version::ProtocolVersion;
+use miners_version::{Feature, ProtocolVersion};
 
+use super::State;
 use crate::*;
 
 pub mod clientbound;
 pub mod serverbound;
+pub mod velocity;
 
 parsing_tree! {
     login_cb_custom login_cb_tree crate::netty::login::clientbound::;
     0x00 => {
         0..=12 => Disconnect0<'a>,
         13..=384 => Disconnect0<'a>,
-        // 385..=390 => _385,
+        385..=764 => Disconnect0<'a>,
     },
     0x01 => {
         0..=18 => EncryptionRequest0<'a>, //EncryptionResponse0<'a>,
         19..=384 => EncryptionRequest19<'a>, //EncryptionResponse19<'a>,
-        // 385..=390 => _385,
+        385..=764 => EncryptionRequest19<'a>,
     },
     0x02 => {
         0..=384 => Success0<'a>,
@@ -23,20 +25,17 @@ parsing_tree! {
         //5 => Success5<'a>,
         //6..=13 => Success0<'a>,
         //14..=384 => Success5<'a>,
-        // 385..=390 => _385,
-        // 391..=706 => _391,
-        // 707..=758 => _707,
-        // 759..=760 => _759,
+        385..=706 => Success0<'a>,
+        707..=758 => Success707<'a>,
         // 1073741825..=1073741905 => _1073741825,
+        759..=764 => Success759<'a>,
     },
     0x03 => {
         27..=384 => SetCompression27,
-        // 385..=390 => _385,
-        // 391..=760 => _391,
+        385..=764 => SetCompression27,
     },
     0x04 => {
-        // 385..=390 => _385,
-        // 391..=760 => _391,
+        385..=764 => LoginPluginRequest385<'a>,
     }
 }
 login_cb_custom! {
@@ -67,33 +66,52 @@ impl<'a> CbLogin<'a> {
             {Err(decode::Error::InvalidId)}
         }
     }
+
+    /// The state of the following clientbound packets if it changes after
+    /// this one. Since 1.20.2 (pv764) the login success is acknowledged
+    /// before the serverbound packets switch to configuration, before that
+    /// they switch to play right after it was sent.
+    pub fn next_state(&self, version: ProtocolVersion) -> Option<State> {
+        match self {
+            Self::Success0(_) | Self::Success707(_) | Self::Success759(_) => {
+                Some(if version.supports(Feature::Configuration) {
+                    State::Configuration
+                } else {
+                    State::Play
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
 parsing_tree! {
     login_sb_custom login_sb_tree crate::netty::login::serverbound::;
     0x00 => {
         0..=384 => LoginStart0<'a>,
-        // 385..=390 => _385,
-        // 391..=758 => _391,
-        // 759 => _759,
-        // 760 => _760,
+        385..=758 => LoginStart0<'a>,
+        759 => LoginStart759<'a>,
+        760 => LoginStart760<'a>,
         // 1073741825..=1073741905 => _1073741825,
         // 1073741906..=1073741907 => _1073741906,
         // 1073741908..=1073741918 => _1073741908,
+        761..=763 => LoginStart761<'a>,
+        764 => LoginStart764<'a>,
     },
     0x01 => {
         0..=18 => EncryptionResponse0<'a>,
         19..=384 => EncryptionResponse19<'a>,
-        // 385..=390 => _385,
-        // 391..=758 => _391,
-        // 759..=760 => _759,
+        385..=758 => EncryptionResponse19<'a>,
+        759..=760 => EncryptionResponse759<'a>,
         // 1073741825..=1073741905 => _1073741825,
+        761..=764 => EncryptionResponse19<'a>,
     },
     0x02 => {
-        // 385..=390 => _385,
-        // 391..=758 => _391,
-        // 759..=760 => _759,
+        385..=764 => LoginPluginResponse385<'a>,
         // 1073741825..=1073741906 => _1073741825,
+    },
+    0x03 => {
+        764 => LoginAcknowledged764,
     }
 }
 login_sb_custom! {
@@ -124,4 +142,99 @@ impl<'a> SbLogin<'a> {
             {Err(decode::Error::InvalidId)}
         }
     }
+
+    /// The state of the following serverbound packets if it changes after
+    /// this one.
+    pub fn next_state(&self) -> Option<State> {
+        match self {
+            Self::LoginAcknowledged764(_) => Some(State::Configuration),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn login_state() {
+    let success = CbLogin::Success0(clientbound::Success0 {
+        uuid: uuid::Uuid::nil().into(),
+        username: "Notch".into(),
+    });
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    assert_eq!(success.next_state(pv(340)), Some(State::Play));
+    assert_eq!(success.next_state(pv(764)), Some(State::Configuration));
+
+    let ack = SbLogin::parse(RawPacket::from((0x03, &[][..])), pv(764)).unwrap();
+    assert_eq!(ack.next_state(), Some(State::Configuration));
+    assert!(SbLogin::parse(RawPacket::from((0x03, &[][..])), pv(763)).is_err());
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn login_ranges() {
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    // a disconnect with the reason "a"
+    let data = [0x01, b'a'];
+    for version in [404, 578, 754, 760] {
+        let packet = CbLogin::parse(RawPacket::from((0x00, &data[..])), pv(version)).unwrap();
+        assert!(matches!(packet, CbLogin::Disconnect0(_)));
+    }
+    let uuid = uuid::Uuid::nil();
+    let mut data = vec![];
+    clientbound::Success707 {
+        uuid,
+        username: "Notch".into(),
+    }
+    .encode(&mut data)
+    .unwrap();
+    let packet = CbLogin::parse(RawPacket::from((0x02, &data[..])), pv(754)).unwrap();
+    assert!(matches!(packet, CbLogin::Success707(_)));
+    assert_eq!(packet.next_state(pv(754)), Some(State::Play));
+
+    let response = SbLogin::EncryptionResponse759(serverbound::EncryptionResponse759 {
+        secret: std::borrow::Cow::Borrowed(&[1, 2]),
+        verification: serverbound::Verification759::Signature {
+            salt: 3,
+            signature: std::borrow::Cow::Borrowed(&[4]),
+        },
+    });
+    let mut data = vec![];
+    response
+        .encode_for_version(pv(760), &mut data)
+        .unwrap()
+        .unwrap();
+    let packet = SbLogin::parse(RawPacket::from((data[0] as i32, &data[1..])), pv(760)).unwrap();
+    let SbLogin::EncryptionResponse759(response) = packet else {
+        panic!("expected an encryption response");
+    };
+    assert_eq!(response.secret[..], [1, 2]);
+    assert!(matches!(
+        response.verification,
+        serverbound::Verification759::Signature { salt: 3, .. }
+    ));
+    assert!(response.encode_for_version(pv(758), &mut vec![]).is_none());
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn success_profile() {
+    let mut profile = miners_profile::GameProfile::offline("Notch");
+    profile.properties.push(miners_profile::Property {
+        name: "textures".into(),
+        value: "e30=".into(),
+        signature: None,
+    });
+
+    let mut data = Vec::new();
+    clientbound::Success759::from(&profile)
+        .encode(&mut data)
+        .unwrap();
+    let decoded = clientbound::Success759::decode(&mut std::io::Cursor::new(&data[..])).unwrap();
+    assert_eq!(decoded.profile(), profile);
+
+    let success = clientbound::Success0::from(&profile);
+    assert_eq!(
+        success.profile().unwrap(),
+        miners_profile::GameProfile::offline("Notch")
+    );
 }
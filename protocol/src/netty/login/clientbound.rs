@@ -3,6 +3,9 @@ use ::miners_encoding::{attrs::StringUuid, decode, encode, Decode, Encode};
 use std::{borrow::Cow, str::FromStr};
 use uuid::Uuid;
 
+use crate::netty::play::clientbound::PlayerProperty19;
+use miners_profile::{GameProfile, Property};
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Disconnect0<'a> {
     // chat most likely, for sure starting pv13
@@ -65,8 +68,104 @@ impl<'a> Encode for Success5<'a> {
     }
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Success707<'a> {
+    pub uuid: Uuid,
+    pub username: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Success759<'a> {
+    pub uuid: Uuid,
+    pub username: Cow<'a, str>,
+    /// The properties of the player's game profile, like its textures
+    pub properties: Vec<PlayerProperty19<'a>>,
+}
+
+impl<'a> From<&'a GameProfile> for Success0<'a> {
+    fn from(profile: &'a GameProfile) -> Self {
+        Self {
+            uuid: profile.uuid.into(),
+            username: Cow::Borrowed(&profile.name),
+        }
+    }
+}
+
+impl Success0<'_> {
+    /// The profile of the player, `None` if the uuid is empty.
+    pub fn profile(&self) -> Option<GameProfile> {
+        let uuid = self.uuid.clone().into_inner()?;
+        Some(GameProfile::new(uuid, self.username.to_string()))
+    }
+}
+
+impl<'a> From<&'a GameProfile> for Success5<'a> {
+    fn from(profile: &'a GameProfile) -> Self {
+        Self {
+            uuid: Some(profile.uuid),
+            username: Cow::Borrowed(&profile.name),
+        }
+    }
+}
+
+impl Success5<'_> {
+    /// The profile of the player, `None` if the uuid is empty.
+    pub fn profile(&self) -> Option<GameProfile> {
+        Some(GameProfile::new(self.uuid?, self.username.to_string()))
+    }
+}
+
+impl<'a> From<&'a GameProfile> for Success707<'a> {
+    fn from(profile: &'a GameProfile) -> Self {
+        Self {
+            uuid: profile.uuid,
+            username: Cow::Borrowed(&profile.name),
+        }
+    }
+}
+
+impl Success707<'_> {
+    pub fn profile(&self) -> GameProfile {
+        GameProfile::new(self.uuid, self.username.to_string())
+    }
+}
+
+impl<'a> From<&'a GameProfile> for Success759<'a> {
+    fn from(profile: &'a GameProfile) -> Self {
+        Self {
+            uuid: profile.uuid,
+            username: Cow::Borrowed(&profile.name),
+            properties: profile.properties.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Success759<'_> {
+    pub fn profile(&self) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            name: self.username.to_string(),
+            properties: self.properties.iter().map(Property::from).collect(),
+        }
+    }
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct SetCompression27 {
     #[encoding(varint)]
     pub threshold: i32,
 }
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Asks the client for custom data during login, the client answers with a
+/// [`serverbound::LoginPluginResponse385`][resp] carrying the same
+/// `message_id`. Vanilla clients always answer unsuccessfully.
+///
+/// [resp]: super::serverbound::LoginPluginResponse385
+pub struct LoginPluginRequest385<'a> {
+    #[encoding(varint)]
+    pub message_id: i32,
+    pub channel: Cow<'a, str>,
+    #[encoding(rest)]
+    pub data: Cow<'a, [u8]>,
+}
@@ -1,10 +1,48 @@
+use ::miners_encoding::{attrs::Rest, attrs::Var, decode, encode, Decode, Encode};
+
 use std::borrow::Cow;
 
+use uuid::Uuid;
+
+use crate::netty::play::clientbound::PlayerPublicKey759;
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct LoginStart0<'a> {
     pub username: Cow<'a, str>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct LoginStart759<'a> {
+    pub username: Cow<'a, str>,
+    /// The key used to sign chat messages, `None` if the player doesn't sign
+    /// them
+    pub public_key: Option<PlayerPublicKey759<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct LoginStart760<'a> {
+    pub username: Cow<'a, str>,
+    /// The key used to sign chat messages, `None` if the player doesn't sign
+    /// them
+    pub public_key: Option<PlayerPublicKey759<'a>>,
+    /// The uuid of the logged in account, the public key belongs to it
+    pub uuid: Option<Uuid>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct LoginStart761<'a> {
+    pub username: Cow<'a, str>,
+    /// The uuid of the logged in account, unused by vanilla servers
+    pub uuid: Option<Uuid>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct LoginStart764<'a> {
+    pub username: Cow<'a, str>,
+    /// The uuid of the logged in account, unused by vanilla servers
+    pub uuid: Uuid,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct EncryptionResponse0<'a> {
     #[encoding(counted = "u16")]
@@ -18,3 +56,95 @@ pub struct EncryptionResponse19<'a> {
     pub secret: Cow<'a, [u8]>,
     pub verify_token: Cow<'a, [u8]>,
 }
+
+#[derive(ToStatic, Debug)]
+pub struct EncryptionResponse759<'a> {
+    pub secret: Cow<'a, [u8]>,
+    pub verification: Verification759<'a>,
+}
+
+#[derive(ToStatic, Debug)]
+/// How the client proves that it knows the private key of the server
+pub enum Verification759<'a> {
+    /// The encrypted verify token of the encryption request
+    VerifyToken(Cow<'a, [u8]>),
+    /// The verify token signed with the chat key of the player, sent if
+    /// it gave a public key in the login start
+    Signature { salt: i64, signature: Cow<'a, [u8]> },
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for EncryptionResponse759<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let secret = Decode::decode(cursor)?;
+        let verification = match bool::decode(cursor)? {
+            true => Verification759::VerifyToken(Decode::decode(cursor)?),
+            false => Verification759::Signature {
+                salt: i64::decode(cursor)?,
+                signature: Decode::decode(cursor)?,
+            },
+        };
+        Ok(Self {
+            secret,
+            verification,
+        })
+    }
+}
+
+impl<'a> Encode for EncryptionResponse759<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.secret.encode(writer)?;
+        match &self.verification {
+            Verification759::VerifyToken(verify_token) => {
+                true.encode(writer)?;
+                verify_token.encode(writer)?;
+            }
+            Verification759::Signature { salt, signature } => {
+                false.encode(writer)?;
+                salt.encode(writer)?;
+                signature.encode(writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// The answer to a
+/// [`clientbound::LoginPluginRequest385`][req] with the same `message_id`.
+///
+/// [req]: super::clientbound::LoginPluginRequest385
+pub struct LoginPluginResponse385<'a> {
+    // varint
+    pub message_id: i32,
+    /// `None` if the client didn't understand the request
+    pub data: Option<Cow<'a, [u8]>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for LoginPluginResponse385<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let message_id = Var::decode(cursor)?.into_inner();
+        let data = match bool::decode(cursor)? {
+            true => Some(Rest::<Cow<[u8]>>::decode(cursor)?.into_inner()),
+            false => None,
+        };
+        Ok(Self { message_id, data })
+    }
+}
+
+impl<'a> Encode for LoginPluginResponse385<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.message_id).encode(writer)?;
+        self.data.is_some().encode(writer)?;
+        if let Some(data) = &self.data {
+            Rest::from(data).encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Acknowledges the [`clientbound::Success759`][success], packets after this
+/// one are in the configuration state.
+///
+/// [success]: super::clientbound::Success759
+pub struct LoginAcknowledged764 {}
@@ -0,0 +1,243 @@
+//! Velocity's modern player info forwarding.
+//!
+//! The proxy is asked with a [`LoginPluginRequest385`] on [`CHANNEL`] and
+//! answers with a [`LoginPluginResponse385`] carrying the address, uuid, name
+//! and properties of the player, signed with HMAC-SHA256 using the secret
+//! shared between proxy and backend.
+
+use std::borrow::Cow;
+
+use ::miners_encoding::{attrs::Var, decode, encode, Decode, Encode};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::clientbound::{LoginPluginRequest385, Success0};
+use super::serverbound::LoginPluginResponse385;
+use crate::netty::play::clientbound::{PlayerProperty19, PlayerPublicKey759};
+
+pub const CHANNEL: &str = "velocity:player_info";
+
+/// Address, uuid, name and properties
+pub const DEFAULT_VERSION: u8 = 1;
+/// Additionally forwards the public key of the player
+pub const WITH_KEY_VERSION: u8 = 2;
+/// Additionally forwards the uuid the public key was signed for
+pub const WITH_KEY_V2_VERSION: u8 = 3;
+/// Stops forwarding the public key
+pub const LAZY_SESSION_VERSION: u8 = 4;
+/// The highest forwarding version understood by [`PlayerInfo`]
+pub const MAX_VERSION: u8 = LAZY_SESSION_VERSION;
+
+/// Length of the HMAC-SHA256 signature in front of the forwarded data
+const SIGNATURE_LEN: usize = 32;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ForwardingError {
+    /// The proxy didn't understand the request, most likely the player
+    /// connected directly or forwarding is disabled on the proxy.
+    #[error("the proxy didn't forward the player info")]
+    NotForwarded,
+    #[error("the forwarded player info has an invalid signature")]
+    InvalidSignature,
+    #[error("unsupported forwarding version {0}")]
+    UnsupportedVersion(i32),
+    #[error(transparent)]
+    Decode(#[from] decode::Error),
+}
+
+/// Creates the request asking the proxy for the player info, `version` is the
+/// highest forwarding version the backend supports.
+pub fn request(message_id: i32, version: u8) -> LoginPluginRequest385<'static> {
+    LoginPluginRequest385 {
+        message_id,
+        channel: Cow::Borrowed(CHANNEL),
+        // the proxy assumes the default version if no version is sent
+        data: match version {
+            DEFAULT_VERSION => Cow::Borrowed(&[]),
+            version => Cow::Owned(vec![version]),
+        },
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub struct PlayerInfo<'a> {
+    // varint
+    pub version: i32,
+    /// The address the player connected to the proxy from
+    pub address: Cow<'a, str>,
+    pub uuid: Uuid,
+    pub name: Cow<'a, str>,
+    /// The properties of the player's game profile, like its textures
+    pub properties: Vec<PlayerProperty19<'a>>,
+    /// Only forwarded in versions 2 and 3
+    pub key: Option<PlayerPublicKey759<'a>>,
+    /// The uuid the key was signed for if it differs from `uuid`, only
+    /// forwarded in version 3
+    pub key_holder: Option<Uuid>,
+}
+
+impl<'a> PlayerInfo<'a> {
+    /// Verifies the signature of the data sent by the proxy and decodes the
+    /// player info.
+    pub fn from_response(
+        secret: &[u8],
+        response: &'a LoginPluginResponse385,
+    ) -> Result<Self, ForwardingError> {
+        match &response.data {
+            Some(data) => Self::decode_signed(secret, data),
+            None => Err(ForwardingError::NotForwarded),
+        }
+    }
+
+    /// Verifies the signature in front of `data` and decodes the player info.
+    pub fn decode_signed(secret: &[u8], data: &'a [u8]) -> Result<Self, ForwardingError> {
+        if data.len() < SIGNATURE_LEN {
+            return Err(ForwardingError::InvalidSignature);
+        }
+        let (signature, data) = data.split_at(SIGNATURE_LEN);
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret)
+            .map_err(|_| ForwardingError::InvalidSignature)?;
+        mac.update(data);
+        mac.verify_slice(signature)
+            .map_err(|_| ForwardingError::InvalidSignature)?;
+
+        let mut cursor = std::io::Cursor::new(data);
+        let version = Var::<i32>::decode(&mut cursor)?.into_inner();
+        if !(DEFAULT_VERSION as i32..=MAX_VERSION as i32).contains(&version) {
+            return Err(ForwardingError::UnsupportedVersion(version));
+        }
+        let address = Cow::decode(&mut cursor)?;
+        let uuid = Uuid::decode(&mut cursor)?;
+        let name = Cow::decode(&mut cursor)?;
+        let properties = Vec::decode(&mut cursor)?;
+        let (key, key_holder) = Self::decode_key(version, &mut cursor)?;
+        Ok(Self {
+            version,
+            address,
+            uuid,
+            name,
+            properties,
+            key,
+            key_holder,
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn decode_key(
+        version: i32,
+        cursor: &mut std::io::Cursor<&'a [u8]>,
+    ) -> decode::Result<(Option<PlayerPublicKey759<'a>>, Option<Uuid>)> {
+        if !(WITH_KEY_VERSION as i32..LAZY_SESSION_VERSION as i32).contains(&version) {
+            return Ok((None, None));
+        }
+        let key = PlayerPublicKey759::decode(cursor)?;
+        let key_holder = if version >= WITH_KEY_V2_VERSION as i32 {
+            Option::decode(cursor)?
+        } else {
+            None
+        };
+        Ok((Some(key), key_holder))
+    }
+
+    /// Encodes the player info signed with `secret` the way the proxy sends
+    /// it.
+    pub fn encode_signed(
+        &self,
+        secret: &[u8],
+        writer: &mut impl std::io::Write,
+    ) -> encode::Result<()> {
+        let with_key =
+            (WITH_KEY_VERSION as i32..LAZY_SESSION_VERSION as i32).contains(&self.version);
+        if with_key != self.key.is_some() {
+            return Err(encode::Error::Custom(
+                "the key has to be forwarded in versions 2 and 3 only",
+            ));
+        }
+        if self.key_holder.is_some() && self.version != WITH_KEY_V2_VERSION as i32 {
+            return Err(encode::Error::Custom(
+                "the key holder can only be forwarded in version 3",
+            ));
+        }
+        let mut data = vec![];
+        Var::from(self.version).encode(&mut data)?;
+        self.address.encode(&mut data)?;
+        self.uuid.encode(&mut data)?;
+        self.name.encode(&mut data)?;
+        self.properties.encode(&mut data)?;
+        if let Some(key) = &self.key {
+            key.encode(&mut data)?;
+            if self.version >= WITH_KEY_V2_VERSION as i32 {
+                self.key_holder.encode(&mut data)?;
+            }
+        }
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret)
+            .map_err(|_| encode::Error::Custom("invalid forwarding secret"))?;
+        mac.update(&data);
+        writer.write_all(&mac.finalize().into_bytes())?;
+        writer.write_all(&data)?;
+        Ok(())
+    }
+
+    /// The login success packet for the forwarded player.
+    pub fn success(&self) -> Success0<'_> {
+        Success0 {
+            uuid: self.uuid.into(),
+            username: Cow::Borrowed(&self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::miners_encoding::attrs::StringUuid;
+
+    #[test]
+    fn player_info() {
+        let info = PlayerInfo {
+            version: WITH_KEY_V2_VERSION as i32,
+            address: Cow::Borrowed("127.0.0.1"),
+            uuid: Uuid::from_u128(0x069a79f444e94726a5befca90e38aaf5),
+            name: Cow::Borrowed("Notch"),
+            properties: vec![PlayerProperty19 {
+                name: Cow::Borrowed("textures"),
+                value: Cow::Borrowed("e30="),
+                signature: Some(Cow::Borrowed("c2ln")),
+            }],
+            key: Some(PlayerPublicKey759 {
+                expires_at: 1_700_000_000_000,
+                key: Cow::Borrowed(&[1, 2, 3]),
+                signature: Cow::Borrowed(&[4, 5, 6]),
+            }),
+            key_holder: None,
+        };
+        let mut data = vec![];
+        #[allow(clippy::unwrap_used)]
+        info.encode_signed(b"secret", &mut data).unwrap();
+
+        #[allow(clippy::unwrap_used)]
+        let decoded = PlayerInfo::decode_signed(b"secret", &data).unwrap();
+        assert_eq!(decoded.address, "127.0.0.1");
+        assert_eq!(decoded.uuid, info.uuid);
+        assert_eq!(decoded.name, "Notch");
+        assert_eq!(decoded.properties[0].value, "e30=");
+        assert_eq!(
+            decoded.key.as_ref().map(|key| key.expires_at),
+            Some(1_700_000_000_000)
+        );
+        assert_eq!(decoded.key_holder, None);
+        assert_eq!(decoded.success().uuid, StringUuid::from(info.uuid));
+
+        assert!(matches!(
+            PlayerInfo::decode_signed(b"wrong secret", &data),
+            Err(ForwardingError::InvalidSignature)
+        ));
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        assert!(matches!(
+            PlayerInfo::decode_signed(b"secret", &data),
+            Err(ForwardingError::InvalidSignature)
+        ));
+    }
+}
@@ -2,10 +2,20 @@ use miners_version::ProtocolVersion;
 
 use crate::*;
 
+pub mod channel;
 pub mod clientbound;
 pub mod serverbound;
+pub mod unified;
 
 use crate::netty::types::entity_metadata::*;
+use crate::netty::types::particle::*;
+use crate::netty::types::slot::*;
+
+/// Returns whether the play packets of `version` are mapped. Snapshots and
+/// the releases after 1.19.2 (pv760) only get up to the configuration state.
+pub fn is_mapped(version: ProtocolVersion) -> bool {
+    *version <= 760
+}
 
 #[derive(Encoding, ToStatic, Debug)]
 #[encoding(from = "u8")]
@@ -36,64 +46,78 @@ parsing_tree! {
     0x00 => {
         0..=31 => KeepAlive0,
         32..=66 => KeepAlive32,
+        100..=457 => SpawnObject100,
+        458..=758 => SpawnObject458,
+        759..=760 => SpawnObject759,
         // 67..=99 => _67,
-        // 100..=457 => _100,
-        // 458..=758 => _458,
-        // 759..=760 => _759,
         // 1073741825..=1073741901 => _1073741825,
     },
     0x01 => {
         0 => JoinGame0,
         1..=28 => JoinGame1<'a>,
         29..=66 => JoinGame29<'a>,
+        100..=760 => SpawnExpOrb100,
         // 67..=99 => _67,
-        // 100..=760 => _100,
         // ... and all other versions
     },
     0x02 => {
         0..=5 => ChatMessage0<'a>,
         6..=12 => ChatMessage6<'a>,
         13..=66 => ChatMessage6<'a>,
+        100..=578 => SpawnGlobalEntity100,
+        721..=758 => SpawnMob550,
+        759..=760 => SpawnPlayer550,
         // 67..=99 => _67,
-        // 100..=719 => _100,
-        // 721..=758 => _721,
-        // 759..=760 => _759,
+        // 579..=719 => _100,
         // 1073741825..=1073741901 => _1073741825,
         // 1073741902..=1073741903 => _1073741902,
     },
     0x03 => {
         0..=66 => TimeUpdate0,
+        100..=210 => SpawnMob100 => SpawnMob100<EntityMetadata57<'a>>,
+        301..=345 => SpawnMob301 => SpawnMob301<EntityMetadata57<'a>>,
+        346..=352 => SpawnMob346 => SpawnMob301<EntityMetadata346<'a>>,
+        353..=401 => SpawnMob353 => SpawnMob301<EntityMetadata353<'a>>,
+        402..=440 => SpawnMob402 => SpawnMob301<EntityMetadata402<'a>>,
+        441..=462 => SpawnMob441 => SpawnMob301<EntityMetadata441<'a>>,
+        463..=498 => SpawnMob463 => SpawnMob301<EntityMetadata463<'a>>,
+        550..=578 => SpawnMob550,
+        721..=758 => SpawnPainting441,
+        759..=760 => Animation67,
         // 67..=99 => _67,
-        // 100..=210 => _100,
-        // 301..=498 => _301,
-        // 550..=719 => _550,
-        // 721..=758 => _721,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741901 => _1073741825,
         // 1073741902..=1073741903 => _1073741902,
-
     },
     0x04 => {
         0..=6 => EntityEquipment0<'a>,
         7..=48 => EntityEquipment7<'a>,
         49..=66 => EntityEquipment49<'a>,
+        95..=352 => SpawnPainting95<'a>,
+        353..=440 => SpawnPainting353,
+        441..=578 => SpawnPainting441,
+        721..=758 => SpawnPlayer550,
+        759..=760 => Statistics346,
         // 67..=94 => _67,
-        // 95..=352 => _95,
-        // 353..=719 => _353,
-        // 721..=758 => _721,
-        // 759..=760 => _759,
+        // 579..=719 => _353,
         // 1073741825..=1073741901 => _1073741825,
         // 1073741902..=1073741903 => _1073741902,
     },
     0x05 => {
         0..=5 => SpawnPosition0,
         6..=66 => SpawnPosition6,
+        100..=345 => SpawnPlayer100 => SpawnPlayer100<EntityMetadata57<'a>>,
+        346..=352 => SpawnPlayer346 => SpawnPlayer100<EntityMetadata346<'a>>,
+        353..=401 => SpawnPlayer353 => SpawnPlayer100<EntityMetadata353<'a>>,
+        402..=440 => SpawnPlayer402 => SpawnPlayer100<EntityMetadata402<'a>>,
+        441..=462 => SpawnPlayer441 => SpawnPlayer100<EntityMetadata441<'a>>,
+        463..=498 => SpawnPlayer463 => SpawnPlayer100<EntityMetadata463<'a>>,
+        550..=578 => SpawnPlayer550,
+        721..=754 => Animation67,
+        755..=758 => SculkVibrationSignal755<'a>,
+        759..=760 => AcknowledgeBlockChange759,
         // 67..=99 => _67,
-        // 100..=498 => _100,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -105,10 +129,11 @@ parsing_tree! {
     0x06 => {
         0..=6 => UpdateHealth0,
         7..=66 => UpdateHealth7,
-        // 67..=719 => _67,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        67..=578 => Animation67,
+        721..=754 => Statistics346,
+        755..=758 => Animation67,
+        759..=760 => BlockBreakAnimation441,
+        // 579..=719 => _67,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -120,11 +145,12 @@ parsing_tree! {
     0x07 => {
         0 => Respawn0,
         1..=66 => Respawn1<'a>,
-        // 67..=345 => _67,
-        // 346..=719 => _346,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        67..=345 => Statistics0<'a>,
+        346..=578 => Statistics346,
+        721..=754 => AcknowledgePlayerDigging494,
+        755..=758 => Statistics346,
+        759..=760 => UpdateBlockEntity757<'a>,
+        // 579..=719 => _346,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -137,17 +163,19 @@ parsing_tree! {
     0x08 => {
         0..=5 => PositionAndLook0,
         6..=66 => PositionAndLook6,
-        // 67..=317 => _67,
+        67..=317 => BlockBreakAnimation6,
+        332..=440 => BlockBreakAnimation6,
+        441..=498 => BlockBreakAnimation441,
+        550..=578 => AcknowledgePlayerDigging494,
+        721..=754 => BlockBreakAnimation441,
+        755..=758 => AcknowledgePlayerDigging494,
+        759..=760 => BlockAction441,
         // 318..=320 => _318,
         // 321..=323 => _321,
         // 324..=325 => _324,
         // 326..=327 => _326,
         // 328..=331 => _328,
-        // 332..=498 => _332,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -159,13 +187,15 @@ parsing_tree! {
     },
     0x09 => {
         0..=66 => HeldItemChange0,
-        // 67..=317 => _67,
+        67..=317 => UpdateBlockEntity67<'a>,
+        332..=440 => UpdateBlockEntity67<'a>,
+        441..=498 => UpdateBlockEntity441<'a>,
+        550..=578 => BlockBreakAnimation441,
+        721..=754 => UpdateBlockEntity441<'a>,
+        755..=758 => BlockBreakAnimation441,
+        759..=760 => BlockChange441,
         // 318..=331 => _318,
-        // 332..=498 => _332,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -178,14 +208,16 @@ parsing_tree! {
         0..=5 => UseBed0,
         6 => UseBed6,
         7..=66 => UseBed7,
-        // 67..=317 => _67,
+        67..=317 => BlockAction6,
+        332..=440 => BlockAction6,
+        441..=498 => BlockAction441,
+        550..=578 => UpdateBlockEntity441<'a>,
+        721..=754 => BlockAction441,
+        755..=756 => UpdateBlockEntity441<'a>,
+        757..=758 => UpdateBlockEntity757<'a>,
+        759..=760 => BossBar67<'a>,
         // 318..=331 => _318,
-        // 332..=498 => _332,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -197,13 +229,15 @@ parsing_tree! {
     },
     0x0b => {
         0..=66 => Animation0,
-        // 67..=317 => _67,
+        67..=317 => BlockChange25,
+        332..=440 => BlockChange25,
+        441..=498 => BlockChange441,
+        550..=578 => BlockAction441,
+        721..=754 => BlockChange441,
+        755..=758 => BlockAction441,
+        759..=760 => ServerDifficulty464,
         // 318..=331 => _318,
-        // 332..=498 => _332,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -221,15 +255,15 @@ parsing_tree! {
         19..=48 => SpawnPlayer19<'a>,
         49..=56 => SpawnPlayer49<PackedEntityMetadata0<'a>>,
         57..=66 => SpawnPlayer57 => SpawnPlayer49<EntityMetadata57<'a>>,
-        // 67..=317 => _67,
+        67..=317 => BossBar67<'a>,
+        332..=498 => BossBar67<'a>,
+        550..=578 => BlockChange441,
+        721..=754 => BossBar67<'a>,
+        755..=758 => BlockChange441,
+        759..=760 => ChatPreview759<'a>,
         // 318..=331 => _318,
-        // 332..=498 => _332,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
-        // 1073741832..=1073741833 => _1073741832,f
         // 1073741834 => _1073741834,
         // 1073741835..=1073741898 => _1073741835,
         // 1073741899..=1073741900 => _1073741899,
@@ -237,18 +271,20 @@ parsing_tree! {
         // 1073741902..=1073741903 => _1073741902,
         // 1073741904..=1073741905 => _1073741904,
         // 1073741906..=1073741907 => _1073741906,
+        // 1073741832..=1073741833 => _1073741832,f
     },
     0x0d => {
         0..=6 => CollectItem0,
         7..=66 => CollectItem7,
-        // 67..=317 => _67,
+        67..=317 => ServerDifficulty6,
+        332..=463 => ServerDifficulty6,
+        464..=498 => ServerDifficulty464,
+        550..=578 => BossBar67<'a>,
+        721..=754 => ServerDifficulty464,
+        755..=758 => BossBar67<'a>,
+        759..=760 => ClearTitles755,
         // 318..=331 => _318,
-        // 332..=463 => _332,
-        // 464..=498 => _464,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -262,15 +298,16 @@ parsing_tree! {
     },
     0x0e => {
         0..=48 => SpawnObject0,
-        // 49..=66 => SpawnObject49,
-        // 67..=317 => _67,
+        67..=317 => TabComplete0<'a>,
+        332..=340 => TabComplete0<'a>,
+        343..=498 => ChatMessage6<'a>,
+        550..=578 => ServerDifficulty464,
+        721..=754 => ChatMessage721<'a>,
+        755..=758 => ServerDifficulty464,
+        759..=760 => TabComplete357<'a>,
         // 318..=331 => _318,
-        // 332..=342 => _332,
-        // 343..=498 => _343,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 341..=342 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -280,20 +317,23 @@ parsing_tree! {
         // 1073741902..=1073741903 => _1073741902,
         // 1073741904..=1073741905 => _1073741904,
         // 1073741906..=1073741907 => _1073741906,
+        // 49..=66 => SpawnObject49,
     },
     0x0f => {
         0..=48 => SpawnMob0<'a>,
+        67..=317 => ChatMessage6<'a>,
+        332..=340 => ChatMessage6<'a>,
+        343..=498 => MultiBlockChange25,
+        550..=578 => ChatMessage6<'a>,
+        721..=738 => MultiBlockChange25,
+        740..=754 => TabComplete357<'a>,
+        755..=758 => ChatMessage721<'a>,
+        759..=760 => DeclareCommands759<'a>,
         // 49..=66 => _49,
-        // 67..=317 => _67,
         // 318..=331 => _318,
-        // 332..=342 => _332,
-        // 343..=498 => _343,
-        // 550..=717 => _550,
+        // 341..=342 => _332,
+        // 579..=717 => _550,
         // 718..=719 => _718,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -307,19 +347,21 @@ parsing_tree! {
     0x10 => {
         0..=7 => SpawnPainting0<'a>,
         8..=66 => SpawnPainting8<'a>,
-        // 67..=317 => _67,
+        67..=317 => MultiBlockChange25,
+        332..=340 => MultiBlockChange25,
+        357..=498 => TabComplete357<'a>,
+        550..=578 => MultiBlockChange25,
+        721..=738 => TabComplete357<'a>,
+        743..=754 => DeclareCommands346<'a>,
+        755..=758 => ClearTitles755,
+        759..=760 => CloseWindow0,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345 => _345,
         // 346..=356 => _346,
-        // 357..=498 => _357,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
+        // 579..=719 => _550,
         // 740..=741 => _740,
-        // 743..=754 => _743,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -333,16 +375,18 @@ parsing_tree! {
     },
     0x11 => {
         0..=66 => SpawnExpOrb0,
-        // 67..=317 => _67,
+        67..=317 => ConfirmTransaction0,
+        332..=340 => ConfirmTransaction0,
+        346..=498 => DeclareCommands346<'a>,
+        550..=578 => TabComplete357<'a>,
+        721..=738 => DeclareCommands346<'a>,
+        740..=754 => ConfirmTransaction0,
+        755..=758 => TabComplete357<'a>,
+        759..=760 => WindowItems756 => WindowItems756<Slot402<'a>>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345 => _345,
-        // 346..=498 => _346,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -357,15 +401,17 @@ parsing_tree! {
     0x12 => {
         0..=6 => EntityVelocity0,
         7..=66 => EntityVelocity7,
-        // 67..=317 => _67,
+        67..=317 => CloseWindow0,
+        332..=340 => CloseWindow0,
+        345..=498 => ConfirmTransaction0,
+        550..=578 => DeclareCommands346<'a>,
+        721..=738 => ConfirmTransaction0,
+        740..=754 => CloseWindow0,
+        755..=758 => DeclareCommands346<'a>,
+        759..=760 => WindowProperty0,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=498 => _345,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -381,15 +427,17 @@ parsing_tree! {
     0x13 => {
         0..=6 => DestroyEntities0,
         7..=66 => DestroyEntities7,
-        // 67..=317 => _67,
+        67..=317 => OpenWindow6<'a>,
+        332..=340 => OpenWindow6<'a>,
+        345..=498 => CloseWindow0,
+        550..=578 => ConfirmTransaction0,
+        721..=738 => CloseWindow0,
+        740..=754 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        755..=758 => CloseWindow0,
+        759..=760 => SetSlot756 => SetSlot756<Slot402<'a>>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=498 => _345,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -405,19 +453,21 @@ parsing_tree! {
     0x14 => {
         0..=6 => Entity0,
         7..=66 => Entity7,
-        // 67..=210 => _67,
-        // 301..=317 => _301,
+        67..=210 => WindowItems67<'a>,
+        301..=317 => WindowItems67<'a>,
+        332..=340 => WindowItems67<'a>,
+        345..=451 => OpenWindow6<'a>,
+        471..=498 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        550..=578 => CloseWindow0,
+        721..=738 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        740..=754 => WindowProperty0,
+        755 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        756..=758 => WindowItems756 => WindowItems756<Slot402<'a>>,
+        759..=760 => SetCoolDown67,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=451 => _345,
+        // 341..=344 => _332,
         // 452..=470 => _452,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755 => _755,
-        // 756..=758 => _756,
-        // 759..=760 => _759,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -435,33 +485,40 @@ parsing_tree! {
         0..=6 => EntityRelativeMove0,
         7..=21 => EntityRelativeMove7,
         22..=66 => EntityRelativeMove22,
-        // 67..=317 => _67,
+        67..=317 => WindowProperty0,
+        332..=340 => WindowProperty0,
+        345 => WindowItems67<'a>,
+        346..=401 => WindowItems346 => WindowItems346<Slot346<'a>>,
+        402..=470 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        471..=498 => WindowProperty0,
+        550..=578 => WindowItems402 => WindowItems346<Slot402<'a>>,
+        721..=738 => WindowProperty0,
+        740..=754 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        755..=758 => WindowProperty0,
+        759 => PluginMessage32<'a>,
+        760 => CustomChatCompletions760<'a>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
     },
     0x16 => {
         0..=6 => EntityLook0,
         7..=21 => EntityLook7,
         22..=66 => EntityLook22,
-        // 67..=317 => _67,
+        67..=317 => SetSlot67<'a>,
+        332..=340 => SetSlot67<'a>,
+        345..=470 => WindowProperty0,
+        471..=498 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        550..=578 => WindowProperty0,
+        721..=738 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        740..=754 => SetCoolDown67,
+        755 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        756..=758 => SetSlot756 => SetSlot756<Slot402<'a>>,
+        759 => NamedSoundEffect759<'a>,
+        760 => PluginMessage32<'a>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755 => _755,
-        // 756..=758 => _756,
-        // 759 => _759,
-        // 760 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -480,17 +537,21 @@ parsing_tree! {
         0..=6 => EntityLookAndRelativeMove0,
         7..=21 => EntityLookAndRelativeMove7,
         22..=66 => EntityLookAndRelativeMove22,
-        // 67..=317 => _67,
+        67..=317 => SetCoolDown67,
+        332..=340 => SetCoolDown67,
+        345 => SetSlot67<'a>,
+        346..=401 => SetSlot346 => SetSlot346<Slot346<'a>>,
+        402..=470 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        471..=498 => SetCoolDown67,
+        550..=578 => SetSlot402 => SetSlot346<Slot402<'a>>,
+        721..=738 => SetCoolDown67,
+        740..=754 => PluginMessage32<'a>,
+        755..=758 => SetCoolDown67,
+        759 => Disconnect0<'a>,
+        760 => NamedSoundEffect759<'a>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -503,23 +564,24 @@ parsing_tree! {
         // 1073741904..=1073741905 => _1073741904,
         // 1073741906..=1073741907 => _1073741906,
         // 1073741908..=1073741919 => _1073741908,
-
     },
     0x18 => {
         0..=6 => EntityTeleport0,
         7..=21 => EntityTeleport7,
         22..=66 => EntityTeleport22,
-        // 67..=317 => _67,
+        67..=317 => PluginMessage32<'a>,
+        332..=340 => PluginMessage32<'a>,
+        345..=470 => SetCoolDown67,
+        471..=498 => PluginMessage32<'a>,
+        550..=578 => SetCoolDown67,
+        721..=738 => PluginMessage32<'a>,
+        740..=754 => NamedSoundEffect201<'a>,
+        755..=758 => PluginMessage32<'a>,
+        759 => EntityStatus80,
+        760 => HideMessage760<'a>,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -537,22 +599,25 @@ parsing_tree! {
     0x19 => {
         0..=6 => EntityHeadLook0,
         7..=66 => EntityHeadLook7,
+        95..=110 => NamedSoundEffect95<'a>,
+        201..=317 => NamedSoundEffect201<'a>,
+        332..=340 => NamedSoundEffect201<'a>,
+        385..=470 => PluginMessage32<'a>,
+        471..=498 => NamedSoundEffect201<'a>,
+        550..=578 => PluginMessage32<'a>,
+        721..=738 => NamedSoundEffect201<'a>,
+        740..=754 => Disconnect0<'a>,
+        755..=758 => NamedSoundEffect201<'a>,
+        759 => Explosion755,
+        760 => Disconnect0<'a>,
         // 67..=79 => _67,
         // 80..=94 => _80,
-        // 95..=110 => _95,
-        // 201..=317 => _201,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=384 => _345,
-        // 385..=470 => _385,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -568,17 +633,20 @@ parsing_tree! {
     },
     0x1a => {
         0..=79 => EntityStatus0,
-        // 80..=317 => _80,
+        80..=317 => Disconnect0<'a>,
+        332..=340 => Disconnect0<'a>,
+        345..=470 => NamedSoundEffect201<'a>,
+        471..=498 => Disconnect0<'a>,
+        550..=578 => NamedSoundEffect201<'a>,
+        721..=738 => Disconnect0<'a>,
+        740..=754 => EntityStatus80,
+        755..=758 => Disconnect0<'a>,
+        759 => UnloadChunk80,
+        760 => EntityStatus80,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -594,18 +662,20 @@ parsing_tree! {
     },
     0x1b => {
         0..=66 => AttachEntity0,
+        80..=317 => EntityStatus80,
+        332..=340 => EntityStatus80,
+        345..=470 => Disconnect0<'a>,
+        471..=498 => EntityStatus80,
+        550..=578 => Disconnect0<'a>,
+        721..=738 => EntityStatus80,
+        740..=754 => Explosion0,
+        755..=758 => EntityStatus80,
+        759 => ChangeGameState721,
+        760 => Explosion755,
         // 67..=79 => _67,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -624,18 +694,21 @@ parsing_tree! {
         0..=6 => EntityMetadata0<'a>,
         7..=56 => EntityMetadata7<PackedEntityMetadata0<'a>>,
         57..=66 => EntityMetadata57 => EntityMetadata7<EntityMetadata57<'a>>,
+        80..=317 => Explosion0,
+        332..=340 => Explosion0,
+        345..=470 => EntityStatus80,
+        471..=498 => Explosion0,
+        550..=578 => EntityStatus80,
+        721..=738 => Explosion0,
+        740..=754 => UnloadChunk80,
+        755..=758 => Explosion755,
+        759 => OpenHorseWindow471,
+        760 => UnloadChunk80,
         // 67..=79 => _67,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=344 => _332,
-        // 345..=470 => _345,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 341..=344 => _332,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -653,19 +726,22 @@ parsing_tree! {
         0..=6 => EntityEffect0,
         7..=9 => EntityEffect7,
         10..=66 => EntityEffect10,
+        80..=317 => UnloadChunk80,
+        332..=340 => UnloadChunk80,
+        389..=470 => NbtQueryResponse389<'a>,
+        471..=498 => UnloadChunk80,
+        550..=578 => Explosion0,
+        721..=738 => UnloadChunk80,
+        740..=754 => ChangeGameState721,
+        755..=758 => UnloadChunk80,
+        759 => InitializeWorldBorder755,
+        760 => ChangeGameState721,
         // 67..=79 => _67,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -682,20 +758,23 @@ parsing_tree! {
     0x1e => {
         0..=6 => RemoveEntityEffect0,
         7..=66 => RemoveEntityEffect7,
+        86..=317 => ChangeGameState86,
+        332..=340 => ChangeGameState86,
+        389..=470 => Explosion0,
+        471..=498 => ChangeGameState86,
+        550..=578 => UnloadChunk80,
+        721..=738 => ChangeGameState721,
+        740..=754 => OpenHorseWindow471,
+        755..=758 => ChangeGameState721,
+        759 => KeepAlive339,
+        760 => OpenHorseWindow471,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -712,21 +791,23 @@ parsing_tree! {
     0x1f => {
         0..=6 => SetExperience0,
         7..=66 => SetExperience7,
+        86..=317 => KeepAlive32,
+        332..=338 => KeepAlive32,
+        339..=340 => KeepAlive339,
+        389..=470 => UnloadChunk80,
+        471..=498 => OpenHorseWindow471,
+        550..=578 => ChangeGameState86,
+        721..=738 => OpenHorseWindow471,
+        740..=754 => KeepAlive339,
+        755..=758 => OpenHorseWindow471,
+        759 => ChunkDataAndUpdateLight757<'a>,
+        760 => InitializeWorldBorder755,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=338 => _332,
-        // 339..=344 => _339,
+        // 341..=344 => _339,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -744,23 +825,25 @@ parsing_tree! {
     0x20 => {
         0..=6 => EntityProperties0<'a>,
         7..=66 => EntityProperties7<'a>,
+        86..=109 => ChunkData86<'a>,
+        110..=317 => ChunkData110<'a>,
+        332..=340 => ChunkData110<'a>,
+        389..=470 => ChangeGameState86,
+        471..=498 => KeepAlive339,
+        550..=578 => OpenHorseWindow471,
+        721..=738 => KeepAlive339,
+        746..=754 => ChunkData746<'a>,
+        755..=758 => InitializeWorldBorder755,
+        759 => Effect441,
+        760 => KeepAlive339,
         // 67..=69 => _67,
         // 70..=79 => _70,
         // 80..=85 => _80,
-        // 86..=109 => _86,
-        // 110..=317 => _110,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
+        // 579..=719 => _550,
         // 740..=744 => _740,
-        // 746..=754 => _746,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741827 => _1073741825,
         // 1073741829..=1073741831 => _1073741829,
         // 1073741832..=1073741833 => _1073741832,
@@ -780,22 +863,24 @@ parsing_tree! {
         0..=22 => ChunkData0<'a>,
         23..=26 => ChunkData23<'a>,
         27..=59 => ChunkData27<'a>,
+        86..=317 => Effect6,
+        332..=340 => Effect6,
+        389..=470 => KeepAlive339,
+        471..=498 => ChunkData471<'a>,
+        550..=578 => KeepAlive339,
+        732..=738 => ChunkData732<'a>,
+        740..=754 => Effect441,
+        755..=758 => KeepAlive339,
+        759 => Particle759 => Particle759<Particle759<'a, Slot402<'a>>>,
+        760 => ChunkDataAndUpdateLight757<'a>,
         // 60..=66 => _60,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
+        // 579..=719 => _550,
         // 721..=730 => _721,
-        // 732..=738 => _732,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -814,25 +899,27 @@ parsing_tree! {
         0..=3 => MultiBlockChange0,
         4..=24 => MultiBlockChange4,
         25..=61 => MultiBlockChange25,
+        86..=317 => Particle86<'a>,
+        332..=340 => Particle86<'a>,
+        389..=404 => ChunkData389<'a>,
+        471..=498 => Effect441,
+        552..=578 => ChunkData552<'a>,
+        721..=738 => Effect441,
+        740..=754 => Particle706 => Particle569<Particle706<'a, Slot402<'a>>>,
+        755..=756 => ChunkData755<'a>,
+        757..=758 => ChunkDataAndUpdateLight757<'a>,
+        759 => UpdateLight755<'a>,
+        760 => Effect441,
         // 62..=66 => _62,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=404 => _389,
         // 443..=470 => _443,
-        // 471..=498 => _471,
         // 550..=551 => _550,
-        // 552..=719 => _552,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _552,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -852,22 +939,25 @@ parsing_tree! {
         0..=5 => BlockChange0,
         6..=24 => BlockChange6,
         25..=61 => BlockChange25,
+        86..=107 => JoinGame86<'a>,
+        108..=317 => JoinGame108<'a>,
+        332..=340 => JoinGame108<'a>,
+        389..=440 => Effect6,
+        441..=470 => Effect441,
+        471..=498 => Particle463 => Particle389<Particle463<'a, Slot402<'a>>>,
+        550..=578 => Effect441,
+        721..=738 => Particle706 => Particle569<Particle706<'a, Slot402<'a>>>,
+        740..=754 => UpdateLight725<'a>,
+        755..=758 => Effect441,
+        759 => JoinGame759<'a>,
+        760 => Particle759 => Particle759<Particle759<'a, Slot402<'a>>>,
         // 62..=66 => _62,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=107 => _86,
-        // 108..=317 => _108,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741827 => _1073741825,
         // 1073741829..=1073741831 => _1073741829,
         // 1073741832..=1073741833 => _1073741832,
@@ -888,23 +978,28 @@ parsing_tree! {
     0x24 => {
         0..=5 => BlockAction0,
         6..=61 => BlockAction6,
+        86..=317 => Maps86<'a>,
+        332..=340 => Maps86<'a>,
+        389..=401 => Particle389 => Particle389<Particle353<'a, Slot346<'a>>>,
+        402..=462 => Particle402 => Particle389<Particle353<'a, Slot402<'a>>>,
+        463..=470 => Particle463 => Particle389<Particle463<'a, Slot402<'a>>>,
+        471..=498 => UpdateLight471<'a>,
+        569..=578 => Particle569 => Particle569<Particle463<'a, Slot402<'a>>>,
+        725..=738 => UpdateLight725<'a>,
+        748..=754 => JoinGame748<'a>,
+        755..=756 => Particle755 => Particle569<Particle755<'a, Slot402<'a>>>,
+        757..=758 => Particle757 => Particle569<Particle757<'a, Slot402<'a>>>,
+        759 => Maps755<'a>,
+        760 => UpdateLight755<'a>,
         // 62..=66 => _62,
         // 67..=85 => _67,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
         // 550..=567 => _550,
-        // 569..=719 => _569,
+        // 579..=719 => _569,
         // 721..=722 => _721,
-        // 725..=738 => _725,
         // 740..=746 => _740,
-        // 748..=754 => _748,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -924,26 +1019,28 @@ parsing_tree! {
     0x25 => {
         0..=5 => BlockBreakAnimation0,
         6..=61 => BlockBreakAnimation6,
+        100..=317 => EntityRelativeMove100,
+        332..=340 => Entity7,
+        389..=463 => JoinGame108<'a>,
+        468..=498 => JoinGame468<'a>,
+        550..=578 => UpdateLight471<'a>,
+        730..=736 => JoinGame730<'a>,
+        740..=754 => Maps452<'a>,
+        755..=758 => UpdateLight755<'a>,
+        759 => TradeList495 => TradeList495<Slot402<'a>>,
+        760 => JoinGame759<'a>,
         // 62..=66 => _62,
         // 67..=85 => _67,
         // 86..=99 => _86,
-        // 100..=317 => _100,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=363 => _345,
         // 364..=372 => _364,
         // 373..=388 => _373,
-        // 389..=463 => _389,
         // 464..=467 => _464,
-        // 468..=498 => _468,
-        // 550..=719 => _550,
+        // 579..=719 => _550,
         // 721..=729 => _721,
-        // 730..=736 => _730,
         // 738 => _738,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741829 => _1073741825,
         // 1073741830..=1073741831 => _1073741830,
         // 1073741832..=1073741833 => _1073741832,
@@ -963,26 +1060,28 @@ parsing_tree! {
         0..=22 => MapChunkBulk0<'a>,
         23..=26 => MapChunkBulk23<'a>,
         27..=59 => MapChunkBulk27<'a>,
+        100..=317 => EntityLookAndRelativeMove100,
+        318..=340 => EntityRelativeMove100,
+        389..=451 => Maps389<'a>,
+        452..=498 => Maps452<'a>,
+        552..=578 => JoinGame552<'a>,
+        721..=738 => Maps452<'a>,
+        740..=754 => TradeList495 => TradeList495<Slot402<'a>>,
+        755..=756 => JoinGame748<'a>,
+        757..=758 => JoinGame757<'a>,
+        759 => EntityRelativeMove100,
+        760 => Maps755<'a>,
         // 60..=61 => _60,
         // 62..=66 => _62,
         // 67..=85 => _67,
         // 86..=99 => _86,
-        // 100..=317 => _100,
-        // 318..=344 => _318,
+        // 341..=344 => _318,
         // 345..=388 => _345,
-        // 389..=451 => _389,
-        // 452..=498 => _452,
         // 550..=551 => _550,
-        // 552..=715 => _552,
+        // 579..=715 => _552,
         // 716..=717 => _716,
         // 718 => _718,
         // 719 => _719,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1000,20 +1099,22 @@ parsing_tree! {
     },
     0x27 => {
         0..=66 => Explosion0,
+        86..=317 => EntityLook22,
+        318..=340 => EntityLookAndRelativeMove100,
+        389..=470 => Entity7,
+        471..=485 => TradeList471 => TradeList471<Slot402<'a>>,
+        486..=494 => TradeList486 => TradeList486<Slot402<'a>>,
+        495..=498 => TradeList495 => TradeList495<Slot402<'a>>,
+        550..=578 => Maps452<'a>,
+        721..=738 => TradeList495 => TradeList495<Slot402<'a>>,
+        740..=754 => EntityRelativeMove100,
+        755..=758 => Maps755<'a>,
+        759 => EntityLookAndRelativeMove100,
+        760 => TradeList495 => TradeList495<Slot402<'a>>,
         // 67..=85 => _67,
-        // 86..=317 => _86,
-        // 318..=344 => _318,
+        // 341..=344 => _318,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=485 => _471,
-        // 486..=494 => _486,
-        // 495..=498 => _495,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1031,17 +1132,19 @@ parsing_tree! {
     0x28 => {
         0..=5 => Effect0,
         6..=66 => Effect6,
+        86..=317 => Entity7,
+        318..=340 => EntityLook22,
+        389..=498 => EntityRelativeMove100,
+        550..=578 => TradeList495 => TradeList495<Slot402<'a>>,
+        721..=738 => EntityRelativeMove100,
+        740..=754 => EntityLookAndRelativeMove100,
+        755..=758 => TradeList495 => TradeList495<Slot402<'a>>,
+        759 => EntityLook22,
+        760 => EntityRelativeMove100,
         // 67..=85 => _67,
-        // 86..=317 => _86,
-        // 318..=344 => _318,
+        // 341..=344 => _318,
         // 345..=388 => _345,
-        // 389..=498 => _389,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1059,18 +1162,20 @@ parsing_tree! {
     0x29 => {
         0 => SoundEffect0<'a>,
         1..=66 => SoundEffect1<'a>,
+        86..=317 => VehicleMove86,
+        332..=340 => VehicleMove86,
+        389..=498 => EntityLookAndRelativeMove100,
+        550..=578 => EntityRelativeMove100,
+        721..=738 => EntityLookAndRelativeMove100,
+        740..=754 => EntityLook22,
+        755..=758 => EntityRelativeMove100,
+        759 => VehicleMove86,
+        760 => EntityLookAndRelativeMove100,
         // 67..=85 => _67,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=498 => _389,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1088,22 +1193,23 @@ parsing_tree! {
     0x2a => {
         0..=16 => Particle0<'a>,
         17..=26 => Particle17<'a>,
-        // nothing really changed
         27..=28 => Particle17<'a>,
         29..=66 => Particle29<'a>,
+        86..=317 => SignEditorOpen6,
+        332..=340 => SignEditorOpen6,
+        389..=498 => EntityLook22,
+        550..=578 => EntityLookAndRelativeMove100,
+        721..=738 => EntityLook22,
+        740..=754 => Entity7,
+        755..=758 => EntityLookAndRelativeMove100,
+        759 => OpenBook471,
+        760 => EntityLook22,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=344 => _332,
+        // 341..=344 => _332,
         // 345..=388 => _345,
-        // 389..=498 => _389,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1117,24 +1223,28 @@ parsing_tree! {
         // 1073741906..=1073741907 => _1073741906,
         // 1073741908..=1073741919 => _1073741908,
         // 1073741920 => _1073741920,
+        // nothing really changed
     },
     0x2b => {
         0..=66 => ChangeGameState0,
+        86..=317 => PlayerAbilities0,
+        332..=335 => PlayerAbilities0,
+        336..=340 => CraftRecipeResponse336,
+        389..=470 => VehicleMove86,
+        471..=498 => Entity7,
+        550..=578 => EntityLook22,
+        721..=738 => Entity7,
+        740..=754 => VehicleMove86,
+        755..=758 => EntityLook22,
+        759 => OpenWindow471<'a>,
+        760 => VehicleMove86,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=388 => _345,
-        // 389..=470 => _389,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -1150,23 +1260,27 @@ parsing_tree! {
     },
     0x2c => {
         0..=66 => SpawnGlobalEntity0,
+        86..=317 => CombatEvent8<'a>,
+        332..=335 => CombatEvent8<'a>,
+        336..=340 => PlayerAbilities0,
+        389..=440 => SignEditorOpen6,
+        441..=450 => SignEditorOpen441,
+        471..=498 => VehicleMove86,
+        550..=578 => Entity7,
+        721..=738 => VehicleMove86,
+        740..=754 => OpenBook471,
+        755..=758 => VehicleMove86,
+        759 => SignEditorOpen441,
+        760 => OpenBook471,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=347 => _345,
         // 348..=388 => _348,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -1184,22 +1298,25 @@ parsing_tree! {
         0..=5 => OpenWindow0<'a>,
         6..=12 => OpenWindow6<'a>,
         13..=66 => OpenWindow6<'a>,
+        86..=317 => PlayerListItem28<'a>,
+        332..=335 => PlayerListItem28<'a>,
+        336..=340 => CombatEvent8<'a>,
+        389..=450 => CraftRecipeResponse389<'a>,
+        471..=498 => OpenBook471,
+        550..=578 => VehicleMove86,
+        721..=738 => OpenBook471,
+        740..=754 => OpenWindow471<'a>,
+        755..=758 => OpenBook471,
+        759 => Ping755,
+        760 => OpenWindow471<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=388 => _345,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -1215,23 +1332,26 @@ parsing_tree! {
     },
     0x2e => {
         0..=66 => CloseWindow0,
+        86..=317 => PositionAndLook86,
+        332..=335 => PositionAndLook86,
+        336..=340 => PlayerListItem28<'a>,
+        389..=450 => PlayerAbilities0,
+        471..=498 => OpenWindow471<'a>,
+        550..=578 => OpenBook471,
+        721..=738 => OpenWindow471<'a>,
+        740..=754 => SignEditorOpen441,
+        755..=758 => OpenWindow471<'a>,
+        759 => CraftRecipeResponse389<'a>,
+        760 => SignEditorOpen441,
         // 67..=78 => _67,
         // 79 => _79,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=388 => _345,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760..=1073741831 => _760,
+        // 579..=719 => _550,
+        // 761..=1073741831 => _760,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
         // 1073741835..=1073741839 => _1073741835,
@@ -1247,22 +1367,24 @@ parsing_tree! {
     },
     0x2f => {
         0..=66 => SetSlot0,
+        86..=317 => UseBed7,
+        332..=335 => UseBed7,
+        336..=340 => PositionAndLook86,
+        389..=450 => CombatEvent8<'a>,
+        471..=498 => SignEditorOpen441,
+        550..=578 => OpenWindow471<'a>,
+        721..=738 => SignEditorOpen441,
+        740..=754 => CraftRecipeResponse389<'a>,
+        755..=758 => SignEditorOpen441,
+        759 => PlayerAbilities0,
+        760 => Ping755,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=388 => _345,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1280,26 +1402,28 @@ parsing_tree! {
     },
     0x30 => {
         0..=66 => WindowItems0,
+        86..=110 => DestroyEntities7,
+        201..=317 => DestroyEntities7,
+        333..=335 => UnlockRecipes333,
+        336..=340 => UseBed7,
+        389..=450 => PlayerListItem28<'a>,
+        471..=498 => CraftRecipeResponse389<'a>,
+        550..=578 => SignEditorOpen441,
+        721..=738 => CraftRecipeResponse389<'a>,
+        740..=754 => PlayerAbilities0,
+        755..=758 => Ping755,
+        759 => PlayerChatMessage759<'a>,
+        760 => CraftRecipeResponse389<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=110 => _86,
-        // 201..=317 => _201,
         // 318..=331 => _318,
         // 332 => _332,
-        // 333..=335 => _333,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352 => _352,
         // 353..=388 => _353,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1316,25 +1440,27 @@ parsing_tree! {
     },
     0x31 => {
         0..=66 => WindowProperty0,
+        86..=317 => RemoveEntityEffect7,
+        332..=335 => DestroyEntities7,
+        336..=340 => UnlockRecipes333,
+        389..=450 => FacePlayer389,
+        471..=498 => PlayerAbilities0,
+        550..=578 => CraftRecipeResponse389<'a>,
+        721..=738 => PlayerAbilities0,
+        740..=754 => CombatEvent8<'a>,
+        755..=758 => CraftRecipeResponse389<'a>,
+        759 => EndCombatEvent755,
+        760 => PlayerAbilities0,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=319 => _318,
         // 320..=323 => _320,
         // 324..=331 => _324,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1351,24 +1477,26 @@ parsing_tree! {
     },
     0x32 => {
         0..=66 => ConfirmTransaction0,
+        86..=317 => ResourcePackSend32<'a>,
+        332..=335 => RemoveEntityEffect7,
+        336..=340 => DestroyEntities7,
+        389..=450 => PositionAndLook86,
+        471..=498 => CombatEvent8<'a>,
+        550..=578 => PlayerAbilities0,
+        721..=738 => CombatEvent8<'a>,
+        740..=754 => PlayerListItem28<'a>,
+        755..=758 => PlayerAbilities0,
+        759 => EnterCombatEvent755,
+        760 => MessageHeader760<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=347 => _345,
         // 348..=351 => _348,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1387,26 +1515,28 @@ parsing_tree! {
     0x33 => {
         0..=5 => UpdateSign0<'a>,
         6..=20 => UpdateSign6<'a>,
-        // chatcomponent
         21..=66 => UpdateSign6<'a>,
+        86..=317 => Respawn86<'a>,
+        332..=335 => ResourcePackSend32<'a>,
+        336..=340 => RemoveEntityEffect7,
+        389..=440 => UseBed7,
+        441..=450 => UseBed441,
+        471..=498 => PlayerListItem28<'a>,
+        550..=578 => CombatEvent8<'a>,
+        721..=738 => PlayerListItem28<'a>,
+        740..=754 => FacePlayer389,
+        755..=758 => EndCombatEvent755,
+        759 => DeathCombatEvent755<'a>,
+        760 => PlayerChatMessage760<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=356 => _352,
         // 357..=388 => _357,
-        // 389..=450 => _389,
         // 451..=470 => _451,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1423,29 +1553,32 @@ parsing_tree! {
         // 1073741921 => _1073741921,
         // 1073741922 => _1073741922,
         // 1073741923..=1073741924 => _1073741923,
+        // chatcomponent
     },
     0x34 => {
         0..=26 => Maps0,
+        86..=317 => EntityHeadLook7,
+        332..=335 => Respawn86<'a>,
+        336..=340 => ResourcePackSend32<'a>,
+        389..=450 => UnlockRecipes389<'a>,
+        471..=498 => FacePlayer389,
+        550..=578 => PlayerListItem28<'a>,
+        721..=738 => FacePlayer389,
+        740..=754 => PositionAndLook86,
+        755..=758 => EnterCombatEvent755,
+        759 => PlayerListItem759<'a>,
+        760 => EndCombatEvent755,
         // 27..=57 => _27,
         // 58..=66 => _58,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1463,24 +1596,26 @@ parsing_tree! {
     0x35 => {
         0..=5 => UpdateBlockEntity0,
         6..=66 => UpdateBlockEntity6,
+        86..=317 => WorldBorder32,
+        332..=335 => EntityHeadLook7,
+        336..=340 => Respawn86<'a>,
+        389..=450 => DestroyEntities7,
+        471..=498 => PositionAndLook86,
+        550..=578 => FacePlayer389,
+        721..=738 => PositionAndLook86,
+        740..=754 => UnlockRecipes721<'a>,
+        755..=758 => DeathCombatEvent755<'a>,
+        759 => FacePlayer389,
+        760 => EnterCombatEvent755,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1499,25 +1634,27 @@ parsing_tree! {
     0x36 => {
         0..=5 => SignEditorOpen0,
         6..=66 => SignEditorOpen6,
+        86..=317 => Camera9,
+        332..=335 => SelectAdvancementTab332<'a>,
+        336..=340 => EntityHeadLook7,
+        389..=450 => RemoveEntityEffect7,
+        471..=498 => UnlockRecipes389<'a>,
+        550..=578 => PositionAndLook86,
+        721..=736 => UnlockRecipes721<'a>,
+        740..=754 => DestroyEntities7,
+        755..=758 => PlayerListItem28<'a>,
+        759 => PositionAndLook755,
+        760 => DeathCombatEvent755<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=736 => _721,
+        // 579..=719 => _550,
         // 738 => _738,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1534,23 +1671,25 @@ parsing_tree! {
     },
     0x37 => {
         0..=66 => Statistics0<'a>,
+        86..=317 => HeldItemChange0,
+        318..=335 => WorldBorder32,
+        336..=340 => SelectAdvancementTab332<'a>,
+        389..=450 => ResourcePackSend32<'a>,
+        471..=498 => DestroyEntities7,
+        550..=578 => UnlockRecipes389<'a>,
+        721..=738 => DestroyEntities7,
+        740..=754 => RemoveEntityEffect7,
+        755..=758 => FacePlayer389,
+        759 => UnlockRecipes721<'a>,
+        760 => PlayerListItem759<'a>,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1571,24 +1710,26 @@ parsing_tree! {
         17..=18 => PlayerListItem17<'a>,
         19..=27 => PlayerListItem19<'a>,
         28..=66 => PlayerListItem28<'a>,
+        86..=317 => DisplayScoreboard0<'a>,
+        318..=335 => Camera9,
+        336..=340 => WorldBorder32,
+        389..=450 => Respawn86<'a>,
+        471..=498 => RemoveEntityEffect7,
+        550..=578 => DestroyEntities7,
+        721..=738 => RemoveEntityEffect7,
+        740..=754 => ResourcePackSend32<'a>,
+        755..=758 => PositionAndLook755,
+        759 => DestroyEntities7,
+        760 => FacePlayer389,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=463 => _461,
         // 464..=470 => _464,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
-        // 740..=754 => _740,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741827 => _1073741825,
         // 1073741829..=1073741831 => _1073741829,
         // 1073741832..=1073741833 => _1073741832,
@@ -1606,24 +1747,26 @@ parsing_tree! {
     },
     0x39 => {
         0..=66 => PlayerAbilities0,
+        86..=317 => EntityMetadata57 => EntityMetadata7<EntityMetadata57<'a>>,
+        318..=335 => HeldItemChange0,
+        336..=340 => Camera9,
+        389..=450 => EntityHeadLook7,
+        471..=498 => ResourcePackSend32<'a>,
+        550..=578 => RemoveEntityEffect7,
+        721..=738 => ResourcePackSend32<'a>,
+        748..=754 => Respawn748<'a>,
+        755..=758 => UnlockRecipes721<'a>,
+        759 => RemoveEntityEffect758,
+        760 => PositionAndLook755,
         // 67..=79 => _67,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=738 => _721,
+        // 579..=719 => _550,
         // 740..=746 => _740,
-        // 748..=754 => _748,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1642,26 +1785,28 @@ parsing_tree! {
     },
     0x3a => {
         0..=66 => TabComplete0<'a>,
+        86..=317 => AttachEntity86,
+        318..=335 => DisplayScoreboard0<'a>,
+        336..=340 => HeldItemChange0,
+        389..=450 => SelectAdvancementTab332<'a>,
+        471..=498 => Respawn471<'a>,
+        550..=578 => ResourcePackSend32<'a>,
+        730..=738 => Respawn730<'a>,
+        740..=754 => EntityHeadLook7,
+        755 => DestroyEntity755,
+        756..=758 => DestroyEntities7,
+        759 => ResourcePackSend755<'a>,
+        760 => UnlockRecipes721<'a>,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
+        // 579..=719 => _550,
         // 721..=729 => _721,
-        // 730..=738 => _730,
-        // 740..=754 => _740,
-        // 755 => _755,
-        // 756..=758 => _756,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1680,30 +1825,32 @@ parsing_tree! {
     0x3b => {
         0..=11 => ScoreboardObjective0<'a>,
         12..=66 => ScoreboardObjective12<'a>,
+        86..=317 => EntityVelocity7,
+        318..=335 => EntityMetadata57 => EntityMetadata7<EntityMetadata57<'a>>,
+        336..=340 => DisplayScoreboard0<'a>,
+        389..=450 => WorldBorder32,
+        471..=498 => EntityHeadLook7,
+        552..=578 => Respawn552<'a>,
+        721..=738 => EntityHeadLook7,
+        748..=754 => MultiBlockChange748,
+        755..=757 => RemoveEntityEffect7,
+        758 => RemoveEntityEffect758,
+        759 => Respawn759<'a>,
+        760 => DestroyEntities7,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
         // 550..=551 => _550,
-        // 552..=713 => _552,
+        // 579..=713 => _552,
         // 714..=715 => _714,
         // 716..=717 => _716,
         // 718 => _718,
         // 719 => _719,
-        // 721..=738 => _721,
         // 740..=746 => _740,
-        // 748..=754 => _748,
-        // 755..=757 => _755,
-        // 758 => _758,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1723,26 +1870,26 @@ parsing_tree! {
     0x3c => {
         0..=6 => UpdateScore0<'a>,
         7..=18 => UpdateScore7<'a>,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=20 => UpdateScore7<'a>,
         21..=66 => UpdateScore21<'a>,
+        86..=317 => EntityEquipment49<'a>,
+        318..=335 => AttachEntity86,
+        336..=340 => EntityMetadata57 => EntityMetadata7<EntityMetadata57<'a>>,
+        389..=450 => Camera9,
+        471..=498 => SelectAdvancementTab332<'a>,
+        550..=578 => EntityHeadLook7,
+        721..=754 => SelectAdvancementTab332<'a>,
+        755..=758 => ResourcePackSend755<'a>,
+        759 => EntityHeadLook7,
+        760 => RemoveEntityEffect758,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1756,25 +1903,29 @@ parsing_tree! {
         // 1073741904..=1073741907 => _1073741904,
         // 1073741908..=1073741919 => _1073741908,
         // 1073741920 => _1073741920,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x3d => {
         0..=66 => DisplayScoreboard0<'a>,
+        86..=317 => SetExperience7,
+        318..=335 => EntityVelocity7,
+        336..=340 => AttachEntity86,
+        389..=450 => HeldItemChange0,
+        471..=498 => WorldBorder32,
+        550..=578 => SelectAdvancementTab332<'a>,
+        721..=754 => WorldBorder32,
+        755..=758 => Respawn748<'a>,
+        759 => MultiBlockChange748,
+        760 => ResourcePackSend755<'a>,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1793,22 +1944,24 @@ parsing_tree! {
         0..=6 => Teams0<'a>,
         7..=10 => Teams7<'a>,
         11..=66 => Teams11<'a>,
+        86..=317 => UpdateHealth7,
+        318..=335 => EntityEquipment49<'a>,
+        336..=340 => EntityVelocity7,
+        389..=450 => DisplayScoreboard0<'a>,
+        471..=498 => Camera9,
+        550..=578 => WorldBorder32,
+        721..=754 => Camera9,
+        755..=758 => EntityHeadLook7,
+        759 => SelectAdvancementTab332<'a>,
+        760 => Respawn759<'a>,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1827,22 +1980,26 @@ parsing_tree! {
         0..=28 => PluginMessage0<'a>,
         29..=31 => PluginMessage29<'a>,
         32..=66 => PluginMessage32<'a>,
+        86..=317 => ScoreboardObjective86<'a>,
+        318..=335 => SetExperience7,
+        336..=340 => EntityEquipment49<'a>,
+        389..=401 => EntityMetadata353 => EntityMetadata7<EntityMetadata353<'a>>,
+        402..=440 => EntityMetadata402 => EntityMetadata7<EntityMetadata402<'a>>,
+        441..=450 => EntityMetadata441 => EntityMetadata7<EntityMetadata441<'a>>,
+        471..=498 => HeldItemChange0,
+        550..=578 => Camera9,
+        721..=754 => HeldItemChange0,
+        755..=758 => MultiBlockChange748,
+        759 => ServerData759<'a>,
+        760 => EntityHeadLook7,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1860,22 +2017,24 @@ parsing_tree! {
     0x40 => {
         0..=12 => Disconnect0<'a>,
         13..=66 => Disconnect0<'a>,
+        86..=317 => SetPassengers86,
+        318..=335 => UpdateHealth7,
+        336..=340 => SetExperience7,
+        389..=450 => AttachEntity86,
+        471..=498 => UpdateViewPosition471,
+        550..=578 => HeldItemChange0,
+        721..=754 => UpdateViewPosition471,
+        755..=758 => SelectAdvancementTab332<'a>,
+        759 => ActionBar755<'a>,
+        760 => MultiBlockChange748,
         // 67..=76 => _67,
         // 77..=85 => _77,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1892,23 +2051,25 @@ parsing_tree! {
     },
     0x41 => {
         6..=66 => ServerDifficulty6,
+        86..=317 => Teams86<'a>,
+        318..=335 => ScoreboardObjective86<'a>,
+        336..=340 => UpdateHealth7,
+        389..=450 => EntityVelocity7,
+        471..=498 => UpdateViewDistance471,
+        550..=578 => UpdateViewPosition471,
+        721..=754 => UpdateViewDistance471,
+        755..=758 => ActionBar755<'a>,
+        759 => WorldBorderCenter755,
+        760 => SelectAdvancementTab332<'a>,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1926,27 +2087,28 @@ parsing_tree! {
     0x42 => {
         7 => CombatEvent7<'a>,
         8..=18 => CombatEvent8<'a>,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=48 => CombatEvent8<'a>,
+        86..=317 => UpdateScore21<'a>,
+        318..=335 => SetPassengers86,
+        336..=340 => ScoreboardObjective86<'a>,
+        389..=401 => EntityEquipment346 => EntityEquipment346<Slot346<'a>>,
+        402..=450 => EntityEquipment402 => EntityEquipment346<Slot402<'a>>,
+        471..=498 => DisplayScoreboard0<'a>,
+        550..=578 => UpdateViewDistance471,
+        721..=754 => SpawnPosition441,
+        755..=758 => WorldBorderCenter755,
+        759 => WorldBorderLerpSize755,
+        760 => ServerData760<'a>,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -1961,29 +2123,33 @@ parsing_tree! {
         // 1073741908..=1073741919 => _1073741908,
         // 1073741920 => _1073741920,
         // 1073741921..=1073741923 => _1073741921,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x43 => {
         9..=66 => Camera9,
+        86..=317 => SpawnPosition6,
+        318..=335 => Teams86<'a>,
+        336..=340 => SetPassengers86,
+        389..=450 => SetExperience7,
+        471..=498 => EntityMetadata463 => EntityMetadata7<EntityMetadata463<'a>>,
+        550..=578 => DisplayScoreboard0<'a>,
+        721..=754 => DisplayScoreboard0<'a>,
+        755..=758 => WorldBorderLerpSize755,
+        759 => WorldBorderSize755,
+        760 => ActionBar755<'a>,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345 => _345,
         // 346..=348 => _346,
         // 349..=351 => _349,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2001,28 +2167,28 @@ parsing_tree! {
         15 => WorldBorder15,
         16 => WorldBorder16,
         17..=18 => WorldBorder17,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=31 => WorldBorder17,
         32..=66 => WorldBorder32,
+        86..=317 => TimeUpdate0,
+        318..=335 => UpdateScore21<'a>,
+        336..=340 => Teams86<'a>,
+        389..=450 => UpdateHealth7,
+        471..=498 => AttachEntity86,
+        550..=578 => EntityMetadata463 => EntityMetadata7<EntityMetadata463<'a>>,
+        721..=754 => EntityMetadata706 => EntityMetadata7<EntityMetadata706<'a>>,
+        755..=758 => WorldBorderSize755,
+        759 => WorldBorderWarningDelay755,
+        760 => WorldBorderCenter755,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2034,30 +2200,35 @@ parsing_tree! {
         // 1073741901 => _1073741901,
         // 1073741902..=1073741919 => _1073741902,
         // 1073741920 => _1073741920,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x45 => {
         18 => Title18<'a>,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=66 => Title18<'a>,
+        86..=210 => Title18<'a>,
+        301..=317 => Title301<'a>,
+        318..=335 => SpawnPosition6,
+        336..=340 => UpdateScore21<'a>,
+        390..=450 => ScoreboardObjective390<'a>,
+        471..=498 => EntityVelocity7,
+        550..=578 => AttachEntity86,
+        721..=754 => AttachEntity86,
+        755..=758 => WorldBorderWarningDelay755,
+        759 => WorldBorderWarningReach755,
+        760 => WorldBorderLerpSize755,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=317 => _86,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 211..=300 => _86,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
         // 389 => _389,
-        // 390..=450 => _390,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2065,29 +2236,34 @@ parsing_tree! {
         // 1073741840..=1073741903 => _1073741840,
         // 1073741904..=1073741907 => _1073741904,
         // 1073741908..=1073741920 => _1073741908,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x46 => {
         27..=66 => SetCompression27,
+        86..=109 => UpdateSign6<'a>,
+        110 => SoundEffect95,
+        201..=317 => SoundEffect201,
+        318..=335 => TimeUpdate0,
+        336..=340 => SpawnPosition6,
+        389..=450 => SetPassengers86,
+        471..=498 => EntityEquipment402 => EntityEquipment346<Slot402<'a>>,
+        550..=578 => EntityVelocity7,
+        721..=754 => EntityVelocity7,
+        755..=758 => WorldBorderWarningReach755,
+        759 => Camera9,
+        760 => WorldBorderSize755,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=109 => _86,
-        // 110 => _110,
-        // 201..=317 => _201,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=374 => _352,
         // 375..=388 => _375,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2099,28 +2275,30 @@ parsing_tree! {
     },
     0x47 => {
         28..=66 => PlayerListHeaderAndFooter28<'a>,
+        95..=109 => SoundEffect95,
+        110..=317 => PlayerListHeaderAndFooter28<'a>,
+        318..=335 => Title301<'a>,
+        336..=340 => TimeUpdate0,
+        390..=450 => Teams390<'a>,
+        471..=498 => SetExperience7,
+        550..=578 => EntityEquipment402 => EntityEquipment346<Slot402<'a>>,
+        732..=754 => EntityEquipment732 => EntityEquipment732<Slot402<'a>>,
+        755..=758 => Camera9,
+        759 => HeldItemChange0,
+        760 => WorldBorderWarningDelay755,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
         // 86..=94 => _86,
-        // 95..=109 => _95,
-        // 110..=317 => _110,
-        // 318..=335 => _318,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
         // 389 => _389,
-        // 390..=450 => _390,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
         // 721..=730 => _721,
-        // 732..=754 => _732,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2136,29 +2314,31 @@ parsing_tree! {
     },
     0x48 => {
         32..=66 => ResourcePackSend32<'a>,
+        86..=109 => PlayerListHeaderAndFooter28<'a>,
+        110..=210 => CollectItem7,
+        301..=317 => CollectItem301,
+        326..=335 => SoundEffect201,
+        336..=340 => Title301<'a>,
+        389..=450 => UpdateScore21<'a>,
+        471..=498 => UpdateHealth7,
+        550..=578 => SetExperience7,
+        721..=754 => SetExperience7,
+        755..=758 => HeldItemChange0,
+        759 => UpdateViewPosition471,
+        760 => WorldBorderWarningReach755,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=109 => _86,
-        // 110..=210 => _110,
-        // 301..=317 => _301,
         // 318..=320 => _318,
         // 321 => _321,
         // 322..=325 => _322,
-        // 326..=335 => _326,
-        // 336..=344 => _336,
+        // 341..=344 => _336,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2174,27 +2354,31 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x49 => {
+        86..=109 => CollectItem7,
+        110..=317 => EntityTeleport100,
+        318..=335 => PlayerListHeaderAndFooter28<'a>,
+        336..=340 => SoundEffect201,
+        389..=440 => SpawnPosition6,
+        441..=450 => SpawnPosition441,
+        471..=498 => ScoreboardObjective390<'a>,
+        550..=578 => UpdateHealth7,
+        721..=754 => UpdateHealth7,
+        755..=758 => UpdateViewPosition471,
+        759 => UpdateViewDistance471,
+        760 => Camera9,
         // 33..=48 => _33,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=109 => _86,
-        // 110..=317 => _110,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759..=760 => _759,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2211,30 +2395,30 @@ parsing_tree! {
     },
     0x4a => {
         48 => SetCoolDown48,
-        // SetCoolDown was immediately removed again in the next version
-        // 49..=59
+        100..=109 => EntityTeleport100,
+        110..=317 => EntityProperties7<'a>,
+        318..=335 => CollectItem301,
+        336..=340 => PlayerListHeaderAndFooter28<'a>,
+        389..=450 => TimeUpdate0,
+        471..=498 => SetPassengers86,
+        550..=578 => ScoreboardObjective390<'a>,
+        721..=754 => ScoreboardObjective390<'a>,
+        755..=758 => UpdateViewDistance471,
+        759 => SpawnPosition755,
+        760 => HeldItemChange0,
         // 60..=66 => _60,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=85 => _80,
         // 86..=99 => _86,
-        // 100..=109 => _100,
-        // 110..=317 => _110,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2248,27 +2432,31 @@ parsing_tree! {
         // 1073741904..=1073741907 => _1073741904,
         // 1073741908..=1073741919 => _1073741908,
         // 1073741920 => _1073741920,
+        // SetCoolDown was immediately removed again in the next version
+        // 49..=59
     },
     0x4b => {
+        86..=109 => EntityProperties7<'a>,
+        110..=317 => EntityEffect86,
+        318..=335 => EntityTeleport100,
+        336..=340 => CollectItem301,
+        389..=450 => Title301<'a>,
+        471..=498 => Teams390<'a>,
+        550..=578 => SetPassengers86,
+        721..=754 => SetPassengers86,
+        755..=758 => SpawnPosition755,
+        759 => SetDisplayChatPreview759,
+        760 => UpdateViewPosition471,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=109 => _86,
-        // 110..=317 => _110,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2285,25 +2473,27 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x4c => {
+        86..=109 => EntityEffect86,
+        332..=335 => Advancements332 => Advancements332<'a, Slot0<'a>>,
+        336..=340 => EntityTeleport100,
+        389..=450 => StopSound389<'a>,
+        471..=498 => UpdateScore21<'a>,
+        550..=578 => Teams390<'a>,
+        721..=754 => Teams390<'a>,
+        755..=759 => DisplayScoreboard0<'a>,
+        760 => UpdateViewDistance471,
         // 77..=79 => _77,
         // 80..=85 => _80,
-        // 86..=109 => _86,
         // 110..=317 => _110,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2320,23 +2510,27 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x4d => {
+        332..=335 => EntityProperties7<'a>,
+        336..=340 => Advancements332 => Advancements332<'a, Slot0<'a>>,
+        389..=450 => SoundEffect201,
+        471..=498 => SpawnPosition441,
+        550..=578 => UpdateScore21<'a>,
+        721..=754 => UpdateScore21<'a>,
+        755..=756 => EntityMetadata755 => EntityMetadata7<EntityMetadata755<'a>>,
+        757..=758 => EntityMetadata757 => EntityMetadata7<EntityMetadata757<'a>>,
+        759 => EntityMetadata759 => EntityMetadata7<EntityMetadata759<'a>>,
+        760 => SpawnPosition755,
         // 80..=85 => _80,
         // 86..=317 => _86,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=450 => _389,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2353,22 +2547,24 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x4e => {
+        332..=335 => EntityEffect86,
+        336..=340 => EntityProperties7<'a>,
+        389..=404 => PlayerListHeaderAndFooter28<'a>,
+        471..=498 => TimeUpdate0,
+        550..=578 => SpawnPosition441,
+        721..=754 => TimeUpdate0,
+        755..=759 => AttachEntity86,
+        760 => SetDisplayChatPreview759,
         // 330..=331 => _330,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=706 => _550,
+        // 579..=706 => _550,
         // 707..=719 => _707,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2385,19 +2581,21 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x4f => {
-        // 336..=342 => _336,
+        336..=340 => EntityEffect86,
+        389..=404 => CollectItem301,
+        471..=498 => Title301<'a>,
+        550..=578 => TimeUpdate0,
+        721..=754 => Title301<'a>,
+        755..=759 => EntityVelocity7,
+        760 => DisplayScoreboard0<'a>,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2414,18 +2612,19 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x50 => {
+        389..=404 => EntityTeleport100,
+        471..=498 => EntitySoundEffect471,
+        550..=578 => Title301<'a>,
+        721..=754 => EntitySoundEffect471,
+        755..=759 => EntityEquipment732 => EntityEquipment732<Slot402<'a>>,
+        760 => EntityMetadata759 => EntityMetadata7<EntityMetadata759<'a>>,
         // 343..=344 => _343,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2442,17 +2641,19 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x51 => {
+        389..=401 => Advancements346 => Advancements332<'a, Slot346<'a>>,
+        402..=404 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        471..=498 => SoundEffect201,
+        550..=578 => EntitySoundEffect471,
+        721..=754 => SoundEffect201,
+        755..=759 => SetExperience7,
+        760 => AttachEntity86,
         // 345..=351 => _345,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2469,17 +2670,18 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x52 => {
+        389..=404 => EntityProperties7<'a>,
+        471..=498 => StopSound389<'a>,
+        550..=578 => SoundEffect201,
+        721..=754 => StopSound389<'a>,
+        755..=759 => UpdateHealth7,
+        760 => EntityVelocity7,
         // 348..=351 => _348,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2496,17 +2698,18 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x53 => {
+        389..=404 => EntityEffect86,
+        471..=498 => PlayerListHeaderAndFooter28<'a>,
+        550..=578 => StopSound389<'a>,
+        721..=754 => PlayerListHeaderAndFooter28<'a>,
+        755..=759 => ScoreboardObjective390<'a>,
+        760 => EntityEquipment732 => EntityEquipment732<Slot402<'a>>,
         // 349..=351 => _349,
         // 352..=388 => _352,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2523,16 +2726,18 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x54 => {        // 352..=372 => _352,
+        389..=401 => DeclareRecipes389 => DeclareRecipes389<'a, Slot346<'a>>,
+        402..=404 => DeclareRecipes402 => DeclareRecipes389<'a, Slot402<'a>>,
+        471..=498 => NbtQueryResponse389<'a>,
+        550..=578 => PlayerListHeaderAndFooter28<'a>,
+        721..=754 => NbtQueryResponse389<'a>,
+        755..=759 => SetPassengers86,
+        760 => SetExperience7,
         // 373..=388 => _373,
-        // 389..=404 => _389,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2549,15 +2754,16 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x55 => {
-        // 389..=404 => _389,
+        389..=404 => Tags389<'a>,
+        471..=498 => CollectItem301,
+        550..=578 => NbtQueryResponse389<'a>,
+        721..=754 => CollectItem301,
+        755..=759 => Teams390<'a>,
+        760 => UpdateHealth7,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2574,15 +2780,16 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x56 => {
+        471..=498 => EntityTeleport100,
+        550..=578 => CollectItem301,
+        721..=754 => EntityTeleport100,
+        755..=759 => UpdateScore21<'a>,
+        760 => ScoreboardObjective390<'a>,
         // 443..=450 => _443,
         // 451..=452 => _451,
         // 453..=460 => _453,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=759 => _755,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2599,15 +2806,16 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x57 => {
+        471..=498 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        550..=578 => EntityTeleport100,
+        721..=754 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        755..=756 => SetTitleSubtitle755<'a>,
+        757..=759 => UpdateSimulationDistance757,
+        760 => SetPassengers86,
         // 443..=450 => _443,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2624,14 +2832,15 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x58 => {
+        471..=498 => EntityProperties7<'a>,
+        550..=578 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        721..=754 => EntityProperties7<'a>,
+        755..=756 => TimeUpdate0,
+        757..=759 => SetTitleSubtitle755<'a>,
+        760 => Teams390<'a>,
         // 451..=460 => _451,
         // 461..=470 => _461,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2648,16 +2857,17 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x59 => {
+        471..=498 => EntityEffect86,
+        550..=578 => EntityProperties7<'a>,
+        721..=754 => EntityEffect86,
+        755..=756 => SetTitleText755<'a>,
+        757..=759 => TimeUpdate0,
+        760 => UpdateScore21<'a>,
         // 452..=460 => _452,
         // 461..=463 => _461,
         // 464..=470 => _464,
-        // 471..=498 => _471,
-        // 550..=709 => _550,
+        // 579..=709 => _550,
         // 710..=719 => _710,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2675,15 +2885,16 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x5a => {
+        471..=498 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        550..=578 => EntityEffect86,
+        721..=754 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        755..=756 => SetTitleTimes755,
+        757..=759 => SetTitleText755<'a>,
+        760 => UpdateSimulationDistance757,
         // 452..=460 => _452,
         // 461..=467 => _461,
         // 468..=470 => _468,
-        // 471..=498 => _471,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2701,13 +2912,13 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x5b => {
-        // 471..=485 => _471,
-        // 486..=498 => _486,
-        // 550..=719 => _550,
-        // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
+        471..=498 => Tags443<'a>,
+        550..=578 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        721..=754 => Tags443<'a>,
+        755..=756 => EntitySoundEffect471,
+        757..=759 => SetTitleTimes755,
+        760 => SetTitleSubtitle755<'a>,
+        // 579..=719 => _550,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832..=1073741833 => _1073741832,
         // 1073741834 => _1073741834,
@@ -2724,13 +2935,14 @@ parsing_tree! {
         // 1073741909..=1073741919 => _1073741909,
     },
     0x5c => {
-        // 494..=498 => _494,
-        // 550..=719 => _550,
+        494..=498 => AcknowledgePlayerDigging494,
+        550..=578 => Tags443<'a>,
+        755..=756 => SoundEffect201,
+        757..=758 => EntitySoundEffect471,
+        759 => EntitySoundEffect759,
+        760 => TimeUpdate0,
+        // 579..=719 => _550,
         // 721..=754 => _721,
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741831 => _1073741825,
         // 1073741832 => _1073741832,
         // 1073741833 => _1073741833,
@@ -2751,10 +2963,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x5d => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => StopSound389<'a>,
+        757..=758 => SoundEffect201,
+        759 => SoundEffect759,
+        760 => SetTitleText755<'a>,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2770,9 +2982,9 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x5e => {
-        // 755..=756 => _755,
-        // 757..=759 => _757,
-        // 760 => _760,
+        755..=756 => PlayerListHeaderAndFooter28<'a>,
+        757..=759 => StopSound389<'a>,
+        760 => SetTitleTimes755,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2788,10 +3000,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x5f => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => NbtQueryResponse389<'a>,
+        757..=758 => PlayerListHeaderAndFooter28<'a>,
+        759 => SystemChatMessage759<'a>,
+        760 => EntitySoundEffect759,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2808,10 +3020,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x60 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => CollectItem301,
+        757..=758 => NbtQueryResponse389<'a>,
+        759 => PlayerListHeaderAndFooter28<'a>,
+        760 => SoundEffect759,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2827,10 +3039,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x61 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => EntityTeleport100,
+        757..=758 => CollectItem301,
+        759 => NbtQueryResponse389<'a>,
+        760 => StopSound389<'a>,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2846,10 +3058,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x62 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        757..=758 => EntityTeleport100,
+        759 => CollectItem301,
+        760 => SystemChatMessage760<'a>,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2866,10 +3078,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x63 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => EntityProperties755<'a>,
+        757..=758 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        759 => EntityTeleport100,
+        760 => PlayerListHeaderAndFooter28<'a>,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2886,10 +3098,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x64 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => EntityEffect86,
+        757..=758 => EntityProperties755<'a>,
+        759 => Advancements402 => Advancements332<'a, Slot402<'a>>,
+        760 => NbtQueryResponse389<'a>,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2905,11 +3117,11 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x65 => {
-        // 755..=756 => _755,
-        // 757 => _757,
-        // 758 => _758,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        757 => EntityEffect86,
+        758 => EntityEffect758,
+        759 => EntityProperties755<'a>,
+        760 => CollectItem301,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2926,10 +3138,10 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x66 => {
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        755..=756 => Tags755<'a>,
+        757..=758 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        759 => EntityEffect759<'a>,
+        760 => EntityTeleport100,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741850 => _1073741842,
@@ -2943,9 +3155,9 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x67 => {
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
+        757..=758 => Tags755<'a>,
+        759 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+        760 => Advancements402 => Advancements332<'a, Slot402<'a>>,
         // 1073741825..=1073741867 => _1073741825,
         // 1073741868..=1073741898 => _1073741868,
         // 1073741899..=1073741900 => _1073741899,
@@ -2956,20 +3168,27 @@ parsing_tree! {
         // 1073741920 => _1073741920,
     },
     0x68 => {
-        // 759 => _759,
-        // 760 => _760,
+        759 => Tags755<'a>,
+        760 => EntityProperties755<'a>,
         // 1073741825..=1073741908 => _1073741825,
         // 1073741909..=1073741919 => _1073741909,
         // 1073741920 => _1073741920,
     },
     0x69 => {
-        // 760 => _760,
+        760 => EntityEffect759<'a>,
         // 1073741825..=1073741919 => _1073741825,
         // 1073741920 => _1073741920,
     },
+    0x6a => {
+        760 => DeclareRecipes471 => DeclareRecipes471<'a, Slot402<'a>>,
+    },
+    0x6b => {
+        760 => Tags755<'a>,
+    },
 }
 
 play_cb_custom! {
+    #[derive(Debug)]
     pub enum CbPlay<'a> {
         #(#PacketName(#PacketTypeLt),)
     }
@@ -2990,6 +3209,11 @@ impl<'a> CbPlay<'a> {
     pub fn parse(packet: RawPacket<'a>, version: ProtocolVersion) -> Result<Self, decode::Error> {
         let (id, data): (i32, &[u8]) = packet.into();
         let mut cursor = std::io::Cursor::new(data);
+        if !is_mapped(version) {
+            return Err(decode::Error::Custom(
+                "the play packets of this version aren't mapped",
+            ));
+        }
         let pv = *version;
         play_cb_tree! {
             id, pv,
@@ -3004,43 +3228,51 @@ parsing_tree! {
     0x00 => {
         0..=6 => KeepAlive0,
         7..=66 => KeepAlive7,
+        80..=340 => TeleportConfirm80,
+        389..=760 => TeleportConfirm80,
         // 67..=79 => _67,
     },
     0x01 => {
         0..=79 => ChatMessage0<'a>,
-        // 80..=317 => _80,
+        80..=317 => TabComplete80<'a>,
+        322..=335 => PrepareCraftingGrid322<'a>,
+        336..=340 => TabComplete80<'a>,
+        389..=440 => QueryBlockNbt389,
+        441..=760 => QueryBlockNbt441,
         // 318..=319 => _318,
         // 320..=321 => _320,
-        // 322..=335 => _322,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=388 => _343,
     },
     0x02 => {
         0..=6 => UseEntity0,
         7..=18 => UseEntity7,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=32 => UseEntity7,
         33..=48 => UseEntity33,
+        80..=317 => ChatMessage0<'a>,
+        318..=335 => TabComplete80<'a>,
+        336..=340 => ChatMessage0<'a>,
+        389..=463 => ChatMessage0<'a>,
+        464..=760 => SetDifficulty464,
         // 49..=66 => _49,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=388 => _343,
-        // 389..=463 => _389,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x03 => {
         0..=66 => Player0,
+        80..=317 => ClientStatus80,
+        318..=335 => ChatMessage0<'a>,
+        336..=340 => ClientStatus80,
+        389..=463 => ClientStatus80,
+        464..=758 => ChatMessage0<'a>,
+        759 => ChatCommand759<'a>,
+        760 => MessageAcknowledgment760<'a>,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=388 => _343,
-        // 389..=463 => _389,
-        // 464..=758 => _464,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741905 => _1073741825,
         // 1073741906 => _1073741906,
         // 1073741907..=1073741908 => _1073741907,
@@ -3049,34 +3281,36 @@ parsing_tree! {
     0x04 => {
         0..=9 => PlayerPosition0,
         10..=66 => PlayerPosition10,
+        80..=317 => ClientSettings80<'a>,
+        318..=335 => ClientStatus80,
+        336..=340 => ClientSettings80<'a>,
+        389..=463 => ClientSettings80<'a>,
+        464..=758 => ClientStatus80,
+        759 => ChatMessage759<'a>,
+        760 => ChatCommand760<'a>,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=463 => _389,
-        // 464..=758 => _464,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741906 => _1073741825,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
     },
     0x05 => {
         0..=66 => PlayerLook0,
+        80..=317 => ConfirmTransaction0,
+        318..=335 => ClientSettings80<'a>,
+        336..=340 => ConfirmTransaction0,
+        389..=463 => TabComplete389<'a>,
+        464..=754 => ClientSettings80<'a>,
+        755..=756 => ClientSettings755<'a>,
+        757..=758 => ClientSettings757<'a>,
+        759 => ChatPreview759<'a>,
+        760 => ChatMessage760<'a>,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755..=756 => _755,
-        // 757..=758 => _757,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741838 => _1073741825,
         // 1073741839..=1073741871 => _1073741839,
         // 1073741872..=1073741906 => _1073741872,
@@ -3086,16 +3320,17 @@ parsing_tree! {
     0x06 => {
         0..=9 => PlayerPositionAndLook0,
         10..=66 => PlayerPositionAndLook10,
+        80..=317 => EnchantItem0,
+        318..=335 => ConfirmTransaction0,
+        336..=340 => EnchantItem0,
+        389..=463 => ConfirmTransaction0,
+        464..=758 => TabComplete389<'a>,
+        759 => ClientStatus80,
+        760 => ChatPreview759<'a>,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=463 => _389,
-        // 464..=758 => _464,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741906 => _1073741825,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
@@ -3103,42 +3338,45 @@ parsing_tree! {
     0x07 => {
         0..=5 => PlayerDigging0,
         6..=18 => PlayerDigging6,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=66 => PlayerDigging6,
+        83..=317 => ClickWindow83 => ClickWindow83<Slot0<'a>>,
+        318..=335 => EnchantItem0,
+        336..=340 => ClickWindow83 => ClickWindow83<Slot0<'a>>,
+        389..=463 => EnchantItem0,
+        464..=754 => ConfirmTransaction0,
+        755..=758 => EnchantItem0,
+        759 => ClientSettings757<'a>,
+        760 => ClientStatus80,
         // 67..=79 => _67,
         // 80..=82 => _80,
-        // 83..=317 => _83,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x08 => {
         0..=5 => PlayerBlockPlacement0,
         6..=48 => PlayerBlockPlacement6,
+        80..=317 => CloseWindow0,
+        318..=335 => ClickWindow83 => ClickWindow83<Slot0<'a>>,
+        336..=340 => CloseWindow0,
+        389..=401 => ClickWindow346 => ClickWindow83<Slot346<'a>>,
+        402..=463 => ClickWindow402 => ClickWindow83<Slot402<'a>>,
+        464..=754 => EnchantItem0,
+        755 => ClickWindow755 => ClickWindow755<Slot402<'a>>,
+        756..=758 => ClickWindow756 => ClickWindow756<Slot402<'a>>,
+        759 => TabComplete389<'a>,
+        760 => ClientSettings757<'a>,
         // 49..=66 => _49,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=388 => _345,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755 => _755,
-        // 756..=758 => _756,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741859 => _1073741842,
         // 1073741860..=1073741906 => _1073741860,
@@ -3147,19 +3385,20 @@ parsing_tree! {
     },
     0x09 => {
         0..=48 => HeldItemChange0,
+        80..=317 => PluginMessage32<'a>,
+        318..=335 => CloseWindow0,
+        336..=340 => PluginMessage32<'a>,
+        389..=463 => CloseWindow0,
+        464..=754 => ClickWindow402 => ClickWindow83<Slot402<'a>>,
+        755..=758 => CloseWindow0,
+        759 => EnchantItem0,
+        760 => TabComplete389<'a>,
         // 49..=66 => _49,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3168,19 +3407,20 @@ parsing_tree! {
     0x0a => {
         0..=6 => Animation0,
         7..=48 => Animation7,
+        80..=317 => UseEntity80,
+        318..=335 => PluginMessage32<'a>,
+        336..=340 => UseEntity80,
+        389..=463 => PluginMessage32<'a>,
+        464..=754 => CloseWindow0,
+        755..=758 => PluginMessage32<'a>,
+        759 => ClickWindow756 => ClickWindow756<Slot402<'a>>,
+        760 => EnchantItem0,
         // 49..=66 => _49,
         // 67..=79 => _67,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3189,49 +3429,51 @@ parsing_tree! {
     0x0b => {
         0..=6 => EntityAction0,
         7..=18 => EntityAction7,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=48 => EntityAction7,
+        80..=317 => KeepAlive7,
+        318..=335 => UseEntity80,
+        336..=338 => KeepAlive7,
+        339..=340 => KeepAlive339,
+        389..=393 => EditBook389 => EditBook389<Slot346<'a>>,
+        394..=401 => EditBook394 => EditBook394<Slot346<'a>>,
+        402..=463 => EditBook402 => EditBook394<Slot402<'a>>,
+        464..=754 => PluginMessage32<'a>,
+        755 => EditBook402 => EditBook394<Slot402<'a>>,
+        756..=758 => EditBook756<'a>,
+        759 => CloseWindow0,
+        760 => ClickWindow756 => ClickWindow756<Slot402<'a>>,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=338 => _336,
-        // 339..=342 => _339,
+        // 341..=342 => _339,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=393 => _389,
-        // 394..=463 => _394,
-        // 464..=754 => _464,
-        // 755 => _755,
-        // 756..=758 => _756,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741859 => _1073741842,
         // 1073741860..=1073741906 => _1073741860,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x0c => {
         0..=6 => SteerVehicle0,
         7..=48 => SteerVehicle7,
+        77..=317 => PlayerPosition10,
+        318..=335 => KeepAlive7,
+        336..=340 => Player0,
+        389..=463 => QueryEntityNbt389,
+        464..=754 => EditBook402 => EditBook394<Slot402<'a>>,
+        755..=758 => QueryEntityNbt389,
+        759 => PluginMessage32<'a>,
+        760 => CloseWindow0,
         // 49..=66 => _49,
         // 67..=76 => _67,
-        // 77..=317 => _77,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=753 => _464,
-        // 754 => _754,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3239,20 +3481,21 @@ parsing_tree! {
     },
     0x0d => {
         0..=48 => CloseWindow0,
+        77..=317 => PlayerPositionAndLook10,
+        332..=335 => Player0,
+        336..=340 => PlayerPosition10,
+        389..=463 => UseEntity80,
+        464..=754 => QueryEntityNbt389,
+        755..=758 => UseEntity729,
+        759 => EditBook756<'a>,
+        760 => PluginMessage32<'a>,
         // 49..=66 => _49,
         // 67..=76 => _67,
-        // 77..=317 => _77,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=754 => _464,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3260,21 +3503,23 @@ parsing_tree! {
     },
     0x0e => {
         0..=48 => ClickWindow0,
+        77..=317 => PlayerLook0,
+        332..=335 => PlayerPosition10,
+        336..=340 => PlayerPositionAndLook10,
+        389..=463 => KeepAlive339,
+        464..=578 => UseEntity80,
+        729..=754 => UseEntity729,
+        755..=758 => GenerateStructure719,
+        759 => QueryEntityNbt389,
+        760 => EditBook756<'a>,
         // 49..=76 => _49,
-        // 77..=317 => _77,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=722 => _464,
+        // 579..=722 => _464,
         // 725..=727 => _725,
-        // 729..=754 => _729,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741839 => _1073741825,
         // 1073741840..=1073741841 => _1073741840,
         // 1073741842..=1073741906 => _1073741842,
@@ -3283,23 +3528,25 @@ parsing_tree! {
     },
     0x0f => {
         0..=48 => ConfirmTransaction0,
+        77..=317 => Player0,
+        332..=335 => PlayerPositionAndLook10,
+        336..=340 => PlayerLook0,
+        389..=463 => Player0,
+        471..=578 => KeepAlive339,
+        719..=754 => GenerateStructure719,
+        755..=758 => KeepAlive339,
+        759 => UseEntity729,
+        760 => QueryEntityNbt389,
         // 49..=66 => _49,
         // 67..=76 => _67,
-        // 77..=317 => _77,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
+        // 579..=711 => _471,
         // 712..=718 => _712,
-        // 719..=754 => _719,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3307,23 +3554,25 @@ parsing_tree! {
     },
     0x10 => {
         0..=48 => CreativeInventoryAction0,
+        80..=317 => VehicleMove80,
+        332..=335 => PlayerLook0,
+        336..=340 => VehicleMove80,
+        389..=463 => PlayerPosition10,
+        471..=578 => LockDifficulty471,
+        712..=754 => KeepAlive339,
+        755..=758 => LockDifficulty471,
+        759 => GenerateStructure719,
+        760 => UseEntity729,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=335 => _332,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _471,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3331,23 +3580,25 @@ parsing_tree! {
     },
     0x11 => {
         0..=48 => EnchantItem0,
+        97..=317 => SteerBoat97,
+        318..=335 => VehicleMove80,
+        336..=340 => SteerBoat97,
+        389..=463 => PlayerPositionAndLook10,
+        471..=578 => PlayerPosition10,
+        712..=754 => LockDifficulty471,
+        755..=758 => PlayerPosition10,
+        759 => KeepAlive339,
+        760 => GenerateStructure719,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
         // 80..=96 => _80,
-        // 97..=317 => _97,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _471,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3356,52 +3607,56 @@ parsing_tree! {
     0x12 => {
         0..=5 => UpdateSign0<'a>,
         6..=20 => UpdateSign6<'a>,
-        // chatcomponent
         21..=46 => UpdateSign6<'a>,
-        // no real changes
         47 => UpdateSign6<'a>,
-        // revert whatever was changed before
         48 => UpdateSign6<'a>,
+        80..=317 => PlayerAbilities0,
+        318..=335 => SteerBoat97,
+        336..=340 => CraftRecipeRequest336,
+        389..=463 => PlayerLook0,
+        471..=578 => PlayerPositionAndLook10,
+        712..=754 => PlayerPosition10,
+        755..=758 => PlayerPositionAndLook10,
+        759 => LockDifficulty471,
+        760 => KeepAlive339,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=335 => _318,
-        // 336..=342 => _336,
+        // 341..=342 => _336,
         // 343..=344 => _343,
         // 345..=347 => _345,
         // 348..=385 => _348,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _471,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
+        // chatcomponent
+        // no real changes
+        // revert whatever was changed before
     },
     0x13 => {
         0..=48 => PlayerAbilities0,
+        80..=317 => PlayerDigging80,
+        318..=340 => PlayerAbilities0,
+        389..=463 => VehicleMove80,
+        471..=578 => PlayerLook0,
+        712..=754 => PlayerPositionAndLook10,
+        755..=758 => PlayerLook0,
+        759 => PlayerPosition10,
+        760 => LockDifficulty471,
         // 49..=61 => _49,
         // 62..=66 => _62,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=342 => _318,
+        // 341..=342 => _318,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _471,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3410,21 +3665,23 @@ parsing_tree! {
     0x14 => {
         0..=36 => TabComplete0<'a>,
         37..=48 => TabComplete37<'a>,
+        80..=317 => EntityAction80,
+        318..=340 => PlayerDigging80,
+        389..=463 => SteerBoat97,
+        471..=578 => Player0,
+        712..=754 => PlayerLook0,
+        755..=758 => Player0,
+        759 => PlayerPositionAndLook10,
+        760 => PlayerPosition10,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=342 => _318,
+        // 341..=342 => _318,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=470 => _464,
-        // 471..=711 => _471,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _471,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3433,21 +3690,23 @@ parsing_tree! {
     0x15 => {
         0..=5 => ClientSettings0<'a>,
         6..=48 => ClientSettings6<'a>,
+        80..=317 => SteerVehicle7,
+        318..=340 => EntityAction80,
+        389..=463 => PickItem389,
+        464..=578 => VehicleMove80,
+        712..=754 => Player0,
+        755..=758 => VehicleMove80,
+        759 => PlayerLook0,
+        760 => PlayerPositionAndLook10,
         // 49..=58 => _49,
         // 59..=66 => _59,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=342 => _318,
+        // 341..=342 => _318,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3455,51 +3714,55 @@ parsing_tree! {
     },
     0x16 => {
         0..=18 => ClientStatus0,
-        // changed to using varint, range of values
-        // makes u8 compatible though
         19..=48 => ClientStatus0,
+        80..=203 => ResourcePackStatus32<'a>,
+        204..=317 => ResourcePackStatus204,
+        318..=340 => SteerVehicle7,
+        389..=463 => CraftRecipeRequest389<'a>,
+        464..=578 => SteerBoat97,
+        712..=754 => VehicleMove80,
+        755..=758 => SteerBoat97,
+        759 => Player0,
+        760 => PlayerLook0,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=203 => _80,
-        // 204..=317 => _204,
-        // 318..=342 => _318,
+        // 341..=342 => _318,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
+        // changed to using varint, range of values
+        // makes u8 compatible though
     },
     0x17 => {
         0..=28 => PluginMessage0<'a>,
         29..=31 => PluginMessage29<'a>,
         32..=48 => PluginMessage32<'a>,
+        80..=317 => HeldItemChange0,
+        333..=340 => CraftingBookData333,
+        389..=463 => PlayerAbilities0,
+        464..=578 => PickItem389,
+        712..=754 => SteerBoat97,
+        755..=758 => PickItem389,
+        759 => VehicleMove80,
+        760 => Player0,
         // 49..=76 => _49,
         // 77..=79 => _77,
-        // 80..=317 => _80,
         // 318..=319 => _318,
         // 320..=323 => _320,
         // 324..=332 => _324,
-        // 333..=342 => _333,
+        // 341..=342 => _333,
         // 343..=344 => _343,
         // 345..=347 => _345,
         // 348..=356 => _348,
         // 357..=385 => _357,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3507,20 +3770,23 @@ parsing_tree! {
     },
     0x18 => {
         17..=48 => Spectate17,
+        80..=317 => CreativeInventoryAction80 => CreativeInventoryAction80<Slot0<'a>>,
+        318..=340 => ResourcePackStatus204,
+        389..=440 => PlayerDigging80,
+        441..=463 => PlayerDigging441,
+        464..=578 => CraftRecipeRequest389<'a>,
+        712..=754 => PickItem389,
+        755..=758 => CraftRecipeRequest389<'a>,
+        759 => SteerBoat97,
+        760 => VehicleMove80,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
-        // 318..=342 => _318,
+        // 341..=342 => _318,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
@@ -3528,43 +3794,47 @@ parsing_tree! {
     },
     0x19 => {
         32..=48 => ResourcePackStatus32<'a>,
+        80..=317 => UpdateSign6<'a>,
+        332..=340 => AdvancementTab332<'a>,
+        389..=463 => EntityAction80,
+        464..=578 => PlayerAbilities0,
+        712..=754 => CraftRecipeRequest389<'a>,
+        755..=758 => PlayerAbilities727,
+        759 => PickItem389,
+        760 => SteerBoat97,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1a => {
+        80..=317 => Animation80,
+        332..=340 => HeldItemChange0,
+        389..=463 => SteerVehicle7,
+        464..=578 => PlayerDigging441,
+        727..=754 => PlayerAbilities727,
+        755..=758 => PlayerDigging441,
+        759 => CraftRecipeRequest389<'a>,
+        760 => PickItem389,
         // 49..=66 => _49,
         // 67..=76 => _67,
         // 77..=79 => _77,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
+        // 579..=711 => _464,
         // 712..=725 => _712,
-        // 727..=754 => _727,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741897 => _1073741842,
         // 1073741898..=1073741900 => _1073741898,
@@ -3574,59 +3844,65 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1b => {
+        80..=317 => Spectate17,
+        332..=340 => CreativeInventoryAction80 => CreativeInventoryAction80<Slot0<'a>>,
+        389..=450 => RecipeBookData389<'a>,
+        464..=578 => EntityAction80,
+        712..=754 => PlayerDigging441,
+        755..=758 => EntityAction80,
+        759 => PlayerAbilities727,
+        760 => CraftRecipeRequest389<'a>,
         // 77..=79 => _77,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=450 => _389,
         // 451..=463 => _451,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1c => {
+        80..=308 => PlayerBlockPlacement80,
+        309..=317 => PlayerBlockPlacement309,
+        332..=340 => UpdateSign6<'a>,
+        389..=463 => NameItem389<'a>,
+        464..=578 => SteerVehicle7,
+        712..=754 => EntityAction80,
+        755..=758 => SteerVehicle7,
+        759 => PlayerDigging759,
+        760 => PlayerAbilities727,
         // 77..=79 => _77,
-        // 80..=308 => _80,
-        // 309..=317 => _309,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741906 => _1073741842,
         // 1073741907 => _1073741907,
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1d => {
+        80..=317 => UseItem80,
+        332..=340 => Animation80,
+        389..=463 => ResourcePackStatus204,
+        464..=578 => RecipeBookData451<'a>,
+        712..=754 => SteerVehicle7,
+        755..=758 => Pong755,
+        759 => EntityAction80,
+        760 => PlayerDigging759,
         // 79 => _79,
-        // 80..=317 => _80,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=754 => _712,
-        // 755..=758 => _755,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3634,18 +3910,20 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1e => {
+        332..=340 => Spectate17,
+        389..=463 => AdvancementTab332<'a>,
+        464..=578 => NameItem389<'a>,
+        712..=736 => RecipeBookData451<'a>,
+        738..=758 => SetRecipeBookState738,
+        759 => SteerVehicle7,
+        760 => EntityAction80,
         // 0..=317 => _0,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3653,17 +3931,19 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x1f => {
+        332..=340 => PlayerBlockPlacement309,
+        389..=463 => SelectTrade389,
+        464..=578 => ResourcePackStatus204,
+        712..=736 => NameItem389<'a>,
+        738..=758 => SetDisplayedRecipe738<'a>,
+        759 => Pong755,
+        760 => SteerVehicle7,
         // 318..=331 => _318,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3671,17 +3951,19 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x20 => {
+        332..=340 => UseItem80,
+        389..=463 => SetBeaconEffect389,
+        464..=578 => AdvancementTab332<'a>,
+        712..=736 => ResourcePackStatus204,
+        738..=758 => NameItem389<'a>,
+        759 => SetRecipeBookState738,
+        760 => Pong755,
         // 330..=331 => _330,
-        // 332..=342 => _332,
+        // 341..=342 => _332,
         // 343..=344 => _343,
         // 345..=385 => _345,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3689,13 +3971,14 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x21 => {
+        389..=463 => HeldItemChange0,
+        464..=578 => SelectTrade389,
+        712..=736 => AdvancementTab332<'a>,
+        738..=758 => ResourcePackStatus204,
+        759 => SetDisplayedRecipe738<'a>,
+        760 => SetRecipeBookState738,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3703,13 +3986,15 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x22 => {
+        389..=440 => UpdateCommandBlock389<'a>,
+        441..=463 => UpdateCommandBlock441<'a>,
+        464..=578 => SetBeaconEffect389,
+        712..=736 => SelectTrade389,
+        738..=758 => AdvancementTab332<'a>,
+        759 => NameItem389<'a>,
+        760 => SetDisplayedRecipe738<'a>,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3717,13 +4002,14 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x23 => {
+        389..=463 => UpdateCommandBlockMinecart389<'a>,
+        464..=578 => HeldItemChange0,
+        712..=736 => SetBeaconEffect389,
+        738..=758 => SelectTrade389,
+        759 => ResourcePackStatus204,
+        760 => NameItem389<'a>,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3731,13 +4017,15 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x24 => {
+        389..=401 => CreativeInventoryAction346 => CreativeInventoryAction80<Slot346<'a>>,
+        402..=463 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
+        464..=578 => UpdateCommandBlock441<'a>,
+        712..=736 => HeldItemChange0,
+        738..=758 => SetBeaconEffect389,
+        759 => AdvancementTab332<'a>,
+        760 => ResourcePackStatus204,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741902 => _1073741851,
@@ -3746,13 +4034,15 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x25 => {
+        389..=440 => UpdateStructureBlock389<'a>,
+        441..=463 => UpdateStructureBlock441<'a>,
+        464..=578 => UpdateCommandBlockMinecart389<'a>,
+        712..=736 => UpdateCommandBlock441<'a>,
+        738..=758 => HeldItemChange0,
+        759 => SelectTrade389,
+        760 => AdvancementTab332<'a>,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3760,13 +4050,15 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x26 => {
+        389..=440 => UpdateSign6<'a>,
+        441..=463 => UpdateSign441<'a>,
+        464..=578 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
+        712..=736 => UpdateCommandBlockMinecart389<'a>,
+        738..=758 => UpdateCommandBlock441<'a>,
+        759 => SetBeaconEffect759,
+        760 => SelectTrade389,
         // 386..=388 => _386,
-        // 389..=463 => _389,
-        // 464..=711 => _464,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _464,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3774,15 +4066,16 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x27 => {
+        389..=463 => Animation80,
+        468..=578 => UpdateJigsawBlock468<'a>,
+        712..=736 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
+        738..=758 => UpdateCommandBlockMinecart389<'a>,
+        759 => HeldItemChange0,
+        760 => SetBeaconEffect759,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=467 => _464,
-        // 468..=707 => _468,
+        // 579..=707 => _468,
         // 708..=711 => _708,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3790,14 +4083,15 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x28 => {
+        389..=463 => Spectate17,
+        468..=578 => UpdateStructureBlock441<'a>,
+        712..=736 => UpdateJigsawBlock712<'a>,
+        738..=758 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
+        759 => UpdateCommandBlock441<'a>,
+        760 => HeldItemChange0,
         // 386..=388 => _386,
-        // 389..=463 => _389,
         // 464..=467 => _464,
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3805,14 +4099,16 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x29 => {
-        // 389..=452 => _389,
+        389..=440 => PlayerBlockPlacement309,
+        441..=452 => PlayerBlockPlacement441,
+        468..=578 => UpdateSign441<'a>,
+        712..=736 => UpdateStructureBlock441<'a>,
+        738..=758 => UpdateJigsawBlock712<'a>,
+        759 => UpdateCommandBlockMinecart389<'a>,
+        760 => UpdateCommandBlock441<'a>,
         // 453..=463 => _453,
         // 464..=467 => _464,
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3820,13 +4116,14 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2a => {
-        // 389..=463 => _389,
+        389..=463 => UseItem80,
+        468..=578 => Animation80,
+        712..=736 => UpdateSign441<'a>,
+        738..=758 => UpdateStructureBlock441<'a>,
+        759 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
+        760 => UpdateCommandBlockMinecart389<'a>,
         // 464..=467 => _464,
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3834,12 +4131,13 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2b => {
+        468..=578 => Spectate17,
+        712..=736 => Animation80,
+        738..=758 => UpdateSign441<'a>,
+        759 => UpdateJigsawBlock712<'a>,
+        760 => CreativeInventoryAction402 => CreativeInventoryAction80<Slot402<'a>>,
         // 464..=467 => _464,
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3847,12 +4145,13 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2c => {
+        468..=578 => PlayerBlockPlacement453,
+        712..=736 => Spectate17,
+        738..=758 => Animation80,
+        759 => UpdateStructureBlock441<'a>,
+        760 => UpdateJigsawBlock712<'a>,
         // 464..=467 => _464,
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741906 => _1073741851,
@@ -3860,11 +4159,12 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2d => {
-        // 468..=711 => _468,
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        468..=578 => UseItem80,
+        712..=736 => PlayerBlockPlacement453,
+        738..=758 => Spectate17,
+        759 => UpdateSign441<'a>,
+        760 => UpdateStructureBlock441<'a>,
+        // 579..=711 => _468,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741900 => _1073741851,
@@ -3874,10 +4174,10 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2e => {
-        // 712..=736 => _712,
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760 => _760,
+        712..=736 => UseItem80,
+        738..=758 => PlayerBlockPlacement453,
+        759 => Animation80,
+        760 => UpdateSign441<'a>,
         // 1073741825..=1073741841 => _1073741825,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741897 => _1073741851,
@@ -3888,9 +4188,10 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x2f => {
-        // 738..=758 => _738,
-        // 759 => _759,
-        // 760..=1073741841 => _760,
+        738..=758 => UseItem80,
+        759 => Spectate17,
+        760 => Animation80,
+        // 761..=1073741841 => _760,
         // 1073741842..=1073741850 => _1073741842,
         // 1073741851..=1073741897 => _1073741851,
         // 1073741898..=1073741900 => _1073741898,
@@ -3900,8 +4201,8 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x30 => {
-        // 759 => _759,
-        // 760 => _760,
+        759 => PlayerBlockPlacement759,
+        760 => Spectate17,
         // 1073741825..=1073741900 => _1073741825,
         // 1073741901 => _1073741901,
         // 1073741902..=1073741906 => _1073741902,
@@ -3909,17 +4210,18 @@ parsing_tree! {
         // 1073741908..=1073741921 => _1073741908,
     },
     0x31 => {
-        // 759 => _759,
-        // 760 => _760,
+        759 => UseItem759,
+        760 => PlayerBlockPlacement759,
         // 1073741825..=1073741907 => _1073741825,
         // 1073741908..=1073741921 => _1073741908,
     },
     0x32 => {
-        // 760 => _760,
+        760 => UseItem759,
         // 1073741825..=1073741921 => _1073741825,
     },
 }
 play_sb_custom! {
+    #[derive(Debug)]
     pub enum SbPlay<'a> {
         #(#PacketName(#PacketTypeLt),)
     }
@@ -3940,6 +4242,11 @@ impl<'a> SbPlay<'a> {
     pub fn parse(packet: RawPacket<'a>, version: ProtocolVersion) -> Result<Self, decode::Error> {
         let (id, data): (i32, &[u8]) = packet.into();
         let mut cursor = std::io::Cursor::new(data);
+        if !is_mapped(version) {
+            return Err(decode::Error::Custom(
+                "the play packets of this version aren't mapped",
+            ));
+        }
         let pv = *version;
         play_sb_tree! {
             id, pv,
@@ -3948,3 +4255,15 @@ impl<'a> SbPlay<'a> {
         }
     }
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn unmapped_versions() {
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    // keep alive
+    let data = [0, 0, 0, 0, 0, 0, 0, 1];
+    assert!(CbPlay::parse(RawPacket::from((0x20, &data[..])), pv(760)).is_ok());
+    assert!(CbPlay::parse(RawPacket::from((0x20, &data[..])), pv(761)).is_err());
+    assert!(SbPlay::parse(RawPacket::from((0x12, &data[..])), pv(764)).is_err());
+    assert!(!is_mapped(pv(0x40000000 | 5)));
+}
@@ -0,0 +1,544 @@
+//! Typed payloads of plugin messages.
+//!
+//! Plugin messages only carry a channel name and raw bytes, a
+//! [`ChannelRegistry`] maps the channel names to [`PluginChannel`]s decoding
+//! the bytes. Starting with 1.13 (pv 393) channel names are namespaced, the
+//! legacy channels without a namespaced name were replaced by packets.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::{Cursor, Write};
+
+use miners_version::{Feature, ProtocolVersion};
+
+use crate::netty::types::slot::Slot0;
+use crate::*;
+
+pub trait PluginChannel<'a>: Sized {
+    /// The name of the channel before 1.13
+    const LEGACY_NAME: Option<&'static str>;
+    /// The name of the channel since 1.13
+    const NAME: Option<&'static str>;
+
+    fn decode(data: &'a [u8], version: ProtocolVersion) -> decode::Result<Self>;
+    fn encode(&self, version: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()>;
+
+    /// The name of the channel in `version`, `None` if the channel doesn't
+    /// exist in that version.
+    fn channel(version: ProtocolVersion) -> Option<&'static str> {
+        if version.uses_namespaced_channels() {
+            Self::NAME
+        } else {
+            Self::LEGACY_NAME
+        }
+    }
+
+    fn to_data(&self, version: ProtocolVersion) -> encode::Result<Vec<u8>> {
+        let mut data = vec![];
+        self.encode(version, &mut data)?;
+        Ok(data)
+    }
+}
+
+/// A plugin message packet in any direction or version.
+pub trait ChannelMessage {
+    fn channel(&self) -> &str;
+    fn data(&self) -> &[u8];
+}
+
+macro_rules! channel_message {
+    ($($packet:ty),*) => {$(
+        impl<'a> ChannelMessage for $packet {
+            fn channel(&self) -> &str {
+                &self.channel
+            }
+            fn data(&self) -> &[u8] {
+                &self.data
+            }
+        }
+    )*};
+}
+channel_message!(
+    super::clientbound::PluginMessage0<'a>,
+    super::clientbound::PluginMessage29<'a>,
+    super::clientbound::PluginMessage32<'a>,
+    super::serverbound::PluginMessage0<'a>,
+    super::serverbound::PluginMessage29<'a>,
+    super::serverbound::PluginMessage32<'a>,
+    crate::netty::configuration::clientbound::PluginMessage764<'a>,
+    crate::netty::configuration::serverbound::PluginMessage764<'a>
+);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Names {
+    Legacy,
+    Namespaced,
+    Any,
+}
+
+/// The payloads decoded by a [`ChannelRegistry`], usually an enum over the
+/// [`PluginChannel`]s it knows.
+pub trait ChannelPayloads {
+    type Payload<'a>;
+}
+
+pub type ChannelDecoder<P> =
+    for<'a> fn(&'a [u8], ProtocolVersion) -> decode::Result<<P as ChannelPayloads>::Payload<'a>>;
+
+/// Maps channel names to the decoders of their payloads.
+pub struct ChannelRegistry<P: ChannelPayloads> {
+    channels: HashMap<&'static str, (Names, ChannelDecoder<P>)>,
+}
+
+impl<P: ChannelPayloads> ChannelRegistry<P> {
+    /// Creates a registry without any channels.
+    pub fn new() -> Self {
+        Self {
+            channels: HashMap::new(),
+        }
+    }
+
+    /// Registers `decoder` under the legacy name for versions before 1.13
+    /// and under the namespaced name since, usually the names are the
+    /// [`PluginChannel::LEGACY_NAME`] and [`PluginChannel::NAME`] of the
+    /// decoded channel.
+    pub fn register(
+        &mut self,
+        legacy_name: Option<&'static str>,
+        name: Option<&'static str>,
+        decoder: ChannelDecoder<P>,
+    ) {
+        match (legacy_name, name) {
+            (Some(legacy_name), Some(name)) if legacy_name == name => {
+                self.channels.insert(name, (Names::Any, decoder));
+            }
+            (legacy_name, name) => {
+                if let Some(legacy_name) = legacy_name {
+                    self.channels.insert(legacy_name, (Names::Legacy, decoder));
+                }
+                if let Some(name) = name {
+                    self.channels.insert(name, (Names::Namespaced, decoder));
+                }
+            }
+        }
+    }
+
+    pub fn is_registered(&self, channel: &str, version: ProtocolVersion) -> bool {
+        self.get(channel, version).is_some()
+    }
+
+    fn get(&self, channel: &str, version: ProtocolVersion) -> Option<ChannelDecoder<P>> {
+        let (names, decoder) = self.channels.get(channel)?;
+        match (names, version.uses_namespaced_channels()) {
+            (Names::Any, _) | (Names::Legacy, false) | (Names::Namespaced, true) => Some(*decoder),
+            _ => None,
+        }
+    }
+
+    /// Decodes the payload of `channel`, `None` if the channel isn't
+    /// registered for `version`.
+    pub fn decode<'a>(
+        &self,
+        channel: &str,
+        data: &'a [u8],
+        version: ProtocolVersion,
+    ) -> Option<decode::Result<P::Payload<'a>>> {
+        self.get(channel, version)
+            .map(|decoder| decoder(data, version))
+    }
+
+    pub fn decode_message<'a>(
+        &self,
+        message: &'a impl ChannelMessage,
+        version: ProtocolVersion,
+    ) -> Option<decode::Result<P::Payload<'a>>> {
+        self.decode(message.channel(), message.data(), version)
+    }
+}
+
+impl<P: ChannelPayloads> Default for ChannelRegistry<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The [`ChannelPayloads`] of the channels used by vanilla
+pub enum VanillaChannels {}
+impl ChannelPayloads for VanillaChannels {
+    type Payload<'a> = VanillaChannel<'a>;
+}
+
+impl ChannelRegistry<VanillaChannels> {
+    /// Creates a registry with all the channels used by vanilla.
+    pub fn vanilla() -> Self {
+        macro_rules! register {
+            ($registry:ident, $($channel:ident),*) => {$(
+                $registry.register($channel::LEGACY_NAME, $channel::NAME, |data, version| {
+                    $channel::decode(data, version).map(VanillaChannel::$channel)
+                });
+            )*};
+        }
+        let mut registry = Self::new();
+        register!(
+            registry,
+            Brand,
+            Register,
+            Unregister,
+            BookEdit,
+            BookSign,
+            TradeSelect,
+            CommandBlock,
+            Beacon
+        );
+        registry
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub enum VanillaChannel<'a> {
+    Brand(Brand<'a>),
+    Register(Register<'a>),
+    Unregister(Unregister<'a>),
+    BookEdit(BookEdit<'a>),
+    BookSign(BookSign<'a>),
+    TradeSelect(TradeSelect),
+    CommandBlock(CommandBlock<'a>),
+    Beacon(Beacon),
+}
+
+impl<'a> VanillaChannel<'a> {
+    pub fn channel(&self, version: ProtocolVersion) -> Option<&'static str> {
+        match self {
+            Self::Brand(_) => Brand::channel(version),
+            Self::Register(_) => Register::channel(version),
+            Self::Unregister(_) => Unregister::channel(version),
+            Self::BookEdit(_) => BookEdit::channel(version),
+            Self::BookSign(_) => BookSign::channel(version),
+            Self::TradeSelect(_) => TradeSelect::channel(version),
+            Self::CommandBlock(_) => CommandBlock::channel(version),
+            Self::Beacon(_) => Beacon::channel(version),
+        }
+    }
+
+    pub fn encode(&self, version: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        match self {
+            Self::Brand(payload) => payload.encode(version, writer),
+            Self::Register(payload) => payload.encode(version, writer),
+            Self::Unregister(payload) => payload.encode(version, writer),
+            Self::BookEdit(payload) => payload.encode(version, writer),
+            Self::BookSign(payload) => payload.encode(version, writer),
+            Self::TradeSelect(payload) => payload.encode(version, writer),
+            Self::CommandBlock(payload) => payload.encode(version, writer),
+            Self::Beacon(payload) => payload.encode(version, writer),
+        }
+    }
+}
+
+/// The server or client brand, like `vanilla`
+#[derive(ToStatic, Debug)]
+pub struct Brand<'a> {
+    pub brand: Cow<'a, str>,
+}
+impl<'a> PluginChannel<'a> for Brand<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|Brand");
+    const NAME: Option<&'static str> = Some("minecraft:brand");
+
+    fn decode(data: &'a [u8], version: ProtocolVersion) -> decode::Result<Self> {
+        let brand = if version.supports(Feature::PrefixedBrand) {
+            Decode::decode(&mut Cursor::new(data))?
+        } else {
+            Cow::Borrowed(std::str::from_utf8(data)?)
+        };
+        Ok(Self { brand })
+    }
+    fn encode(&self, version: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        if version.supports(Feature::PrefixedBrand) {
+            self.brand.encode(writer)
+        } else {
+            writer.write_all(self.brand.as_bytes())?;
+            Ok(())
+        }
+    }
+}
+
+fn decode_channels(data: &[u8]) -> decode::Result<Vec<Cow<'_, str>>> {
+    Ok(std::str::from_utf8(data)?
+        .split('\0')
+        .filter(|channel| !channel.is_empty())
+        .map(Cow::Borrowed)
+        .collect())
+}
+fn encode_channels(channels: &[Cow<str>], writer: &mut impl Write) -> encode::Result<()> {
+    writer.write_all(channels.join("\0").as_bytes())?;
+    Ok(())
+}
+
+/// The channels the sender listens on
+#[derive(ToStatic, Debug)]
+pub struct Register<'a> {
+    pub channels: Vec<Cow<'a, str>>,
+}
+impl<'a> PluginChannel<'a> for Register<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("REGISTER");
+    const NAME: Option<&'static str> = Some("minecraft:register");
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        Ok(Self {
+            channels: decode_channels(data)?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        encode_channels(&self.channels, writer)
+    }
+}
+
+/// The channels the sender no longer listens on
+#[derive(ToStatic, Debug)]
+pub struct Unregister<'a> {
+    pub channels: Vec<Cow<'a, str>>,
+}
+impl<'a> PluginChannel<'a> for Unregister<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("UNREGISTER");
+    const NAME: Option<&'static str> = Some("minecraft:unregister");
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        Ok(Self {
+            channels: decode_channels(data)?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        encode_channels(&self.channels, writer)
+    }
+}
+
+/// Serverbound, replaced by the edit book packet in 1.13
+#[derive(ToStatic, Debug)]
+pub struct BookEdit<'a> {
+    pub book: Slot0<'a>,
+}
+impl<'a> PluginChannel<'a> for BookEdit<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|BEdit");
+    const NAME: Option<&'static str> = None;
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        Ok(Self {
+            book: Decode::decode(&mut Cursor::new(data))?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        self.book.encode(writer)
+    }
+}
+
+/// Serverbound, replaced by the edit book packet in 1.13
+#[derive(ToStatic, Debug)]
+pub struct BookSign<'a> {
+    pub book: Slot0<'a>,
+}
+impl<'a> PluginChannel<'a> for BookSign<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|BSign");
+    const NAME: Option<&'static str> = None;
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        Ok(Self {
+            book: Decode::decode(&mut Cursor::new(data))?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        self.book.encode(writer)
+    }
+}
+
+/// Serverbound, replaced by the select trade packet in 1.13
+#[derive(ToStatic, Debug)]
+pub struct TradeSelect {
+    pub selected_slot: i32,
+}
+impl<'a> PluginChannel<'a> for TradeSelect {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|TrSel");
+    const NAME: Option<&'static str> = None;
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        Ok(Self {
+            selected_slot: Decode::decode(&mut Cursor::new(data))?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        self.selected_slot.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub enum CommandBlockTarget {
+    Block { x: i32, y: i32, z: i32 },
+    Minecart { entity_id: i32 },
+}
+
+/// Serverbound, replaced by the update command block packets in 1.13
+#[derive(ToStatic, Debug)]
+pub struct CommandBlock<'a> {
+    pub target: CommandBlockTarget,
+    pub command: Cow<'a, str>,
+    /// Not sent before 1.8 (pv 47)
+    pub track_output: bool,
+}
+impl<'a> PluginChannel<'a> for CommandBlock<'a> {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|AdvCdm");
+    const NAME: Option<&'static str> = None;
+
+    fn decode(data: &'a [u8], version: ProtocolVersion) -> decode::Result<Self> {
+        let cursor = &mut Cursor::new(data);
+        let target = match u8::decode(cursor)? {
+            0 => CommandBlockTarget::Block {
+                x: i32::decode(cursor)?,
+                y: i32::decode(cursor)?,
+                z: i32::decode(cursor)?,
+            },
+            1 => CommandBlockTarget::Minecart {
+                entity_id: i32::decode(cursor)?,
+            },
+            _ => return Err(decode::Error::InvalidId),
+        };
+        Ok(Self {
+            target,
+            command: Decode::decode(cursor)?,
+            track_output: version.supports(Feature::TrackOutput) && bool::decode(cursor)?,
+        })
+    }
+    fn encode(&self, version: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        match self.target {
+            CommandBlockTarget::Block { x, y, z } => {
+                0u8.encode(writer)?;
+                x.encode(writer)?;
+                y.encode(writer)?;
+                z.encode(writer)?;
+            }
+            CommandBlockTarget::Minecart { entity_id } => {
+                1u8.encode(writer)?;
+                entity_id.encode(writer)?;
+            }
+        }
+        self.command.encode(writer)?;
+        if version.supports(Feature::TrackOutput) {
+            self.track_output.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serverbound, replaced by the set beacon effect packet in 1.13
+#[derive(ToStatic, Debug)]
+pub struct Beacon {
+    pub primary_effect: i32,
+    pub secondary_effect: i32,
+}
+impl<'a> PluginChannel<'a> for Beacon {
+    const LEGACY_NAME: Option<&'static str> = Some("MC|Beacon");
+    const NAME: Option<&'static str> = None;
+
+    fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+        let cursor = &mut Cursor::new(data);
+        Ok(Self {
+            primary_effect: i32::decode(cursor)?,
+            secondary_effect: i32::decode(cursor)?,
+        })
+    }
+    fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+        self.primary_effect.encode(writer)?;
+        self.secondary_effect.encode(writer)
+    }
+}
+
+#[test]
+fn vanilla_channels() {
+    #[allow(clippy::unwrap_used)]
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    let registry = ChannelRegistry::vanilla();
+
+    let message = super::serverbound::PluginMessage32 {
+        channel: Cow::Borrowed("minecraft:brand"),
+        data: Cow::Borrowed(b"\x07vanilla"),
+    };
+    assert!(matches!(
+        registry.decode_message(&message, pv(760)),
+        Some(Ok(VanillaChannel::Brand(Brand { brand }))) if brand == "vanilla"
+    ));
+    assert!(registry.decode_message(&message, pv(340)).is_none());
+    // the brand isn't prefixed with its length before 1.8
+    assert!(matches!(
+        registry.decode("MC|Brand", b"vanilla", pv(5)),
+        Some(Ok(VanillaChannel::Brand(Brand { brand }))) if brand == "vanilla"
+    ));
+    let mut data = vec![];
+    #[allow(clippy::unwrap_used)]
+    Brand {
+        brand: Cow::Borrowed("vanilla"),
+    }
+    .encode(pv(5), &mut data)
+    .unwrap();
+    assert_eq!(data, b"vanilla");
+    assert!(registry.is_registered("MC|Brand", pv(340)));
+    assert!(!registry.is_registered("MC|BEdit", pv(393)));
+
+    assert!(matches!(
+        registry.decode("REGISTER", b"a:b\0c:d\0", pv(47)),
+        Some(Ok(VanillaChannel::Register(Register { channels }))) if channels == ["a:b", "c:d"]
+    ));
+
+    let command_block = VanillaChannel::CommandBlock(CommandBlock {
+        target: CommandBlockTarget::Minecart { entity_id: 5 },
+        command: Cow::Borrowed("say hi"),
+        track_output: true,
+    });
+    assert_eq!(command_block.channel(pv(47)), Some("MC|AdvCdm"));
+    assert_eq!(command_block.channel(pv(393)), None);
+    let mut data = vec![];
+    #[allow(clippy::unwrap_used)]
+    command_block.encode(pv(47), &mut data).unwrap();
+    assert_eq!(data, b"\x01\0\0\0\x05\x06say hi\x01");
+    assert!(matches!(
+        registry.decode("MC|AdvCdm", &data, pv(47)),
+        Some(Ok(VanillaChannel::CommandBlock(CommandBlock {
+            target: CommandBlockTarget::Minecart { entity_id: 5 },
+            track_output: true,
+            ..
+        })))
+    ));
+}
+
+#[test]
+fn custom_channel() {
+    #[derive(Debug, PartialEq)]
+    struct Ping(u8);
+    impl<'a> PluginChannel<'a> for Ping {
+        const LEGACY_NAME: Option<&'static str> = Some("example:ping");
+        const NAME: Option<&'static str> = Some("example:ping");
+
+        fn decode(data: &'a [u8], _: ProtocolVersion) -> decode::Result<Self> {
+            Ok(Self(u8::decode(&mut Cursor::new(data))?))
+        }
+        fn encode(&self, _: ProtocolVersion, writer: &mut impl Write) -> encode::Result<()> {
+            self.0.encode(writer)
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    enum Pings {}
+    impl ChannelPayloads for Pings {
+        type Payload<'a> = Ping;
+    }
+    let mut registry = ChannelRegistry::<Pings>::new();
+    registry.register(Ping::LEGACY_NAME, Ping::NAME, |data, version| {
+        Ping::decode(data, version)
+    });
+    assert!(matches!(
+        registry.decode("example:ping", &[3], pv(47)),
+        Some(Ok(Ping(3)))
+    ));
+    assert!(matches!(
+        registry.decode("example:ping", &[4], pv(760)),
+        Some(Ok(Ping(4)))
+    ));
+    assert!(registry.decode("example:pong", &[4], pv(760)).is_none());
+}
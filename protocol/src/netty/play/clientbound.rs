@@ -1,6 +1,8 @@
 use crate::netty::types::angle::Angle;
+use crate::netty::types::chunk::ChunkColumn86;
 use crate::netty::types::entity_metadata::PackedEntityMetadata0;
-use crate::netty::types::position::Position6;
+use crate::netty::types::particle::{DecodeParticle, EncodeParticle};
+use crate::netty::types::position::{Position441, Position6};
 use crate::netty::types::slot::Slot0;
 
 use ::miners_encoding::{
@@ -8,6 +10,8 @@ use ::miners_encoding::{
     decode, encode, Decode, Encode,
 };
 
+use miners_nbt::Nbt;
+use miners_profile::{GameProfile, Property};
 use std::borrow::Cow;
 use uuid::Uuid;
 
@@ -46,6 +50,17 @@ pub struct KeepAlive32 {
     pub id: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Keep Alive
+///
+/// Since pv339 (1.12.2-pre2) the id is a long, see
+/// [`serverbound::KeepAlive339`][ka339] for the response.
+///
+/// [ka339]: super::serverbound::KeepAlive339
+pub struct KeepAlive339 {
+    pub id: i64,
+}
+
 #[derive(ToStatic, Debug)]
 /// Sent after the Login Sequence
 ///
@@ -220,6 +235,196 @@ impl Encode for JoinGame29<'_> {
     }
 }
 
+#[derive(ToStatic, Debug)]
+/// Join Game
+///
+/// Like [`JoinGame29`] but with the spectator gamemode.
+pub struct JoinGame86<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    pub dimension: Dimension0,
+    pub difficulty: Difficulty0,
+    pub max_players: u8,
+    pub level_type: Cow<'a, str>,
+    pub reduced_debug_info: bool,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for JoinGame86<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = i32::decode(cursor)?;
+        let (hardcore, gamemode) = GameMode86::decode_with_hardcore(cursor)?;
+        Ok(Self {
+            entity_id,
+            hardcore,
+            gamemode,
+            dimension: Dimension0::decode(cursor)?,
+            difficulty: Difficulty0::decode(cursor)?,
+            max_players: u8::decode(cursor)?,
+            level_type: Cow::decode(cursor)?,
+            reduced_debug_info: bool::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for JoinGame86<'_> {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.entity_id.encode(writer)?;
+        self.gamemode.encode_with_hardcore(self.hardcore, writer)?;
+        self.dimension.encode(writer)?;
+        self.difficulty.encode(writer)?;
+        self.max_players.encode(writer)?;
+        self.level_type.encode(writer)?;
+        self.reduced_debug_info.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Join Game
+///
+/// Since pv108 (1.9.1-pre1) the dimension is sent as an int.
+pub struct JoinGame108<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    pub dimension: Dimension86,
+    pub difficulty: Difficulty0,
+    pub max_players: u8,
+    pub level_type: Cow<'a, str>,
+    pub reduced_debug_info: bool,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for JoinGame108<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = i32::decode(cursor)?;
+        let (hardcore, gamemode) = GameMode86::decode_with_hardcore(cursor)?;
+        Ok(Self {
+            entity_id,
+            hardcore,
+            gamemode,
+            dimension: Dimension86::decode(cursor)?,
+            difficulty: Difficulty0::decode(cursor)?,
+            max_players: u8::decode(cursor)?,
+            level_type: Cow::decode(cursor)?,
+            reduced_debug_info: bool::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for JoinGame108<'_> {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.entity_id.encode(writer)?;
+        self.gamemode.encode_with_hardcore(self.hardcore, writer)?;
+        self.dimension.encode(writer)?;
+        self.difficulty.encode(writer)?;
+        self.max_players.encode(writer)?;
+        self.level_type.encode(writer)?;
+        self.reduced_debug_info.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Join Game
+///
+/// Since pv468 (19w11a) the difficulty is sent in
+/// [`ServerDifficulty464`] instead and the view distance is sent.
+pub struct JoinGame468<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    pub dimension: Dimension86,
+    pub max_players: u8,
+    pub level_type: Cow<'a, str>,
+    // varint
+    pub view_distance: i32,
+    pub reduced_debug_info: bool,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for JoinGame468<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = i32::decode(cursor)?;
+        let (hardcore, gamemode) = GameMode86::decode_with_hardcore(cursor)?;
+        Ok(Self {
+            entity_id,
+            hardcore,
+            gamemode,
+            dimension: Dimension86::decode(cursor)?,
+            max_players: u8::decode(cursor)?,
+            level_type: Cow::decode(cursor)?,
+            view_distance: Var::decode(cursor)?.into_inner(),
+            reduced_debug_info: bool::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for JoinGame468<'_> {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.entity_id.encode(writer)?;
+        self.gamemode.encode_with_hardcore(self.hardcore, writer)?;
+        self.dimension.encode(writer)?;
+        self.max_players.encode(writer)?;
+        self.level_type.encode(writer)?;
+        Var::from(self.view_distance).encode(writer)?;
+        self.reduced_debug_info.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Join Game
+///
+/// Since pv552 (19w36a) the first 8 bytes of the SHA-256 hash of the world
+/// seed are sent for biome noise and the respawn screen can be disabled.
+pub struct JoinGame552<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    pub dimension: Dimension86,
+    pub hashed_seed: i64,
+    pub max_players: u8,
+    pub level_type: Cow<'a, str>,
+    // varint
+    pub view_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for JoinGame552<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = i32::decode(cursor)?;
+        let (hardcore, gamemode) = GameMode86::decode_with_hardcore(cursor)?;
+        Ok(Self {
+            entity_id,
+            hardcore,
+            gamemode,
+            dimension: Dimension86::decode(cursor)?,
+            hashed_seed: i64::decode(cursor)?,
+            max_players: u8::decode(cursor)?,
+            level_type: Cow::decode(cursor)?,
+            view_distance: Var::decode(cursor)?.into_inner(),
+            reduced_debug_info: bool::decode(cursor)?,
+            enable_respawn_screen: bool::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for JoinGame552<'_> {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.entity_id.encode(writer)?;
+        self.gamemode.encode_with_hardcore(self.hardcore, writer)?;
+        self.dimension.encode(writer)?;
+        self.hashed_seed.encode(writer)?;
+        self.max_players.encode(writer)?;
+        self.level_type.encode(writer)?;
+        Var::from(self.view_distance).encode(writer)?;
+        self.reduced_debug_info.encode(writer)?;
+        self.enable_respawn_screen.encode(writer)?;
+        Ok(())
+    }
+}
+
 #[derive(Encoding, ToStatic, Clone, Copy, Debug)]
 #[encoding(from = "u8")]
 pub enum GameMode0 {
@@ -228,6 +433,39 @@ pub enum GameMode0 {
     Adventure,
 }
 
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
+#[encoding(from = "u8")]
+pub enum GameMode86 {
+    Survival = 0,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+impl GameMode86 {
+    /// Decodes the gamemode byte of [`JoinGame86`] and [`JoinGame108`]
+    /// which also carries the hardcore flag.
+    fn decode_with_hardcore(cursor: &mut std::io::Cursor<&[u8]>) -> decode::Result<(bool, Self)> {
+        let bitfield = u8::decode(cursor)?;
+        let gamemode = match bitfield & 0b111 {
+            0 => GameMode86::Survival,
+            1 => GameMode86::Creative,
+            2 => GameMode86::Adventure,
+            3 => GameMode86::Spectator,
+            _ => return Err(decode::Error::InvalidId),
+        };
+        Ok((bitfield & 0x08 != 0, gamemode))
+    }
+
+    fn encode_with_hardcore(
+        self,
+        hardcore: bool,
+        writer: &mut impl ::std::io::Write,
+    ) -> encode::Result<()> {
+        (self as u8 | ((hardcore as u8) << 3)).encode(writer)
+    }
+}
+
 pub use super::Difficulty0;
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -238,6 +476,14 @@ pub enum Dimension0 {
     End,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "i32")]
+pub enum Dimension86 {
+    Nether = -1,
+    Overworld = 0,
+    End,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Chat Message
 ///
@@ -267,7 +513,7 @@ pub struct ChatMessage6<'a> {
     pub position: ChatMessagePosition6,
 }
 
-#[derive(Encoding, ToStatic, Debug)]
+#[derive(Encoding, ToStatic, Clone, Copy, PartialEq, Eq, Debug)]
 #[encoding(from = "u8")]
 pub enum ChatMessagePosition6 {
     Chat = 0,
@@ -347,6 +593,18 @@ pub struct EntityEquipment49<'a> {
 }
 
 #[derive(Encoding, ToStatic, Debug)]
+/// Entity Equipment
+///
+/// Generic over the slot format which changed in pv346 (17w45a) and pv402
+/// (1.13-pre7).
+pub struct EntityEquipment346<Slot> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub slot: EquipmentSlot49,
+    pub item: Slot,
+}
+
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
 #[encoding(from = "u8")]
 pub enum EquipmentSlot49 {
     Hand = 0,
@@ -372,7 +630,7 @@ pub struct SpawnPosition0 {
     pub z: i32,
 }
 
-#[derive(Bitfield, ToStatic, Clone, Copy)]
+#[derive(Bitfield, ToStatic, Clone, Copy, Debug)]
 /// Spawn Position
 ///
 /// Sent by the server after login to specify the coordinates of the spawn
@@ -384,10 +642,19 @@ pub struct SpawnPosition0 {
 pub struct SpawnPosition6 {
     #[encoding(bits = 26)]
     pub x: i32,
-    #[encoding(bits = 26)]
-    pub z: i32,
     #[encoding(bits = 12)]
     pub y: i16,
+    #[encoding(bits = 26)]
+    pub z: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Position
+///
+/// Since pv441 (18w43a) positions are packed with the y coordinate in the
+/// lowest bits.
+pub struct SpawnPosition441 {
+    pub location: Position441,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -485,6 +752,41 @@ pub struct Respawn1<'a> {
     pub level_type: Cow<'a, str>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// To change the player's dimension (overworld/nether/end), send them a
+/// respawn packet with the appropriate dimension, followed by prechunks/chunks
+/// for the new dimension, and finally a position and look packet.
+pub struct Respawn86<'a> {
+    pub dimension: Dimension86,
+    pub difficulty: Difficulty0,
+    pub gamemode: GameMode86,
+    pub level_type: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// Since pv471 (1.14) the difficulty is only sent in
+/// [`ServerDifficulty464`].
+pub struct Respawn471<'a> {
+    pub dimension: Dimension86,
+    pub gamemode: GameMode86,
+    pub level_type: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// Since pv552 (19w36a) the hashed seed is sent, see [`JoinGame552`].
+pub struct Respawn552<'a> {
+    pub dimension: Dimension86,
+    pub hashed_seed: i64,
+    pub gamemode: GameMode86,
+    pub level_type: Cow<'a, str>,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Synchronize Player Position
 ///
@@ -605,6 +907,24 @@ fn position_and_look_bitfield6() {
     assert_eq!(&cursor[..], &val[..])
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Player Position And Look
+///
+/// Since pv86 (1.9) the client has to confirm the teleport with a
+/// [`serverbound::TeleportConfirm80`][tc] containing the same teleport id.
+///
+/// [tc]: super::serverbound::TeleportConfirm80
+pub struct PositionAndLook86 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub relativity: PositionAndLookBitfield6,
+    #[encoding(varint)]
+    pub teleport_id: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Held Item Change
 ///
@@ -673,6 +993,14 @@ pub struct UseBed7 {
     pub location: Position6,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UseBed441 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    /// Position of the head part of the targeted bed
+    pub location: Position441,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Animation
 ///
@@ -686,6 +1014,27 @@ pub struct Animation0 {
     pub animation: super::AnimationId0,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Animation
+///
+/// Sent whenever an entity should change animation.
+pub struct Animation67 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub animation: AnimationId67,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "u8")]
+pub enum AnimationId67 {
+    SwingMainArm = 0,
+    TakeDamage,
+    LeaveBed,
+    SwingOffhand,
+    CriticalEffect,
+    MagicCriticalEffect,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Spawn Player
 ///
@@ -851,16 +1200,92 @@ pub struct SpawnPlayer49<EntityMetadata> {
 }
 
 #[derive(Encoding, ToStatic, Debug)]
-pub struct PlayerProperty<'a> {
-    pub name: Cow<'a, str>,
-    pub value: Cow<'a, str>,
-    pub signature: Cow<'a, str>,
+/// Spawn Player
+///
+/// This packet is sent by the server when a player comes into visible range,
+/// **not** when a player joins.
+///
+/// Since pv100 (15w51a) positions are sent as doubles.
+pub struct SpawnPlayer100<EntityMetadata> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub player_uuid: Uuid,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub metadata: EntityMetadata,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
-/// Collect Item
-///
-/// Sent when an entity collects an item.
+/// Spawn Player
+///
+/// Since pv550 (19w34a) the metadata is sent in a separate entity metadata
+/// packet.
+pub struct SpawnPlayer550 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub player_uuid: Uuid,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerProperty<'a> {
+    pub name: Cow<'a, str>,
+    pub value: Cow<'a, str>,
+    pub signature: Cow<'a, str>,
+}
+
+impl<'a> From<&'a Property> for PlayerProperty<'a> {
+    /// Unsigned properties are sent with an empty signature.
+    fn from(property: &'a Property) -> Self {
+        Self {
+            name: Cow::Borrowed(&property.name),
+            value: Cow::Borrowed(&property.value),
+            signature: Cow::Borrowed(property.signature.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+impl From<&PlayerProperty<'_>> for Property {
+    fn from(property: &PlayerProperty<'_>) -> Self {
+        Self {
+            name: property.name.to_string(),
+            value: property.value.to_string(),
+            signature: (!property.signature.is_empty()).then(|| property.signature.to_string()),
+        }
+    }
+}
+
+impl SpawnPlayer0<'_> {
+    /// The profile of the spawned player, `None` if the uuid is empty.
+    pub fn profile(&self) -> Option<GameProfile> {
+        let uuid = self.player_uuid.clone().into_inner()?;
+        Some(GameProfile::new(uuid, self.name.to_string()))
+    }
+}
+
+impl SpawnPlayer5<'_> {
+    /// The profile of the spawned player, `None` if the uuid is empty.
+    pub fn profile(&self) -> Option<GameProfile> {
+        let uuid = self.player_uuid.clone().into_inner()?;
+        Some(GameProfile {
+            uuid,
+            name: self.name.to_string(),
+            properties: self.properties.iter().map(Property::from).collect(),
+        })
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Collect Item
+///
+/// Sent when an entity collects an item.
 ///
 /// The sole purpose seems to be to play the animation of the item flying
 /// towards the collector.
@@ -910,6 +1335,17 @@ pub struct CollectItem7 {
     pub collector_id: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CollectItem301 {
+    #[encoding(varint)]
+    pub collected_id: i32,
+    #[encoding(varint)]
+    pub collector_id: i32,
+    /// The number of items picked up, 1 for experience orbs.
+    #[encoding(varint)]
+    pub count: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Spawn Object
 ///
@@ -963,6 +1399,7 @@ pub enum ObjectKind0 {
     ThrownEnderpearl,
     WitherSkull,
     ShulkerBullet,
+    LlamaSpit,
     #[encoding(case = "70")]
     FallingObject,
     ItemFrame,
@@ -973,6 +1410,7 @@ pub enum ObjectKind0 {
     FireworkRocket,
     LeashKnot,
     ArmorStand,
+    EvocationFangs,
     #[encoding(case = "90")]
     FishingFloat,
     SpectralArrow,
@@ -1039,6 +1477,53 @@ impl Encode for ObjectData0 {
     }
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Object
+///
+/// Sent by the server when a vehicle or other object is created.
+///
+/// Since pv100 (15w51a) positions are sent as doubles and the velocity is
+/// always present.
+pub struct SpawnObject100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub object_uuid: Uuid,
+    pub kind: ObjectKind0,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: Angle,
+    pub yaw: Angle,
+    /// Meaning dependent on the value of `kind`.
+    pub data: i32,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Object
+///
+/// Since pv458 (19w05a) the kind is a varint id of the entity type
+/// registry.
+pub struct SpawnObject458 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub object_uuid: Uuid,
+    #[encoding(varint)]
+    pub kind: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: Angle,
+    pub yaw: Angle,
+    /// Meaning dependent on the value of `kind`.
+    pub data: i32,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub enum EntityKind0 {
     Mob = 48,
@@ -1113,6 +1598,71 @@ pub struct SpawnMob0<'a> {
     pub metadata: PackedEntityMetadata0<'a>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Mob
+///
+/// Sent by the server when a mob entity is spawned.
+pub struct SpawnMob100<EntityMetadata> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    pub kind: u8,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub head_pitch: Angle,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+    pub metadata: EntityMetadata,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Mob
+///
+/// Since pv301 (16w32a) the kind is sent as a varint.
+pub struct SpawnMob301<EntityMetadata> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    #[encoding(varint)]
+    pub kind: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub head_pitch: Angle,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+    pub metadata: EntityMetadata,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Mob
+///
+/// Since pv550 (19w34a) the metadata is sent in a separate entity metadata
+/// packet.
+pub struct SpawnMob550 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    #[encoding(varint)]
+    pub kind: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub head_pitch: Angle,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Spawn Painting
 ///
@@ -1153,6 +1703,52 @@ pub enum Direction0 {
     PosX,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SpawnPainting95<'a> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    // TODO: #[max_len(13)]
+    pub title: Cow<'a, str>,
+    pub location: Position6,
+    pub direction: Direction95,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "u8")]
+pub enum Direction95 {
+    South = 0,
+    West,
+    North,
+    East,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Painting
+///
+/// Since pv353 (17w47a) the painting is sent as an id of the motive
+/// registry.
+pub struct SpawnPainting353 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    #[encoding(varint)]
+    pub motive: i32,
+    pub location: Position6,
+    pub direction: Direction95,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SpawnPainting441 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub entity_uuid: Uuid,
+    #[encoding(varint)]
+    pub motive: i32,
+    pub location: Position441,
+    pub direction: Direction95,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Spawn Experience Orb
 ///
@@ -1173,6 +1769,16 @@ pub struct SpawnExpOrb0 {
     pub count: i16,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SpawnExpOrb100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub count: i16,
+}
+
 #[derive(ToStatic, Debug)]
 /// Entity Velocity
 ///
@@ -1397,6 +2003,23 @@ pub struct EntityRelativeMove22 {
     pub on_ground: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Relative Move
+///
+/// Since pv100 (15w51a) the change in position is sent as a short in 1/4096
+/// of a block.
+pub struct EntityRelativeMove100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    #[encoding(fixed(12, "i16"))]
+    pub dx: f64,
+    #[encoding(fixed(12, "i16"))]
+    pub dy: f64,
+    #[encoding(fixed(12, "i16"))]
+    pub dz: f64,
+    pub on_ground: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Entity Update (Look)
 ///
@@ -1525,6 +2148,25 @@ pub struct EntityLookAndRelativeMove22 {
     pub on_ground: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Look And Relative Move
+///
+/// Since pv100 (15w51a) the change in position is sent as a short in 1/4096
+/// of a block.
+pub struct EntityLookAndRelativeMove100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    #[encoding(fixed(12, "i16"))]
+    pub dx: f64,
+    #[encoding(fixed(12, "i16"))]
+    pub dy: f64,
+    #[encoding(fixed(12, "i16"))]
+    pub dz: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub on_ground: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Entity Update (Absolute Move, Look)
 ///
@@ -1589,6 +2231,21 @@ pub struct EntityTeleport22 {
     pub on_ground: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Teleport
+///
+/// Since pv100 (15w51a) positions are sent as doubles.
+pub struct EntityTeleport100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub on_ground: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Entity Update (Head Look)
 ///
@@ -1644,6 +2301,14 @@ pub enum Status0 {
     FireworkExplosion,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct EntityStatus80 {
+    pub entity_id: i32,
+    // the meaning of the status depends on the entity kind and grows with
+    // almost every version, so it is not an enum
+    pub entity_status: i8,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Attach Entity
 ///
@@ -1659,6 +2324,44 @@ pub struct AttachEntity0 {
     pub leash: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Attach Entity
+///
+/// Since pv86 (1.9) this is only used for leashes, see [`SetPassengers86`]
+/// for vehicles.
+pub struct AttachEntity86 {
+    pub attached_entity_id: i32,
+    /// -1 to detach
+    pub holding_entity_id: i32,
+}
+
+#[derive(ToStatic, Debug)]
+/// Set Passengers
+pub struct SetPassengers86 {
+    // varint
+    pub entity_id: i32,
+    // varint count of varints
+    pub passengers: Vec<i32>,
+}
+
+impl<'dec> Decode<'dec> for SetPassengers86 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = Var::decode(cursor)?.into_inner();
+        let passengers = decode_varint_array(cursor)?;
+        Ok(Self {
+            entity_id,
+            passengers,
+        })
+    }
+}
+
+impl Encode for SetPassengers86 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.entity_id).encode(writer)?;
+        encode_varint_array(&self.passengers, writer)
+    }
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Entity Metadata
 ///
@@ -1736,6 +2439,27 @@ pub struct EntityEffect10 {
     pub hide_particles: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct EntityEffect86 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    // TODO: effect ids
+    pub effect_id: i8,
+    pub amplifier: i8,
+    #[encoding(varint)]
+    pub duration: i32,
+    pub flags: EntityEffectFlags86,
+}
+
+#[derive(Bitfield, ToStatic, Debug)]
+#[encoding(typ = "u8", reverse)]
+pub struct EntityEffectFlags86 {
+    #[encoding(bool)]
+    pub ambient: bool,
+    #[encoding(bool)]
+    pub show_particles: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// Remove Entity Effect
 ///
@@ -1819,6 +2543,7 @@ pub struct Modifier0 {
 
 #[derive(Encoding, ToStatic, Debug)]
 pub struct EntityProperties7<'a> {
+    #[encoding(varint)]
     pub entity_id: i32,
     #[encoding(counted = "u32")]
     pub properties: Vec<EntityProperty7<'a>>,
@@ -1842,6 +2567,7 @@ pub struct EntityProperty7<'a> {
 /// multiply modifiers.
 ///
 /// <https://minecraft.fandom.com/wiki/Attribute#Vanilla_modifiers>
+#[encoding(from = "u8")]
 pub enum ModifierOperation0 {
     /// Adds all of the modifiers' amounts to the current value of the
     /// attribute. For example, modifying an attribute with
@@ -1911,21 +2637,178 @@ pub struct ChunkData27<'a> {
     pub data: Cow<'a, [u8]>,
 }
 
-#[derive(ToStatic, Debug)]
-pub struct MultiBlockChange0 {
-    // varint
+#[derive(Encoding, ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since pv86 (1.9) the data is sent in the paletted section format, use
+/// [`ChunkData86::column`] to decode it.
+pub struct ChunkData86<'a> {
     pub chunk_x: i32,
-    // varint
-    pub chunk_y: i32,
-    // count(u16)
-    pub records: Vec<Record>,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    #[encoding(varint)]
+    pub primary_bitmask: i32,
+    pub data: Cow<'a, [u8]>,
 }
 
-impl<'dec> Decode<'dec> for MultiBlockChange0 {
-    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
-        let chunk_x: i32 = Var::decode(cursor)?.into_inner();
-        let chunk_y: i32 = Var::decode(cursor)?.into_inner();
-        let record_count = u16::decode(cursor)?;
+impl<'a> ChunkData86<'a> {
+    /// Decodes the sections of this chunk, `sky_light` has to be set in
+    /// dimensions with sky light.
+    pub fn column(&self, sky_light: bool) -> decode::Result<ChunkColumn86<'_>> {
+        ChunkColumn86::decode(&self.data, self.primary_bitmask, self.full_chunk, sky_light)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since pv110 (1.9.4) the block entities of the chunk are sent with it.
+pub struct ChunkData110<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    #[encoding(varint)]
+    pub primary_bitmask: i32,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+impl<'a> ChunkData110<'a> {
+    /// Decodes the sections of this chunk, `sky_light` has to be set in
+    /// dimensions with sky light.
+    pub fn column(&self, sky_light: bool) -> decode::Result<ChunkColumn86<'_>> {
+        ChunkColumn86::decode(&self.data, self.primary_bitmask, self.full_chunk, sky_light)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since pv389 (1.13-pre7) the sections use the flattened block states, so
+/// they can't be read by [`ChunkColumn86`].
+pub struct ChunkData389<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    #[encoding(varint)]
+    pub primary_bitmask: i32,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since pv471 (1.14) the heightmaps are sent and the light is sent in
+/// [`UpdateLight471`].
+pub struct ChunkData471<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    #[encoding(varint)]
+    pub primary_bitmask: i32,
+    pub heightmaps: Nbt<'a>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since pv552 (19w36a) the biomes are sent in front of the sections as
+/// 1024 ints, one for every 4x4x4 area.
+pub struct ChunkData552<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    // varint
+    pub primary_bitmask: i32,
+    pub heightmaps: Nbt<'a>,
+    /// Only sent if `full_chunk` is set
+    pub biomes: Option<Vec<i32>>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for ChunkData552<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x = i32::decode(cursor)?;
+        let chunk_z = i32::decode(cursor)?;
+        let full_chunk = bool::decode(cursor)?;
+        let primary_bitmask = Var::decode(cursor)?.into_inner();
+        let heightmaps = Nbt::decode(cursor)?;
+        let biomes = if full_chunk {
+            Some(
+                (0..1024)
+                    .map(|_| i32::decode(cursor))
+                    .collect::<Result<_, _>>()?,
+            )
+        } else {
+            None
+        };
+        Ok(Self {
+            chunk_x,
+            chunk_z,
+            full_chunk,
+            primary_bitmask,
+            heightmaps,
+            biomes,
+            data: Cow::decode(cursor)?,
+            block_entities: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for ChunkData552<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.chunk_x.encode(writer)?;
+        self.chunk_z.encode(writer)?;
+        self.full_chunk.encode(writer)?;
+        Var::from(self.primary_bitmask).encode(writer)?;
+        self.heightmaps.encode(writer)?;
+        match (self.full_chunk, &self.biomes) {
+            (true, Some(biomes)) if biomes.len() == 1024 => {
+                for biome in biomes {
+                    biome.encode(writer)?;
+                }
+            }
+            (false, None) => {}
+            _ => {
+                return Err(encode::Error::Custom(
+                    "biomes have to be exactly 1024 ints if and only if full_chunk is set",
+                ))
+            }
+        }
+        self.data.encode(writer)?;
+        self.block_entities.encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Unload Chunk
+///
+/// Tells the client to unload a chunk column, replaces sending a chunk data
+/// packet without any sections.
+pub struct UnloadChunk80 {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct MultiBlockChange0 {
+    // varint
+    pub chunk_x: i32,
+    // varint
+    pub chunk_y: i32,
+    // count(u16)
+    pub records: Vec<Record>,
+}
+
+impl<'dec> Decode<'dec> for MultiBlockChange0 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x: i32 = Var::decode(cursor)?.into_inner();
+        let chunk_y: i32 = Var::decode(cursor)?.into_inner();
+        let record_count = u16::decode(cursor)?;
         let data_size: i32 = i32::decode(cursor)?;
         if data_size != record_count as i32 * 4 {
             // TODO: different error
@@ -2078,6 +2961,14 @@ pub struct BlockChange25 {
     pub block_id: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct BlockChange441 {
+    pub location: Position441,
+    /// The id of the block state in the global palette
+    #[encoding(varint)]
+    pub block_id: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct BlockAction0 {
     pub x: i32,
@@ -2100,6 +2991,16 @@ pub struct BlockAction6 {
     pub block_type: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct BlockAction441 {
+    pub location: Position441,
+    pub action_id: u8,
+    pub action_param: u8,
+    /// The block type ID for the block, not including metadata/damage value
+    #[encoding(varint)]
+    pub block_type: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct BlockBreakAnimation0 {
     #[encoding(varint)]
@@ -2120,6 +3021,15 @@ pub struct BlockBreakAnimation6 {
     pub destroy_stage: u8,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct BlockBreakAnimation441 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub location: Position441,
+    /// 0-9
+    pub destroy_stage: u8,
+}
+
 #[derive(ToStatic, Debug)]
 pub struct MapChunkBulk0<'a> {
     /// Whether or not the chunk data contains a light nibble array. This is
@@ -2277,6 +3187,14 @@ pub struct Effect6 {
     pub disable_rel_volume: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Effect441 {
+    pub effect_id: i32,
+    pub location: Position441,
+    pub effect_data: i32,
+    pub disable_rel_volume: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct SoundEffect0<'a> {
     pub effect_id: Cow<'a, str>,
@@ -2323,6 +3241,93 @@ pub struct SoundEffect1<'a> {
     pub pitch: u8,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum SoundCategory95 {
+    Master = 0,
+    Music,
+    Records,
+    Weather,
+    Blocks,
+    Hostile,
+    Neutral,
+    Players,
+    Ambient,
+    Voice,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Sound Effect
+///
+/// Plays a hardcoded sound effect by its id.
+pub struct SoundEffect95 {
+    #[encoding(varint)]
+    pub sound_id: i32,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    /// 63 is 100%, can be more
+    pub pitch: u8,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Sound Effect
+///
+/// Since pv201 (16w20a) the pitch is a float.
+pub struct SoundEffect201 {
+    #[encoding(varint)]
+    pub sound_id: i32,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    pub pitch: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Named Sound Effect
+///
+/// Plays a sound effect by its name, used for custom sounds.
+pub struct NamedSoundEffect95<'a> {
+    pub sound_name: Cow<'a, str>,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    /// 63 is 100%, can be more
+    pub pitch: u8,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Named Sound Effect
+///
+/// Since pv201 (16w20a) the pitch is a float.
+pub struct NamedSoundEffect201<'a> {
+    pub sound_name: Cow<'a, str>,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    pub pitch: f32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Particle0<'a> {
     // TODO: specific strings into enum
@@ -2384,6 +3389,143 @@ pub struct Particle29<'a> {
     pub data: Cow<'a, [u8]>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Particle86<'a> {
+    // TODO: particle ids into enum
+    pub particle_id: i32,
+    pub long_distance: bool,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub offset_z: f32,
+    pub speed: f32,
+    pub number: i32,
+    // TODO: read exact number of varints using the particle id
+    #[encoding(rest)]
+    pub data: Cow<'a, [u8]>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Particle
+///
+/// Since pv389 (1.13-pre7) the data of the particle is typed, see
+/// [`Particle353`][p353] and [`Particle463`][p463]. The id is an int in front
+/// of the other fields while the data follows them.
+///
+/// [p353]: crate::netty::types::particle::Particle353
+/// [p463]: crate::netty::types::particle::Particle463
+pub struct Particle389<Particle> {
+    pub long_distance: bool,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub offset_z: f32,
+    pub speed: f32,
+    pub count: i32,
+    pub particle: Particle,
+}
+
+impl<'dec, Particle> Decode<'dec> for Particle389<Particle>
+where
+    Particle: DecodeParticle<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let id = i32::decode(cursor)?;
+        Ok(Self {
+            long_distance: bool::decode(cursor)?,
+            x: f32::decode(cursor)?,
+            y: f32::decode(cursor)?,
+            z: f32::decode(cursor)?,
+            offset_x: f32::decode(cursor)?,
+            offset_y: f32::decode(cursor)?,
+            offset_z: f32::decode(cursor)?,
+            speed: f32::decode(cursor)?,
+            count: i32::decode(cursor)?,
+            particle: Particle::decode_data(id, cursor)?,
+        })
+    }
+}
+
+impl<Particle> Encode for Particle389<Particle>
+where
+    Particle: EncodeParticle,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.particle.id().encode(writer)?;
+        self.long_distance.encode(writer)?;
+        self.x.encode(writer)?;
+        self.y.encode(writer)?;
+        self.z.encode(writer)?;
+        self.offset_x.encode(writer)?;
+        self.offset_y.encode(writer)?;
+        self.offset_z.encode(writer)?;
+        self.speed.encode(writer)?;
+        self.count.encode(writer)?;
+        self.particle.encode_data(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Particle
+///
+/// Since pv569 (1.15-pre4) the position is sent as doubles.
+pub struct Particle569<Particle> {
+    pub long_distance: bool,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub offset_z: f32,
+    pub speed: f32,
+    pub count: i32,
+    pub particle: Particle,
+}
+
+impl<'dec, Particle> Decode<'dec> for Particle569<Particle>
+where
+    Particle: DecodeParticle<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let id = i32::decode(cursor)?;
+        Ok(Self {
+            long_distance: bool::decode(cursor)?,
+            x: f64::decode(cursor)?,
+            y: f64::decode(cursor)?,
+            z: f64::decode(cursor)?,
+            offset_x: f32::decode(cursor)?,
+            offset_y: f32::decode(cursor)?,
+            offset_z: f32::decode(cursor)?,
+            speed: f32::decode(cursor)?,
+            count: i32::decode(cursor)?,
+            particle: Particle::decode_data(id, cursor)?,
+        })
+    }
+}
+
+impl<Particle> Encode for Particle569<Particle>
+where
+    Particle: EncodeParticle,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.particle.id().encode(writer)?;
+        self.long_distance.encode(writer)?;
+        self.x.encode(writer)?;
+        self.y.encode(writer)?;
+        self.z.encode(writer)?;
+        self.offset_x.encode(writer)?;
+        self.offset_y.encode(writer)?;
+        self.offset_z.encode(writer)?;
+        self.speed.encode(writer)?;
+        self.count.encode(writer)?;
+        self.particle.encode_data(writer)
+    }
+}
+
 // #[derive(Encoding, ToStatic, Debug)]
 // struct ChangeGameState0 {
 //     reason: GameStateChangeReason,
@@ -2407,32 +3549,40 @@ pub enum ChangeGameState0 {
     FadeTime(f32),
 }
 
-#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
-#[encoding(from = "u8")]
-pub enum DemoMessage0 {
-    WelcomeToDemo = 0,
-    MovementControl = 101,
-    JumpControl,
-    InventoryControl,
+#[derive(ToStatic, Debug)]
+pub enum ChangeGameState86 {
+    InvalidBed,
+    EndRaining,
+    BeginRaining,
+    ChangeGameMode(GameMode86),
+    ExitEnd { show_credits: bool },
+    DemoMessage(DemoMessage0),
+    ArrowHitPlayer,
+    FadeValue(f32),
+    FadeTime(f32),
+    ElderGuardianAppearance,
 }
 
-impl<'dec> Decode<'dec> for ChangeGameState0 {
+impl<'dec> Decode<'dec> for ChangeGameState86 {
     fn decode(cursor: &'_ mut std::io::Cursor<&[u8]>) -> decode::Result<Self> {
         let reason = u8::decode(cursor)?;
         let value = f32::decode(cursor)?;
         use self::DemoMessage0::*;
-        use ChangeGameState0::*;
+        use ChangeGameState86::*;
         Ok(match reason {
             0 => InvalidBed,
-            1 => BeginRaining,
-            2 => EndRaining,
+            1 => EndRaining,
+            2 => BeginRaining,
             3 => ChangeGameMode(match value as u8 {
-                0 => GameMode0::Survival,
-                1 => GameMode0::Creative,
-                2 => GameMode0::Adventure,
+                0 => GameMode86::Survival,
+                1 => GameMode86::Creative,
+                2 => GameMode86::Adventure,
+                3 => GameMode86::Spectator,
                 _ => return Err(decode::Error::InvalidId),
             }),
-            4 => EnterCredits,
+            4 => ExitEnd {
+                show_credits: value != 0.0,
+            },
             5 => DemoMessage(match value as u8 {
                 0 => WelcomeToDemo,
                 101 => MovementControl,
@@ -2440,26 +3590,28 @@ impl<'dec> Decode<'dec> for ChangeGameState0 {
                 103 => InventoryControl,
                 _ => return Err(decode::Error::InvalidId),
             }),
-            6 => BowHitSound,
+            6 => ArrowHitPlayer,
             7 => FadeValue(value),
             8 => FadeTime(value),
+            10 => ElderGuardianAppearance,
             _ => return Err(decode::Error::InvalidId),
         })
     }
 }
 
-impl Encode for ChangeGameState0 {
+impl Encode for ChangeGameState86 {
     fn encode(&self, writer: &mut impl std::io::Write) -> Result<(), encode::Error> {
         let (reason, value) = match self {
-            ChangeGameState0::InvalidBed => (0u8, 0.0),
-            ChangeGameState0::BeginRaining => (1, 0.0),
-            ChangeGameState0::EndRaining => (2, 0.0),
-            ChangeGameState0::ChangeGameMode(gamemode) => (3, *gamemode as u8 as f32),
-            ChangeGameState0::EnterCredits => (4, 0.0),
-            ChangeGameState0::DemoMessage(demomessage) => (5, *demomessage as u8 as f32),
-            ChangeGameState0::BowHitSound => (6, 0.0),
-            ChangeGameState0::FadeValue(value) => (7, *value),
-            ChangeGameState0::FadeTime(value) => (8, *value),
+            ChangeGameState86::InvalidBed => (0u8, 0.0),
+            ChangeGameState86::EndRaining => (1, 0.0),
+            ChangeGameState86::BeginRaining => (2, 0.0),
+            ChangeGameState86::ChangeGameMode(gamemode) => (3, *gamemode as u8 as f32),
+            ChangeGameState86::ExitEnd { show_credits } => (4, *show_credits as u8 as f32),
+            ChangeGameState86::DemoMessage(demomessage) => (5, *demomessage as u8 as f32),
+            ChangeGameState86::ArrowHitPlayer => (6, 0.0),
+            ChangeGameState86::FadeValue(value) => (7, *value),
+            ChangeGameState86::FadeTime(value) => (8, *value),
+            ChangeGameState86::ElderGuardianAppearance => (10, 0.0),
         };
         reason.encode(writer)?;
         value.encode(writer)?;
@@ -2467,12 +3619,72 @@ impl Encode for ChangeGameState0 {
     }
 }
 
-#[derive(Encoding, ToStatic, Debug)]
-pub struct SpawnGlobalEntity0 {
-    #[encoding(varint)]
-    pub entity_id: i32,
-    /// The global entity type, currently always 1 for thunderbolt.
-    pub kind: u8,
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
+#[encoding(from = "u8")]
+pub enum DemoMessage0 {
+    WelcomeToDemo = 0,
+    MovementControl = 101,
+    JumpControl,
+    InventoryControl,
+}
+
+impl<'dec> Decode<'dec> for ChangeGameState0 {
+    fn decode(cursor: &'_ mut std::io::Cursor<&[u8]>) -> decode::Result<Self> {
+        let reason = u8::decode(cursor)?;
+        let value = f32::decode(cursor)?;
+        use self::DemoMessage0::*;
+        use ChangeGameState0::*;
+        Ok(match reason {
+            0 => InvalidBed,
+            1 => BeginRaining,
+            2 => EndRaining,
+            3 => ChangeGameMode(match value as u8 {
+                0 => GameMode0::Survival,
+                1 => GameMode0::Creative,
+                2 => GameMode0::Adventure,
+                _ => return Err(decode::Error::InvalidId),
+            }),
+            4 => EnterCredits,
+            5 => DemoMessage(match value as u8 {
+                0 => WelcomeToDemo,
+                101 => MovementControl,
+                102 => JumpControl,
+                103 => InventoryControl,
+                _ => return Err(decode::Error::InvalidId),
+            }),
+            6 => BowHitSound,
+            7 => FadeValue(value),
+            8 => FadeTime(value),
+            _ => return Err(decode::Error::InvalidId),
+        })
+    }
+}
+
+impl Encode for ChangeGameState0 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> Result<(), encode::Error> {
+        let (reason, value) = match self {
+            ChangeGameState0::InvalidBed => (0u8, 0.0),
+            ChangeGameState0::BeginRaining => (1, 0.0),
+            ChangeGameState0::EndRaining => (2, 0.0),
+            ChangeGameState0::ChangeGameMode(gamemode) => (3, *gamemode as u8 as f32),
+            ChangeGameState0::EnterCredits => (4, 0.0),
+            ChangeGameState0::DemoMessage(demomessage) => (5, *demomessage as u8 as f32),
+            ChangeGameState0::BowHitSound => (6, 0.0),
+            ChangeGameState0::FadeValue(value) => (7, *value),
+            ChangeGameState0::FadeTime(value) => (8, *value),
+        };
+        reason.encode(writer)?;
+        value.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SpawnGlobalEntity0 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    /// The global entity type, currently always 1 for thunderbolt.
+    pub kind: u8,
     #[encoding(fixed(5, "i32"))]
     pub x: f64,
     #[encoding(fixed(5, "i32"))]
@@ -2481,6 +3693,17 @@ pub struct SpawnGlobalEntity0 {
     pub z: f64,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SpawnGlobalEntity100 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    /// The only global entity is the thunderbolt with kind 1.
+    pub kind: u8,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
 #[derive(ToStatic, Debug)]
 pub struct OpenWindow0<'a> {
     pub window_id: u8,
@@ -2591,6 +3814,7 @@ impl<'dec: 'a, 'a> Decode<'dec> for OpenWindow6<'a> {
             "EntityHorse" => Horse {
                 entity_id: i32::decode(cursor)?,
             },
+            "minecraft:shulker_box" => ShulkerBox,
             _ => return Err(decode::Error::InvalidId),
         };
         Ok(Self {
@@ -2618,6 +3842,7 @@ impl<'a> Encode for OpenWindow6<'a> {
             Hopper => ("minecraft:hopper", None),
             Dropper => ("minecraft:dropper", None),
             Horse { entity_id } => ("EntityHorse", Some(entity_id)),
+            ShulkerBox => ("minecraft:shulker_box", None),
         };
         kind.encode(writer)?;
         self.title.encode(writer)?;
@@ -2629,6 +3854,21 @@ impl<'a> Encode for OpenWindow6<'a> {
     }
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Open Window
+///
+/// Since pv471 (1.14) the kind is a varint id of the menu registry and the
+/// slot count is no longer sent. Horses are opened with
+/// [`OpenHorseWindow471`].
+pub struct OpenWindow471<'a> {
+    #[encoding(varint)]
+    pub window_id: i32,
+    #[encoding(varint)]
+    pub kind: i32,
+    // TODO: chat
+    pub title: Cow<'a, str>,
+}
+
 // #[derive(Encoding, ToStatic, Debug)]
 #[derive(ToStatic, Debug)]
 // TODO: very good place for #[separate]
@@ -2675,6 +3915,7 @@ pub enum InventoryKind6 {
     Horse {
         entity_id: i32,
     },
+    ShulkerBox,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -2698,6 +3939,29 @@ pub struct SetSlot0 {
     // data: Slot
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Slot
+///
+/// Sent by the server when an item in a slot (in a window) is added/removed.
+pub struct SetSlot67<'a> {
+    /// -1 and slot -1 for the item held by the cursor, 0 for the inventory.
+    pub window_id: i8,
+    pub slot: i16,
+    pub item: Slot0<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Slot
+///
+/// Generic over the slot format which changed in pv346 (17w45a) and pv402
+/// (1.13-pre7).
+pub struct SetSlot346<Slot> {
+    /// -1 and slot -1 for the item held by the cursor, 0 for the inventory.
+    pub window_id: i8,
+    pub slot: i16,
+    pub item: Slot,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct WindowItems0 {
     /// The id of window which items are being sent for. 0 for player inventory.
@@ -2707,6 +3971,28 @@ pub struct WindowItems0 {
     // slots: Vec<Slot>
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Window Items
+///
+/// Sent by the server when items in multiple slots (in a window) are
+/// added/removed.
+pub struct WindowItems67<'a> {
+    pub window_id: u8,
+    #[encoding(counted = "u16")]
+    pub slots: Vec<Slot0<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Window Items
+///
+/// Generic over the slot format which changed in pv346 (17w45a) and pv402
+/// (1.13-pre7).
+pub struct WindowItems346<Slot> {
+    pub window_id: u8,
+    #[encoding(counted = "u16")]
+    pub slots: Vec<Slot>,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 /// see <https://wiki.vg/index.php?title=Pre-release_protocol&oldid=5007#Window_Property>
 pub struct WindowProperty0 {
@@ -2788,6 +4074,291 @@ pub struct UpdateBlockEntity6 {
     // data: Nbt
 }
 
+#[derive(ToStatic, Debug)]
+/// Update Block Entity
+///
+/// Since pv67 the nbt is sent uncompressed.
+pub struct UpdateBlockEntity67<'a> {
+    pub location: Position6,
+    pub action: u8,
+    /// `None` to remove the block entity
+    pub data: Option<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UpdateBlockEntity67<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let location = Position6::decode(cursor)?;
+        let action = u8::decode(cursor)?;
+        let data = decode_optional_nbt(cursor)?;
+        Ok(Self {
+            location,
+            action,
+            data,
+        })
+    }
+}
+
+impl<'a> Encode for UpdateBlockEntity67<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.location.encode(writer)?;
+        self.action.encode(writer)?;
+        encode_optional_nbt(&self.data, writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub struct UpdateBlockEntity441<'a> {
+    pub location: Position441,
+    pub action: u8,
+    /// `None` to remove the block entity
+    pub data: Option<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UpdateBlockEntity441<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let location = Position441::decode(cursor)?;
+        let action = u8::decode(cursor)?;
+        let data = decode_optional_nbt(cursor)?;
+        Ok(Self {
+            location,
+            action,
+            data,
+        })
+    }
+}
+
+impl<'a> Encode for UpdateBlockEntity441<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.location.encode(writer)?;
+        self.action.encode(writer)?;
+        encode_optional_nbt(&self.data, writer)
+    }
+}
+
+/// Network nbt which is either a named compound or a single TAG_End.
+fn decode_optional_nbt<'dec>(
+    cursor: &mut std::io::Cursor<&'dec [u8]>,
+) -> decode::Result<Option<Nbt<'dec>>> {
+    match cursor.get_ref().get(cursor.position() as usize) {
+        Some(0) => {
+            cursor.set_position(cursor.position() + 1);
+            Ok(None)
+        }
+        _ => Ok(Some(Nbt::decode(cursor)?)),
+    }
+}
+
+fn encode_optional_nbt(nbt: &Option<Nbt>, writer: &mut impl std::io::Write) -> encode::Result<()> {
+    match nbt {
+        Some(nbt) => nbt.encode(writer),
+        None => 0u8.encode(writer),
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Map
+///
+/// Updates a rectangular area on a map item.
+pub struct Maps86<'a> {
+    // varint
+    pub item_damage: i32,
+    pub scale: i8,
+    pub tracking_position: bool,
+    pub icons: Vec<MapIcon86>,
+    /// `None` if no columns are updated
+    pub data: Option<MapData86<'a>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Map
+///
+/// Since pv389 (1.13-pre7) icons are sent with a varint kind and can have a
+/// display name.
+pub struct Maps389<'a> {
+    // varint
+    pub item_damage: i32,
+    pub scale: i8,
+    pub tracking_position: bool,
+    pub icons: Vec<MapIcon389<'a>>,
+    /// `None` if no columns are updated
+    pub data: Option<MapData86<'a>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Map
+///
+/// Since pv452 (19w02a) maps can be locked in a cartography table.
+pub struct Maps452<'a> {
+    // varint
+    pub item_damage: i32,
+    pub scale: i8,
+    pub tracking_position: bool,
+    pub locked: bool,
+    pub icons: Vec<MapIcon389<'a>>,
+    /// `None` if no columns are updated
+    pub data: Option<MapData86<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct MapIcon389<'a> {
+    #[encoding(varint)]
+    pub kind: i32,
+    pub x: i8,
+    pub z: i8,
+    /// 0-15
+    pub direction: i8,
+    // TODO: chat
+    pub display_name: Option<Cow<'a, str>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Maps389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            item_damage: Var::decode(cursor)?.into_inner(),
+            scale: i8::decode(cursor)?,
+            tracking_position: bool::decode(cursor)?,
+            icons: Vec::decode(cursor)?,
+            data: MapData86::decode_optional(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Maps389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.item_damage).encode(writer)?;
+        self.scale.encode(writer)?;
+        self.tracking_position.encode(writer)?;
+        self.icons.encode(writer)?;
+        MapData86::encode_optional(&self.data, writer)
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Maps452<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            item_damage: Var::decode(cursor)?.into_inner(),
+            scale: i8::decode(cursor)?,
+            tracking_position: bool::decode(cursor)?,
+            locked: bool::decode(cursor)?,
+            icons: Vec::decode(cursor)?,
+            data: MapData86::decode_optional(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Maps452<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.item_damage).encode(writer)?;
+        self.scale.encode(writer)?;
+        self.tracking_position.encode(writer)?;
+        self.locked.encode(writer)?;
+        self.icons.encode(writer)?;
+        MapData86::encode_optional(&self.data, writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub struct MapIcon86 {
+    /// 0-15
+    pub direction: u8,
+    /// 0-15
+    pub kind: u8,
+    pub x: i8,
+    pub z: i8,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct MapData86<'a> {
+    pub columns: u8,
+    pub rows: u8,
+    pub x: i8,
+    pub z: i8,
+    pub data: Cow<'a, [u8]>,
+}
+
+impl<'a> MapData86<'a> {
+    /// The data is prefixed by the number of updated columns, which is 0 if
+    /// no data follows.
+    fn decode_optional<'dec: 'a>(
+        cursor: &mut std::io::Cursor<&'dec [u8]>,
+    ) -> decode::Result<Option<Self>> {
+        Ok(match u8::decode(cursor)? {
+            0 => None,
+            columns => Some(MapData86 {
+                columns,
+                rows: u8::decode(cursor)?,
+                x: i8::decode(cursor)?,
+                z: i8::decode(cursor)?,
+                data: Cow::decode(cursor)?,
+            }),
+        })
+    }
+
+    fn encode_optional(
+        data: &Option<Self>,
+        writer: &mut impl std::io::Write,
+    ) -> encode::Result<()> {
+        match data {
+            None => 0u8.encode(writer),
+            Some(data) => {
+                debug_assert_ne!(data.columns, 0);
+                data.columns.encode(writer)?;
+                data.rows.encode(writer)?;
+                data.x.encode(writer)?;
+                data.z.encode(writer)?;
+                data.data.encode(writer)
+            }
+        }
+    }
+}
+
+impl<'dec> Decode<'dec> for MapIcon86 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let direction_and_kind = u8::decode(cursor)?;
+        Ok(Self {
+            direction: direction_and_kind >> 4,
+            kind: direction_and_kind & 0x0f,
+            x: i8::decode(cursor)?,
+            z: i8::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for MapIcon86 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        ((self.direction << 4) | (self.kind & 0x0f)).encode(writer)?;
+        self.x.encode(writer)?;
+        self.z.encode(writer)
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Maps86<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let item_damage = Var::decode(cursor)?.into_inner();
+        let scale = i8::decode(cursor)?;
+        let tracking_position = bool::decode(cursor)?;
+        let icons = Vec::decode(cursor)?;
+        let data = MapData86::decode_optional(cursor)?;
+        Ok(Self {
+            item_damage,
+            scale,
+            tracking_position,
+            icons,
+            data,
+        })
+    }
+}
+
+impl<'a> Encode for Maps86<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.item_damage).encode(writer)?;
+        self.scale.encode(writer)?;
+        self.tracking_position.encode(writer)?;
+        self.icons.encode(writer)?;
+        MapData86::encode_optional(&self.data, writer)
+    }
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct SignEditorOpen0 {
     pub x: i32,
@@ -2795,14 +4366,19 @@ pub struct SignEditorOpen0 {
     pub z: i32,
 }
 
-#[derive(Bitfield, ToStatic)]
+#[derive(Bitfield, ToStatic, Debug)]
 pub struct SignEditorOpen6 {
     #[encoding(bits = 26)]
     pub x: i32,
-    #[encoding(bits = 26)]
-    pub z: i32,
     #[encoding(bits = 12)]
     pub y: i16,
+    #[encoding(bits = 26)]
+    pub z: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SignEditorOpen441 {
+    pub location: Position441,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -2818,6 +4394,25 @@ pub struct Statistic0<'a> {
     pub amount: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Statistics
+///
+/// Since pv346 (17w45a) statistics are identified by registry ids instead of
+/// names.
+pub struct Statistics346 {
+    pub entries: Vec<Statistic346>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Statistic346 {
+    #[encoding(varint)]
+    pub category_id: i32,
+    #[encoding(varint)]
+    pub statistic_id: i32,
+    #[encoding(varint)]
+    pub value: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct PlayerListItem0<'a> {
     /// Supports chat colouring, limited to 16 characters.
@@ -2879,12 +4474,13 @@ pub enum PlayerListItem19<'a> {
 }
 
 #[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
 pub enum PlayerListItem28<'a> {
     #[encoding(case = "0")]
     AddPlayers(Vec<PlayerListAddPlayer28<'a>>),
     UpdateGamemode(Vec<PlayerListUpdateGamemode17>),
     UpdateLatency(Vec<PlayerListUpdateLatency17>),
-    UpdateDisplayName(Vec<PlayerListUpdateDisplayName28>),
+    UpdateDisplayName(Vec<PlayerListUpdateDisplayName28<'a>>),
     RemovePlayers(Vec<Uuid>),
 }
 
@@ -2904,16 +4500,17 @@ pub struct PlayerListAddPlayer28<'a> {
     pub name: Cow<'a, str>,
     pub properties: Vec<PlayerProperty19<'a>>,
     pub gamemode: GameMode17,
-    // varint
+    #[encoding(varint)]
     pub ping: i32,
     // TODO: chat
     pub display_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
-pub struct PlayerListUpdateDisplayName28 {
+pub struct PlayerListUpdateDisplayName28<'a> {
     pub uuid: Uuid,
-    pub display_name: Option<Uuid>,
+    // TODO: chat
+    pub display_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -2923,18 +4520,97 @@ pub struct PlayerProperty19<'a> {
     pub signature: Option<Cow<'a, str>>,
 }
 
-#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
-pub enum GameMode17 {
-    Survival = 0,
-    Creative,
-    Adventure,
+impl<'a> From<&'a Property> for PlayerProperty19<'a> {
+    fn from(property: &'a Property) -> Self {
+        Self {
+            name: Cow::Borrowed(&property.name),
+            value: Cow::Borrowed(&property.value),
+            signature: property.signature.as_deref().map(Cow::Borrowed),
+        }
+    }
 }
 
-#[derive(ToStatic, Debug)]
-pub struct PlayerAbilities0 {
-    pub invulnerable: bool,
-    pub flying: bool,
-    pub allow_flying: bool,
+impl From<&PlayerProperty19<'_>> for Property {
+    fn from(property: &PlayerProperty19<'_>) -> Self {
+        Self {
+            name: property.name.to_string(),
+            value: property.value.to_string(),
+            signature: property.signature.as_ref().map(|s| s.to_string()),
+        }
+    }
+}
+
+impl<'a> PlayerListAddPlayer17<'a> {
+    pub fn new(profile: &'a GameProfile, gamemode: GameMode17, ping: i32) -> Self {
+        Self {
+            uuid: profile.uuid,
+            name: Cow::Borrowed(&profile.name),
+            gamemode,
+            ping,
+        }
+    }
+
+    pub fn profile(&self) -> GameProfile {
+        GameProfile::new(self.uuid, self.name.to_string())
+    }
+}
+
+impl<'a> PlayerListAddPlayer19<'a> {
+    pub fn new(profile: &'a GameProfile, gamemode: GameMode17, ping: i32) -> Self {
+        Self {
+            uuid: profile.uuid,
+            name: Cow::Borrowed(&profile.name),
+            properties: profile.properties.iter().map(Into::into).collect(),
+            gamemode,
+            ping,
+        }
+    }
+
+    pub fn profile(&self) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            name: self.name.to_string(),
+            properties: self.properties.iter().map(Property::from).collect(),
+        }
+    }
+}
+
+impl<'a> PlayerListAddPlayer28<'a> {
+    /// Creates an entry without a display name.
+    pub fn new(profile: &'a GameProfile, gamemode: GameMode17, ping: i32) -> Self {
+        Self {
+            uuid: profile.uuid,
+            name: Cow::Borrowed(&profile.name),
+            properties: profile.properties.iter().map(Into::into).collect(),
+            gamemode,
+            ping,
+            display_name: None,
+        }
+    }
+
+    pub fn profile(&self) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            name: self.name.to_string(),
+            properties: self.properties.iter().map(Property::from).collect(),
+        }
+    }
+}
+
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
+#[encoding(varint)]
+pub enum GameMode17 {
+    Survival = 0,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct PlayerAbilities0 {
+    pub invulnerable: bool,
+    pub flying: bool,
+    pub allow_flying: bool,
     pub creative_mode: bool,
     pub flying_speed: f32,
     /// Modifies the field of view, like a speed potion. A Notchian server will
@@ -2974,6 +4650,32 @@ pub struct TabComplete0<'a> {
     pub matches: Vec<Cow<'a, str>>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Tab Complete
+///
+/// Since pv357 (17w46a) the completions replace a part of the text sent in
+/// [`serverbound::TabComplete389`][tc] and may have a tooltip.
+///
+/// [tc]: super::serverbound::TabComplete389
+pub struct TabComplete357<'a> {
+    #[encoding(varint)]
+    pub transaction_id: i32,
+    /// Start of the text to replace
+    #[encoding(varint)]
+    pub start: i32,
+    /// Length of the text to replace
+    #[encoding(varint)]
+    pub length: i32,
+    pub matches: Vec<TabCompleteMatch357<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct TabCompleteMatch357<'a> {
+    pub text: Cow<'a, str>,
+    // TODO: chat
+    pub tooltip: Option<Cow<'a, str>>,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct ScoreboardObjective0<'a> {
     pub name: Cow<'a, str>,
@@ -3022,6 +4724,46 @@ pub enum ScoreboardObjectiveKind12 {
     Hearts,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ScoreboardObjective86<'a> {
+    pub name: Cow<'a, str>,
+    pub action: ScoreboardObjectiveAction12<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Scoreboard Objective
+///
+/// Since pv390 (1.13-pre8) the display name is a chat component and the
+/// kind is sent as a varint.
+pub struct ScoreboardObjective390<'a> {
+    pub name: Cow<'a, str>,
+    pub action: ScoreboardObjectiveAction390<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "u8")]
+pub enum ScoreboardObjectiveAction390<'a> {
+    #[encoding(case = "0")]
+    Create {
+        // TODO: chat
+        value: Cow<'a, str>,
+        kind: ScoreboardObjectiveKind390,
+    },
+    Remove,
+    Update {
+        // TODO: chat
+        value: Cow<'a, str>,
+        kind: ScoreboardObjectiveKind390,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum ScoreboardObjectiveKind390 {
+    Integer = 0,
+    Hearts,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct UpdateScore0<'a> {
     /// The name of the score to be updated or removed
@@ -3224,6 +4966,111 @@ pub enum NameTagVisibility11 {
     Never,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Teams86<'a> {
+    pub name: Cow<'a, str>,
+    pub action: TeamAction86<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "u8")]
+pub enum TeamAction86<'a> {
+    #[encoding(case = "0")]
+    Create {
+        display_name: Cow<'a, str>,
+        prefix: Cow<'a, str>,
+        suffix: Cow<'a, str>,
+        flags: TeamFlags86,
+        name_tag_vis: NameTagVisibility11,
+        collision_rule: CollisionRule86,
+        color: i8,
+        players: Vec<Cow<'a, str>>,
+    },
+    Remove,
+    Update {
+        display_name: Cow<'a, str>,
+        prefix: Cow<'a, str>,
+        suffix: Cow<'a, str>,
+        flags: TeamFlags86,
+        name_tag_vis: NameTagVisibility11,
+        collision_rule: CollisionRule86,
+        color: i8,
+    },
+    AddPlayers {
+        players: Vec<Cow<'a, str>>,
+    },
+    RemovePlayers {
+        players: Vec<Cow<'a, str>>,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Teams
+///
+/// Since pv390 (1.13-pre8) the display name, prefix and suffix are chat
+/// components and the color is a varint sent in front of them.
+pub struct Teams390<'a> {
+    pub name: Cow<'a, str>,
+    pub action: TeamAction390<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "u8")]
+pub enum TeamAction390<'a> {
+    #[encoding(case = "0")]
+    Create {
+        // TODO: chat
+        display_name: Cow<'a, str>,
+        flags: TeamFlags86,
+        name_tag_vis: NameTagVisibility11,
+        collision_rule: CollisionRule86,
+        #[encoding(varint)]
+        color: i32,
+        prefix: Cow<'a, str>,
+        suffix: Cow<'a, str>,
+        players: Vec<Cow<'a, str>>,
+    },
+    Remove,
+    Update {
+        display_name: Cow<'a, str>,
+        flags: TeamFlags86,
+        name_tag_vis: NameTagVisibility11,
+        collision_rule: CollisionRule86,
+        #[encoding(varint)]
+        color: i32,
+        prefix: Cow<'a, str>,
+        suffix: Cow<'a, str>,
+    },
+    AddPlayers {
+        players: Vec<Cow<'a, str>>,
+    },
+    RemovePlayers {
+        players: Vec<Cow<'a, str>>,
+    },
+}
+
+#[derive(Bitfield, ToStatic, Debug)]
+#[encoding(typ = "u8", reverse)]
+pub struct TeamFlags86 {
+    #[encoding(bool)]
+    pub friendly_fire: bool,
+    #[encoding(bool)]
+    pub see_invisible_teammates: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(from = "&str")]
+pub enum CollisionRule86 {
+    #[encoding(case = "\"always\"")]
+    Always,
+    #[encoding(case = "\"pushOtherTeams\"")]
+    PushOtherTeams,
+    #[encoding(case = "\"pushOwnTeam\"")]
+    PushOwnTeam,
+    #[encoding(case = "\"never\"")]
+    Never,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 // https://dinnerbone.com/blog/2012/01/13/minecraft-plugin-channels-messaging/
 pub struct PluginMessage0<'a> {
@@ -3258,6 +5105,18 @@ pub struct ServerDifficulty6 {
     pub difficulty: Difficulty0,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+/// Server Difficulty
+///
+/// Since pv464 (19w14a) the difficulty can be locked, see
+/// [`serverbound::LockDifficulty471`][ld].
+///
+/// [ld]: super::serverbound::LockDifficulty471
+pub struct ServerDifficulty464 {
+    pub difficulty: Difficulty0,
+    pub locked: bool,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 #[encoding(from = "i32")]
 pub enum CombatEvent7<'a> {
@@ -3401,6 +5260,7 @@ pub enum WorldBorder17 {
 }
 
 #[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
 pub enum WorldBorder32 {
     #[encoding(case = "0")]
     SetSize {
@@ -3463,6 +5323,33 @@ pub enum Title18<'a> {
         /// ticks
         fade_out: i32,
     },
+    Hide,
+    Reset,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+/// Title
+///
+/// Since pv301 (16w32a) text can be shown above the hotbar.
+pub enum Title301<'a> {
+    #[encoding(case = "0")]
+    SetTitle {
+        text: Cow<'a, str>,
+    },
+    SetSubTitle {
+        text: Cow<'a, str>,
+    },
+    SetActionBar {
+        text: Cow<'a, str>,
+    },
+    SetTimesAndDisplay {
+        fade_in: i32,
+        stay: i32,
+        fade_out: i32,
+    },
+    Hide,
+    Reset,
 }
 
 #[derive(Encoding, ToStatic, Debug)]
@@ -3498,3 +5385,3299 @@ pub struct SetCoolDown48 {
     #[encoding(varint)]
     pub cooldown: i32,
 }
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Cooldown
+///
+/// Applies a cooldown period to all items with the given type.
+pub struct SetCoolDown67 {
+    #[encoding(varint)]
+    pub item_id: i32,
+    /// Number of ticks to apply a cooldown for, or 0 to clear the cooldown.
+    #[encoding(varint)]
+    pub cooldown: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Boss Bar
+pub struct BossBar67<'a> {
+    /// Identifies the boss bar in later updates.
+    pub uuid: Uuid,
+    pub action: BossBarAction67<'a>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum BossBarAction67<'a> {
+    #[encoding(case = "0")]
+    Add {
+        // TODO: chat
+        title: Cow<'a, str>,
+        /// From 0 to 1.
+        health: f32,
+        color: BossBarColor67,
+        division: BossBarDivision67,
+        flags: BossBarFlags67,
+    },
+    Remove,
+    UpdateHealth {
+        health: f32,
+    },
+    UpdateTitle {
+        title: Cow<'a, str>,
+    },
+    UpdateStyle {
+        color: BossBarColor67,
+        division: BossBarDivision67,
+    },
+    UpdateFlags {
+        flags: BossBarFlags67,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum BossBarColor67 {
+    Pink = 0,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    White,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum BossBarDivision67 {
+    None = 0,
+    Notches6,
+    Notches10,
+    Notches12,
+    Notches20,
+}
+
+#[derive(Bitfield, ToStatic, Debug)]
+#[encoding(typ = "u8", reverse)]
+pub struct BossBarFlags67 {
+    #[encoding(bool)]
+    pub darken_sky: bool,
+    /// Also used to create fog before pv201 (16w20a).
+    #[encoding(bool)]
+    pub dragon_bar: bool,
+    #[encoding(bool)]
+    pub create_fog: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Vehicle Move
+///
+/// Sent by the server to correct the position of the vehicle the player is
+/// controlling.
+pub struct VehicleMove86 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+#[derive(ToStatic, Debug)]
+/// Unlock Recipes
+pub struct UnlockRecipes333 {
+    pub action: UnlockRecipesAction333,
+    pub crafting_book_open: bool,
+    pub filtering_craftable: bool,
+    // varint count of varints
+    pub recipe_ids: Vec<i32>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum UnlockRecipesAction333 {
+    /// All recipes in `to_be_displayed` are marked as new in the recipe book.
+    Init {
+        to_be_displayed: Vec<i32>,
+    },
+    Add,
+    Remove,
+}
+
+fn decode_varint_array(cursor: &mut std::io::Cursor<&[u8]>) -> decode::Result<Vec<i32>> {
+    let len = Var::<u32>::decode(cursor)?.into_inner();
+    (0..len)
+        .map(|_| Var::<i32>::decode(cursor).map(|var| var.into_inner()))
+        .collect()
+}
+
+fn encode_varint_array(array: &[i32], writer: &mut impl std::io::Write) -> encode::Result<()> {
+    Var::from(array.len() as u32).encode(writer)?;
+    for value in array {
+        Var::from(*value).encode(writer)?;
+    }
+    Ok(())
+}
+
+impl<'dec> Decode<'dec> for UnlockRecipes333 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let action = Var::<i32>::decode(cursor)?.into_inner();
+        let crafting_book_open = bool::decode(cursor)?;
+        let filtering_craftable = bool::decode(cursor)?;
+        let recipe_ids = decode_varint_array(cursor)?;
+        let action = match action {
+            0 => UnlockRecipesAction333::Init {
+                to_be_displayed: decode_varint_array(cursor)?,
+            },
+            1 => UnlockRecipesAction333::Add,
+            2 => UnlockRecipesAction333::Remove,
+            _ => return Err(decode::Error::InvalidId),
+        };
+        Ok(Self {
+            action,
+            crafting_book_open,
+            filtering_craftable,
+            recipe_ids,
+        })
+    }
+}
+
+impl Encode for UnlockRecipes333 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let action = match self.action {
+            UnlockRecipesAction333::Init { .. } => 0,
+            UnlockRecipesAction333::Add => 1,
+            UnlockRecipesAction333::Remove => 2,
+        };
+        Var::from(action).encode(writer)?;
+        self.crafting_book_open.encode(writer)?;
+        self.filtering_craftable.encode(writer)?;
+        encode_varint_array(&self.recipe_ids, writer)?;
+        if let UnlockRecipesAction333::Init { to_be_displayed } = &self.action {
+            encode_varint_array(to_be_displayed, writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Craft Recipe Response
+///
+/// Response to [`serverbound::CraftRecipeRequest336`][crr], shows a ghost
+/// recipe in the crafting grid.
+///
+/// [crr]: super::serverbound::CraftRecipeRequest336
+pub struct CraftRecipeResponse336 {
+    pub window_id: i8,
+    #[encoding(varint)]
+    pub recipe_id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Craft Recipe Response
+///
+/// Since pv389 (1.13-pre7) recipes are identified by their name, see
+/// [`DeclareRecipes389`].
+pub struct CraftRecipeResponse389<'a> {
+    pub window_id: i8,
+    pub recipe: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Select Advancement Tab
+pub struct SelectAdvancementTab332<'a> {
+    /// `None` to deselect the tab
+    pub identifier: Option<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Advancements
+pub struct Advancements332<'a, Slot> {
+    pub reset: bool,
+    pub advancements: Vec<AdvancementMapping332<'a, Slot>>,
+    pub removed: Vec<Cow<'a, str>>,
+    pub progress: Vec<AdvancementProgress332<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct AdvancementMapping332<'a, Slot> {
+    pub identifier: Cow<'a, str>,
+    pub parent: Option<Cow<'a, str>>,
+    pub display: Option<AdvancementDisplay332<'a, Slot>>,
+    pub criteria: Vec<Cow<'a, str>>,
+    /// All of the inner arrays have to be satisfied with at least one of
+    /// their criteria.
+    pub requirements: Vec<Vec<Cow<'a, str>>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct AdvancementDisplay332<'a, Slot> {
+    // TODO: chat
+    pub title: Cow<'a, str>,
+    pub description: Cow<'a, str>,
+    pub icon: Slot,
+    pub frame: AdvancementFrame332,
+    pub show_toast: bool,
+    pub hidden: bool,
+    pub background_texture: Option<Cow<'a, str>>,
+    pub x: f32,
+    pub y: f32,
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for AdvancementDisplay332<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let title = Cow::decode(cursor)?;
+        let description = Cow::decode(cursor)?;
+        let icon = Slot::decode(cursor)?;
+        let frame = AdvancementFrame332::decode(cursor)?;
+        let flags = i32::decode(cursor)?;
+        let background_texture = match flags & 0x01 != 0 {
+            true => Some(Cow::decode(cursor)?),
+            false => None,
+        };
+        Ok(Self {
+            title,
+            description,
+            icon,
+            frame,
+            show_toast: flags & 0x02 != 0,
+            hidden: flags & 0x04 != 0,
+            background_texture,
+            x: f32::decode(cursor)?,
+            y: f32::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a, Slot> Encode for AdvancementDisplay332<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.title.encode(writer)?;
+        self.description.encode(writer)?;
+        self.icon.encode(writer)?;
+        self.frame.encode(writer)?;
+        (self.background_texture.is_some() as i32
+            | (self.show_toast as i32) << 1
+            | (self.hidden as i32) << 2)
+            .encode(writer)?;
+        if let Some(background_texture) = &self.background_texture {
+            background_texture.encode(writer)?;
+        }
+        self.x.encode(writer)?;
+        self.y.encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum AdvancementFrame332 {
+    Task = 0,
+    Challenge,
+    Goal,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct AdvancementProgress332<'a> {
+    pub identifier: Cow<'a, str>,
+    pub criteria: Vec<CriterionProgress332<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CriterionProgress332<'a> {
+    pub identifier: Cow<'a, str>,
+    /// The time of achieving it in milliseconds since the epoch, `None` if
+    /// the criterion is not achieved.
+    pub achieved_at: Option<i64>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Declare Commands
+///
+/// Sends the command graph used by the client for tab completion and syntax
+/// highlighting.
+pub struct DeclareCommands346<'a> {
+    pub nodes: Vec<CommandNode346<'a>>,
+    // varint
+    /// Index of the root node in `nodes`
+    pub root_index: i32,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct CommandNode346<'a> {
+    pub kind: CommandNodeKind346<'a>,
+    /// Whether the command is complete when ending at this node
+    pub executable: bool,
+    /// Indices of the children in [`DeclareCommands346::nodes`]
+    pub children: Vec<i32>,
+    /// Index of the node this one redirects to
+    pub redirect: Option<i32>,
+    /// Identifier of the suggestions the server should be asked for, only
+    /// used for arguments.
+    pub suggestions: Option<Cow<'a, str>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum CommandNodeKind346<'a> {
+    Root,
+    Literal {
+        name: Cow<'a, str>,
+    },
+    Argument {
+        name: Cow<'a, str>,
+        parser: CommandParser346<'a>,
+    },
+}
+
+#[derive(ToStatic, Debug)]
+pub enum CommandParser346<'a> {
+    /// brigadier:double
+    Double { min: Option<f64>, max: Option<f64> },
+    /// brigadier:float
+    Float { min: Option<f32>, max: Option<f32> },
+    /// brigadier:integer
+    Integer { min: Option<i32>, max: Option<i32> },
+    /// brigadier:long
+    Long { min: Option<i64>, max: Option<i64> },
+    /// brigadier:string
+    String(StringKind346),
+    /// minecraft:entity, 0x01 for a single entity, 0x02 for players only
+    Entity { flags: u8 },
+    /// minecraft:score_holder, 0x01 for multiple score holders
+    ScoreHolder { flags: u8 },
+    /// minecraft:range
+    Range { decimals: bool },
+    /// minecraft:resource_or_tag, since 1.18.2
+    ResourceOrTag { registry: Cow<'a, str> },
+    /// minecraft:resource, since 1.18.2
+    Resource { registry: Cow<'a, str> },
+    /// Parsers without properties
+    Other(Cow<'a, str>),
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum StringKind346 {
+    SingleWord = 0,
+    QuotablePhrase,
+    GreedyPhrase,
+}
+
+fn decode_bounds<'dec, T: Decode<'dec>>(
+    cursor: &mut std::io::Cursor<&'dec [u8]>,
+) -> decode::Result<(Option<T>, Option<T>)> {
+    let flags = u8::decode(cursor)?;
+    let min = if flags & 0x01 != 0 {
+        Some(T::decode(cursor)?)
+    } else {
+        None
+    };
+    let max = if flags & 0x02 != 0 {
+        Some(T::decode(cursor)?)
+    } else {
+        None
+    };
+    Ok((min, max))
+}
+
+fn encode_bounds<T: Encode>(
+    min: &Option<T>,
+    max: &Option<T>,
+    writer: &mut impl std::io::Write,
+) -> encode::Result<()> {
+    (min.is_some() as u8 | (max.is_some() as u8) << 1).encode(writer)?;
+    if let Some(min) = min {
+        min.encode(writer)?;
+    }
+    if let Some(max) = max {
+        max.encode(writer)?;
+    }
+    Ok(())
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for CommandParser346<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        use CommandParser346::*;
+        Ok(match <&str>::decode(cursor)? {
+            "brigadier:double" => {
+                let (min, max) = decode_bounds(cursor)?;
+                Double { min, max }
+            }
+            "brigadier:float" => {
+                let (min, max) = decode_bounds(cursor)?;
+                Float { min, max }
+            }
+            "brigadier:integer" => {
+                let (min, max) = decode_bounds(cursor)?;
+                Integer { min, max }
+            }
+            "brigadier:long" => {
+                let (min, max) = decode_bounds(cursor)?;
+                Long { min, max }
+            }
+            "brigadier:string" => String(StringKind346::decode(cursor)?),
+            "minecraft:entity" => Entity {
+                flags: u8::decode(cursor)?,
+            },
+            "minecraft:score_holder" => ScoreHolder {
+                flags: u8::decode(cursor)?,
+            },
+            "minecraft:range" => Range {
+                decimals: bool::decode(cursor)?,
+            },
+            "minecraft:resource_or_tag" => ResourceOrTag {
+                registry: Cow::decode(cursor)?,
+            },
+            "minecraft:resource" => Resource {
+                registry: Cow::decode(cursor)?,
+            },
+            parser => Other(Cow::Borrowed(parser)),
+        })
+    }
+}
+
+impl<'a> Encode for CommandParser346<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        use CommandParser346::*;
+        match self {
+            Double { min, max } => {
+                "brigadier:double".encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Float { min, max } => {
+                "brigadier:float".encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Integer { min, max } => {
+                "brigadier:integer".encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Long { min, max } => {
+                "brigadier:long".encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            String(kind) => {
+                "brigadier:string".encode(writer)?;
+                kind.encode(writer)
+            }
+            Entity { flags } => {
+                "minecraft:entity".encode(writer)?;
+                flags.encode(writer)
+            }
+            ScoreHolder { flags } => {
+                "minecraft:score_holder".encode(writer)?;
+                flags.encode(writer)
+            }
+            Range { decimals } => {
+                "minecraft:range".encode(writer)?;
+                decimals.encode(writer)
+            }
+            ResourceOrTag { registry } => {
+                "minecraft:resource_or_tag".encode(writer)?;
+                registry.encode(writer)
+            }
+            Resource { registry } => {
+                "minecraft:resource".encode(writer)?;
+                registry.encode(writer)
+            }
+            Other(parser) => parser.encode(writer),
+        }
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for CommandNode346<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let flags = u8::decode(cursor)?;
+        let children = decode_varint_array(cursor)?;
+        let redirect = if flags & 0x08 != 0 {
+            Some(Var::decode(cursor)?.into_inner())
+        } else {
+            None
+        };
+        let kind = match flags & 0x03 {
+            0 => CommandNodeKind346::Root,
+            1 => CommandNodeKind346::Literal {
+                name: Cow::decode(cursor)?,
+            },
+            2 => CommandNodeKind346::Argument {
+                name: Cow::decode(cursor)?,
+                parser: CommandParser346::decode(cursor)?,
+            },
+            _ => return Err(decode::Error::InvalidId),
+        };
+        let suggestions = if flags & 0x10 != 0 {
+            Some(Cow::decode(cursor)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            kind,
+            executable: flags & 0x04 != 0,
+            children,
+            redirect,
+            suggestions,
+        })
+    }
+}
+
+impl<'a> Encode for CommandNode346<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let kind = match self.kind {
+            CommandNodeKind346::Root => 0,
+            CommandNodeKind346::Literal { .. } => 1,
+            CommandNodeKind346::Argument { .. } => 2,
+        };
+        let flags = kind
+            | (self.executable as u8) << 2
+            | (self.redirect.is_some() as u8) << 3
+            | (self.suggestions.is_some() as u8) << 4;
+        flags.encode(writer)?;
+        encode_varint_array(&self.children, writer)?;
+        if let Some(redirect) = self.redirect {
+            Var::from(redirect).encode(writer)?;
+        }
+        match &self.kind {
+            CommandNodeKind346::Root => {}
+            CommandNodeKind346::Literal { name } => name.encode(writer)?,
+            CommandNodeKind346::Argument { name, parser } => {
+                name.encode(writer)?;
+                parser.encode(writer)?;
+            }
+        }
+        if let Some(suggestions) = &self.suggestions {
+            suggestions.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for DeclareCommands346<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            nodes: Vec::decode(cursor)?,
+            root_index: Var::decode(cursor)?.into_inner(),
+        })
+    }
+}
+
+impl<'a> Encode for DeclareCommands346<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.nodes.encode(writer)?;
+        Var::from(self.root_index).encode(writer)
+    }
+}
+
+#[test]
+fn declare_commands346() {
+    let mut val = vec![
+        3, 0x00, 1, 1, 0x05, 1, 2, 2, b't', b'p', 0x16, 0, 1, b'x', 17,
+    ];
+    val.extend_from_slice(b"brigadier:integer");
+    val.extend_from_slice(&[0x01, 0, 0, 0, 0, 20]);
+    val.extend_from_slice(b"minecraft:ask_server");
+    val.push(0);
+    let mut cursor = std::io::Cursor::new(&val[..]);
+    #[allow(clippy::unwrap_used)]
+    let res = DeclareCommands346::decode(&mut cursor).unwrap();
+    assert_eq!(res.root_index, 0);
+    assert_eq!(res.nodes.len(), 3);
+    assert!(matches!(res.nodes[0].kind, CommandNodeKind346::Root));
+    assert_eq!(res.nodes[0].children, [1]);
+    assert!(res.nodes[1].executable);
+    assert!(matches!(
+        &res.nodes[2].kind,
+        CommandNodeKind346::Argument {
+            name,
+            parser: CommandParser346::Integer {
+                min: Some(0),
+                max: None
+            }
+        } if name == "x"
+    ));
+    assert_eq!(
+        res.nodes[2].suggestions.as_deref(),
+        Some("minecraft:ask_server")
+    );
+    let mut cursor = vec![];
+    #[allow(clippy::unwrap_used)]
+    res.encode(&mut cursor).unwrap();
+    assert_eq!(cursor, val);
+}
+
+#[derive(ToStatic, Debug)]
+/// Unlock Recipes
+///
+/// Since pv389 (1.13-pre7) recipes are identified by their name and the
+/// smelting recipe book has its own state.
+pub struct UnlockRecipes389<'a> {
+    pub action: UnlockRecipesAction389<'a>,
+    pub crafting_book_open: bool,
+    pub crafting_filtering_craftable: bool,
+    pub smelting_book_open: bool,
+    pub smelting_filtering_craftable: bool,
+    pub recipe_ids: Vec<Cow<'a, str>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum UnlockRecipesAction389<'a> {
+    /// All recipes in `to_be_displayed` are marked as new in the recipe book.
+    Init {
+        to_be_displayed: Vec<Cow<'a, str>>,
+    },
+    Add,
+    Remove,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UnlockRecipes389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let action = Var::<i32>::decode(cursor)?.into_inner();
+        let crafting_book_open = bool::decode(cursor)?;
+        let crafting_filtering_craftable = bool::decode(cursor)?;
+        let smelting_book_open = bool::decode(cursor)?;
+        let smelting_filtering_craftable = bool::decode(cursor)?;
+        let recipe_ids = Vec::decode(cursor)?;
+        let action = match action {
+            0 => UnlockRecipesAction389::Init {
+                to_be_displayed: Vec::decode(cursor)?,
+            },
+            1 => UnlockRecipesAction389::Add,
+            2 => UnlockRecipesAction389::Remove,
+            _ => return Err(decode::Error::InvalidId),
+        };
+        Ok(Self {
+            action,
+            crafting_book_open,
+            crafting_filtering_craftable,
+            smelting_book_open,
+            smelting_filtering_craftable,
+            recipe_ids,
+        })
+    }
+}
+
+impl<'a> Encode for UnlockRecipes389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let action = match self.action {
+            UnlockRecipesAction389::Init { .. } => 0,
+            UnlockRecipesAction389::Add => 1,
+            UnlockRecipesAction389::Remove => 2,
+        };
+        Var::from(action).encode(writer)?;
+        self.crafting_book_open.encode(writer)?;
+        self.crafting_filtering_craftable.encode(writer)?;
+        self.smelting_book_open.encode(writer)?;
+        self.smelting_filtering_craftable.encode(writer)?;
+        self.recipe_ids.encode(writer)?;
+        if let UnlockRecipesAction389::Init { to_be_displayed } = &self.action {
+            to_be_displayed.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Nbt Query Response
+///
+/// Response to [`serverbound::QueryBlockNbt389`][qb] and
+/// [`serverbound::QueryEntityNbt389`][qe].
+///
+/// [qb]: super::serverbound::QueryBlockNbt389
+/// [qe]: super::serverbound::QueryEntityNbt389
+pub struct NbtQueryResponse389<'a> {
+    // varint
+    pub transaction_id: i32,
+    /// `None` if there is no block entity or entity
+    pub data: Option<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for NbtQueryResponse389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            transaction_id: Var::decode(cursor)?.into_inner(),
+            data: decode_optional_nbt(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for NbtQueryResponse389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.transaction_id).encode(writer)?;
+        encode_optional_nbt(&self.data, writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Face Player
+///
+/// Rotates the player to look at a position or an entity.
+pub struct FacePlayer389 {
+    pub anchor: FacePlayerAnchor389,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    /// If set the player looks at the entity instead of the position
+    pub entity: Option<FacePlayerEntity389>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct FacePlayerEntity389 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub anchor: FacePlayerAnchor389,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum FacePlayerAnchor389 {
+    Feet = 0,
+    Eyes,
+}
+
+#[derive(ToStatic, Debug)]
+/// Stop Sound
+pub struct StopSound389<'a> {
+    /// `None` to stop sounds of all categories
+    pub source: Option<SoundCategory95>,
+    /// `None` to stop all sounds
+    pub sound: Option<Cow<'a, str>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for StopSound389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let flags = u8::decode(cursor)?;
+        let source = if flags & 0x01 != 0 {
+            Some(SoundCategory95::decode(cursor)?)
+        } else {
+            None
+        };
+        let sound = if flags & 0x02 != 0 {
+            Some(Cow::decode(cursor)?)
+        } else {
+            None
+        };
+        Ok(Self { source, sound })
+    }
+}
+
+impl<'a> Encode for StopSound389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        (self.source.is_some() as u8 | (self.sound.is_some() as u8) << 1).encode(writer)?;
+        if let Some(source) = &self.source {
+            source.encode(writer)?;
+        }
+        if let Some(sound) = &self.sound {
+            sound.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Declare Recipes
+///
+/// Sends all recipes of the server, they are referenced by their id in
+/// [`UnlockRecipes389`] and [`CraftRecipeResponse389`].
+pub struct DeclareRecipes389<'a, Slot> {
+    pub recipes: Vec<Recipe389<'a, Slot>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct Recipe389<'a, Slot> {
+    pub id: Cow<'a, str>,
+    pub data: RecipeData389<'a, Slot>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum RecipeData389<'a, Slot> {
+    /// crafting_shapeless
+    CraftingShapeless(ShapelessRecipe389<'a, Slot>),
+    /// crafting_shaped
+    CraftingShaped(ShapedRecipe389<'a, Slot>),
+    /// smelting
+    Smelting(CookingRecipe389<'a, Slot>),
+    /// Recipes without data like crafting_special_armordye
+    Special(Cow<'a, str>),
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ShapelessRecipe389<'a, Slot> {
+    pub group: Cow<'a, str>,
+    /// Every ingredient is a list of items which can be used
+    pub ingredients: Vec<Vec<Slot>>,
+    pub result: Slot,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct ShapedRecipe389<'a, Slot> {
+    // varint
+    pub width: i32,
+    // varint
+    pub height: i32,
+    pub group: Cow<'a, str>,
+    /// `width * height` ingredients, row by row without a count prefix.
+    pub ingredients: Vec<Vec<Slot>>,
+    pub result: Slot,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CookingRecipe389<'a, Slot> {
+    pub group: Cow<'a, str>,
+    pub ingredient: Vec<Slot>,
+    pub result: Slot,
+    pub experience: f32,
+    #[encoding(varint)]
+    pub cooking_time: i32,
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for ShapedRecipe389<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let width: i32 = Var::decode(cursor)?.into_inner();
+        let height = Var::decode(cursor)?.into_inner();
+        let group = Cow::decode(cursor)?;
+        let ingredients = (0..width.saturating_mul(height))
+            .map(|_| Vec::decode(cursor))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            width,
+            height,
+            group,
+            ingredients,
+            result: Slot::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a, Slot> Encode for ShapedRecipe389<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        if self.ingredients.len() as i64 != self.width as i64 * self.height as i64 {
+            return Err(encode::Error::Custom(
+                "shaped recipe needs exactly width * height ingredients",
+            ));
+        }
+        Var::from(self.width).encode(writer)?;
+        Var::from(self.height).encode(writer)?;
+        self.group.encode(writer)?;
+        for ingredient in &self.ingredients {
+            ingredient.encode(writer)?;
+        }
+        self.result.encode(writer)
+    }
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for Recipe389<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let id = Cow::decode(cursor)?;
+        let data = match <&str>::decode(cursor)? {
+            "crafting_shapeless" => RecipeData389::CraftingShapeless(Decode::decode(cursor)?),
+            "crafting_shaped" => RecipeData389::CraftingShaped(Decode::decode(cursor)?),
+            "smelting" => RecipeData389::Smelting(Decode::decode(cursor)?),
+            kind => RecipeData389::Special(Cow::Borrowed(kind)),
+        };
+        Ok(Self { id, data })
+    }
+}
+
+impl<'a, Slot> Encode for Recipe389<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.id.encode(writer)?;
+        match &self.data {
+            RecipeData389::CraftingShapeless(recipe) => {
+                "crafting_shapeless".encode(writer)?;
+                recipe.encode(writer)
+            }
+            RecipeData389::CraftingShaped(recipe) => {
+                "crafting_shaped".encode(writer)?;
+                recipe.encode(writer)
+            }
+            RecipeData389::Smelting(recipe) => {
+                "smelting".encode(writer)?;
+                recipe.encode(writer)
+            }
+            RecipeData389::Special(kind) => kind.encode(writer),
+        }
+    }
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for DeclareRecipes389<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            recipes: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a, Slot> Encode for DeclareRecipes389<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.recipes.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub struct Tags389<'a> {
+    pub block_tags: Vec<Tag389<'a>>,
+    pub item_tags: Vec<Tag389<'a>>,
+    pub fluid_tags: Vec<Tag389<'a>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct Tag389<'a> {
+    pub name: Cow<'a, str>,
+    // varint count of varints
+    /// Registry ids of the tagged blocks, items or fluids
+    pub entries: Vec<i32>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Tag389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            name: Cow::decode(cursor)?,
+            entries: decode_varint_array(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Tag389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.name.encode(writer)?;
+        encode_varint_array(&self.entries, writer)
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Tags389<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            block_tags: Vec::decode(cursor)?,
+            item_tags: Vec::decode(cursor)?,
+            fluid_tags: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Tags389<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.block_tags.encode(writer)?;
+        self.item_tags.encode(writer)?;
+        self.fluid_tags.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Tags
+///
+/// Since pv443 (18w43c) entity types can be tagged too.
+pub struct Tags443<'a> {
+    pub block_tags: Vec<Tag389<'a>>,
+    pub item_tags: Vec<Tag389<'a>>,
+    pub fluid_tags: Vec<Tag389<'a>>,
+    pub entity_tags: Vec<Tag389<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Tags443<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            block_tags: Vec::decode(cursor)?,
+            item_tags: Vec::decode(cursor)?,
+            fluid_tags: Vec::decode(cursor)?,
+            entity_tags: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Tags443<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.block_tags.encode(writer)?;
+        self.item_tags.encode(writer)?;
+        self.fluid_tags.encode(writer)?;
+        self.entity_tags.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Declare Recipes
+///
+/// Since pv471 (1.14) the recipe type is namespaced and sent in front of the
+/// id, there are new cooking recipes and stonecutting.
+pub struct DeclareRecipes471<'a, Slot> {
+    pub recipes: Vec<Recipe471<'a, Slot>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct Recipe471<'a, Slot> {
+    pub id: Cow<'a, str>,
+    pub data: RecipeData471<'a, Slot>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum RecipeData471<'a, Slot> {
+    /// minecraft:crafting_shapeless
+    CraftingShapeless(ShapelessRecipe389<'a, Slot>),
+    /// minecraft:crafting_shaped
+    CraftingShaped(ShapedRecipe389<'a, Slot>),
+    /// minecraft:smelting
+    Smelting(CookingRecipe389<'a, Slot>),
+    /// minecraft:blasting
+    Blasting(CookingRecipe389<'a, Slot>),
+    /// minecraft:smoking
+    Smoking(CookingRecipe389<'a, Slot>),
+    /// minecraft:campfire_cooking
+    CampfireCooking(CookingRecipe389<'a, Slot>),
+    /// minecraft:stonecutting
+    Stonecutting(StonecuttingRecipe471<'a, Slot>),
+    /// minecraft:smithing, since 1.16
+    Smithing(SmithingRecipe721<Slot>),
+    /// Recipes without data like minecraft:crafting_special_armordye
+    Special(Cow<'a, str>),
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct StonecuttingRecipe471<'a, Slot> {
+    pub group: Cow<'a, str>,
+    pub ingredient: Vec<Slot>,
+    pub result: Slot,
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for Recipe471<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        use RecipeData471::*;
+        let kind = <&str>::decode(cursor)?;
+        let id = Cow::decode(cursor)?;
+        let data = match kind {
+            "minecraft:crafting_shapeless" => CraftingShapeless(Decode::decode(cursor)?),
+            "minecraft:crafting_shaped" => CraftingShaped(Decode::decode(cursor)?),
+            "minecraft:smelting" => Smelting(Decode::decode(cursor)?),
+            "minecraft:blasting" => Blasting(Decode::decode(cursor)?),
+            "minecraft:smoking" => Smoking(Decode::decode(cursor)?),
+            "minecraft:campfire_cooking" => CampfireCooking(Decode::decode(cursor)?),
+            "minecraft:stonecutting" => Stonecutting(Decode::decode(cursor)?),
+            "minecraft:smithing" => Smithing(Decode::decode(cursor)?),
+            kind => Special(Cow::Borrowed(kind)),
+        };
+        Ok(Self { id, data })
+    }
+}
+
+impl<'a, Slot> Encode for Recipe471<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        use RecipeData471::*;
+        let kind = match &self.data {
+            CraftingShapeless(_) => "minecraft:crafting_shapeless",
+            CraftingShaped(_) => "minecraft:crafting_shaped",
+            Smelting(_) => "minecraft:smelting",
+            Blasting(_) => "minecraft:blasting",
+            Smoking(_) => "minecraft:smoking",
+            CampfireCooking(_) => "minecraft:campfire_cooking",
+            Stonecutting(_) => "minecraft:stonecutting",
+            Smithing(_) => "minecraft:smithing",
+            Special(kind) => kind,
+        };
+        kind.encode(writer)?;
+        self.id.encode(writer)?;
+        match &self.data {
+            CraftingShapeless(recipe) => recipe.encode(writer),
+            CraftingShaped(recipe) => recipe.encode(writer),
+            Smelting(recipe) | Blasting(recipe) | Smoking(recipe) | CampfireCooking(recipe) => {
+                recipe.encode(writer)
+            }
+            Stonecutting(recipe) => recipe.encode(writer),
+            Smithing(recipe) => recipe.encode(writer),
+            Special(_) => Ok(()),
+        }
+    }
+}
+
+impl<'dec: 'a, 'a, Slot> Decode<'dec> for DeclareRecipes471<'a, Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            recipes: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a, Slot> Encode for DeclareRecipes471<'a, Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.recipes.encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Open Horse Window
+pub struct OpenHorseWindow471 {
+    pub window_id: u8,
+    #[encoding(varint)]
+    pub slot_count: i32,
+    pub entity_id: i32,
+}
+
+#[derive(ToStatic, Debug)]
+/// Update Light
+///
+/// Sends the light of the sections of a chunk column, including one section
+/// below and one above the world.
+pub struct UpdateLight471<'a> {
+    // varint
+    pub chunk_x: i32,
+    // varint
+    pub chunk_z: i32,
+    // varint
+    pub sky_light_mask: i32,
+    // varint
+    pub block_light_mask: i32,
+    // varint
+    pub empty_sky_light_mask: i32,
+    // varint
+    pub empty_block_light_mask: i32,
+    /// One array of 2048 bytes for every bit set in `sky_light_mask`
+    pub sky_light: Vec<Cow<'a, [u8]>>,
+    /// One array of 2048 bytes for every bit set in `block_light_mask`
+    pub block_light: Vec<Cow<'a, [u8]>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UpdateLight471<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x = Var::decode(cursor)?.into_inner();
+        let chunk_z = Var::decode(cursor)?.into_inner();
+        let sky_light_mask: i32 = Var::decode(cursor)?.into_inner();
+        let block_light_mask: i32 = Var::decode(cursor)?.into_inner();
+        let empty_sky_light_mask = Var::decode(cursor)?.into_inner();
+        let empty_block_light_mask = Var::decode(cursor)?.into_inner();
+        let sky_light = (0..sky_light_mask.count_ones())
+            .map(|_| Cow::decode(cursor))
+            .collect::<Result<_, _>>()?;
+        let block_light = (0..block_light_mask.count_ones())
+            .map(|_| Cow::decode(cursor))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            chunk_x,
+            chunk_z,
+            sky_light_mask,
+            block_light_mask,
+            empty_sky_light_mask,
+            empty_block_light_mask,
+            sky_light,
+            block_light,
+        })
+    }
+}
+
+impl<'a> Encode for UpdateLight471<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        if self.sky_light.len() != self.sky_light_mask.count_ones() as usize
+            || self.block_light.len() != self.block_light_mask.count_ones() as usize
+        {
+            return Err(encode::Error::Custom(
+                "number of light arrays doesn't match the light masks",
+            ));
+        }
+        Var::from(self.chunk_x).encode(writer)?;
+        Var::from(self.chunk_z).encode(writer)?;
+        Var::from(self.sky_light_mask).encode(writer)?;
+        Var::from(self.block_light_mask).encode(writer)?;
+        Var::from(self.empty_sky_light_mask).encode(writer)?;
+        Var::from(self.empty_block_light_mask).encode(writer)?;
+        for array in self.sky_light.iter().chain(&self.block_light) {
+            array.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Trade List
+///
+/// Sends the trades of a villager after its window was opened.
+pub struct TradeList471<Slot> {
+    #[encoding(varint)]
+    pub window_id: i32,
+    #[encoding(counted = "u8")]
+    pub trades: Vec<Trade471<Slot>>,
+    #[encoding(varint)]
+    pub villager_level: i32,
+    #[encoding(varint)]
+    pub experience: i32,
+    /// `false` for wandering traders
+    pub is_regular_villager: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Trade List
+///
+/// Since pv486 (1.14.1-pre1) the client is told whether the villager can
+/// restock.
+pub struct TradeList486<Slot> {
+    #[encoding(varint)]
+    pub window_id: i32,
+    #[encoding(counted = "u8")]
+    pub trades: Vec<Trade471<Slot>>,
+    #[encoding(varint)]
+    pub villager_level: i32,
+    #[encoding(varint)]
+    pub experience: i32,
+    /// `false` for wandering traders
+    pub is_regular_villager: bool,
+    pub can_restock: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Trade List
+///
+/// Since pv495 (1.14.3-pre4) every trade has a demand.
+pub struct TradeList495<Slot> {
+    #[encoding(varint)]
+    pub window_id: i32,
+    #[encoding(counted = "u8")]
+    pub trades: Vec<Trade495<Slot>>,
+    #[encoding(varint)]
+    pub villager_level: i32,
+    #[encoding(varint)]
+    pub experience: i32,
+    /// `false` for wandering traders
+    pub is_regular_villager: bool,
+    pub can_restock: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Trade471<Slot> {
+    pub input_1: Slot,
+    pub output: Slot,
+    pub input_2: Option<Slot>,
+    pub disabled: bool,
+    pub uses: i32,
+    pub max_uses: i32,
+    pub experience: i32,
+    pub special_price: i32,
+    pub price_multiplier: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Trade495<Slot> {
+    pub input_1: Slot,
+    pub output: Slot,
+    pub input_2: Option<Slot>,
+    pub disabled: bool,
+    pub uses: i32,
+    pub max_uses: i32,
+    pub experience: i32,
+    pub special_price: i32,
+    pub price_multiplier: f32,
+    pub demand: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Open Book
+///
+/// Opens the written book in the given hand.
+pub struct OpenBook471 {
+    pub hand: super::serverbound::Hand80,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Update View Position
+///
+/// Sent when the player crosses a chunk border, chunks outside the view
+/// distance around this chunk are unloaded by the client.
+pub struct UpdateViewPosition471 {
+    #[encoding(varint)]
+    pub chunk_x: i32,
+    #[encoding(varint)]
+    pub chunk_z: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Update View Distance
+pub struct UpdateViewDistance471 {
+    #[encoding(varint)]
+    pub view_distance: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Sound Effect
+///
+/// Plays a sound effect following an entity.
+pub struct EntitySoundEffect471 {
+    #[encoding(varint)]
+    pub sound_id: i32,
+    pub category: SoundCategory95,
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub volume: f32,
+    pub pitch: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Acknowledge Player Digging
+///
+/// Confirms or denies a [`serverbound::PlayerDigging441`][pd] action.
+///
+/// [pd]: super::serverbound::PlayerDigging441
+pub struct AcknowledgePlayerDigging494 {
+    pub location: Position441,
+    /// The block state after the action
+    #[encoding(varint)]
+    pub block: i32,
+    pub status: DiggingStatus494,
+    pub successful: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum DiggingStatus494 {
+    Started = 0,
+    Cancelled,
+    Finished,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chat Message
+///
+/// Since 1.16 the uuid of the sender is sent along, it is nil for messages
+/// not sent by a player.
+pub struct ChatMessage721<'a> {
+    // TODO: add ChatMessage json thing
+    pub message: Cow<'a, str>,
+    pub position: ChatMessagePosition6,
+    pub sender: Uuid,
+}
+
+#[derive(ToStatic, Debug)]
+/// Change Game State
+///
+/// Since 1.15 the respawn screen can be toggled and the pufferfish sting
+/// sound is played through this packet too.
+pub enum ChangeGameState721 {
+    InvalidBed,
+    EndRaining,
+    BeginRaining,
+    ChangeGameMode(GameMode86),
+    ExitEnd {
+        show_credits: bool,
+    },
+    DemoMessage(DemoMessage0),
+    ArrowHitPlayer,
+    FadeValue(f32),
+    FadeTime(f32),
+    PufferfishSting,
+    ElderGuardianAppearance,
+    /// `false` if the player respawns immediately
+    EnableRespawnScreen {
+        enabled: bool,
+    },
+}
+
+impl<'dec> Decode<'dec> for ChangeGameState721 {
+    fn decode(cursor: &'_ mut std::io::Cursor<&[u8]>) -> decode::Result<Self> {
+        let reason = u8::decode(cursor)?;
+        let value = f32::decode(cursor)?;
+        use self::DemoMessage0::*;
+        use ChangeGameState721::*;
+        Ok(match reason {
+            0 => InvalidBed,
+            1 => EndRaining,
+            2 => BeginRaining,
+            3 => ChangeGameMode(match value as u8 {
+                0 => GameMode86::Survival,
+                1 => GameMode86::Creative,
+                2 => GameMode86::Adventure,
+                3 => GameMode86::Spectator,
+                _ => return Err(decode::Error::InvalidId),
+            }),
+            4 => ExitEnd {
+                show_credits: value != 0.0,
+            },
+            5 => DemoMessage(match value as u8 {
+                0 => WelcomeToDemo,
+                101 => MovementControl,
+                102 => JumpControl,
+                103 => InventoryControl,
+                _ => return Err(decode::Error::InvalidId),
+            }),
+            6 => ArrowHitPlayer,
+            7 => FadeValue(value),
+            8 => FadeTime(value),
+            9 => PufferfishSting,
+            10 => ElderGuardianAppearance,
+            11 => EnableRespawnScreen {
+                enabled: value == 0.0,
+            },
+            _ => return Err(decode::Error::InvalidId),
+        })
+    }
+}
+
+impl Encode for ChangeGameState721 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> Result<(), encode::Error> {
+        let (reason, value) = match self {
+            ChangeGameState721::InvalidBed => (0u8, 0.0),
+            ChangeGameState721::EndRaining => (1, 0.0),
+            ChangeGameState721::BeginRaining => (2, 0.0),
+            ChangeGameState721::ChangeGameMode(gamemode) => (3, *gamemode as u8 as f32),
+            ChangeGameState721::ExitEnd { show_credits } => (4, *show_credits as u8 as f32),
+            ChangeGameState721::DemoMessage(demomessage) => (5, *demomessage as u8 as f32),
+            ChangeGameState721::ArrowHitPlayer => (6, 0.0),
+            ChangeGameState721::FadeValue(value) => (7, *value),
+            ChangeGameState721::FadeTime(value) => (8, *value),
+            ChangeGameState721::PufferfishSting => (9, 0.0),
+            ChangeGameState721::ElderGuardianAppearance => (10, 0.0),
+            ChangeGameState721::EnableRespawnScreen { enabled } => (11, !*enabled as u8 as f32),
+        };
+        reason.encode(writer)?;
+        value.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Update Light
+///
+/// Since 1.16 the client is told whether it can trust the light at the edges
+/// of the chunk or has to recalculate it.
+pub struct UpdateLight725<'a> {
+    // varint
+    pub chunk_x: i32,
+    // varint
+    pub chunk_z: i32,
+    pub trust_edges: bool,
+    // varint
+    pub sky_light_mask: i32,
+    // varint
+    pub block_light_mask: i32,
+    // varint
+    pub empty_sky_light_mask: i32,
+    // varint
+    pub empty_block_light_mask: i32,
+    /// One array of 2048 bytes for every bit set in `sky_light_mask`
+    pub sky_light: Vec<Cow<'a, [u8]>>,
+    /// One array of 2048 bytes for every bit set in `block_light_mask`
+    pub block_light: Vec<Cow<'a, [u8]>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UpdateLight725<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x = Var::decode(cursor)?.into_inner();
+        let chunk_z = Var::decode(cursor)?.into_inner();
+        let trust_edges = bool::decode(cursor)?;
+        let sky_light_mask: i32 = Var::decode(cursor)?.into_inner();
+        let block_light_mask: i32 = Var::decode(cursor)?.into_inner();
+        let empty_sky_light_mask = Var::decode(cursor)?.into_inner();
+        let empty_block_light_mask = Var::decode(cursor)?.into_inner();
+        let sky_light = (0..sky_light_mask.count_ones())
+            .map(|_| Cow::decode(cursor))
+            .collect::<Result<_, _>>()?;
+        let block_light = (0..block_light_mask.count_ones())
+            .map(|_| Cow::decode(cursor))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            chunk_x,
+            chunk_z,
+            trust_edges,
+            sky_light_mask,
+            block_light_mask,
+            empty_sky_light_mask,
+            empty_block_light_mask,
+            sky_light,
+            block_light,
+        })
+    }
+}
+
+impl<'a> Encode for UpdateLight725<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        if self.sky_light.len() != self.sky_light_mask.count_ones() as usize
+            || self.block_light.len() != self.block_light_mask.count_ones() as usize
+        {
+            return Err(encode::Error::Custom(
+                "number of light arrays doesn't match the light masks",
+            ));
+        }
+        Var::from(self.chunk_x).encode(writer)?;
+        Var::from(self.chunk_z).encode(writer)?;
+        self.trust_edges.encode(writer)?;
+        Var::from(self.sky_light_mask).encode(writer)?;
+        Var::from(self.block_light_mask).encode(writer)?;
+        Var::from(self.empty_sky_light_mask).encode(writer)?;
+        Var::from(self.empty_block_light_mask).encode(writer)?;
+        for array in self.sky_light.iter().chain(&self.block_light) {
+            array.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Join Game
+///
+/// Since 1.16 dimensions are data driven, the dimension codec holds every
+/// dimension type of the server and the dimension and world of the player
+/// are sent as identifiers.
+pub struct JoinGame730<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    /// Identifiers of all worlds on the server
+    pub world_names: Vec<Cow<'a, str>>,
+    pub dimension_codec: Nbt<'a>,
+    /// Identifier of one of the dimension types in `dimension_codec`
+    pub dimension: Cow<'a, str>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    pub max_players: u8,
+    // varint
+    pub view_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    /// Debug worlds can't be modified and have predefined blocks
+    pub is_debug: bool,
+    /// Superflat worlds have a different void fog and horizon height
+    pub is_flat: bool,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for JoinGame730<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = i32::decode(cursor)?;
+        let (hardcore, gamemode) = GameMode86::decode_with_hardcore(cursor)?;
+        Ok(Self {
+            entity_id,
+            hardcore,
+            gamemode,
+            previous_gamemode: i8::decode(cursor)?,
+            world_names: Vec::decode(cursor)?,
+            dimension_codec: Nbt::decode(cursor)?,
+            dimension: Cow::decode(cursor)?,
+            world_name: Cow::decode(cursor)?,
+            hashed_seed: i64::decode(cursor)?,
+            max_players: u8::decode(cursor)?,
+            view_distance: Var::decode(cursor)?.into_inner(),
+            reduced_debug_info: bool::decode(cursor)?,
+            enable_respawn_screen: bool::decode(cursor)?,
+            is_debug: bool::decode(cursor)?,
+            is_flat: bool::decode(cursor)?,
+        })
+    }
+}
+
+impl Encode for JoinGame730<'_> {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.entity_id.encode(writer)?;
+        self.gamemode.encode_with_hardcore(self.hardcore, writer)?;
+        self.previous_gamemode.encode(writer)?;
+        self.world_names.encode(writer)?;
+        self.dimension_codec.encode(writer)?;
+        self.dimension.encode(writer)?;
+        self.world_name.encode(writer)?;
+        self.hashed_seed.encode(writer)?;
+        self.max_players.encode(writer)?;
+        Var::from(self.view_distance).encode(writer)?;
+        self.reduced_debug_info.encode(writer)?;
+        self.enable_respawn_screen.encode(writer)?;
+        self.is_debug.encode(writer)?;
+        self.is_flat.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// Since 1.16 the dimension and world are sent as identifiers, see
+/// [`JoinGame730`].
+pub struct Respawn730<'a> {
+    /// Identifier of one of the dimension types in
+    /// [`JoinGame730::dimension_codec`]
+    pub dimension: Cow<'a, str>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// Whether the entity metadata is kept, `false` when respawning after
+    /// death
+    pub copy_metadata: bool,
+}
+
+#[derive(ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since 1.16 the client can be told to discard the light of the chunk it
+/// already has.
+pub struct ChunkData732<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    pub ignore_old_data: bool,
+    // varint
+    pub primary_bitmask: i32,
+    pub heightmaps: Nbt<'a>,
+    /// Only sent if `full_chunk` is set
+    pub biomes: Option<Vec<i32>>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for ChunkData732<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x = i32::decode(cursor)?;
+        let chunk_z = i32::decode(cursor)?;
+        let full_chunk = bool::decode(cursor)?;
+        let ignore_old_data = bool::decode(cursor)?;
+        let primary_bitmask = Var::decode(cursor)?.into_inner();
+        let heightmaps = Nbt::decode(cursor)?;
+        let biomes = if full_chunk {
+            Some(
+                (0..1024)
+                    .map(|_| i32::decode(cursor))
+                    .collect::<Result<_, _>>()?,
+            )
+        } else {
+            None
+        };
+        Ok(Self {
+            chunk_x,
+            chunk_z,
+            full_chunk,
+            ignore_old_data,
+            primary_bitmask,
+            heightmaps,
+            biomes,
+            data: Cow::decode(cursor)?,
+            block_entities: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for ChunkData732<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.chunk_x.encode(writer)?;
+        self.chunk_z.encode(writer)?;
+        self.full_chunk.encode(writer)?;
+        self.ignore_old_data.encode(writer)?;
+        Var::from(self.primary_bitmask).encode(writer)?;
+        self.heightmaps.encode(writer)?;
+        match (self.full_chunk, &self.biomes) {
+            (true, Some(biomes)) if biomes.len() == 1024 => {
+                for biome in biomes {
+                    biome.encode(writer)?;
+                }
+            }
+            (false, None) => {}
+            _ => {
+                return Err(encode::Error::Custom(
+                    "biomes have to be exactly 1024 ints if and only if full_chunk is set",
+                ))
+            }
+        }
+        self.data.encode(writer)?;
+        self.block_entities.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Unlock Recipes
+///
+/// Since 1.16 the recipe books of the blast furnace and the smoker have
+/// their own state.
+pub struct UnlockRecipes721<'a> {
+    pub action: UnlockRecipesAction389<'a>,
+    pub crafting_book_open: bool,
+    pub crafting_filtering_craftable: bool,
+    pub smelting_book_open: bool,
+    pub smelting_filtering_craftable: bool,
+    pub blasting_book_open: bool,
+    pub blasting_filtering_craftable: bool,
+    pub smoking_book_open: bool,
+    pub smoking_filtering_craftable: bool,
+    pub recipe_ids: Vec<Cow<'a, str>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UnlockRecipes721<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let action = Var::<i32>::decode(cursor)?.into_inner();
+        let crafting_book_open = bool::decode(cursor)?;
+        let crafting_filtering_craftable = bool::decode(cursor)?;
+        let smelting_book_open = bool::decode(cursor)?;
+        let smelting_filtering_craftable = bool::decode(cursor)?;
+        let blasting_book_open = bool::decode(cursor)?;
+        let blasting_filtering_craftable = bool::decode(cursor)?;
+        let smoking_book_open = bool::decode(cursor)?;
+        let smoking_filtering_craftable = bool::decode(cursor)?;
+        let recipe_ids = Vec::decode(cursor)?;
+        let action = match action {
+            0 => UnlockRecipesAction389::Init {
+                to_be_displayed: Vec::decode(cursor)?,
+            },
+            1 => UnlockRecipesAction389::Add,
+            2 => UnlockRecipesAction389::Remove,
+            _ => return Err(decode::Error::InvalidId),
+        };
+        Ok(Self {
+            action,
+            crafting_book_open,
+            crafting_filtering_craftable,
+            smelting_book_open,
+            smelting_filtering_craftable,
+            blasting_book_open,
+            blasting_filtering_craftable,
+            smoking_book_open,
+            smoking_filtering_craftable,
+            recipe_ids,
+        })
+    }
+}
+
+impl<'a> Encode for UnlockRecipes721<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let action = match self.action {
+            UnlockRecipesAction389::Init { .. } => 0,
+            UnlockRecipesAction389::Add => 1,
+            UnlockRecipesAction389::Remove => 2,
+        };
+        Var::from(action).encode(writer)?;
+        self.crafting_book_open.encode(writer)?;
+        self.crafting_filtering_craftable.encode(writer)?;
+        self.smelting_book_open.encode(writer)?;
+        self.smelting_filtering_craftable.encode(writer)?;
+        self.blasting_book_open.encode(writer)?;
+        self.blasting_filtering_craftable.encode(writer)?;
+        self.smoking_book_open.encode(writer)?;
+        self.smoking_filtering_craftable.encode(writer)?;
+        self.recipe_ids.encode(writer)?;
+        if let UnlockRecipesAction389::Init { to_be_displayed } = &self.action {
+            to_be_displayed.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Entity Equipment
+///
+/// Since 1.16 all equipment slots of an entity can be updated at once, the
+/// highest bit of each slot byte tells whether another entry follows.
+pub struct EntityEquipment732<Slot> {
+    // varint
+    pub entity_id: i32,
+    /// Has to contain at least one entry
+    pub equipment: Vec<Equipment732<Slot>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct Equipment732<Slot> {
+    pub slot: EquipmentSlot49,
+    pub item: Slot,
+}
+
+impl<'dec, Slot> Decode<'dec> for EntityEquipment732<Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let entity_id = Var::decode(cursor)?.into_inner();
+        let mut equipment = Vec::new();
+        loop {
+            let raw = u8::decode(cursor)?;
+            let slot = match raw & 0x7f {
+                0 => EquipmentSlot49::Hand,
+                1 => EquipmentSlot49::Offhand,
+                2 => EquipmentSlot49::Boots,
+                3 => EquipmentSlot49::Leggings,
+                4 => EquipmentSlot49::Chestplate,
+                5 => EquipmentSlot49::Helmet,
+                _ => return Err(decode::Error::InvalidId),
+            };
+            equipment.push(Equipment732 {
+                slot,
+                item: Slot::decode(cursor)?,
+            });
+            if raw & 0x80 == 0 {
+                break;
+            }
+        }
+        Ok(Self {
+            entity_id,
+            equipment,
+        })
+    }
+}
+
+impl<Slot> Encode for EntityEquipment732<Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        if self.equipment.is_empty() {
+            return Err(encode::Error::Custom(
+                "entity equipment needs at least one entry",
+            ));
+        }
+        Var::from(self.entity_id).encode(writer)?;
+        let last = self.equipment.len() - 1;
+        for (i, Equipment732 { slot, item }) in self.equipment.iter().enumerate() {
+            let more = if i == last { 0 } else { 0x80 };
+            (*slot as u8 | more).encode(writer)?;
+            item.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Smithing recipes take a base item and an addition, since 1.16.
+pub struct SmithingRecipe721<Slot> {
+    pub base: Vec<Slot>,
+    pub addition: Vec<Slot>,
+    pub result: Slot,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Join Game
+///
+/// Since 1.16.2 the dimension codec also holds the biomes and the full
+/// dimension type of the world the player spawns in is sent.
+pub struct JoinGame748<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    /// Identifiers of all worlds on the server
+    pub world_names: Vec<Cow<'a, str>>,
+    /// The dimension type and biome registries
+    pub dimension_codec: Nbt<'a>,
+    /// The dimension type of the world the player spawns in
+    pub dimension: Nbt<'a>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    #[encoding(varint)]
+    pub max_players: i32,
+    #[encoding(varint)]
+    pub view_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    /// Debug worlds can't be modified and have predefined blocks
+    pub is_debug: bool,
+    /// Superflat worlds have a different void fog and horizon height
+    pub is_flat: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// Since 1.16.2 the full dimension type is sent, see [`JoinGame748`].
+pub struct Respawn748<'a> {
+    pub dimension: Nbt<'a>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// Whether the entity metadata is kept, `false` when respawning after
+    /// death
+    pub copy_metadata: bool,
+}
+
+#[derive(ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since 1.16.2 the biomes are sent as a varint array, its length depends
+/// on the height of the world.
+pub struct ChunkData746<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub full_chunk: bool,
+    // varint
+    pub primary_bitmask: i32,
+    pub heightmaps: Nbt<'a>,
+    // varint count of varints
+    /// Only sent if `full_chunk` is set
+    pub biomes: Option<Vec<i32>>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for ChunkData746<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let chunk_x = i32::decode(cursor)?;
+        let chunk_z = i32::decode(cursor)?;
+        let full_chunk = bool::decode(cursor)?;
+        let primary_bitmask = Var::decode(cursor)?.into_inner();
+        let heightmaps = Nbt::decode(cursor)?;
+        let biomes = if full_chunk {
+            Some(decode_varint_array(cursor)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            chunk_x,
+            chunk_z,
+            full_chunk,
+            primary_bitmask,
+            heightmaps,
+            biomes,
+            data: Cow::decode(cursor)?,
+            block_entities: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for ChunkData746<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.chunk_x.encode(writer)?;
+        self.chunk_z.encode(writer)?;
+        self.full_chunk.encode(writer)?;
+        Var::from(self.primary_bitmask).encode(writer)?;
+        self.heightmaps.encode(writer)?;
+        match (self.full_chunk, &self.biomes) {
+            (true, Some(biomes)) => encode_varint_array(biomes, writer)?,
+            (false, None) => {}
+            _ => {
+                return Err(encode::Error::Custom(
+                    "biomes have to be sent if and only if full_chunk is set",
+                ))
+            }
+        }
+        self.data.encode(writer)?;
+        self.block_entities.encode(writer)
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Multi Block Change
+///
+/// Since 1.16.2 the changed blocks of a single chunk section are sent.
+pub struct MultiBlockChange748 {
+    /// The section coordinates packed as `x << 42 | z << 20 | y`, 22 bits
+    /// each for x and z and 20 bits for y
+    pub section_position: i64,
+    /// The inverse of `trust_edges` in [`UpdateLight725`]
+    pub suppress_light_updates: bool,
+    // varint count of varlongs
+    /// Packed as `block_state << 12 | x << 8 | z << 4 | y` with the
+    /// coordinates relative to the section
+    pub blocks: Vec<i64>,
+}
+
+impl<'dec> Decode<'dec> for MultiBlockChange748 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let section_position = i64::decode(cursor)?;
+        let suppress_light_updates = bool::decode(cursor)?;
+        let len = Var::<u32>::decode(cursor)?.into_inner();
+        let blocks = (0..len)
+            .map(|_| Var::<i64>::decode(cursor).map(|var| var.into_inner()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            section_position,
+            suppress_light_updates,
+            blocks,
+        })
+    }
+}
+
+impl Encode for MultiBlockChange748 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.section_position.encode(writer)?;
+        self.suppress_light_updates.encode(writer)?;
+        Var::from(self.blocks.len() as u32).encode(writer)?;
+        for block in &self.blocks {
+            Var::from(*block).encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Sculk Vibration Signal
+///
+/// Shows a vibration travelling from its source to a sculk sensor.
+pub struct SculkVibrationSignal755<'a> {
+    pub source: Position441,
+    pub destination: VibrationDestination755<'a>,
+    // varint
+    /// Ticks until the vibration arrives at its destination
+    pub arrival_ticks: i32,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum VibrationDestination755<'a> {
+    /// minecraft:block
+    Block(Position441),
+    /// minecraft:entity, the varint id of the entity
+    Entity(i32),
+    /// destination neither "minecraft:block" nor "minecraft:entity"
+    Other(Cow<'a, str>),
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for SculkVibrationSignal755<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        use VibrationDestination755::*;
+        let source = Position441::decode(cursor)?;
+        let destination = match <&str>::decode(cursor)? {
+            "minecraft:block" => Block(Position441::decode(cursor)?),
+            "minecraft:entity" => Entity(Var::decode(cursor)?.into_inner()),
+            destination => Other(Cow::Borrowed(destination)),
+        };
+        Ok(Self {
+            source,
+            destination,
+            arrival_ticks: Var::decode(cursor)?.into_inner(),
+        })
+    }
+}
+
+impl<'a> Encode for SculkVibrationSignal755<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        use VibrationDestination755::*;
+        self.source.encode(writer)?;
+        match &self.destination {
+            Block(position) => {
+                "minecraft:block".encode(writer)?;
+                position.encode(writer)?;
+            }
+            Entity(id) => {
+                "minecraft:entity".encode(writer)?;
+                Var::from(*id).encode(writer)?;
+            }
+            Other(destination) => destination.encode(writer)?,
+        }
+        Var::from(self.arrival_ticks).encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Clear Titles
+pub struct ClearTitles755 {
+    /// Whether the fade in, stay and fade out times are reset too
+    pub reset: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Window Items
+///
+/// Since pv756 (1.17.1) the state id of the window is sent which the client
+/// returns in [`serverbound::ClickWindow756`][cw], the item held by the
+/// cursor is sent too.
+///
+/// [cw]: super::serverbound::ClickWindow756
+pub struct WindowItems756<Slot> {
+    pub window_id: u8,
+    #[encoding(varint)]
+    pub state_id: i32,
+    pub items: Vec<Slot>,
+    pub carried_item: Slot,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Slot
+///
+/// Since pv756 (1.17.1) the state id of the window is sent, see
+/// [`WindowItems756`].
+pub struct SetSlot756<Slot> {
+    pub window_id: i8,
+    #[encoding(varint)]
+    pub state_id: i32,
+    pub slot: i16,
+    pub item: Slot,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Explosion
+///
+/// Since 1.17 the number of records is a varint.
+pub struct Explosion755 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub radius: f32,
+    pub records: Vec<ExplosionRecord>,
+    pub motion_x: f32,
+    pub motion_y: f32,
+    pub motion_z: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Initialize World Border
+///
+/// Since 1.17 every action of [`WorldBorder32`] has its own packet.
+pub struct InitializeWorldBorder755 {
+    pub x: f64,
+    pub z: f64,
+    pub old_diameter: f64,
+    pub new_diameter: f64,
+    /// Milliseconds until the new diameter is reached
+    #[encoding(varint)]
+    pub speed: i64,
+    /// Resulting coordinates from a portal teleport are limited to +-value.
+    /// Usually 29999984.
+    #[encoding(varint)]
+    pub portal_teleport_boundary: i32,
+    #[encoding(varint)]
+    pub warning_blocks: i32,
+    #[encoding(varint)]
+    pub warning_time: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// World Border Center
+pub struct WorldBorderCenter755 {
+    pub x: f64,
+    pub z: f64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// World Border Lerp Size
+pub struct WorldBorderLerpSize755 {
+    pub old_diameter: f64,
+    pub new_diameter: f64,
+    /// Milliseconds until the new diameter is reached
+    #[encoding(varint)]
+    pub speed: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// World Border Size
+pub struct WorldBorderSize755 {
+    pub diameter: f64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// World Border Warning Delay
+pub struct WorldBorderWarningDelay755 {
+    /// In seconds
+    #[encoding(varint)]
+    pub warning_time: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// World Border Warning Reach
+pub struct WorldBorderWarningReach755 {
+    /// In blocks
+    #[encoding(varint)]
+    pub warning_blocks: i32,
+}
+
+#[derive(ToStatic, Debug)]
+/// Chunk Data
+///
+/// Since 1.17 the sent sections are a bitset to support taller worlds and
+/// biomes are always sent.
+pub struct ChunkData755<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    /// Bitset of the sections sent in `data`
+    pub primary_bitmask: Vec<i64>,
+    pub heightmaps: Nbt<'a>,
+    // varint count of varints
+    pub biomes: Vec<i32>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for ChunkData755<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            chunk_x: i32::decode(cursor)?,
+            chunk_z: i32::decode(cursor)?,
+            primary_bitmask: Vec::decode(cursor)?,
+            heightmaps: Nbt::decode(cursor)?,
+            biomes: decode_varint_array(cursor)?,
+            data: Cow::decode(cursor)?,
+            block_entities: Vec::decode(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for ChunkData755<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.chunk_x.encode(writer)?;
+        self.chunk_z.encode(writer)?;
+        self.primary_bitmask.encode(writer)?;
+        self.heightmaps.encode(writer)?;
+        encode_varint_array(&self.biomes, writer)?;
+        self.data.encode(writer)?;
+        self.block_entities.encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Update Light
+///
+/// Since 1.17 the light masks are bitsets to support taller worlds and the
+/// light arrays are prefixed by their count.
+pub struct UpdateLight755<'a> {
+    #[encoding(varint)]
+    pub chunk_x: i32,
+    #[encoding(varint)]
+    pub chunk_z: i32,
+    pub trust_edges: bool,
+    pub sky_light_mask: Vec<i64>,
+    pub block_light_mask: Vec<i64>,
+    pub empty_sky_light_mask: Vec<i64>,
+    pub empty_block_light_mask: Vec<i64>,
+    /// One array of 2048 bytes for every bit set in `sky_light_mask`
+    pub sky_light: Vec<Cow<'a, [u8]>>,
+    /// One array of 2048 bytes for every bit set in `block_light_mask`
+    pub block_light: Vec<Cow<'a, [u8]>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Map
+///
+/// Since 1.17 the icons are optional and the tracking position flag is
+/// gone.
+pub struct Maps755<'a> {
+    // varint
+    pub map_id: i32,
+    pub scale: i8,
+    pub locked: bool,
+    /// `None` if the icons didn't change
+    pub icons: Option<Vec<MapIcon389<'a>>>,
+    /// `None` if no columns are updated
+    pub data: Option<MapData86<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for Maps755<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            map_id: Var::decode(cursor)?.into_inner(),
+            scale: i8::decode(cursor)?,
+            locked: bool::decode(cursor)?,
+            icons: Option::decode(cursor)?,
+            data: MapData86::decode_optional(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for Maps755<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.map_id).encode(writer)?;
+        self.scale.encode(writer)?;
+        self.locked.encode(writer)?;
+        self.icons.encode(writer)?;
+        MapData86::encode_optional(&self.data, writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Ping
+///
+/// Answered by [`serverbound::Pong755`][pong] with the same id.
+///
+/// [pong]: super::serverbound::Pong755
+pub struct Ping755 {
+    pub id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// End Combat Event
+pub struct EndCombatEvent755 {
+    /// Length of the combat in ticks
+    #[encoding(varint)]
+    pub duration: i32,
+    pub entity_id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Enter Combat Event
+pub struct EnterCombatEvent755 {}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Death Combat Event
+///
+/// Shows the death screen with the given message.
+pub struct DeathCombatEvent755<'a> {
+    #[encoding(varint)]
+    pub player_id: i32,
+    /// The killer, -1 if there is none
+    pub entity_id: i32,
+    pub message: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Player Position And Look
+///
+/// Since 1.17 the player can be dismounted from their vehicle.
+pub struct PositionAndLook755 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub relativity: PositionAndLookBitfield6,
+    #[encoding(varint)]
+    pub teleport_id: i32,
+    pub dismount_vehicle: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Destroy Entity
+///
+/// Only in 1.17 entities were destroyed one at a time.
+pub struct DestroyEntity755 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Resource Pack Send
+///
+/// Since 1.17 the server can require the resource pack and show a custom
+/// prompt.
+pub struct ResourcePackSend755<'a> {
+    pub url: Cow<'a, str>,
+    /// A 40 character hexadecimal and lowercase SHA-1 hash of the resource
+    /// pack file.
+    pub hash: Cow<'a, str>,
+    /// The client disconnects if it declines a forced resource pack
+    pub forced: bool,
+    pub prompt: Option<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Action Bar
+pub struct ActionBar755<'a> {
+    pub text: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Position
+///
+/// Since 1.17 the angle the compass points at is sent along.
+pub struct SpawnPosition755 {
+    pub location: Position441,
+    pub angle: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Title Subtitle
+pub struct SetTitleSubtitle755<'a> {
+    pub text: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Title Text
+pub struct SetTitleText755<'a> {
+    pub text: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Title Times
+pub struct SetTitleTimes755 {
+    /// In ticks
+    pub fade_in: i32,
+    /// In ticks
+    pub stay: i32,
+    /// In ticks
+    pub fade_out: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Properties
+///
+/// Since 1.17 the number of properties is a varint.
+pub struct EntityProperties755<'a> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub properties: Vec<EntityProperty7<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Tags
+///
+/// Since 1.17 the tags of any registry can be sent.
+pub struct Tags755<'a> {
+    pub registries: Vec<RegistryTags755<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct RegistryTags755<'a> {
+    /// Identifier of the registry, like minecraft:block
+    pub registry: Cow<'a, str>,
+    pub tags: Vec<Tag389<'a>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Update Block Entity
+///
+/// Since 1.18 the type of the block entity is sent instead of an action.
+pub struct UpdateBlockEntity757<'a> {
+    pub location: Position441,
+    // varint
+    pub kind: i32,
+    /// `None` to remove the block entity
+    pub data: Option<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for UpdateBlockEntity757<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            location: Position441::decode(cursor)?,
+            kind: Var::decode(cursor)?.into_inner(),
+            data: decode_optional_nbt(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for UpdateBlockEntity757<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.location.encode(writer)?;
+        Var::from(self.kind).encode(writer)?;
+        encode_optional_nbt(&self.data, writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chunk Data And Update Light
+///
+/// Since 1.18 the light of a chunk is sent together with its blocks and the
+/// biomes are part of the sections.
+pub struct ChunkDataAndUpdateLight757<'a> {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub heightmaps: Nbt<'a>,
+    pub data: Cow<'a, [u8]>,
+    pub block_entities: Vec<ChunkBlockEntity757<'a>>,
+    pub trust_edges: bool,
+    pub sky_light_mask: Vec<i64>,
+    pub block_light_mask: Vec<i64>,
+    pub empty_sky_light_mask: Vec<i64>,
+    pub empty_block_light_mask: Vec<i64>,
+    /// One array of 2048 bytes for every bit set in `sky_light_mask`
+    pub sky_light: Vec<Cow<'a, [u8]>>,
+    /// One array of 2048 bytes for every bit set in `block_light_mask`
+    pub block_light: Vec<Cow<'a, [u8]>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct ChunkBlockEntity757<'a> {
+    /// The position inside the chunk packed as `x << 4 | z`
+    pub packed_xz: u8,
+    pub y: i16,
+    // varint
+    pub kind: i32,
+    pub data: Option<Nbt<'a>>,
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for ChunkBlockEntity757<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            packed_xz: u8::decode(cursor)?,
+            y: i16::decode(cursor)?,
+            kind: Var::decode(cursor)?.into_inner(),
+            data: decode_optional_nbt(cursor)?,
+        })
+    }
+}
+
+impl<'a> Encode for ChunkBlockEntity757<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.packed_xz.encode(writer)?;
+        self.y.encode(writer)?;
+        Var::from(self.kind).encode(writer)?;
+        encode_optional_nbt(&self.data, writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Join Game
+///
+/// Since 1.18 the simulation distance is sent separately from the view
+/// distance.
+pub struct JoinGame757<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    /// Identifiers of all worlds on the server
+    pub world_names: Vec<Cow<'a, str>>,
+    /// The dimension type and biome registries
+    pub dimension_codec: Nbt<'a>,
+    /// The dimension type of the world the player spawns in
+    pub dimension: Nbt<'a>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    #[encoding(varint)]
+    pub max_players: i32,
+    #[encoding(varint)]
+    pub view_distance: i32,
+    #[encoding(varint)]
+    pub simulation_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    /// Debug worlds can't be modified and have predefined blocks
+    pub is_debug: bool,
+    /// Superflat worlds have a different void fog and horizon height
+    pub is_flat: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Update Simulation Distance
+pub struct UpdateSimulationDistance757 {
+    #[encoding(varint)]
+    pub simulation_distance: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Remove Entity Effect
+///
+/// Since 1.18.2 the effect id is a varint.
+pub struct RemoveEntityEffect758 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    #[encoding(varint)]
+    pub effect_id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Effect
+///
+/// Since 1.18.2 the effect id is a varint.
+pub struct EntityEffect758 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    #[encoding(varint)]
+    pub effect_id: i32,
+    pub amplifier: i8,
+    #[encoding(varint)]
+    pub duration: i32,
+    pub flags: EntityEffectFlags86,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Spawn Object
+///
+/// Since 1.19 all non-player entities, including mobs and paintings, are
+/// spawned with this packet.
+pub struct SpawnObject759 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub object_uuid: Uuid,
+    #[encoding(varint)]
+    pub kind: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: Angle,
+    pub yaw: Angle,
+    pub head_yaw: Angle,
+    /// Meaning dependent on the value of `kind`.
+    #[encoding(varint)]
+    pub data: i32,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Acknowledge Block Change
+///
+/// Confirms all block changes of the client up to the given sequence number,
+/// see [`serverbound::PlayerDigging759`][pd].
+///
+/// [pd]: super::serverbound::PlayerDigging759
+pub struct AcknowledgeBlockChange759 {
+    #[encoding(varint)]
+    pub sequence: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Chat Preview
+///
+/// Answers a [`serverbound::ChatPreview759`][cp] with the decorated
+/// message.
+///
+/// [cp]: super::serverbound::ChatPreview759
+pub struct ChatPreview759<'a> {
+    pub query_id: i32,
+    pub message: Option<Cow<'a, str>>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Declare Commands
+///
+/// Since 1.19 the parsers are identified by their id in the argument type
+/// registry.
+pub struct DeclareCommands759<'a> {
+    pub nodes: Vec<CommandNode759<'a>>,
+    // varint
+    /// Index of the root node in `nodes`
+    pub root_index: i32,
+}
+
+#[derive(ToStatic, Debug)]
+pub struct CommandNode759<'a> {
+    pub kind: CommandNodeKind759<'a>,
+    /// Whether the command is complete when ending at this node
+    pub executable: bool,
+    /// Indices of the children in [`DeclareCommands759::nodes`]
+    pub children: Vec<i32>,
+    /// Index of the node this one redirects to
+    pub redirect: Option<i32>,
+    /// Identifier of the suggestions the server should be asked for, only
+    /// used for arguments.
+    pub suggestions: Option<Cow<'a, str>>,
+}
+
+#[derive(ToStatic, Debug)]
+pub enum CommandNodeKind759<'a> {
+    Root,
+    Literal {
+        name: Cow<'a, str>,
+    },
+    Argument {
+        name: Cow<'a, str>,
+        parser: CommandParser759<'a>,
+    },
+}
+
+#[derive(ToStatic, Debug)]
+pub enum CommandParser759<'a> {
+    /// brigadier:float
+    Float { min: Option<f32>, max: Option<f32> },
+    /// brigadier:double
+    Double { min: Option<f64>, max: Option<f64> },
+    /// brigadier:integer
+    Integer { min: Option<i32>, max: Option<i32> },
+    /// brigadier:long
+    Long { min: Option<i64>, max: Option<i64> },
+    /// brigadier:string
+    String(StringKind346),
+    /// minecraft:entity, 0x01 for a single entity, 0x02 for players only
+    Entity { flags: u8 },
+    /// minecraft:score_holder, 0x01 for multiple score holders
+    ScoreHolder { flags: u8 },
+    /// minecraft:resource_or_tag
+    ResourceOrTag { registry: Cow<'a, str> },
+    /// minecraft:resource
+    Resource { registry: Cow<'a, str> },
+    /// The id of a parser without properties
+    Other(i32),
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for CommandParser759<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        use CommandParser759::*;
+        Ok(match Var::<i32>::decode(cursor)?.into_inner() {
+            1 => {
+                let (min, max) = decode_bounds(cursor)?;
+                Float { min, max }
+            }
+            2 => {
+                let (min, max) = decode_bounds(cursor)?;
+                Double { min, max }
+            }
+            3 => {
+                let (min, max) = decode_bounds(cursor)?;
+                Integer { min, max }
+            }
+            4 => {
+                let (min, max) = decode_bounds(cursor)?;
+                Long { min, max }
+            }
+            5 => String(StringKind346::decode(cursor)?),
+            6 => Entity {
+                flags: u8::decode(cursor)?,
+            },
+            29 => ScoreHolder {
+                flags: u8::decode(cursor)?,
+            },
+            43 => ResourceOrTag {
+                registry: Cow::decode(cursor)?,
+            },
+            44 => Resource {
+                registry: Cow::decode(cursor)?,
+            },
+            parser => Other(parser),
+        })
+    }
+}
+
+impl<'a> Encode for CommandParser759<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        use CommandParser759::*;
+        match self {
+            Float { min, max } => {
+                Var::from(1).encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Double { min, max } => {
+                Var::from(2).encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Integer { min, max } => {
+                Var::from(3).encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            Long { min, max } => {
+                Var::from(4).encode(writer)?;
+                encode_bounds(min, max, writer)
+            }
+            String(kind) => {
+                Var::from(5).encode(writer)?;
+                kind.encode(writer)
+            }
+            Entity { flags } => {
+                Var::from(6).encode(writer)?;
+                flags.encode(writer)
+            }
+            ScoreHolder { flags } => {
+                Var::from(29).encode(writer)?;
+                flags.encode(writer)
+            }
+            ResourceOrTag { registry } => {
+                Var::from(43).encode(writer)?;
+                registry.encode(writer)
+            }
+            Resource { registry } => {
+                Var::from(44).encode(writer)?;
+                registry.encode(writer)
+            }
+            Other(parser) => Var::from(*parser).encode(writer),
+        }
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for CommandNode759<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let flags = u8::decode(cursor)?;
+        let children = decode_varint_array(cursor)?;
+        let redirect = if flags & 0x08 != 0 {
+            Some(Var::decode(cursor)?.into_inner())
+        } else {
+            None
+        };
+        let kind = match flags & 0x03 {
+            0 => CommandNodeKind759::Root,
+            1 => CommandNodeKind759::Literal {
+                name: Cow::decode(cursor)?,
+            },
+            2 => CommandNodeKind759::Argument {
+                name: Cow::decode(cursor)?,
+                parser: CommandParser759::decode(cursor)?,
+            },
+            _ => return Err(decode::Error::InvalidId),
+        };
+        let suggestions = if flags & 0x10 != 0 {
+            Some(Cow::decode(cursor)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            kind,
+            executable: flags & 0x04 != 0,
+            children,
+            redirect,
+            suggestions,
+        })
+    }
+}
+
+impl<'a> Encode for CommandNode759<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let kind = match self.kind {
+            CommandNodeKind759::Root => 0,
+            CommandNodeKind759::Literal { .. } => 1,
+            CommandNodeKind759::Argument { .. } => 2,
+        };
+        let flags = kind
+            | (self.executable as u8) << 2
+            | (self.redirect.is_some() as u8) << 3
+            | (self.suggestions.is_some() as u8) << 4;
+        flags.encode(writer)?;
+        encode_varint_array(&self.children, writer)?;
+        if let Some(redirect) = self.redirect {
+            Var::from(redirect).encode(writer)?;
+        }
+        match &self.kind {
+            CommandNodeKind759::Root => {}
+            CommandNodeKind759::Literal { name } => name.encode(writer)?,
+            CommandNodeKind759::Argument { name, parser } => {
+                name.encode(writer)?;
+                parser.encode(writer)?;
+            }
+        }
+        if let Some(suggestions) = &self.suggestions {
+            suggestions.encode(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'dec: 'a, 'a> Decode<'dec> for DeclareCommands759<'a> {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        Ok(Self {
+            nodes: Vec::decode(cursor)?,
+            root_index: Var::decode(cursor)?.into_inner(),
+        })
+    }
+}
+
+impl<'a> Encode for DeclareCommands759<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.nodes.encode(writer)?;
+        Var::from(self.root_index).encode(writer)
+    }
+}
+
+#[test]
+fn declare_commands759() {
+    let val = [
+        2, 0x00, 1, 1, 0x06, 0, 1, b'x', 3, 0x03, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 9, 0,
+    ];
+    let mut cursor = std::io::Cursor::new(&val[..]);
+    #[allow(clippy::unwrap_used)]
+    let res = DeclareCommands759::decode(&mut cursor).unwrap();
+    assert_eq!(res.root_index, 0);
+    assert!(matches!(
+        &res.nodes[1].kind,
+        CommandNodeKind759::Argument {
+            name,
+            parser: CommandParser759::Integer {
+                min: Some(-1),
+                max: Some(9)
+            }
+        } if name == "x"
+    ));
+    let mut encoded = Vec::new();
+    #[allow(clippy::unwrap_used)]
+    res.encode(&mut encoded).unwrap();
+    assert_eq!(encoded, val);
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Named Sound Effect
+///
+/// Since 1.19 the seed for the sound variant is sent.
+pub struct NamedSoundEffect759<'a> {
+    pub sound_name: Cow<'a, str>,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Sound Effect
+///
+/// Since 1.19 the seed for the sound variant is sent.
+pub struct SoundEffect759 {
+    #[encoding(varint)]
+    pub sound_id: i32,
+    pub category: SoundCategory95,
+    #[encoding(fixed(3, "i32"))]
+    pub x: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub y: f64,
+    #[encoding(fixed(3, "i32"))]
+    pub z: f64,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Sound Effect
+///
+/// Since 1.19 the seed for the sound variant is sent.
+pub struct EntitySoundEffect759 {
+    #[encoding(varint)]
+    pub sound_id: i32,
+    pub category: SoundCategory95,
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+#[derive(ToStatic, Debug)]
+/// Particle
+///
+/// Since 1.19 the particle id is a varint.
+pub struct Particle759<Particle> {
+    pub long_distance: bool,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub offset_z: f32,
+    pub speed: f32,
+    pub count: i32,
+    pub particle: Particle,
+}
+
+impl<'dec, Particle> Decode<'dec> for Particle759<Particle>
+where
+    Particle: DecodeParticle<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let id = Var::decode(cursor)?.into_inner();
+        Ok(Self {
+            long_distance: bool::decode(cursor)?,
+            x: f64::decode(cursor)?,
+            y: f64::decode(cursor)?,
+            z: f64::decode(cursor)?,
+            offset_x: f32::decode(cursor)?,
+            offset_y: f32::decode(cursor)?,
+            offset_z: f32::decode(cursor)?,
+            speed: f32::decode(cursor)?,
+            count: i32::decode(cursor)?,
+            particle: Particle::decode_data(id, cursor)?,
+        })
+    }
+}
+
+impl<Particle> Encode for Particle759<Particle>
+where
+    Particle: EncodeParticle,
+{
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        Var::from(self.particle.id()).encode(writer)?;
+        self.long_distance.encode(writer)?;
+        self.x.encode(writer)?;
+        self.y.encode(writer)?;
+        self.z.encode(writer)?;
+        self.offset_x.encode(writer)?;
+        self.offset_y.encode(writer)?;
+        self.offset_z.encode(writer)?;
+        self.speed.encode(writer)?;
+        self.count.encode(writer)?;
+        self.particle.encode_data(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Join Game
+///
+/// Since 1.19 the registry codec also holds the chat types, the dimension
+/// type is sent as an identifier into it and the last death location of the
+/// player is sent.
+pub struct JoinGame759<'a> {
+    pub entity_id: i32,
+    pub hardcore: bool,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    /// Identifiers of all worlds on the server
+    pub world_names: Vec<Cow<'a, str>>,
+    /// The dimension type, biome and chat type registries
+    pub registry_codec: Nbt<'a>,
+    /// Identifier of one of the dimension types in `registry_codec`
+    pub dimension_type: Cow<'a, str>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    #[encoding(varint)]
+    pub max_players: i32,
+    #[encoding(varint)]
+    pub view_distance: i32,
+    #[encoding(varint)]
+    pub simulation_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    /// Debug worlds can't be modified and have predefined blocks
+    pub is_debug: bool,
+    /// Superflat worlds have a different void fog and horizon height
+    pub is_flat: bool,
+    pub death_location: Option<DeathLocation759<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct DeathLocation759<'a> {
+    pub world_name: Cow<'a, str>,
+    pub location: Position441,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Player Chat Message
+///
+/// Since 1.19 chat messages of players are signed with the key of the
+/// sender.
+pub struct PlayerChatMessage759<'a> {
+    pub signed_content: Cow<'a, str>,
+    /// Shown instead of `signed_content` if the client doesn't only show
+    /// secure chat
+    pub unsigned_content: Option<Cow<'a, str>>,
+    /// Id in the chat type registry of [`JoinGame759::registry_codec`]
+    #[encoding(varint)]
+    pub kind: i32,
+    pub sender: Uuid,
+    pub sender_display_name: Cow<'a, str>,
+    pub sender_team_name: Option<Cow<'a, str>>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub signature: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+/// Player List Item
+///
+/// Since 1.19 the public key of a player is sent when they are added.
+pub enum PlayerListItem759<'a> {
+    #[encoding(case = "0")]
+    AddPlayers(Vec<PlayerListAddPlayer759<'a>>),
+    UpdateGamemode(Vec<PlayerListUpdateGamemode17>),
+    UpdateLatency(Vec<PlayerListUpdateLatency17>),
+    UpdateDisplayName(Vec<PlayerListUpdateDisplayName28<'a>>),
+    RemovePlayers(Vec<Uuid>),
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerListAddPlayer759<'a> {
+    pub uuid: Uuid,
+    pub name: Cow<'a, str>,
+    pub properties: Vec<PlayerProperty19<'a>>,
+    pub gamemode: GameMode17,
+    #[encoding(varint)]
+    pub ping: i32,
+    // TODO: chat
+    pub display_name: Option<Cow<'a, str>>,
+    pub public_key: Option<PlayerPublicKey759<'a>>,
+}
+
+impl<'a> PlayerListAddPlayer759<'a> {
+    /// Creates an entry without a display name or public key.
+    pub fn new(profile: &'a GameProfile, gamemode: GameMode17, ping: i32) -> Self {
+        Self {
+            uuid: profile.uuid,
+            name: Cow::Borrowed(&profile.name),
+            properties: profile.properties.iter().map(Into::into).collect(),
+            gamemode,
+            ping,
+            display_name: None,
+            public_key: None,
+        }
+    }
+
+    pub fn profile(&self) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            name: self.name.to_string(),
+            properties: self.properties.iter().map(Property::from).collect(),
+        }
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerPublicKey759<'a> {
+    /// Milliseconds since the unix epoch
+    pub expires_at: i64,
+    /// DER encoded RSA public key
+    pub key: Cow<'a, [u8]>,
+    /// Signature of the key by Mojang
+    pub signature: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Respawn
+///
+/// Since 1.19 the dimension type is sent as an identifier and the last
+/// death location of the player is sent, see [`JoinGame759`].
+pub struct Respawn759<'a> {
+    /// Identifier of one of the dimension types in
+    /// [`JoinGame759::registry_codec`]
+    pub dimension_type: Cow<'a, str>,
+    pub world_name: Cow<'a, str>,
+    pub hashed_seed: i64,
+    pub gamemode: GameMode86,
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// Whether the entity metadata is kept, `false` when respawning after
+    /// death
+    pub copy_metadata: bool,
+    pub death_location: Option<DeathLocation759<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Server Data
+///
+/// Sends the motd and icon shown in the server list to the client.
+pub struct ServerData759<'a> {
+    pub motd: Option<Cow<'a, str>>,
+    /// Base64 encoded png
+    pub icon: Option<Cow<'a, str>>,
+    pub previews_chat: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Set Display Chat Preview
+pub struct SetDisplayChatPreview759 {
+    pub enabled: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// System Chat Message
+///
+/// Since 1.19 messages not sent by a player have their own packet.
+pub struct SystemChatMessage759<'a> {
+    pub content: Cow<'a, str>,
+    /// Id in the chat type registry of [`JoinGame759::registry_codec`]
+    #[encoding(varint)]
+    pub kind: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Entity Effect
+///
+/// Since 1.19 the factor data of the darkness effect is sent.
+pub struct EntityEffect759<'a> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    #[encoding(varint)]
+    pub effect_id: i32,
+    pub amplifier: i8,
+    #[encoding(varint)]
+    pub duration: i32,
+    pub flags: EntityEffectFlags86,
+    pub factor_codec: Option<Nbt<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Custom Chat Completions
+///
+/// Changes the suggestions shown while typing a chat message.
+pub struct CustomChatCompletions760<'a> {
+    pub action: CustomChatCompletionsAction760,
+    pub entries: Vec<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum CustomChatCompletionsAction760 {
+    Add = 0,
+    Remove,
+    Set,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Hide Message
+///
+/// Removes the message with the given signature from the chat.
+pub struct HideMessage760<'a> {
+    pub signature: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Message Header
+///
+/// Sent instead of a [`PlayerChatMessage760`] which isn't shown to the
+/// player to keep the chain of signatures intact.
+pub struct MessageHeader760<'a> {
+    pub previous_signature: Option<Cow<'a, [u8]>>,
+    pub sender: Uuid,
+    pub header_signature: Cow<'a, [u8]>,
+    /// SHA-256 hash of the message body
+    pub body_digest: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Player Chat Message
+///
+/// Since 1.19.1 every message references the signature of the previous one
+/// and the messages the sender has seen.
+pub struct PlayerChatMessage760<'a> {
+    pub previous_signature: Option<Cow<'a, [u8]>>,
+    pub sender: Uuid,
+    pub header_signature: Cow<'a, [u8]>,
+    pub plain_content: Cow<'a, str>,
+    pub decorated_content: Option<Cow<'a, str>>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub last_seen: Vec<PreviousMessage760<'a>>,
+    /// Shown instead of the content if the client doesn't only show secure
+    /// chat
+    pub unsigned_content: Option<Cow<'a, str>>,
+    pub filter: FilterMask760,
+    /// Id in the chat type registry of [`JoinGame759::registry_codec`]
+    #[encoding(varint)]
+    pub chat_type: i32,
+    pub network_name: Cow<'a, str>,
+    pub network_target_name: Option<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// A message seen by a player, identified by its sender and signature.
+pub struct PreviousMessage760<'a> {
+    pub sender: Uuid,
+    pub signature: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum FilterMask760 {
+    #[encoding(case = "0")]
+    PassThrough,
+    FullyFiltered,
+    /// Bitset of the filtered characters
+    PartiallyFiltered {
+        mask: Vec<i64>,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Server Data
+///
+/// Since 1.19.1 the client is told whether the server enforces secure chat.
+pub struct ServerData760<'a> {
+    pub motd: Option<Cow<'a, str>>,
+    /// Base64 encoded png
+    pub icon: Option<Cow<'a, str>>,
+    pub previews_chat: bool,
+    pub enforces_secure_chat: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// System Chat Message
+///
+/// Since 1.19.1 system messages are either shown in the chat or above the
+/// hotbar.
+pub struct SystemChatMessage760<'a> {
+    pub content: Cow<'a, str>,
+    pub overlay: bool,
+}
@@ -1,6 +1,9 @@
-use crate::netty::types::position::Position6;
+use crate::netty::types::position::{Position441, Position6};
+use crate::netty::types::slot::Slot0;
 
-use ::miners_encoding::{decode, encode, Decode, Encode};
+use ::miners_encoding::{attrs::Var, decode, encode, Decode, Encode};
+
+use super::clientbound::PreviousMessage760;
 
 use std::borrow::Cow;
 use uuid::Uuid;
@@ -16,6 +19,19 @@ pub struct KeepAlive7 {
     pub id: i32,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct KeepAlive339 {
+    pub id: i64,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Sent by the client to confirm a
+/// [`clientbound::PositionAndLook86`][super::clientbound::PositionAndLook86].
+pub struct TeleportConfirm80 {
+    #[encoding(varint)]
+    pub teleport_id: i32,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct ChatMessage0<'a> {
     // TODO: add ChatMessage json thing
@@ -55,6 +71,36 @@ pub enum UseEntityKind33 {
     },
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UseEntity80 {
+    #[encoding(varint)]
+    pub target_id: i32,
+    pub kind: UseEntityKind80,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum UseEntityKind80 {
+    #[encoding(case = "0")]
+    Interact {
+        hand: Hand80,
+    },
+    Attack,
+    InteractAt {
+        x: f32,
+        y: f32,
+        z: f32,
+        hand: Hand80,
+    },
+}
+
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
+#[encoding(varint)]
+pub enum Hand80 {
+    MainHand = 0,
+    OffHand,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Player0 {
     pub on_ground: bool,
@@ -107,6 +153,21 @@ pub struct PlayerPositionAndLook10 {
     pub on_ground: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct VehicleMove80 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SteerBoat97 {
+    pub left_paddle: bool,
+    pub right_paddle: bool,
+}
+
 /// Notchian clients send a 0 (started digging) when they start digging and a 2 (finished digging) once they think they are finished. If digging is aborted, the client simply send a 1 (Cancel digging).
 ///
 /// Status code 4 (drop item) is a special case. In-game, when you use the Drop Item command (keypress 'q'), a dig packet with a status of 4, and all other values set to 0, is sent from client to server. Status code 3 is similar, but drops the entire stack.
@@ -307,6 +368,33 @@ pub enum DiggingAction0 {
     FinishRightClick,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerDigging80 {
+    pub status: DiggingAction80,
+    pub location: Position6,
+    pub face: BlockFace0,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerDigging441 {
+    pub status: DiggingAction80,
+    pub location: Position441,
+    pub face: BlockFace0,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum DiggingAction80 {
+    Started = 0,
+    Cancelled,
+    Finished,
+    DropItemStack,
+    DropItem,
+    /// Shoot arrow / finish eating
+    FinishRightClick,
+    SwapItemInHand,
+}
+
 #[derive(Encoding, ToStatic, Clone, Copy, Debug)]
 #[encoding(from = "u8")]
 pub enum BlockFace0 {
@@ -339,6 +427,68 @@ pub struct PlayerBlockPlacement6 {
     // TODO: WTF (see above)
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerBlockPlacement80 {
+    pub location: Position6,
+    pub face: PlacementFace80,
+    pub hand: Hand80,
+    /// The position of the crosshair on the block, in 1/16 of a block.
+    pub cursor_x: u8,
+    pub cursor_y: u8,
+    pub cursor_z: u8,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerBlockPlacement309 {
+    pub location: Position6,
+    pub face: PlacementFace80,
+    pub hand: Hand80,
+    /// The position of the crosshair on the block, from 0 to 1.
+    pub cursor_x: f32,
+    pub cursor_y: f32,
+    pub cursor_z: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerBlockPlacement441 {
+    pub location: Position441,
+    pub face: PlacementFace80,
+    pub hand: Hand80,
+    /// The position of the crosshair on the block, from 0 to 1.
+    pub cursor_x: f32,
+    pub cursor_y: f32,
+    pub cursor_z: f32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerBlockPlacement453 {
+    pub hand: Hand80,
+    pub location: Position441,
+    pub face: PlacementFace80,
+    /// The position of the crosshair on the block, from 0 to 1.
+    pub cursor_x: f32,
+    pub cursor_y: f32,
+    pub cursor_z: f32,
+    /// Whether the player's head is inside of a block
+    pub inside_block: bool,
+}
+
+#[derive(Encoding, ToStatic, Clone, Copy, Debug)]
+#[encoding(varint)]
+pub enum PlacementFace80 {
+    NegY = 0,
+    PosY,
+    NegZ,
+    PosZ,
+    NegX,
+    PosX,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UseItem80 {
+    pub hand: Hand80,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct HeldItemChange0 {
     /// The slot which the player has selected (0-8)
@@ -355,6 +505,11 @@ pub struct Animation0 {
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Animation7 {}
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct Animation80 {
+    pub hand: Hand80,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct EntityAction0 {
     pub entity_id: i32,
@@ -385,6 +540,30 @@ pub enum EntityAction {
     OpenHorseInventory,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct EntityAction80 {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub action: EntityActionKind80,
+    /// Horse jump boost. Ranged from 0 -> 100.
+    #[encoding(varint)]
+    pub jump_boost: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum EntityActionKind80 {
+    Crouch = 0,
+    Uncrouch,
+    LeaveBed,
+    StartSprinting,
+    StopSprinting,
+    StartJumpWithHorse,
+    StopJumpWithHorse,
+    OpenHorseInventory,
+    StartFlyingWithElytra,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct SteerVehicle0 {
     pub sideways: f32,
@@ -428,7 +607,71 @@ impl<'dec> Decode<'dec> for ClickWindow0 {
         let button = u8::decode(cursor)?;
         let action_id = i16::decode(cursor)?;
         let mode = u8::decode(cursor)?;
+        Ok(Self {
+            window_id,
+            action: ClickAction0::from_raw(mode, button, slot)?,
+            action_id,
+            // slot: Decode::read(cursor)?,
+        })
+    }
+}
+impl Encode for ClickWindow0 {
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.window_id.encode(writer)?;
+        let (mode, button, slot) = self.action.to_raw();
+        slot.encode(writer)?;
+        button.encode(writer)?;
+        self.action_id.encode(writer)?;
+        mode.encode(writer)?;
+        // self.item.write(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+pub struct ClickWindow83<Slot> {
+    pub window_id: u8,
+    pub action: ClickAction0,
+    pub action_id: i16,
+    pub clicked_item: Slot,
+}
+impl<'dec, Slot> Decode<'dec> for ClickWindow83<Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let window_id = u8::decode(cursor)?;
+        let slot = i16::decode(cursor)?;
+        let button = u8::decode(cursor)?;
+        let action_id = i16::decode(cursor)?;
+        let mode = Var::<i32>::decode(cursor)?.into_inner();
+        let mode = u8::try_from(mode).map_err(|_| decode::Error::InvalidId)?;
+        Ok(Self {
+            window_id,
+            action: ClickAction0::from_raw(mode, button, slot)?,
+            action_id,
+            clicked_item: Slot::decode(cursor)?,
+        })
+    }
+}
+impl<Slot> Encode for ClickWindow83<Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.window_id.encode(writer)?;
+        let (mode, button, slot) = self.action.to_raw();
+        slot.encode(writer)?;
+        button.encode(writer)?;
+        self.action_id.encode(writer)?;
+        Var::from(mode as i32).encode(writer)?;
+        self.clicked_item.encode(writer)?;
+        Ok(())
+    }
+}
 
+impl ClickAction0 {
+    fn from_raw(mode: u8, button: u8, slot: i16) -> decode::Result<Self> {
         fn mouse_button(button: u8) -> Result<MouseButton, decode::Error> {
             Ok(match button {
                 0 => MouseButton::Left,
@@ -438,7 +681,7 @@ impl<'dec> Decode<'dec> for ClickWindow0 {
         }
 
         use ClickAction0::*;
-        let action = match mode {
+        Ok(match mode {
             0 => Click {
                 button: mouse_button(button)?,
                 slot,
@@ -500,20 +743,12 @@ impl<'dec> Decode<'dec> for ClickWindow0 {
                 _ => return Err(decode::Error::InvalidId),
             },
             _ => return Err(decode::Error::InvalidId),
-        };
-
-        Ok(Self {
-            window_id,
-            action,
-            action_id,
-            // slot: Decode::read(cursor)?,
         })
     }
-}
-impl Encode for ClickWindow0 {
-    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
-        self.window_id.encode(writer)?;
-        let (mode, button, slot) = match self.action {
+
+    /// mode, button and slot as sent in the packet
+    fn to_raw(self) -> (u8, u8, i16) {
+        match self {
             ClickAction0::Click { button, slot } => (0, button as u8, slot),
             ClickAction0::ShiftClick { button, slot } => (1, button as u8, slot),
             ClickAction0::Number { number, slot } => (2, number as u8, slot),
@@ -544,20 +779,14 @@ impl Encode for ClickWindow0 {
                 DragChange::End => (
                     5,
                     match button {
-                        MouseButton::Left => 0,
+                        MouseButton::Left => 2,
                         MouseButton::Right => 6,
                     },
                     -999,
                 ),
             },
             ClickAction0::DoubleClick { slot } => (6, 0, slot),
-        };
-        slot.encode(writer)?;
-        button.encode(writer)?;
-        self.action_id.encode(writer)?;
-        mode.encode(writer)?;
-        // self.item.write(writer)?;
-        Ok(())
+        }
     }
 }
 
@@ -636,6 +865,12 @@ pub struct CreativeInventoryAction0 {
     // item: Slot
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CreativeInventoryAction80<Slot> {
+    pub slot: i16,
+    pub clicked_item: Slot,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct EnchantItem0 {
     pub window_id: u8,
@@ -663,6 +898,15 @@ pub struct UpdateSign6<'a> {
     pub line4: Cow<'a, str>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateSign441<'a> {
+    pub location: Position441,
+    pub line1: Cow<'a, str>,
+    pub line2: Cow<'a, str>,
+    pub line3: Cow<'a, str>,
+    pub line4: Cow<'a, str>,
+}
+
 #[derive(ToStatic, Debug)]
 pub struct PlayerAbilities0 {
     pub invulnerable: bool,
@@ -712,6 +956,24 @@ pub struct TabComplete37<'a> {
     pub targeted_block: Option<Position6>,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct TabComplete80<'a> {
+    pub text: Cow<'a, str>,
+    /// Whether the text should be completed as a command even without a
+    /// leading slash, used by command blocks.
+    pub assume_command: bool,
+    pub looked_at_block: Option<Position6>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Answered by [`clientbound::TabComplete357`][super::clientbound::TabComplete357]
+/// with the same transaction id.
+pub struct TabComplete389<'a> {
+    #[encoding(varint)]
+    pub transaction_id: i32,
+    pub text: Cow<'a, str>,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct ClientSettings0<'a> {
     pub locale: Cow<'a, str>,
@@ -776,6 +1038,32 @@ pub struct DisplayedSkinParts6 {
     pub hat: bool,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ClientSettings80<'a> {
+    pub locale: Cow<'a, str>,
+    /// Client-side render distance, in chunks
+    pub view_distance: u8,
+    pub chat_mode: ChatMode80,
+    pub chat_colors: bool,
+    pub displayed_skin_parts: DisplayedSkinParts6,
+    pub main_hand: MainHand80,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum ChatMode80 {
+    Enabled = 0,
+    CommandsOnly,
+    Hidden,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum MainHand80 {
+    Left = 0,
+    Right,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 #[encoding(from = "u8")]
 pub enum ClientStatus0 {
@@ -784,6 +1072,14 @@ pub enum ClientStatus0 {
     InventoryAchievement,
 }
 
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum ClientStatus80 {
+    Respawn = 0,
+    RequestStats,
+    InventoryAchievement,
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 // https://dinnerbone.com/blog/2012/01/13/minecraft-plugin-channels-messaging/
 pub struct PluginMessage0<'a> {
@@ -826,3 +1122,670 @@ pub enum ResourcePackStatusResult32 {
     FailedDownload,
     Accepted,
 }
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ResourcePackStatus204 {
+    pub result: ResourcePackStatusResult32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PrepareCraftingGrid322<'a> {
+    pub window_id: u8,
+    pub action_number: i16,
+    #[encoding(counted = "u16")]
+    pub return_entries: Vec<CraftingGridEntry322<'a>>,
+    #[encoding(counted = "u16")]
+    pub prepare_entries: Vec<CraftingGridEntry322<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CraftingGridEntry322<'a> {
+    pub item: Slot0<'a>,
+    pub crafting_slot: u8,
+    pub player_slot: u8,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CraftRecipeRequest336 {
+    pub window_id: i8,
+    #[encoding(varint)]
+    pub recipe_id: i32,
+    pub make_all: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct CraftRecipeRequest389<'a> {
+    pub window_id: i8,
+    pub recipe: Cow<'a, str>,
+    pub make_all: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum CraftingBookData333 {
+    #[encoding(case = "0")]
+    DisplayedRecipe { recipe_id: i32 },
+    CraftingBookStatus {
+        crafting_book_open: bool,
+        crafting_filter: bool,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum RecipeBookData389<'a> {
+    #[encoding(case = "0")]
+    DisplayedRecipe { recipe: Cow<'a, str> },
+    RecipeBookStates {
+        crafting_book_open: bool,
+        crafting_filter: bool,
+        smelting_book_open: bool,
+        smelting_filter: bool,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum RecipeBookData451<'a> {
+    #[encoding(case = "0")]
+    DisplayedRecipe { recipe: Cow<'a, str> },
+    RecipeBookStates {
+        crafting_book_open: bool,
+        crafting_filter: bool,
+        smelting_book_open: bool,
+        smelting_filter: bool,
+        blasting_book_open: bool,
+        blasting_filter: bool,
+        smoking_book_open: bool,
+        smoking_filter: bool,
+    },
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum AdvancementTab332<'a> {
+    #[encoding(case = "0")]
+    OpenedTab {
+        tab_id: Cow<'a, str>,
+    },
+    ClosedScreen,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Answered by [`clientbound::NbtQueryResponse389`][super::clientbound::NbtQueryResponse389]
+/// with the same transaction id.
+pub struct QueryBlockNbt389 {
+    #[encoding(varint)]
+    pub transaction_id: i32,
+    pub location: Position6,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct QueryBlockNbt441 {
+    #[encoding(varint)]
+    pub transaction_id: i32,
+    pub location: Position441,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Answered by [`clientbound::NbtQueryResponse389`][super::clientbound::NbtQueryResponse389]
+/// with the same transaction id.
+pub struct QueryEntityNbt389 {
+    #[encoding(varint)]
+    pub transaction_id: i32,
+    #[encoding(varint)]
+    pub entity_id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct EditBook389<Slot> {
+    pub new_book: Slot,
+    pub is_signing: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct EditBook394<Slot> {
+    pub new_book: Slot,
+    pub is_signing: bool,
+    pub hand: Hand80,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Sent when middle clicking a block whose item is already in the inventory.
+pub struct PickItem389 {
+    #[encoding(varint)]
+    pub slot_to_use: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct NameItem389<'a> {
+    pub item_name: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SelectTrade389 {
+    #[encoding(varint)]
+    pub selected_slot: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SetBeaconEffect389 {
+    #[encoding(varint)]
+    pub primary_effect: i32,
+    #[encoding(varint)]
+    pub secondary_effect: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateCommandBlock389<'a> {
+    pub location: Position6,
+    pub command: Cow<'a, str>,
+    pub mode: CommandBlockMode389,
+    pub flags: CommandBlockFlags389,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateCommandBlock441<'a> {
+    pub location: Position441,
+    pub command: Cow<'a, str>,
+    pub mode: CommandBlockMode389,
+    pub flags: CommandBlockFlags389,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum CommandBlockMode389 {
+    Sequence = 0,
+    Auto,
+    Redstone,
+}
+
+#[derive(Bitfield, ToStatic, Debug)]
+#[encoding(typ = "u8", reverse)]
+pub struct CommandBlockFlags389 {
+    #[encoding(bool)]
+    pub track_output: bool,
+    #[encoding(bool)]
+    pub conditional: bool,
+    #[encoding(bool)]
+    pub automatic: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateCommandBlockMinecart389<'a> {
+    #[encoding(varint)]
+    pub entity_id: i32,
+    pub command: Cow<'a, str>,
+    pub track_output: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateStructureBlock389<'a> {
+    pub location: Position6,
+    pub action: StructureBlockAction389,
+    pub mode: StructureBlockMode389,
+    pub name: Cow<'a, str>,
+    pub offset_x: i8,
+    pub offset_y: i8,
+    pub offset_z: i8,
+    pub size_x: i8,
+    pub size_y: i8,
+    pub size_z: i8,
+    pub mirror: StructureMirror389,
+    pub rotation: StructureRotation389,
+    pub metadata: Cow<'a, str>,
+    /// 0.0..=1.0
+    pub integrity: f32,
+    #[encoding(varint)]
+    pub seed: i64,
+    pub flags: StructureBlockFlags389,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateStructureBlock441<'a> {
+    pub location: Position441,
+    pub action: StructureBlockAction389,
+    pub mode: StructureBlockMode389,
+    pub name: Cow<'a, str>,
+    pub offset_x: i8,
+    pub offset_y: i8,
+    pub offset_z: i8,
+    pub size_x: i8,
+    pub size_y: i8,
+    pub size_z: i8,
+    pub mirror: StructureMirror389,
+    pub rotation: StructureRotation389,
+    pub metadata: Cow<'a, str>,
+    /// 0.0..=1.0
+    pub integrity: f32,
+    #[encoding(varint)]
+    pub seed: i64,
+    pub flags: StructureBlockFlags389,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum StructureBlockAction389 {
+    UpdateData = 0,
+    SaveStructure,
+    LoadStructure,
+    DetectSize,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum StructureBlockMode389 {
+    Save = 0,
+    Load,
+    Corner,
+    Data,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum StructureMirror389 {
+    None = 0,
+    LeftRight,
+    FrontBack,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum StructureRotation389 {
+    None = 0,
+    Clockwise90,
+    Clockwise180,
+    CounterClockwise90,
+}
+
+#[derive(Bitfield, ToStatic, Debug)]
+#[encoding(typ = "u8", reverse)]
+pub struct StructureBlockFlags389 {
+    #[encoding(bool)]
+    pub ignore_entities: bool,
+    #[encoding(bool)]
+    pub show_air: bool,
+    #[encoding(bool)]
+    pub show_bounding_box: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct SetDifficulty464 {
+    pub difficulty: super::Difficulty0,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateJigsawBlock468<'a> {
+    pub location: Position441,
+    pub attachment_type: Cow<'a, str>,
+    pub target_pool: Cow<'a, str>,
+    pub final_state: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct LockDifficulty471 {
+    pub locked: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Generates a structure from the jigsaw block at the given location.
+pub struct GenerateStructure719 {
+    pub location: Position441,
+    #[encoding(varint)]
+    pub levels: i32,
+    pub keep_jigsaws: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UpdateJigsawBlock712<'a> {
+    pub location: Position441,
+    pub name: Cow<'a, str>,
+    pub target: Cow<'a, str>,
+    pub pool: Cow<'a, str>,
+    pub final_state: Cow<'a, str>,
+    /// "rollable" or "aligned"
+    pub joint_type: Cow<'a, str>,
+}
+
+#[derive(ToStatic, Debug)]
+/// Since 1.16 the client only tells the server whether it is flying.
+pub struct PlayerAbilities727 {
+    pub flying: bool,
+}
+impl<'dec> Decode<'dec> for PlayerAbilities727 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> Result<Self, decode::Error> {
+        let flags = u8::decode(cursor)?;
+        Ok(PlayerAbilities727 {
+            flying: flags & 0b0010 != 0,
+        })
+    }
+}
+impl Encode for PlayerAbilities727 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> Result<(), encode::Error> {
+        ((self.flying as u8) << 1).encode(writer)
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UseEntity729 {
+    #[encoding(varint)]
+    pub target_id: i32,
+    pub kind: UseEntityKind80,
+    pub sneaking: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Replaces [`RecipeBookData451`] since 1.16.2.
+pub struct SetRecipeBookState738 {
+    pub book: RecipeBook738,
+    pub book_open: bool,
+    pub filter_active: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+#[encoding(varint)]
+pub enum RecipeBook738 {
+    Crafting = 0,
+    Furnace,
+    BlastFurnace,
+    Smoker,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Replaces [`RecipeBookData451::DisplayedRecipe`] since 1.16.2.
+pub struct SetDisplayedRecipe738<'a> {
+    pub recipe_id: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ClientSettings755<'a> {
+    pub locale: Cow<'a, str>,
+    /// Client-side render distance, in chunks
+    pub view_distance: u8,
+    pub chat_mode: ChatMode80,
+    pub chat_colors: bool,
+    pub displayed_skin_parts: DisplayedSkinParts6,
+    pub main_hand: MainHand80,
+    pub disable_text_filtering: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ClientSettings757<'a> {
+    pub locale: Cow<'a, str>,
+    /// Client-side render distance, in chunks
+    pub view_distance: u8,
+    pub chat_mode: ChatMode80,
+    pub chat_colors: bool,
+    pub displayed_skin_parts: DisplayedSkinParts6,
+    pub main_hand: MainHand80,
+    pub disable_text_filtering: bool,
+    /// Whether the player is shown in the player sample of the server list
+    pub allow_server_listings: bool,
+}
+
+#[derive(ToStatic, Debug)]
+/// Since 1.17 the client sends every slot changed by the click instead of
+/// waiting for a confirmation.
+pub struct ClickWindow755<Slot> {
+    pub window_id: u8,
+    pub action: ClickAction0,
+    pub changed_slots: Vec<ChangedSlot755<Slot>>,
+    pub carried_item: Slot,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ChangedSlot755<Slot> {
+    pub slot: i16,
+    pub item: Slot,
+}
+
+impl<'dec, Slot> Decode<'dec> for ClickWindow755<Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let window_id = u8::decode(cursor)?;
+        let slot = i16::decode(cursor)?;
+        let button = u8::decode(cursor)?;
+        let mode = Var::<i32>::decode(cursor)?.into_inner();
+        let mode = u8::try_from(mode).map_err(|_| decode::Error::InvalidId)?;
+        Ok(Self {
+            window_id,
+            action: ClickAction0::from_raw(mode, button, slot)?,
+            changed_slots: Vec::decode(cursor)?,
+            carried_item: Slot::decode(cursor)?,
+        })
+    }
+}
+impl<Slot> Encode for ClickWindow755<Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.window_id.encode(writer)?;
+        let (mode, button, slot) = self.action.to_raw();
+        slot.encode(writer)?;
+        button.encode(writer)?;
+        Var::from(mode as i32).encode(writer)?;
+        self.changed_slots.encode(writer)?;
+        self.carried_item.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(ToStatic, Debug)]
+/// Since pv756 (1.17.1) the last state id received from
+/// [`clientbound::WindowItems756`][wi] or [`clientbound::SetSlot756`][ss] is
+/// sent back.
+///
+/// [wi]: super::clientbound::WindowItems756
+/// [ss]: super::clientbound::SetSlot756
+pub struct ClickWindow756<Slot> {
+    pub window_id: u8,
+    pub state_id: i32,
+    pub action: ClickAction0,
+    pub changed_slots: Vec<ChangedSlot755<Slot>>,
+    pub carried_item: Slot,
+}
+
+impl<'dec, Slot> Decode<'dec> for ClickWindow756<Slot>
+where
+    Slot: Decode<'dec>,
+{
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let window_id = u8::decode(cursor)?;
+        let state_id = Var::decode(cursor)?.into_inner();
+        let slot = i16::decode(cursor)?;
+        let button = u8::decode(cursor)?;
+        let mode = Var::<i32>::decode(cursor)?.into_inner();
+        let mode = u8::try_from(mode).map_err(|_| decode::Error::InvalidId)?;
+        Ok(Self {
+            window_id,
+            state_id,
+            action: ClickAction0::from_raw(mode, button, slot)?,
+            changed_slots: Vec::decode(cursor)?,
+            carried_item: Slot::decode(cursor)?,
+        })
+    }
+}
+impl<Slot> Encode for ClickWindow756<Slot>
+where
+    Slot: Encode,
+{
+    fn encode(&self, writer: &mut impl ::std::io::Write) -> Result<(), encode::Error> {
+        self.window_id.encode(writer)?;
+        Var::from(self.state_id).encode(writer)?;
+        let (mode, button, slot) = self.action.to_raw();
+        slot.encode(writer)?;
+        button.encode(writer)?;
+        Var::from(mode as i32).encode(writer)?;
+        self.changed_slots.encode(writer)?;
+        self.carried_item.encode(writer)?;
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Answers a [`clientbound::Ping755`][ping] with the same id.
+///
+/// [ping]: super::clientbound::Ping755
+pub struct Pong755 {
+    pub id: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since pv756 (1.17.1) the pages are sent as text instead of a book item.
+pub struct EditBook756<'a> {
+    /// The hotbar slot of the book
+    #[encoding(varint)]
+    pub slot: i32,
+    pub entries: Vec<Cow<'a, str>>,
+    /// Only sent when signing the book
+    pub title: Option<Cow<'a, str>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since 1.19 commands have their own packet so their arguments can be
+/// signed.
+pub struct ChatCommand759<'a> {
+    /// The command without the leading slash
+    pub command: Cow<'a, str>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub argument_signatures: Vec<ArgumentSignature759<'a>>,
+    pub signed_preview: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct ArgumentSignature759<'a> {
+    pub name: Cow<'a, str>,
+    pub signature: Cow<'a, [u8]>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since 1.19 chat messages are signed with the key of the player.
+pub struct ChatMessage759<'a> {
+    pub message: Cow<'a, str>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub signature: Cow<'a, [u8]>,
+    pub signed_preview: bool,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Asks the server for a preview of the message currently typed, answered
+/// by [`clientbound::ChatPreview759`][cp].
+///
+/// [cp]: super::clientbound::ChatPreview759
+pub struct ChatPreview759<'a> {
+    pub query_id: i32,
+    pub message: Cow<'a, str>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since 1.19 block changes are acknowledged by
+/// [`clientbound::AcknowledgeBlockChange759`][abc].
+///
+/// [abc]: super::clientbound::AcknowledgeBlockChange759
+pub struct PlayerDigging759 {
+    pub status: DiggingAction80,
+    pub location: Position441,
+    pub face: BlockFace0,
+    #[encoding(varint)]
+    pub sequence: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct PlayerBlockPlacement759 {
+    pub hand: Hand80,
+    pub location: Position441,
+    pub face: PlacementFace80,
+    /// The position of the crosshair on the block, from 0 to 1.
+    pub cursor_x: f32,
+    pub cursor_y: f32,
+    pub cursor_z: f32,
+    /// Whether the player's head is inside of a block
+    pub inside_block: bool,
+    #[encoding(varint)]
+    pub sequence: i32,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+pub struct UseItem759 {
+    pub hand: Hand80,
+    #[encoding(varint)]
+    pub sequence: i32,
+}
+
+#[derive(ToStatic, Debug)]
+/// Since 1.19 the effects are optional instead of using -1.
+pub struct SetBeaconEffect759 {
+    // varint
+    pub primary_effect: Option<i32>,
+    // varint
+    pub secondary_effect: Option<i32>,
+}
+impl<'dec> Decode<'dec> for SetBeaconEffect759 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let mut effect = || -> decode::Result<Option<i32>> {
+            Ok(match bool::decode(cursor)? {
+                true => Some(Var::decode(cursor)?.into_inner()),
+                false => None,
+            })
+        };
+        Ok(Self {
+            primary_effect: effect()?,
+            secondary_effect: effect()?,
+        })
+    }
+}
+impl Encode for SetBeaconEffect759 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        for effect in [self.primary_effect, self.secondary_effect] {
+            effect.is_some().encode(writer)?;
+            if let Some(effect) = effect {
+                Var::from(effect).encode(writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Tells the server which messages the player has seen, sent when no chat
+/// message is sent for a while.
+pub struct MessageAcknowledgment760<'a> {
+    pub last_seen: Vec<PreviousMessage760<'a>>,
+    pub last_received: Option<PreviousMessage760<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since 1.19.1 the messages seen by the player are acknowledged along with
+/// the command.
+pub struct ChatCommand760<'a> {
+    /// The command without the leading slash
+    pub command: Cow<'a, str>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub argument_signatures: Vec<ArgumentSignature759<'a>>,
+    pub signed_preview: bool,
+    pub last_seen: Vec<PreviousMessage760<'a>>,
+    pub last_received: Option<PreviousMessage760<'a>>,
+}
+
+#[derive(Encoding, ToStatic, Debug)]
+/// Since 1.19.1 the messages seen by the player are acknowledged along with
+/// the message.
+pub struct ChatMessage760<'a> {
+    pub message: Cow<'a, str>,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    pub signature: Cow<'a, [u8]>,
+    pub signed_preview: bool,
+    pub last_seen: Vec<PreviousMessage760<'a>>,
+    pub last_received: Option<PreviousMessage760<'a>>,
+}
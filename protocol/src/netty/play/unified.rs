@@ -0,0 +1,2358 @@
+//! A version independent model of common play packets.
+//!
+//! [`CbPlay`] and [`SbPlay`] packets with a unified representation convert
+//! into a [`Clientbound`] or [`Serverbound`], all others are handed back by
+//! `try_from`. Lowering picks the packet type of the target version and fails
+//! with the first field that version can't carry, or with
+//! [`LowerError::Unsupported`] if the version doesn't have the packet at all.
+//!
+//! Fields only sent in some versions are `Option`s, they have to be `Some`
+//! exactly in the versions sending them.
+
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+use miners_nbt::{Compound, Nbt};
+use miners_version::ProtocolVersion;
+use uuid::Uuid;
+
+use super::clientbound::{
+    self as cb, ChatMessagePosition6, DeathLocation759, Dimension0, Dimension86, GameMode0,
+    GameMode86, PlayerProperty, PositionAndLookBitfield6,
+};
+use super::serverbound::{self as sb, ChangedSlot755, ClickAction0};
+use super::{CbPlay, Difficulty0, SbPlay};
+use crate::netty::types::angle::Angle;
+use crate::netty::types::entity_metadata::{
+    EntityMetadata346, EntityMetadata353, EntityMetadata402, EntityMetadata441, EntityMetadata463,
+    EntityMetadata57, PackedEntityMetadata0,
+};
+use crate::netty::types::position::{Position441, Position6};
+use crate::netty::types::slot::{Slot0, Slot346, Slot402};
+use crate::*;
+
+/// A relative move sent as a byte in 1/32 blocks
+const BYTE_MOVE: RangeInclusive<f64> = -4.0..=127.0 / 32.0;
+/// A relative move sent as a short in 1/4096 blocks (15w51a)
+const SHORT_MOVE: RangeInclusive<f64> = -8.0..=32767.0 / 4096.0;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LowerError {
+    #[error("the packet doesn't exist in pv{0}")]
+    Unsupported(i32),
+    #[error("{field} can't be represented in pv{version}")]
+    Unrepresentable { field: &'static str, version: i32 },
+    #[error("{field} is required in pv{version}")]
+    Missing { field: &'static str, version: i32 },
+}
+
+#[derive(Debug)]
+pub enum Clientbound<'a> {
+    KeepAlive {
+        id: i64,
+    },
+    Chat {
+        message: Cow<'a, str>,
+        /// Since pv6, only system and hotbar messages since 1.19
+        position: Option<ChatMessagePosition6>,
+        /// 1.16 to 1.18.2
+        sender: Option<Uuid>,
+    },
+    TimeUpdate {
+        world_age: i64,
+        time_of_day: i64,
+    },
+    SpawnPosition {
+        x: i32,
+        y: i32,
+        z: i32,
+        /// Since 1.17
+        angle: Option<f32>,
+    },
+    BlockChange {
+        x: i32,
+        y: i32,
+        z: i32,
+        /// `id << 4 | data` before 1.13
+        block_state: i32,
+    },
+    UpdateHealth {
+        health: f32,
+        food: i32,
+        saturation: f32,
+    },
+    HeldItemChange {
+        slot: u8,
+    },
+    EntityRelativeMove {
+        entity_id: i32,
+        dx: f64,
+        dy: f64,
+        dz: f64,
+        /// Since pv22
+        on_ground: Option<bool>,
+    },
+    EntityTeleport {
+        entity_id: i32,
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: Angle,
+        pitch: Angle,
+        /// Since pv22
+        on_ground: Option<bool>,
+    },
+    EntityVelocity {
+        entity_id: i32,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    Disconnect {
+        reason: Cow<'a, str>,
+    },
+    JoinGame {
+        entity_id: i32,
+        hardcore: bool,
+        /// Spectator since 14w05a
+        gamemode: GameMode86,
+        /// Before 1.16
+        dimension: Option<Dimension86>,
+        /// Before 1.14, sent in its own packet since
+        difficulty: Option<Difficulty0>,
+        max_players: i32,
+        /// Since pv1, before 1.16
+        level_type: Option<Cow<'a, str>>,
+        /// Since pv29
+        reduced_debug_info: Option<bool>,
+        /// Since 1.14
+        view_distance: Option<i32>,
+        /// Since 1.15
+        hashed_seed: Option<i64>,
+        /// Since 1.15
+        enable_respawn_screen: Option<bool>,
+        /// Since 1.16
+        world: Option<JoinWorld<'a>>,
+    },
+    /// Teleports the player, closes the loading screen after joining
+    PositionAndLook {
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f32,
+        pitch: f32,
+        /// Before pv6
+        on_ground: Option<bool>,
+        /// Since pv6
+        relativity: Option<PositionAndLookBitfield6>,
+        /// Since 1.9
+        teleport_id: Option<i32>,
+        /// Since 1.17
+        dismount_vehicle: Option<bool>,
+    },
+    SpawnPlayer {
+        entity_id: i32,
+        uuid: Uuid,
+        /// Before pv19
+        name: Option<Cow<'a, str>>,
+        /// pv5 and pv7 to pv18
+        properties: Option<Vec<PlayerProperty<'a>>>,
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: Angle,
+        pitch: Angle,
+        /// Before pv49
+        current_item: Option<u16>,
+        /// Before 1.15
+        metadata: Option<PlayerMetadata<'a>>,
+    },
+    /// Since 1.9, the items of older versions aren't parsed
+    SetSlot {
+        /// -1 and slot -1 for the item held by the cursor
+        window_id: i8,
+        slot: i16,
+        item: Slot<'a>,
+        /// Since 1.17.1
+        state_id: Option<i32>,
+    },
+    /// Since 1.9, the items of older versions aren't parsed
+    WindowItems {
+        window_id: u8,
+        items: Vec<Slot<'a>>,
+        /// Since 1.17.1
+        state_id: Option<i32>,
+        /// Since 1.17.1
+        carried_item: Option<Slot<'a>>,
+    },
+}
+
+/// The world and registries sent on join since 1.16
+#[derive(Debug)]
+pub struct JoinWorld<'a> {
+    /// -1 if there is no previous gamemode
+    pub previous_gamemode: i8,
+    /// Identifiers of all worlds on the server
+    pub world_names: Vec<Cow<'a, str>>,
+    /// The format of the registries changes with almost every version, it
+    /// isn't converted
+    pub registry_codec: Nbt<'a>,
+    pub dimension: DimensionType<'a>,
+    pub world_name: Cow<'a, str>,
+    /// Since 1.18
+    pub simulation_distance: Option<i32>,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// Only sent since 1.19, `None` if the player hasn't died yet
+    pub death_location: Option<DeathLocation759<'a>>,
+}
+
+#[derive(Debug)]
+pub enum DimensionType<'a> {
+    /// Identifier of one of the dimension types in the registry, in 1.16.1 and
+    /// since 1.19
+    Name(Cow<'a, str>),
+    /// The dimension type itself, 1.16.2 to 1.18.2
+    Nbt(Nbt<'a>),
+}
+
+/// The metadata of a spawned player, its format changes with the versions.
+#[derive(Debug)]
+pub enum PlayerMetadata<'a> {
+    /// Before pv57
+    Packed(PackedEntityMetadata0<'a>),
+    /// pv57 to 1.12.2
+    V57(EntityMetadata57<'a>),
+    V346(EntityMetadata346<'a>),
+    V353(EntityMetadata353<'a>),
+    V402(EntityMetadata402<'a>),
+    V441(EntityMetadata441<'a>),
+    /// Until 1.14.4, it's sent in its own packet since
+    V463(EntityMetadata463<'a>),
+}
+
+/// `None` for an empty slot
+pub type Slot<'a> = Option<Item<'a>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item<'a> {
+    pub id: i32,
+    pub count: u8,
+    /// Before 1.13, part of the nbt since
+    pub damage: Option<i16>,
+    pub nbt: Compound<'a>,
+}
+
+#[derive(Debug)]
+pub enum Serverbound<'a> {
+    KeepAlive {
+        id: i64,
+    },
+    /// Unsigned chat, signed chat messages since 1.19 have no unified
+    /// representation
+    Chat {
+        message: Cow<'a, str>,
+    },
+    HeldItemChange {
+        slot: u16,
+    },
+    Player {
+        on_ground: bool,
+    },
+    PlayerPosition {
+        x: f64,
+        /// The feet of the player
+        y: f64,
+        /// Before pv10, the y of the head
+        stance: Option<f64>,
+        z: f64,
+        on_ground: bool,
+    },
+    PlayerLook {
+        yaw: f32,
+        pitch: f32,
+        on_ground: bool,
+    },
+    PlayerPositionAndLook {
+        x: f64,
+        y: f64,
+        /// Before pv10, the y of the head
+        stance: Option<f64>,
+        z: f64,
+        yaw: f32,
+        pitch: f32,
+        on_ground: bool,
+    },
+    /// Since 1.9
+    TeleportConfirm {
+        teleport_id: i32,
+    },
+    CloseWindow {
+        window_id: u8,
+    },
+    /// Since 1.9, the items of older versions aren't parsed
+    ClickWindow {
+        window_id: u8,
+        action: ClickAction0,
+        /// Before 1.17
+        action_id: Option<i16>,
+        /// Before 1.17
+        clicked_item: Option<Slot<'a>>,
+        /// Since 1.17.1
+        state_id: Option<i32>,
+        /// Since 1.17, the new items of all changed slots
+        changed_slots: Option<Vec<(i16, Slot<'a>)>>,
+        /// Since 1.17
+        carried_item: Option<Slot<'a>>,
+    },
+    /// Since 1.9, the items of older versions aren't parsed
+    CreativeInventoryAction {
+        slot: i16,
+        item: Slot<'a>,
+    },
+}
+
+impl<'a> TryFrom<CbPlay<'a>> for Clientbound<'a> {
+    type Error = CbPlay<'a>;
+
+    fn try_from(packet: CbPlay<'a>) -> Result<Self, Self::Error> {
+        use CbPlay::*;
+        Ok(match packet {
+            KeepAlive0(p) => Self::KeepAlive { id: p.id as i64 },
+            KeepAlive32(p) => Self::KeepAlive { id: p.id as i64 },
+            KeepAlive339(p) => Self::KeepAlive { id: p.id },
+            ChatMessage0(p) => Self::Chat {
+                message: p.message,
+                position: None,
+                sender: None,
+            },
+            ChatMessage6(p) => Self::Chat {
+                message: p.message,
+                position: Some(p.position),
+                sender: None,
+            },
+            ChatMessage721(p) => Self::Chat {
+                message: p.message,
+                position: Some(p.position),
+                sender: Some(p.sender),
+            },
+            SystemChatMessage759(p) if p.kind == 1 || p.kind == 2 => Self::Chat {
+                message: p.content,
+                position: Some(match p.kind {
+                    1 => ChatMessagePosition6::System,
+                    _ => ChatMessagePosition6::Hotbar,
+                }),
+                sender: None,
+            },
+            SystemChatMessage760(p) => Self::Chat {
+                message: p.content,
+                position: Some(match p.overlay {
+                    false => ChatMessagePosition6::System,
+                    true => ChatMessagePosition6::Hotbar,
+                }),
+                sender: None,
+            },
+            TimeUpdate0(p) => Self::TimeUpdate {
+                world_age: p.ticks,
+                time_of_day: p.time_of_day,
+            },
+            SpawnPosition0(p) => Self::SpawnPosition {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                angle: None,
+            },
+            SpawnPosition6(p) => Self::SpawnPosition {
+                x: p.x,
+                y: p.y as i32,
+                z: p.z,
+                angle: None,
+            },
+            SpawnPosition441(p) => Self::SpawnPosition {
+                x: p.location.x,
+                y: p.location.y as i32,
+                z: p.location.z,
+                angle: None,
+            },
+            SpawnPosition755(p) => Self::SpawnPosition {
+                x: p.location.x,
+                y: p.location.y as i32,
+                z: p.location.z,
+                angle: Some(p.angle),
+            },
+            BlockChange0(p) => Self::BlockChange {
+                x: p.x,
+                y: p.y as i32,
+                z: p.z,
+                block_state: p.block_type << 4 | (p.block_data & 0b1111) as i32,
+            },
+            BlockChange6(p) => Self::BlockChange {
+                x: p.location.x,
+                y: p.location.y as i32,
+                z: p.location.z,
+                block_state: p.block_type << 4 | (p.block_data & 0b1111) as i32,
+            },
+            BlockChange25(p) => Self::BlockChange {
+                x: p.location.x,
+                y: p.location.y as i32,
+                z: p.location.z,
+                block_state: p.block_id,
+            },
+            BlockChange441(p) => Self::BlockChange {
+                x: p.location.x,
+                y: p.location.y as i32,
+                z: p.location.z,
+                block_state: p.block_id,
+            },
+            UpdateHealth0(p) => Self::UpdateHealth {
+                health: p.health,
+                food: p.food as i32,
+                saturation: p.saturation,
+            },
+            UpdateHealth7(p) => Self::UpdateHealth {
+                health: p.health,
+                food: p.food,
+                saturation: p.saturation,
+            },
+            HeldItemChange0(p) => Self::HeldItemChange { slot: p.slot },
+            EntityRelativeMove0(p) => Self::EntityRelativeMove {
+                entity_id: p.entity_id,
+                dx: p.dx as f64,
+                dy: p.dy as f64,
+                dz: p.dz as f64,
+                on_ground: None,
+            },
+            EntityRelativeMove7(p) => Self::EntityRelativeMove {
+                entity_id: p.entity_id,
+                dx: p.dx as f64,
+                dy: p.dy as f64,
+                dz: p.dz as f64,
+                on_ground: None,
+            },
+            EntityRelativeMove22(p) => Self::EntityRelativeMove {
+                entity_id: p.entity_id,
+                dx: p.dx as f64,
+                dy: p.dy as f64,
+                dz: p.dz as f64,
+                on_ground: Some(p.on_ground),
+            },
+            EntityRelativeMove100(p) => Self::EntityRelativeMove {
+                entity_id: p.entity_id,
+                dx: p.dx,
+                dy: p.dy,
+                dz: p.dz,
+                on_ground: Some(p.on_ground),
+            },
+            EntityTeleport0(p) => Self::EntityTeleport {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: None,
+            },
+            EntityTeleport7(p) => Self::EntityTeleport {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: None,
+            },
+            EntityTeleport22(p) => Self::EntityTeleport {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: Some(p.on_ground),
+            },
+            EntityTeleport100(p) => Self::EntityTeleport {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: Some(p.on_ground),
+            },
+            EntityVelocity0(p) => Self::EntityVelocity {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+            },
+            EntityVelocity7(p) => Self::EntityVelocity {
+                entity_id: p.entity_id,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+            },
+            Disconnect0(p) => Self::Disconnect { reason: p.reason },
+            JoinGame0(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: gamemode86(p.gamemode),
+                dimension: Some(dimension86(p.dimension)),
+                difficulty: Some(p.difficulty),
+                max_players: p.max_players as i32,
+                level_type: None,
+                reduced_debug_info: None,
+                view_distance: None,
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame1(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: gamemode86(p.gamemode),
+                dimension: Some(dimension86(p.dimension)),
+                difficulty: Some(p.difficulty),
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: None,
+                view_distance: None,
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame29(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: gamemode86(p.gamemode),
+                dimension: Some(dimension86(p.dimension)),
+                difficulty: Some(p.difficulty),
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: None,
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame86(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: Some(dimension86(p.dimension)),
+                difficulty: Some(p.difficulty),
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: None,
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame108(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: Some(p.dimension),
+                difficulty: Some(p.difficulty),
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: None,
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame468(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: Some(p.dimension),
+                difficulty: None,
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: None,
+                enable_respawn_screen: None,
+                world: None,
+            },
+            JoinGame552(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: Some(p.dimension),
+                difficulty: None,
+                max_players: p.max_players as i32,
+                level_type: Some(p.level_type),
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: Some(p.hashed_seed),
+                enable_respawn_screen: Some(p.enable_respawn_screen),
+                world: None,
+            },
+            JoinGame730(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: None,
+                difficulty: None,
+                max_players: p.max_players as i32,
+                level_type: None,
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: Some(p.hashed_seed),
+                enable_respawn_screen: Some(p.enable_respawn_screen),
+                world: Some(JoinWorld {
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    registry_codec: p.dimension_codec,
+                    dimension: DimensionType::Name(p.dimension),
+                    world_name: p.world_name,
+                    simulation_distance: None,
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                    death_location: None,
+                }),
+            },
+            JoinGame748(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: None,
+                difficulty: None,
+                max_players: p.max_players,
+                level_type: None,
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: Some(p.hashed_seed),
+                enable_respawn_screen: Some(p.enable_respawn_screen),
+                world: Some(JoinWorld {
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    registry_codec: p.dimension_codec,
+                    dimension: DimensionType::Nbt(p.dimension),
+                    world_name: p.world_name,
+                    simulation_distance: None,
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                    death_location: None,
+                }),
+            },
+            JoinGame757(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: None,
+                difficulty: None,
+                max_players: p.max_players,
+                level_type: None,
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: Some(p.hashed_seed),
+                enable_respawn_screen: Some(p.enable_respawn_screen),
+                world: Some(JoinWorld {
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    registry_codec: p.dimension_codec,
+                    dimension: DimensionType::Nbt(p.dimension),
+                    world_name: p.world_name,
+                    simulation_distance: Some(p.simulation_distance),
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                    death_location: None,
+                }),
+            },
+            JoinGame759(p) => Self::JoinGame {
+                entity_id: p.entity_id,
+                hardcore: p.hardcore,
+                gamemode: p.gamemode,
+                dimension: None,
+                difficulty: None,
+                max_players: p.max_players,
+                level_type: None,
+                reduced_debug_info: Some(p.reduced_debug_info),
+                view_distance: Some(p.view_distance),
+                hashed_seed: Some(p.hashed_seed),
+                enable_respawn_screen: Some(p.enable_respawn_screen),
+                world: Some(JoinWorld {
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    registry_codec: p.registry_codec,
+                    dimension: DimensionType::Name(p.dimension_type),
+                    world_name: p.world_name,
+                    simulation_distance: Some(p.simulation_distance),
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                    death_location: p.death_location,
+                }),
+            },
+            PositionAndLook0(p) => Self::PositionAndLook {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: Some(p.on_ground),
+                relativity: None,
+                teleport_id: None,
+                dismount_vehicle: None,
+            },
+            PositionAndLook6(p) => Self::PositionAndLook {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: None,
+                relativity: Some(p.relativity),
+                teleport_id: None,
+                dismount_vehicle: None,
+            },
+            PositionAndLook86(p) => Self::PositionAndLook {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: None,
+                relativity: Some(p.relativity),
+                teleport_id: Some(p.teleport_id),
+                dismount_vehicle: None,
+            },
+            PositionAndLook755(p) => Self::PositionAndLook {
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: None,
+                relativity: Some(p.relativity),
+                teleport_id: Some(p.teleport_id),
+                dismount_vehicle: Some(p.dismount_vehicle),
+            },
+            SpawnPlayer0(p) => Self::SpawnPlayer {
+                entity_id: p.entity_id,
+                uuid: p.player_uuid.into_inner().unwrap_or_default(),
+                name: Some(p.name),
+                properties: None,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                current_item: Some(p.current_item),
+                metadata: Some(PlayerMetadata::Packed(p.metadata)),
+            },
+            SpawnPlayer5(p) => Self::SpawnPlayer {
+                entity_id: p.entity_id,
+                uuid: p.player_uuid.into_inner().unwrap_or_default(),
+                name: Some(p.name),
+                properties: Some(p.properties),
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                current_item: Some(p.current_item),
+                metadata: Some(PlayerMetadata::Packed(p.metadata)),
+            },
+            SpawnPlayer19(p) => Self::SpawnPlayer {
+                entity_id: p.entity_id,
+                uuid: p.player_uuid,
+                name: None,
+                properties: None,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                current_item: Some(p.current_item),
+                metadata: Some(PlayerMetadata::Packed(p.metadata)),
+            },
+            SpawnPlayer49(p) => spawn_player(p, PlayerMetadata::Packed),
+            SpawnPlayer57(p) => spawn_player(p, PlayerMetadata::V57),
+            SpawnPlayer100(p) => spawn_player100(p, PlayerMetadata::V57),
+            SpawnPlayer346(p) => spawn_player100(p, PlayerMetadata::V346),
+            SpawnPlayer353(p) => spawn_player100(p, PlayerMetadata::V353),
+            SpawnPlayer402(p) => spawn_player100(p, PlayerMetadata::V402),
+            SpawnPlayer441(p) => spawn_player100(p, PlayerMetadata::V441),
+            SpawnPlayer463(p) => spawn_player100(p, PlayerMetadata::V463),
+            SpawnPlayer550(p) => Self::SpawnPlayer {
+                entity_id: p.entity_id,
+                uuid: p.player_uuid,
+                name: None,
+                properties: None,
+                x: p.x,
+                y: p.y,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                current_item: None,
+                metadata: None,
+            },
+            SetSlot67(p) => Self::SetSlot {
+                window_id: p.window_id,
+                slot: p.slot,
+                item: from_slot0(p.item),
+                state_id: None,
+            },
+            SetSlot346(p) => Self::SetSlot {
+                window_id: p.window_id,
+                slot: p.slot,
+                item: from_slot346(p.item),
+                state_id: None,
+            },
+            SetSlot402(p) => Self::SetSlot {
+                window_id: p.window_id,
+                slot: p.slot,
+                item: from_slot402(p.item),
+                state_id: None,
+            },
+            SetSlot756(p) => Self::SetSlot {
+                window_id: p.window_id,
+                slot: p.slot,
+                item: from_slot402(p.item),
+                state_id: Some(p.state_id),
+            },
+            WindowItems67(p) => Self::WindowItems {
+                window_id: p.window_id,
+                items: p.slots.into_iter().map(from_slot0).collect(),
+                state_id: None,
+                carried_item: None,
+            },
+            WindowItems346(p) => Self::WindowItems {
+                window_id: p.window_id,
+                items: p.slots.into_iter().map(from_slot346).collect(),
+                state_id: None,
+                carried_item: None,
+            },
+            WindowItems402(p) => Self::WindowItems {
+                window_id: p.window_id,
+                items: p.slots.into_iter().map(from_slot402).collect(),
+                state_id: None,
+                carried_item: None,
+            },
+            WindowItems756(p) => Self::WindowItems {
+                window_id: p.window_id,
+                items: p.items.into_iter().map(from_slot402).collect(),
+                state_id: Some(p.state_id),
+                carried_item: Some(from_slot402(p.carried_item)),
+            },
+            packet => return Err(packet),
+        })
+    }
+}
+
+impl<'a> TryFrom<SbPlay<'a>> for Serverbound<'a> {
+    type Error = SbPlay<'a>;
+
+    fn try_from(packet: SbPlay<'a>) -> Result<Self, Self::Error> {
+        use SbPlay::*;
+        Ok(match packet {
+            KeepAlive0(p) => Self::KeepAlive { id: p.id as i64 },
+            KeepAlive7(p) => Self::KeepAlive { id: p.id as i64 },
+            KeepAlive339(p) => Self::KeepAlive { id: p.id },
+            ChatMessage0(p) => Self::Chat { message: p.message },
+            HeldItemChange0(p) => Self::HeldItemChange { slot: p.slot },
+            Player0(p) => Self::Player {
+                on_ground: p.on_ground,
+            },
+            PlayerPosition0(p) => Self::PlayerPosition {
+                x: p.x,
+                y: p.y,
+                stance: Some(p.stance),
+                z: p.z,
+                on_ground: p.on_ground,
+            },
+            PlayerPosition10(p) => Self::PlayerPosition {
+                x: p.x,
+                y: p.y,
+                stance: None,
+                z: p.z,
+                on_ground: p.on_ground,
+            },
+            PlayerLook0(p) => Self::PlayerLook {
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: p.on_ground,
+            },
+            PlayerPositionAndLook0(p) => Self::PlayerPositionAndLook {
+                x: p.x,
+                y: p.y,
+                stance: Some(p.stance),
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: p.on_ground,
+            },
+            PlayerPositionAndLook10(p) => Self::PlayerPositionAndLook {
+                x: p.x,
+                y: p.y,
+                stance: None,
+                z: p.z,
+                yaw: p.yaw,
+                pitch: p.pitch,
+                on_ground: p.on_ground,
+            },
+            TeleportConfirm80(p) => Self::TeleportConfirm {
+                teleport_id: p.teleport_id,
+            },
+            CloseWindow0(p) => Self::CloseWindow {
+                window_id: p.window_id,
+            },
+            ClickWindow83(p) => Self::ClickWindow {
+                window_id: p.window_id,
+                action: p.action,
+                action_id: Some(p.action_id),
+                clicked_item: Some(from_slot0(p.clicked_item)),
+                state_id: None,
+                changed_slots: None,
+                carried_item: None,
+            },
+            ClickWindow346(p) => Self::ClickWindow {
+                window_id: p.window_id,
+                action: p.action,
+                action_id: Some(p.action_id),
+                clicked_item: Some(from_slot346(p.clicked_item)),
+                state_id: None,
+                changed_slots: None,
+                carried_item: None,
+            },
+            ClickWindow402(p) => Self::ClickWindow {
+                window_id: p.window_id,
+                action: p.action,
+                action_id: Some(p.action_id),
+                clicked_item: Some(from_slot402(p.clicked_item)),
+                state_id: None,
+                changed_slots: None,
+                carried_item: None,
+            },
+            ClickWindow755(p) => Self::ClickWindow {
+                window_id: p.window_id,
+                action: p.action,
+                action_id: None,
+                clicked_item: None,
+                state_id: None,
+                changed_slots: Some(from_changed_slots(p.changed_slots)),
+                carried_item: Some(from_slot402(p.carried_item)),
+            },
+            ClickWindow756(p) => Self::ClickWindow {
+                window_id: p.window_id,
+                action: p.action,
+                action_id: None,
+                clicked_item: None,
+                state_id: Some(p.state_id),
+                changed_slots: Some(from_changed_slots(p.changed_slots)),
+                carried_item: Some(from_slot402(p.carried_item)),
+            },
+            CreativeInventoryAction80(p) => Self::CreativeInventoryAction {
+                slot: p.slot,
+                item: from_slot0(p.clicked_item),
+            },
+            CreativeInventoryAction346(p) => Self::CreativeInventoryAction {
+                slot: p.slot,
+                item: from_slot346(p.clicked_item),
+            },
+            CreativeInventoryAction402(p) => Self::CreativeInventoryAction {
+                slot: p.slot,
+                item: from_slot402(p.clicked_item),
+            },
+            packet => return Err(packet),
+        })
+    }
+}
+
+fn spawn_player<'a, M>(
+    packet: cb::SpawnPlayer49<M>,
+    metadata: impl FnOnce(M) -> PlayerMetadata<'a>,
+) -> Clientbound<'a> {
+    Clientbound::SpawnPlayer {
+        entity_id: packet.entity_id,
+        uuid: packet.player_uuid,
+        name: None,
+        properties: None,
+        x: packet.x,
+        y: packet.y,
+        z: packet.z,
+        yaw: packet.yaw,
+        pitch: packet.pitch,
+        current_item: None,
+        metadata: Some(metadata(packet.metadata)),
+    }
+}
+
+fn spawn_player100<'a, M>(
+    packet: cb::SpawnPlayer100<M>,
+    metadata: impl FnOnce(M) -> PlayerMetadata<'a>,
+) -> Clientbound<'a> {
+    Clientbound::SpawnPlayer {
+        entity_id: packet.entity_id,
+        uuid: packet.player_uuid,
+        name: None,
+        properties: None,
+        x: packet.x,
+        y: packet.y,
+        z: packet.z,
+        yaw: packet.yaw,
+        pitch: packet.pitch,
+        current_item: None,
+        metadata: Some(metadata(packet.metadata)),
+    }
+}
+
+fn gamemode86(gamemode: GameMode0) -> GameMode86 {
+    match gamemode {
+        GameMode0::Survival => GameMode86::Survival,
+        GameMode0::Creative => GameMode86::Creative,
+        GameMode0::Adventure => GameMode86::Adventure,
+    }
+}
+
+fn dimension86(dimension: Dimension0) -> Dimension86 {
+    match dimension {
+        Dimension0::Nether => Dimension86::Nether,
+        Dimension0::Overworld => Dimension86::Overworld,
+        Dimension0::End => Dimension86::End,
+    }
+}
+
+fn from_slot0(slot: Slot0) -> Slot {
+    match slot {
+        Slot0::Empty => None,
+        Slot0::Item {
+            block_id,
+            count,
+            damage,
+            nbt,
+        } => Some(Item {
+            id: block_id as i32,
+            count,
+            damage: Some(damage),
+            nbt,
+        }),
+    }
+}
+
+fn from_slot346(slot: Slot346) -> Slot {
+    match slot {
+        Slot346::Empty => None,
+        Slot346::Item { id, count, nbt } => Some(Item {
+            id: id as i32,
+            count,
+            damage: None,
+            nbt,
+        }),
+    }
+}
+
+fn from_slot402(slot: Slot402) -> Slot {
+    match slot {
+        Slot402::Empty => None,
+        Slot402::Item { id, count, nbt } => Some(Item {
+            id,
+            count,
+            damage: None,
+            nbt,
+        }),
+    }
+}
+
+fn from_changed_slots(changed: Vec<ChangedSlot755<Slot402>>) -> Vec<(i16, Slot)> {
+    changed
+        .into_iter()
+        .map(|changed| (changed.slot, from_slot402(changed.item)))
+        .collect()
+}
+
+struct Lowering {
+    version: i32,
+}
+
+impl Lowering {
+    fn unrepresentable(&self, field: &'static str) -> LowerError {
+        LowerError::Unrepresentable {
+            field,
+            version: self.version,
+        }
+    }
+
+    fn absent<T>(&self, value: &Option<T>, field: &'static str) -> Result<(), LowerError> {
+        match value {
+            Some(_) => Err(self.unrepresentable(field)),
+            None => Ok(()),
+        }
+    }
+
+    fn required<T>(&self, value: Option<T>, field: &'static str) -> Result<T, LowerError> {
+        value.ok_or(LowerError::Missing {
+            field,
+            version: self.version,
+        })
+    }
+
+    fn convert<T, U: TryFrom<T>>(&self, value: T, field: &'static str) -> Result<U, LowerError> {
+        U::try_from(value).map_err(|_| self.unrepresentable(field))
+    }
+
+    /// Checks that `value` fits into a signed integer of `bits` bits.
+    fn bits(&self, value: i32, bits: u32, field: &'static str) -> Result<i32, LowerError> {
+        let max = 1 << (bits - 1);
+        if (-max..max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(self.unrepresentable(field))
+        }
+    }
+
+    fn position6(&self, x: i32, y: i32, z: i32) -> Result<Position6, LowerError> {
+        Ok(Position6 {
+            x: self.bits(x, 26, "x")?,
+            y: self.bits(y, 12, "y")? as i16,
+            z: self.bits(z, 26, "z")?,
+        })
+    }
+
+    fn relative_move(
+        &self,
+        delta: f64,
+        range: RangeInclusive<f64>,
+        field: &'static str,
+    ) -> Result<f64, LowerError> {
+        if range.contains(&delta) {
+            Ok(delta)
+        } else {
+            Err(self.unrepresentable(field))
+        }
+    }
+
+    fn gamemode0(&self, gamemode: GameMode86) -> Result<GameMode0, LowerError> {
+        Ok(match gamemode {
+            GameMode86::Survival => GameMode0::Survival,
+            GameMode86::Creative => GameMode0::Creative,
+            GameMode86::Adventure => GameMode0::Adventure,
+            GameMode86::Spectator => return Err(self.unrepresentable("gamemode")),
+        })
+    }
+
+    fn dimension0(&self, dimension: Option<Dimension86>) -> Result<Dimension0, LowerError> {
+        Ok(match self.required(dimension, "dimension")? {
+            Dimension86::Nether => Dimension0::Nether,
+            Dimension86::Overworld => Dimension0::Overworld,
+            Dimension86::End => Dimension0::End,
+        })
+    }
+
+    fn slot0<'a>(&self, slot: Slot<'a>) -> Result<Slot0<'a>, LowerError> {
+        Ok(match slot {
+            None => Slot0::Empty,
+            Some(item) => Slot0::Item {
+                block_id: self.convert(item.id, "item id")?,
+                count: item.count,
+                damage: self.required(item.damage, "damage")?,
+                nbt: item.nbt,
+            },
+        })
+    }
+
+    fn slot346<'a>(&self, slot: Slot<'a>) -> Result<Slot346<'a>, LowerError> {
+        Ok(match slot {
+            None => Slot346::Empty,
+            Some(item) => {
+                self.absent(&item.damage, "damage")?;
+                Slot346::Item {
+                    id: self.convert(item.id, "item id")?,
+                    count: item.count,
+                    nbt: item.nbt,
+                }
+            }
+        })
+    }
+
+    fn slot402<'a>(&self, slot: Slot<'a>) -> Result<Slot402<'a>, LowerError> {
+        Ok(match slot {
+            None => Slot402::Empty,
+            Some(item) => {
+                self.absent(&item.damage, "damage")?;
+                Slot402::Item {
+                    id: item.id,
+                    count: item.count,
+                    nbt: item.nbt,
+                }
+            }
+        })
+    }
+
+    fn slots<'a, S>(
+        &self,
+        slots: Vec<Slot<'a>>,
+        lower: impl Fn(&Self, Slot<'a>) -> Result<S, LowerError>,
+    ) -> Result<Vec<S>, LowerError> {
+        slots.into_iter().map(|slot| lower(self, slot)).collect()
+    }
+}
+
+impl<'a> Clientbound<'a> {
+    /// Converts the packet into the packet type of `version`.
+    pub fn lower(self, version: ProtocolVersion) -> Result<CbPlay<'a>, LowerError> {
+        let pv = *version;
+        let l = Lowering { version: pv };
+        let packet = match self {
+            Self::KeepAlive { id } => match pv {
+                339.. => CbPlay::KeepAlive339(cb::KeepAlive339 { id }),
+                32.. => CbPlay::KeepAlive32(cb::KeepAlive32 {
+                    id: l.convert(id, "id")?,
+                }),
+                _ => CbPlay::KeepAlive0(cb::KeepAlive0 {
+                    id: l.convert(id, "id")?,
+                }),
+            },
+            Self::Chat {
+                message,
+                position,
+                sender,
+            } => match pv {
+                759.. => {
+                    l.absent(&sender, "sender")?;
+                    // chat positions are signed player messages since 1.19
+                    let overlay = match l.required(position, "position")? {
+                        ChatMessagePosition6::Chat => return Err(l.unrepresentable("position")),
+                        ChatMessagePosition6::System => false,
+                        ChatMessagePosition6::Hotbar => true,
+                    };
+                    if pv == 759 {
+                        CbPlay::SystemChatMessage759(cb::SystemChatMessage759 {
+                            content: message,
+                            kind: if overlay { 2 } else { 1 },
+                        })
+                    } else {
+                        CbPlay::SystemChatMessage760(cb::SystemChatMessage760 {
+                            content: message,
+                            overlay,
+                        })
+                    }
+                }
+                721.. => CbPlay::ChatMessage721(cb::ChatMessage721 {
+                    message,
+                    position: l.required(position, "position")?,
+                    sender: l.required(sender, "sender")?,
+                }),
+                6.. => {
+                    l.absent(&sender, "sender")?;
+                    CbPlay::ChatMessage6(cb::ChatMessage6 {
+                        message,
+                        position: l.required(position, "position")?,
+                    })
+                }
+                _ => {
+                    l.absent(&position, "position")?;
+                    l.absent(&sender, "sender")?;
+                    CbPlay::ChatMessage0(cb::ChatMessage0 { message })
+                }
+            },
+            Self::TimeUpdate {
+                world_age,
+                time_of_day,
+            } => CbPlay::TimeUpdate0(cb::TimeUpdate0 {
+                ticks: world_age,
+                time_of_day,
+            }),
+            Self::SpawnPosition { x, y, z, angle } => match pv {
+                755.. => CbPlay::SpawnPosition755(cb::SpawnPosition755 {
+                    location: l.position6(x, y, z)?.into(),
+                    angle: l.required(angle, "angle")?,
+                }),
+                441.. => {
+                    l.absent(&angle, "angle")?;
+                    CbPlay::SpawnPosition441(cb::SpawnPosition441 {
+                        location: l.position6(x, y, z)?.into(),
+                    })
+                }
+                6.. => {
+                    l.absent(&angle, "angle")?;
+                    let Position6 { x, y, z } = l.position6(x, y, z)?;
+                    CbPlay::SpawnPosition6(cb::SpawnPosition6 { x, y, z })
+                }
+                _ => {
+                    l.absent(&angle, "angle")?;
+                    CbPlay::SpawnPosition0(cb::SpawnPosition0 { x, y, z })
+                }
+            },
+            Self::BlockChange {
+                x,
+                y,
+                z,
+                block_state,
+            } => match pv {
+                441.. => CbPlay::BlockChange441(cb::BlockChange441 {
+                    location: Position441::from(l.position6(x, y, z)?),
+                    block_id: block_state,
+                }),
+                25.. => CbPlay::BlockChange25(cb::BlockChange25 {
+                    location: l.position6(x, y, z)?,
+                    block_id: block_state,
+                }),
+                6.. => CbPlay::BlockChange6(cb::BlockChange6 {
+                    location: l.position6(x, y, z)?,
+                    block_type: block_state >> 4,
+                    block_data: (block_state & 0b1111) as u8,
+                }),
+                _ => CbPlay::BlockChange0(cb::BlockChange0 {
+                    x,
+                    y: l.convert(y, "y")?,
+                    z,
+                    block_type: block_state >> 4,
+                    block_data: (block_state & 0b1111) as u8,
+                }),
+            },
+            Self::UpdateHealth {
+                health,
+                food,
+                saturation,
+            } => match pv {
+                7.. => CbPlay::UpdateHealth7(cb::UpdateHealth7 {
+                    health,
+                    food,
+                    saturation,
+                }),
+                _ => CbPlay::UpdateHealth0(cb::UpdateHealth0 {
+                    health,
+                    food: l.convert(food, "food")?,
+                    saturation,
+                }),
+            },
+            Self::HeldItemChange { slot } => CbPlay::HeldItemChange0(cb::HeldItemChange0 { slot }),
+            Self::EntityRelativeMove {
+                entity_id,
+                dx,
+                dy,
+                dz,
+                on_ground,
+            } => match pv {
+                100.. => CbPlay::EntityRelativeMove100(cb::EntityRelativeMove100 {
+                    entity_id,
+                    dx: l.relative_move(dx, SHORT_MOVE, "dx")?,
+                    dy: l.relative_move(dy, SHORT_MOVE, "dy")?,
+                    dz: l.relative_move(dz, SHORT_MOVE, "dz")?,
+                    on_ground: l.required(on_ground, "on_ground")?,
+                }),
+                22.. => CbPlay::EntityRelativeMove22(cb::EntityRelativeMove22 {
+                    entity_id,
+                    dx: l.relative_move(dx, BYTE_MOVE, "dx")? as f32,
+                    dy: l.relative_move(dy, BYTE_MOVE, "dy")? as f32,
+                    dz: l.relative_move(dz, BYTE_MOVE, "dz")? as f32,
+                    on_ground: l.required(on_ground, "on_ground")?,
+                }),
+                7.. => {
+                    l.absent(&on_ground, "on_ground")?;
+                    CbPlay::EntityRelativeMove7(cb::EntityRelativeMove7 {
+                        entity_id,
+                        dx: l.relative_move(dx, BYTE_MOVE, "dx")? as f32,
+                        dy: l.relative_move(dy, BYTE_MOVE, "dy")? as f32,
+                        dz: l.relative_move(dz, BYTE_MOVE, "dz")? as f32,
+                    })
+                }
+                _ => {
+                    l.absent(&on_ground, "on_ground")?;
+                    CbPlay::EntityRelativeMove0(cb::EntityRelativeMove0 {
+                        entity_id,
+                        dx: l.relative_move(dx, BYTE_MOVE, "dx")? as f32,
+                        dy: l.relative_move(dy, BYTE_MOVE, "dy")? as f32,
+                        dz: l.relative_move(dz, BYTE_MOVE, "dz")? as f32,
+                    })
+                }
+            },
+            Self::EntityTeleport {
+                entity_id,
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+                on_ground,
+            } => match pv {
+                100.. => CbPlay::EntityTeleport100(cb::EntityTeleport100 {
+                    entity_id,
+                    x,
+                    y,
+                    z,
+                    yaw,
+                    pitch,
+                    on_ground: l.required(on_ground, "on_ground")?,
+                }),
+                22.. => CbPlay::EntityTeleport22(cb::EntityTeleport22 {
+                    entity_id,
+                    x,
+                    y,
+                    z,
+                    yaw,
+                    pitch,
+                    on_ground: l.required(on_ground, "on_ground")?,
+                }),
+                7.. => {
+                    l.absent(&on_ground, "on_ground")?;
+                    CbPlay::EntityTeleport7(cb::EntityTeleport7 {
+                        entity_id,
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                    })
+                }
+                _ => {
+                    l.absent(&on_ground, "on_ground")?;
+                    CbPlay::EntityTeleport0(cb::EntityTeleport0 {
+                        entity_id,
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                    })
+                }
+            },
+            Self::EntityVelocity { entity_id, x, y, z } => match pv {
+                7.. => CbPlay::EntityVelocity7(cb::EntityVelocity7 { entity_id, x, y, z }),
+                _ => CbPlay::EntityVelocity0(cb::EntityVelocity0 { entity_id, x, y, z }),
+            },
+            Self::Disconnect { reason } => CbPlay::Disconnect0(cb::Disconnect0 { reason }),
+            Self::JoinGame {
+                entity_id,
+                hardcore,
+                gamemode,
+                dimension,
+                difficulty,
+                max_players,
+                level_type,
+                reduced_debug_info,
+                view_distance,
+                hashed_seed,
+                enable_respawn_screen,
+                world,
+            } => {
+                if pv < 730 {
+                    l.absent(&world, "world")?;
+                } else {
+                    l.absent(&dimension, "dimension")?;
+                    l.absent(&level_type, "level_type")?;
+                }
+                if pv >= 468 {
+                    l.absent(&difficulty, "difficulty")?;
+                }
+                match pv {
+                    730.. => {
+                        let world = l.required(world, "world")?;
+                        let reduced_debug_info =
+                            l.required(reduced_debug_info, "reduced_debug_info")?;
+                        let view_distance = l.required(view_distance, "view_distance")?;
+                        let hashed_seed = l.required(hashed_seed, "hashed_seed")?;
+                        let enable_respawn_screen =
+                            l.required(enable_respawn_screen, "enable_respawn_screen")?;
+                        if pv < 759 {
+                            l.absent(&world.death_location, "death_location")?;
+                        }
+                        match (pv, world.dimension) {
+                            (759.., DimensionType::Name(dimension_type)) => {
+                                CbPlay::JoinGame759(cb::JoinGame759 {
+                                    entity_id,
+                                    hardcore,
+                                    gamemode,
+                                    previous_gamemode: world.previous_gamemode,
+                                    world_names: world.world_names,
+                                    registry_codec: world.registry_codec,
+                                    dimension_type,
+                                    world_name: world.world_name,
+                                    hashed_seed,
+                                    max_players,
+                                    view_distance,
+                                    simulation_distance: l.required(
+                                        world.simulation_distance,
+                                        "simulation_distance",
+                                    )?,
+                                    reduced_debug_info,
+                                    enable_respawn_screen,
+                                    is_debug: world.is_debug,
+                                    is_flat: world.is_flat,
+                                    death_location: world.death_location,
+                                })
+                            }
+                            (757..=758, DimensionType::Nbt(dimension)) => {
+                                CbPlay::JoinGame757(cb::JoinGame757 {
+                                    entity_id,
+                                    hardcore,
+                                    gamemode,
+                                    previous_gamemode: world.previous_gamemode,
+                                    world_names: world.world_names,
+                                    dimension_codec: world.registry_codec,
+                                    dimension,
+                                    world_name: world.world_name,
+                                    hashed_seed,
+                                    max_players,
+                                    view_distance,
+                                    simulation_distance: l.required(
+                                        world.simulation_distance,
+                                        "simulation_distance",
+                                    )?,
+                                    reduced_debug_info,
+                                    enable_respawn_screen,
+                                    is_debug: world.is_debug,
+                                    is_flat: world.is_flat,
+                                })
+                            }
+                            (748..=756, DimensionType::Nbt(dimension)) => {
+                                l.absent(&world.simulation_distance, "simulation_distance")?;
+                                CbPlay::JoinGame748(cb::JoinGame748 {
+                                    entity_id,
+                                    hardcore,
+                                    gamemode,
+                                    previous_gamemode: world.previous_gamemode,
+                                    world_names: world.world_names,
+                                    dimension_codec: world.registry_codec,
+                                    dimension,
+                                    world_name: world.world_name,
+                                    hashed_seed,
+                                    max_players,
+                                    view_distance,
+                                    reduced_debug_info,
+                                    enable_respawn_screen,
+                                    is_debug: world.is_debug,
+                                    is_flat: world.is_flat,
+                                })
+                            }
+                            (..=747, DimensionType::Name(dimension)) => {
+                                l.absent(&world.simulation_distance, "simulation_distance")?;
+                                CbPlay::JoinGame730(cb::JoinGame730 {
+                                    entity_id,
+                                    hardcore,
+                                    gamemode,
+                                    previous_gamemode: world.previous_gamemode,
+                                    world_names: world.world_names,
+                                    dimension_codec: world.registry_codec,
+                                    dimension,
+                                    world_name: world.world_name,
+                                    hashed_seed,
+                                    max_players: l.convert(max_players, "max_players")?,
+                                    view_distance,
+                                    reduced_debug_info,
+                                    enable_respawn_screen,
+                                    is_debug: world.is_debug,
+                                    is_flat: world.is_flat,
+                                })
+                            }
+                            _ => return Err(l.unrepresentable("dimension")),
+                        }
+                    }
+                    552.. => CbPlay::JoinGame552(cb::JoinGame552 {
+                        entity_id,
+                        hardcore,
+                        gamemode,
+                        dimension: l.required(dimension, "dimension")?,
+                        hashed_seed: l.required(hashed_seed, "hashed_seed")?,
+                        max_players: l.convert(max_players, "max_players")?,
+                        level_type: l.required(level_type, "level_type")?,
+                        view_distance: l.required(view_distance, "view_distance")?,
+                        reduced_debug_info: l.required(reduced_debug_info, "reduced_debug_info")?,
+                        enable_respawn_screen: l
+                            .required(enable_respawn_screen, "enable_respawn_screen")?,
+                    }),
+                    _ => {
+                        l.absent(&hashed_seed, "hashed_seed")?;
+                        l.absent(&enable_respawn_screen, "enable_respawn_screen")?;
+                        let max_players = l.convert(max_players, "max_players")?;
+                        match pv {
+                            468.. => CbPlay::JoinGame468(cb::JoinGame468 {
+                                entity_id,
+                                hardcore,
+                                gamemode,
+                                dimension: l.required(dimension, "dimension")?,
+                                max_players,
+                                level_type: l.required(level_type, "level_type")?,
+                                view_distance: l.required(view_distance, "view_distance")?,
+                                reduced_debug_info: l
+                                    .required(reduced_debug_info, "reduced_debug_info")?,
+                            }),
+                            _ => {
+                                l.absent(&view_distance, "view_distance")?;
+                                let difficulty = l.required(difficulty, "difficulty")?;
+                                match pv {
+                                    108.. => CbPlay::JoinGame108(cb::JoinGame108 {
+                                        entity_id,
+                                        hardcore,
+                                        gamemode,
+                                        dimension: l.required(dimension, "dimension")?,
+                                        difficulty,
+                                        max_players,
+                                        level_type: l.required(level_type, "level_type")?,
+                                        reduced_debug_info: l
+                                            .required(reduced_debug_info, "reduced_debug_info")?,
+                                    }),
+                                    86.. => CbPlay::JoinGame86(cb::JoinGame86 {
+                                        entity_id,
+                                        hardcore,
+                                        gamemode,
+                                        dimension: l.dimension0(dimension)?,
+                                        difficulty,
+                                        max_players,
+                                        level_type: l.required(level_type, "level_type")?,
+                                        reduced_debug_info: l
+                                            .required(reduced_debug_info, "reduced_debug_info")?,
+                                    }),
+                                    29.. => CbPlay::JoinGame29(cb::JoinGame29 {
+                                        entity_id,
+                                        hardcore,
+                                        gamemode: l.gamemode0(gamemode)?,
+                                        dimension: l.dimension0(dimension)?,
+                                        difficulty,
+                                        max_players,
+                                        level_type: l.required(level_type, "level_type")?,
+                                        reduced_debug_info: l
+                                            .required(reduced_debug_info, "reduced_debug_info")?,
+                                    }),
+                                    1.. => {
+                                        l.absent(&reduced_debug_info, "reduced_debug_info")?;
+                                        CbPlay::JoinGame1(cb::JoinGame1 {
+                                            entity_id,
+                                            hardcore,
+                                            gamemode: l.gamemode0(gamemode)?,
+                                            dimension: l.dimension0(dimension)?,
+                                            difficulty,
+                                            max_players,
+                                            level_type: l.required(level_type, "level_type")?,
+                                        })
+                                    }
+                                    _ => {
+                                        l.absent(&reduced_debug_info, "reduced_debug_info")?;
+                                        l.absent(&level_type, "level_type")?;
+                                        CbPlay::JoinGame0(cb::JoinGame0 {
+                                            entity_id,
+                                            hardcore,
+                                            gamemode: l.gamemode0(gamemode)?,
+                                            dimension: l.dimension0(dimension)?,
+                                            difficulty,
+                                            max_players,
+                                        })
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Self::PositionAndLook {
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+                on_ground,
+                relativity,
+                teleport_id,
+                dismount_vehicle,
+            } => {
+                if pv < 755 {
+                    l.absent(&dismount_vehicle, "dismount_vehicle")?;
+                }
+                if pv < 86 {
+                    l.absent(&teleport_id, "teleport_id")?;
+                }
+                if pv >= 6 {
+                    l.absent(&on_ground, "on_ground")?;
+                }
+                match pv {
+                    755.. => CbPlay::PositionAndLook755(cb::PositionAndLook755 {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        relativity: l.required(relativity, "relativity")?,
+                        teleport_id: l.required(teleport_id, "teleport_id")?,
+                        dismount_vehicle: l.required(dismount_vehicle, "dismount_vehicle")?,
+                    }),
+                    86.. => CbPlay::PositionAndLook86(cb::PositionAndLook86 {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        relativity: l.required(relativity, "relativity")?,
+                        teleport_id: l.required(teleport_id, "teleport_id")?,
+                    }),
+                    6.. => CbPlay::PositionAndLook6(cb::PositionAndLook6 {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        relativity: l.required(relativity, "relativity")?,
+                    }),
+                    _ => {
+                        l.absent(&relativity, "relativity")?;
+                        CbPlay::PositionAndLook0(cb::PositionAndLook0 {
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            on_ground: l.required(on_ground, "on_ground")?,
+                        })
+                    }
+                }
+            }
+            Self::SpawnPlayer {
+                entity_id,
+                uuid,
+                name,
+                properties,
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+                current_item,
+                metadata,
+            } => {
+                if pv >= 19 {
+                    l.absent(&name, "name")?;
+                    l.absent(&properties, "properties")?;
+                } else if !matches!(pv, 5 | 7..) {
+                    l.absent(&properties, "properties")?;
+                }
+                if pv >= 49 {
+                    l.absent(&current_item, "current_item")?;
+                }
+                if pv >= 550 {
+                    l.absent(&metadata, "metadata")?;
+                    return lowered(
+                        CbPlay::SpawnPlayer550(cb::SpawnPlayer550 {
+                            entity_id,
+                            player_uuid: uuid,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                        }),
+                        version,
+                    );
+                }
+                let metadata = l.required(metadata, "metadata")?;
+                macro_rules! spawn_player100 {
+                    ($variant:ident, $metadata:expr) => {
+                        CbPlay::$variant(cb::SpawnPlayer100 {
+                            entity_id,
+                            player_uuid: uuid,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            metadata: $metadata,
+                        })
+                    };
+                }
+                match (pv, metadata) {
+                    (463.., PlayerMetadata::V463(m)) => spawn_player100!(SpawnPlayer463, m),
+                    (441..=462, PlayerMetadata::V441(m)) => spawn_player100!(SpawnPlayer441, m),
+                    (402..=440, PlayerMetadata::V402(m)) => spawn_player100!(SpawnPlayer402, m),
+                    (353..=401, PlayerMetadata::V353(m)) => spawn_player100!(SpawnPlayer353, m),
+                    (346..=352, PlayerMetadata::V346(m)) => spawn_player100!(SpawnPlayer346, m),
+                    (100..=345, PlayerMetadata::V57(m)) => spawn_player100!(SpawnPlayer100, m),
+                    (57..=99, PlayerMetadata::V57(metadata)) => {
+                        CbPlay::SpawnPlayer57(cb::SpawnPlayer49 {
+                            entity_id,
+                            player_uuid: uuid,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            metadata,
+                        })
+                    }
+                    (49..=56, PlayerMetadata::Packed(metadata)) => {
+                        CbPlay::SpawnPlayer49(cb::SpawnPlayer49 {
+                            entity_id,
+                            player_uuid: uuid,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            metadata,
+                        })
+                    }
+                    (19..=48, PlayerMetadata::Packed(metadata)) => {
+                        CbPlay::SpawnPlayer19(cb::SpawnPlayer19 {
+                            entity_id,
+                            player_uuid: uuid,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            current_item: l.required(current_item, "current_item")?,
+                            metadata,
+                        })
+                    }
+                    (5 | 7..=18, PlayerMetadata::Packed(metadata)) => {
+                        CbPlay::SpawnPlayer5(cb::SpawnPlayer5 {
+                            entity_id,
+                            player_uuid: uuid.into(),
+                            name: l.required(name, "name")?,
+                            properties: l.required(properties, "properties")?,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            current_item: l.required(current_item, "current_item")?,
+                            metadata,
+                        })
+                    }
+                    (..=6, PlayerMetadata::Packed(metadata)) => {
+                        CbPlay::SpawnPlayer0(cb::SpawnPlayer0 {
+                            entity_id,
+                            player_uuid: uuid.into(),
+                            name: l.required(name, "name")?,
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                            current_item: l.required(current_item, "current_item")?,
+                            metadata,
+                        })
+                    }
+                    _ => return Err(l.unrepresentable("metadata")),
+                }
+            }
+            Self::SetSlot {
+                window_id,
+                slot,
+                item,
+                state_id,
+            } => {
+                if pv < 756 {
+                    l.absent(&state_id, "state_id")?;
+                }
+                match pv {
+                    756.. => CbPlay::SetSlot756(cb::SetSlot756 {
+                        window_id,
+                        state_id: l.required(state_id, "state_id")?,
+                        slot,
+                        item: l.slot402(item)?,
+                    }),
+                    402.. => CbPlay::SetSlot402(cb::SetSlot346 {
+                        window_id,
+                        slot,
+                        item: l.slot402(item)?,
+                    }),
+                    346.. => CbPlay::SetSlot346(cb::SetSlot346 {
+                        window_id,
+                        slot,
+                        item: l.slot346(item)?,
+                    }),
+                    67.. => CbPlay::SetSlot67(cb::SetSlot67 {
+                        window_id,
+                        slot,
+                        item: l.slot0(item)?,
+                    }),
+                    _ => return Err(LowerError::Unsupported(pv)),
+                }
+            }
+            Self::WindowItems {
+                window_id,
+                items,
+                state_id,
+                carried_item,
+            } => {
+                if pv < 756 {
+                    l.absent(&state_id, "state_id")?;
+                    l.absent(&carried_item, "carried_item")?;
+                }
+                match pv {
+                    756.. => CbPlay::WindowItems756(cb::WindowItems756 {
+                        window_id,
+                        state_id: l.required(state_id, "state_id")?,
+                        items: l.slots(items, Lowering::slot402)?,
+                        carried_item: l.slot402(l.required(carried_item, "carried_item")?)?,
+                    }),
+                    402.. => CbPlay::WindowItems402(cb::WindowItems346 {
+                        window_id,
+                        slots: l.slots(items, Lowering::slot402)?,
+                    }),
+                    346.. => CbPlay::WindowItems346(cb::WindowItems346 {
+                        window_id,
+                        slots: l.slots(items, Lowering::slot346)?,
+                    }),
+                    67.. => CbPlay::WindowItems67(cb::WindowItems67 {
+                        window_id,
+                        slots: l.slots(items, Lowering::slot0)?,
+                    }),
+                    _ => return Err(LowerError::Unsupported(pv)),
+                }
+            }
+        };
+        lowered(packet, version)
+    }
+}
+
+/// Checks that `version` has the packet, the packet types of the model aren't
+/// sent in every version.
+fn lowered<P: Packet>(packet: P, version: ProtocolVersion) -> Result<P, LowerError> {
+    match packet.id_for_version(version) {
+        Some(_) => Ok(packet),
+        None => Err(LowerError::Unsupported(*version)),
+    }
+}
+
+impl<'a> Serverbound<'a> {
+    /// Converts the packet into the packet type of `version`.
+    pub fn lower(self, version: ProtocolVersion) -> Result<SbPlay<'a>, LowerError> {
+        let pv = *version;
+        let l = Lowering { version: pv };
+        let packet = match self {
+            Self::KeepAlive { id } => match pv {
+                339.. => SbPlay::KeepAlive339(sb::KeepAlive339 { id }),
+                7.. => SbPlay::KeepAlive7(sb::KeepAlive7 {
+                    id: l.convert(id, "id")?,
+                }),
+                _ => SbPlay::KeepAlive0(sb::KeepAlive0 {
+                    id: l.convert(id, "id")?,
+                }),
+            },
+            Self::Chat { message } => SbPlay::ChatMessage0(sb::ChatMessage0 { message }),
+            Self::HeldItemChange { slot } => SbPlay::HeldItemChange0(sb::HeldItemChange0 { slot }),
+            Self::Player { on_ground } => SbPlay::Player0(sb::Player0 { on_ground }),
+            Self::PlayerPosition {
+                x,
+                y,
+                stance,
+                z,
+                on_ground,
+            } => match pv {
+                10.. => {
+                    l.absent(&stance, "stance")?;
+                    SbPlay::PlayerPosition10(sb::PlayerPosition10 { x, y, z, on_ground })
+                }
+                _ => SbPlay::PlayerPosition0(sb::PlayerPosition0 {
+                    x,
+                    y,
+                    stance: l.required(stance, "stance")?,
+                    z,
+                    on_ground,
+                }),
+            },
+            Self::PlayerLook {
+                yaw,
+                pitch,
+                on_ground,
+            } => SbPlay::PlayerLook0(sb::PlayerLook0 {
+                yaw,
+                pitch,
+                on_ground,
+            }),
+            Self::PlayerPositionAndLook {
+                x,
+                y,
+                stance,
+                z,
+                yaw,
+                pitch,
+                on_ground,
+            } => match pv {
+                10.. => {
+                    l.absent(&stance, "stance")?;
+                    SbPlay::PlayerPositionAndLook10(sb::PlayerPositionAndLook10 {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        on_ground,
+                    })
+                }
+                _ => SbPlay::PlayerPositionAndLook0(sb::PlayerPositionAndLook0 {
+                    x,
+                    y,
+                    stance: l.required(stance, "stance")?,
+                    z,
+                    yaw,
+                    pitch,
+                    on_ground,
+                }),
+            },
+            Self::TeleportConfirm { teleport_id } => {
+                SbPlay::TeleportConfirm80(sb::TeleportConfirm80 { teleport_id })
+            }
+            Self::CloseWindow { window_id } => SbPlay::CloseWindow0(sb::CloseWindow0 { window_id }),
+            Self::ClickWindow {
+                window_id,
+                action,
+                action_id,
+                clicked_item,
+                state_id,
+                changed_slots,
+                carried_item,
+            } => {
+                if pv < 756 {
+                    l.absent(&state_id, "state_id")?;
+                }
+                match pv {
+                    755.. => {
+                        l.absent(&action_id, "action_id")?;
+                        l.absent(&clicked_item, "clicked_item")?;
+                        let changed_slots = l
+                            .required(changed_slots, "changed_slots")?
+                            .into_iter()
+                            .map(|(slot, item)| {
+                                Ok(ChangedSlot755 {
+                                    slot,
+                                    item: l.slot402(item)?,
+                                })
+                            })
+                            .collect::<Result<_, LowerError>>()?;
+                        let carried_item = l.slot402(l.required(carried_item, "carried_item")?)?;
+                        if pv == 755 {
+                            SbPlay::ClickWindow755(sb::ClickWindow755 {
+                                window_id,
+                                action,
+                                changed_slots,
+                                carried_item,
+                            })
+                        } else {
+                            SbPlay::ClickWindow756(sb::ClickWindow756 {
+                                window_id,
+                                state_id: l.required(state_id, "state_id")?,
+                                action,
+                                changed_slots,
+                                carried_item,
+                            })
+                        }
+                    }
+                    83.. => {
+                        l.absent(&changed_slots, "changed_slots")?;
+                        l.absent(&carried_item, "carried_item")?;
+                        let action_id = l.required(action_id, "action_id")?;
+                        let clicked_item = l.required(clicked_item, "clicked_item")?;
+                        match pv {
+                            402.. => SbPlay::ClickWindow402(sb::ClickWindow83 {
+                                window_id,
+                                action,
+                                action_id,
+                                clicked_item: l.slot402(clicked_item)?,
+                            }),
+                            346.. => SbPlay::ClickWindow346(sb::ClickWindow83 {
+                                window_id,
+                                action,
+                                action_id,
+                                clicked_item: l.slot346(clicked_item)?,
+                            }),
+                            _ => SbPlay::ClickWindow83(sb::ClickWindow83 {
+                                window_id,
+                                action,
+                                action_id,
+                                clicked_item: l.slot0(clicked_item)?,
+                            }),
+                        }
+                    }
+                    _ => return Err(LowerError::Unsupported(pv)),
+                }
+            }
+            Self::CreativeInventoryAction { slot, item } => match pv {
+                402.. => SbPlay::CreativeInventoryAction402(sb::CreativeInventoryAction80 {
+                    slot,
+                    clicked_item: l.slot402(item)?,
+                }),
+                346.. => SbPlay::CreativeInventoryAction346(sb::CreativeInventoryAction80 {
+                    slot,
+                    clicked_item: l.slot346(item)?,
+                }),
+                80.. => SbPlay::CreativeInventoryAction80(sb::CreativeInventoryAction80 {
+                    slot,
+                    clicked_item: l.slot0(item)?,
+                }),
+                _ => return Err(LowerError::Unsupported(pv)),
+            },
+        };
+        lowered(packet, version)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+fn pv(pv: i32) -> ProtocolVersion {
+    ProtocolVersion::new(pv).unwrap()
+}
+
+#[test]
+fn keep_alive() {
+    let packet = Clientbound::try_from(CbPlay::KeepAlive32(cb::KeepAlive32 { id: 7 })).unwrap();
+    assert!(matches!(packet, Clientbound::KeepAlive { id: 7 }));
+    assert!(matches!(
+        packet.lower(pv(760)),
+        Ok(CbPlay::KeepAlive339(cb::KeepAlive339 { id: 7 }))
+    ));
+    assert!(matches!(
+        Clientbound::KeepAlive { id: i64::MAX }.lower(pv(47)),
+        Err(LowerError::Unrepresentable { field: "id", .. })
+    ));
+    assert!(matches!(
+        Serverbound::KeepAlive { id: 7 }.lower(pv(5)),
+        Ok(SbPlay::KeepAlive0(sb::KeepAlive0 { id: 7 }))
+    ));
+}
+
+#[test]
+fn spawn_position() {
+    let packet = Clientbound::SpawnPosition {
+        x: 1,
+        y: 64,
+        z: -1,
+        angle: Some(90.0),
+    };
+    assert!(matches!(
+        packet.lower(pv(340)),
+        Err(LowerError::Unrepresentable { field: "angle", .. })
+    ));
+    let packet = Clientbound::SpawnPosition {
+        x: 1,
+        y: 64,
+        z: -1,
+        angle: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(760)),
+        Err(LowerError::Missing { field: "angle", .. })
+    ));
+    let packet = Clientbound::SpawnPosition {
+        x: 1,
+        y: 64,
+        z: -1,
+        angle: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(498)),
+        Ok(CbPlay::SpawnPosition441(cb::SpawnPosition441 {
+            location: Position441 { x: 1, z: -1, y: 64 }
+        }))
+    ));
+}
+
+#[test]
+fn chat() {
+    let packet = Clientbound::Chat {
+        message: Cow::Borrowed("{\"text\":\"hi\"}"),
+        position: Some(ChatMessagePosition6::Hotbar),
+        sender: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(760)),
+        Ok(CbPlay::SystemChatMessage760(cb::SystemChatMessage760 {
+            overlay: true,
+            ..
+        }))
+    ));
+    let packet = Clientbound::Chat {
+        message: Cow::Borrowed("{\"text\":\"hi\"}"),
+        position: Some(ChatMessagePosition6::Chat),
+        sender: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(759)),
+        Err(LowerError::Unrepresentable {
+            field: "position",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn unmodelled() {
+    let packet = CbPlay::CloseWindow0(cb::CloseWindow0 { window_id: 1 });
+    assert!(matches!(
+        Clientbound::try_from(packet),
+        Err(CbPlay::CloseWindow0(cb::CloseWindow0 { window_id: 1 }))
+    ));
+}
+
+#[test]
+fn unsupported() {
+    assert!(matches!(
+        Serverbound::TeleportConfirm { teleport_id: 1 }.lower(pv(47)),
+        Err(LowerError::Unsupported(47))
+    ));
+    let packet = Clientbound::SetSlot {
+        window_id: 0,
+        slot: 36,
+        item: None,
+        state_id: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(47)),
+        Err(LowerError::Unsupported(47))
+    ));
+}
+
+#[test]
+fn join_game() {
+    let join = |view_distance| Clientbound::JoinGame {
+        entity_id: 1,
+        hardcore: false,
+        gamemode: GameMode86::Spectator,
+        dimension: Some(Dimension86::Overworld),
+        difficulty: None,
+        max_players: 20,
+        level_type: Some(Cow::Borrowed("default")),
+        reduced_debug_info: Some(false),
+        view_distance,
+        hashed_seed: None,
+        enable_respawn_screen: None,
+        world: None,
+    };
+    assert!(matches!(
+        join(Some(10)).lower(pv(498)),
+        Ok(CbPlay::JoinGame468(cb::JoinGame468 {
+            view_distance: 10,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        join(Some(10)).lower(pv(340)),
+        Err(LowerError::Unrepresentable {
+            field: "view_distance",
+            ..
+        })
+    ));
+    assert!(matches!(
+        join(None).lower(pv(340)),
+        Err(LowerError::Missing {
+            field: "difficulty",
+            ..
+        })
+    ));
+    assert!(matches!(
+        join(Some(10)).lower(pv(578)),
+        Err(LowerError::Missing {
+            field: "hashed_seed",
+            ..
+        })
+    ));
+    assert!(matches!(
+        join(Some(10)).lower(pv(760)),
+        Err(LowerError::Unrepresentable {
+            field: "dimension",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn set_slot_damage() {
+    let item = |damage| {
+        Some(Item {
+            id: 276,
+            count: 1,
+            damage,
+            nbt: Compound::default(),
+        })
+    };
+    let packet = Clientbound::SetSlot {
+        window_id: 0,
+        slot: 36,
+        item: item(Some(3)),
+        state_id: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(340)),
+        Ok(CbPlay::SetSlot67(cb::SetSlot67 {
+            item: Slot0::Item { damage: 3, .. },
+            ..
+        }))
+    ));
+    let packet = Clientbound::SetSlot {
+        window_id: 0,
+        slot: 36,
+        item: item(Some(3)),
+        state_id: None,
+    };
+    assert!(matches!(
+        packet.lower(pv(404)),
+        Err(LowerError::Unrepresentable {
+            field: "damage",
+            ..
+        })
+    ));
+    let packet = Clientbound::SetSlot {
+        window_id: 0,
+        slot: 36,
+        item: item(None),
+        state_id: Some(1),
+    };
+    let packet = Clientbound::try_from(packet.lower(pv(760)).unwrap()).unwrap();
+    assert!(matches!(
+        packet,
+        Clientbound::SetSlot {
+            item: Some(Item {
+                id: 276,
+                damage: None,
+                ..
+            }),
+            state_id: Some(1),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn player_position() {
+    let packet = |stance| Serverbound::PlayerPosition {
+        x: 0.0,
+        y: 64.0,
+        stance,
+        z: 0.0,
+        on_ground: true,
+    };
+    assert!(matches!(
+        packet(Some(65.62)).lower(pv(5)),
+        Ok(SbPlay::PlayerPosition0(sb::PlayerPosition0 { y, stance, .. })) if y == 64.0 && stance == 65.62
+    ));
+    assert!(matches!(
+        packet(None).lower(pv(5)),
+        Err(LowerError::Missing {
+            field: "stance",
+            ..
+        })
+    ));
+    assert!(matches!(
+        packet(Some(65.62)).lower(pv(47)),
+        Err(LowerError::Unrepresentable {
+            field: "stance",
+            ..
+        })
+    ));
+    assert!(matches!(
+        packet(None).lower(pv(760)),
+        Ok(SbPlay::PlayerPosition10(_))
+    ));
+}
+
+#[test]
+fn relative_move() {
+    let packet = |dx| Clientbound::EntityRelativeMove {
+        entity_id: 1,
+        dx,
+        dy: 0.0,
+        dz: 0.0,
+        on_ground: Some(true),
+    };
+    assert!(packet(127.0 / 32.0).lower(pv(47)).is_ok());
+    assert!(packet(-4.0).lower(pv(47)).is_ok());
+    assert!(matches!(
+        packet(4.0).lower(pv(47)),
+        Err(LowerError::Unrepresentable { field: "dx", .. })
+    ));
+    assert!(packet(-8.0).lower(pv(340)).is_ok());
+    assert!(packet(8.0).lower(pv(340)).is_err());
+}
@@ -8,17 +8,17 @@ pub mod serverbound;
 parsing_tree! {
     status_cb_custom status_cb_tree crate::netty::status::clientbound::;
     0x00 => {
-        0..=760 => Response0::<'a>,
+        0..=764 => Response0,
     },
     0x01 => {
-        0..=760 => Ping0,
+        0..=764 => Ping0,
     }
 }
 status_cb_custom! {
-    pub enum CbStatus<'a> {
+    pub enum CbStatus {
         #(#PacketName(#PacketTypeLt),)
     }
-    impl<'a> Packet for CbStatus<'a> {
+    impl Packet for CbStatus {
         fn id_for_version(&self, version: miners_version::ProtocolVersion) -> Option<i32> {
             match self {#(Self::#PacketName(#packet_name) => #packet_name.id_for_version(version),)}
         }
@@ -31,8 +31,8 @@ status_cb_custom! {
         }
     }
 }
-impl<'a> CbStatus<'a> {
-    pub fn parse(packet: RawPacket<'a>, version: ProtocolVersion) -> Result<Self, decode::Error> {
+impl CbStatus {
+    pub fn parse(packet: RawPacket, version: ProtocolVersion) -> Result<Self, decode::Error> {
         let (id, data): (i32, &[u8]) = packet.into();
         let mut cursor = std::io::Cursor::new(data);
         let pv = *version;
@@ -47,10 +47,10 @@ impl<'a> CbStatus<'a> {
 parsing_tree! {
     status_sb_custom status_sb_tree crate::netty::status::serverbound::;
     0x00 => {
-        0..=760 => Request0,
+        0..=764 => Request0,
     },
     0x01 => {
-        0..=760 => Ping0,
+        0..=764 => Ping0,
     }
 }
 status_sb_custom! {
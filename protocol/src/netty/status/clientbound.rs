@@ -1,12 +1,284 @@
-use std::borrow::Cow;
+use crate::*;
 
-#[derive(Encoding, ToStatic, Debug)]
-pub struct Response0<'a> {
-    // TODO: json thing
-    pub data: Cow<'a, str>,
+use miners_chat::Component;
+use serde::{Deserialize, Deserializer, Serializer};
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(ToStatic, Debug)]
+pub struct Response0 {
+    pub data: StatusResponse,
+}
+
+impl<'dec> Decode<'dec> for Response0 {
+    fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+        let json = <&str as Decode>::decode(cursor)?;
+        let data = serde_json::from_str(json)
+            .map_err(|_| decode::Error::Custom("invalid status response json"))?;
+        Ok(Self { data })
+    }
+}
+impl Encode for Response0 {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        let json = serde_json::to_string(&self.data)
+            .map_err(|_| encode::Error::Custom("failed to serialize status response"))?;
+        json.encode(writer)
+    }
+}
+
+/// The server list ping response.
+///
+/// Parsing is lenient as servers frequently send numbers as strings, leave out
+/// fields or send invalid sample uuids.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusResponse {
+    #[serde(default)]
+    pub version: StatusVersion,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub players: Option<StatusPlayers>,
+    #[serde(default, deserialize_with = "lenient_description")]
+    pub description: Component,
+    /// `data:image/png;base64,` followed by a base64 encoded 64x64 png
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favicon: Option<String>,
+    /// since 1.19.1
+    #[serde(
+        rename = "enforcesSecureChat",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enforces_secure_chat: Option<bool>,
+    /// 1.19 to 1.19.2
+    #[serde(
+        rename = "previewsChat",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub previews_chat: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusVersion {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default = "unknown_protocol", deserialize_with = "lenient_int")]
+    pub protocol: i32,
+}
+
+fn unknown_protocol() -> i32 {
+    -1
+}
+
+impl Default for StatusVersion {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            protocol: unknown_protocol(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StatusPlayers {
+    #[serde(default, deserialize_with = "lenient_int")]
+    pub max: i32,
+    #[serde(default, deserialize_with = "lenient_int")]
+    pub online: i32,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "lenient_sample"
+    )]
+    pub sample: Vec<StatusPlayerSample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusPlayerSample {
+    #[serde(default)]
+    pub name: String,
+    /// invalid uuids are parsed as the nil uuid
+    #[serde(
+        default,
+        serialize_with = "serialize_uuid",
+        deserialize_with = "lenient_uuid"
+    )]
+    pub id: Uuid,
+}
+
+fn lenient_int<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .or_else(|| n.as_f64().map(|f| f as i64))
+            .map(|n| n.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+            .unwrap_or_default(),
+        serde_json::Value::String(s) => s.trim().parse().unwrap_or_default(),
+        _ => 0,
+    })
+}
+
+fn lenient_description<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Component, D::Error> {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    if value.is_null() {
+        return Ok(Component::default());
+    }
+    let description = match serde_json::from_value(value.clone()) {
+        Ok(description) => description,
+        // a description that isn't a valid component is shown as it was sent
+        Err(_) => match value {
+            serde_json::Value::String(text) => Component::text(text),
+            value => Component::text(value.to_string()),
+        },
+    };
+    // old servers send `§` codes in plain text descriptions
+    Ok(match description {
+        Component {
+            content: miners_chat::Content::Text(text),
+            style,
+            extra,
+        } if style.is_empty()
+            && extra.is_empty()
+            && text.contains(miners_chat::legacy::SECTION) =>
+        {
+            Component::from_legacy(&text)
+        }
+        description => description,
+    })
+}
+
+fn lenient_sample<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<StatusPlayerSample>, D::Error> {
+    Ok(Option::<Vec<StatusPlayerSample>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn lenient_uuid<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    let id = Option::<String>::deserialize(deserializer)?;
+    Ok(id.and_then(|id| id.parse().ok()).unwrap_or_default())
+}
+
+fn serialize_uuid<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&uuid.hyphenated())
+}
+
+impl ToStatic for StatusResponse {
+    type Static = StatusResponse;
+
+    fn to_static(&self) -> Self::Static {
+        self.clone()
+    }
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+const FAVICON_PREFIX: &str = "data:image/png;base64,";
+
+impl StatusResponse {
+    pub fn new(version_name: impl Into<String>, protocol: i32) -> Self {
+        Self {
+            version: StatusVersion {
+                name: version_name.into(),
+                protocol,
+            },
+            players: None,
+            description: Component::default(),
+            favicon: None,
+            enforces_secure_chat: None,
+            previews_chat: None,
+        }
+    }
+    pub fn players(mut self, max: i32, online: i32) -> Self {
+        let players = self.players.get_or_insert_with(Default::default);
+        players.max = max;
+        players.online = online;
+        self
+    }
+    /// Adds a player to the sample shown when hovering over the player count.
+    pub fn sample(mut self, name: impl Into<String>, id: Uuid) -> Self {
+        self.players
+            .get_or_insert_with(Default::default)
+            .sample
+            .push(StatusPlayerSample {
+                name: name.into(),
+                id,
+            });
+        self
+    }
+    pub fn description(mut self, description: impl Into<Component>) -> Self {
+        self.description = description.into();
+        self
+    }
+    /// Sets the favicon from the raw bytes of a 64x64 png.
+    pub fn favicon_png(mut self, png: &[u8]) -> Self {
+        self.favicon = Some(format!("{FAVICON_PREFIX}{}", base64::encode(png)));
+        self
+    }
+    pub fn enforces_secure_chat(mut self, enforces_secure_chat: bool) -> Self {
+        self.enforces_secure_chat = Some(enforces_secure_chat);
+        self
+    }
+    pub fn previews_chat(mut self, previews_chat: bool) -> Self {
+        self.previews_chat = Some(previews_chat);
+        self
+    }
+
+    /// Decodes the favicon into the raw bytes of the png.
+    ///
+    /// Some servers line-wrap the base64 data, which is ignored.
+    pub fn favicon_bytes(&self) -> Option<Vec<u8>> {
+        let favicon = self.favicon.as_deref()?;
+        let data = favicon.strip_prefix(FAVICON_PREFIX).unwrap_or(favicon);
+        let data: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+        base64::decode(data).ok()
+    }
 }
 
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Ping0 {
     pub time: i64,
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn status_response() {
+    let data = r#"{"version":{"name":"Paper 1.19.2","protocol":"760"},"players":{"max":"20","online":1.0,"sample":[{"name":"Notch","id":"not-a-uuid"}]},"description":"§cred"}"#;
+    let mut buf = vec![];
+    data.encode(&mut buf).unwrap();
+    let response = Response0::decode(&mut std::io::Cursor::new(&buf)).unwrap();
+    let response = response.data;
+    assert_eq!(
+        response,
+        StatusResponse::new("Paper 1.19.2", 760)
+            .players(20, 1)
+            .sample("Notch", Uuid::nil())
+            .description(Component::text("red").color(miners_chat::Color::Red))
+    );
+
+    let png = [0x89, b'P', b'N', b'G'];
+    let response = response.favicon_png(&png);
+    assert_eq!(response.favicon_bytes().unwrap(), png);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn malformed_description() {
+    let description = |json: &str| {
+        let data =
+            format!(r#"{{"version":{{"name":"1.8.9","protocol":47}},"description":{json}}}"#);
+        serde_json::from_str::<StatusResponse>(&data)
+            .unwrap()
+            .description
+    };
+    assert_eq!(description("null"), Component::default());
+    assert_eq!(
+        description(r#""§aA Server""#),
+        Component::from_legacy("§aA Server")
+    );
+    assert_eq!(
+        description(r#"{"text":"A Server","color":12}"#),
+        Component::text("A Server")
+    );
+    assert_eq!(description("[]"), Component::text("[]"));
+}
@@ -0,0 +1,1731 @@
+//! Translation of play packets between protocol versions.
+//!
+//! A [`Translator`] converts a packet parsed for one version into the packet
+//! types of another, walking through the versioned types one step at a time
+//! (`KeepAlive0` -> `KeepAlive32` -> `KeepAlive339`). Packet ids don't need
+//! to be translated as every packet type knows its id in each version.
+//!
+//! Registry ids like block states and items change between versions without
+//! the protocol telling, they are translated by an [`IdMap`] supplied by the
+//! user. Entity metadata indices of every entity are shifted where vanilla
+//! inserted new base entity fields.
+//!
+//! Only packets with a known translation are supported, all others fail with
+//! [`TranslateError::Unsupported`] when their type doesn't exist in the target
+//! version. Join game packets carry the registries of the server since 1.16,
+//! they are only translated down to older versions and between versions
+//! sharing the registry format. Player metadata can't be carried out of the
+//! packed format used before 1.9, spawned players get the defaults of the
+//! client like they do since 1.15.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use miners_version::ProtocolVersion;
+
+use crate::netty::play::clientbound as cb;
+use crate::netty::play::serverbound as sb;
+use crate::netty::play::{CbPlay, Difficulty0, SbPlay};
+use crate::netty::types::entity_metadata::{
+    BlockId, EntityMetadata, GlobalPos, PackedEntityMetadata0, Value0, Value353, Value57,
+};
+use crate::netty::types::position::{Position441, Position6};
+use crate::netty::types::slot::{Slot0, Slot346, Slot402};
+use crate::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TranslateError {
+    #[error("the packet can't be translated from pv{from} to pv{to}")]
+    Unsupported { from: i32, to: i32 },
+    #[error("{0} is out of range in the target version")]
+    OutOfRange(&'static str),
+    #[error("the {kind} id {id} doesn't exist in the target version")]
+    UnmappedId { kind: &'static str, id: i32 },
+}
+
+type Result<T> = std::result::Result<T, TranslateError>;
+
+/// Translates registry ids between versions, `None` if the id has no
+/// counterpart in `to`.
+pub trait IdMap {
+    fn block_state(&self, id: i32, from: ProtocolVersion, to: ProtocolVersion) -> Option<i32>;
+    fn item(&self, id: i32, from: ProtocolVersion, to: ProtocolVersion) -> Option<i32>;
+}
+
+/// Keeps all ids, only correct between versions sharing their registries.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SameIds;
+impl IdMap for SameIds {
+    fn block_state(&self, id: i32, _: ProtocolVersion, _: ProtocolVersion) -> Option<i32> {
+        Some(id)
+    }
+    fn item(&self, id: i32, _: ProtocolVersion, _: ProtocolVersion) -> Option<i32> {
+        Some(id)
+    }
+}
+
+/// Versions inserting an index into the metadata of every entity, all
+/// following indices are shifted up by one.
+const METADATA_INSERTIONS: [(i32, u8); 3] = [
+    // no gravity
+    (201, 5),
+    // pose
+    (461, 6),
+    // ticks frozen
+    (755, 7),
+];
+/// The metadata indices were reassigned in 1.9
+const METADATA_LAYOUT_PV: i32 = 107;
+/// The vanilla default, sent to clients expecting a view distance from servers
+/// which didn't tell.
+const VIEW_DISTANCE: i32 = 10;
+/// The stance of a player standing upright, relative to their feet.
+const EYE_HEIGHT: f64 = 1.62;
+
+pub struct Translator<'i, I> {
+    from: ProtocolVersion,
+    to: ProtocolVersion,
+    ids: &'i I,
+}
+
+impl<'i, I: IdMap> Translator<'i, I> {
+    pub fn new(from: ProtocolVersion, to: ProtocolVersion, ids: &'i I) -> Self {
+        Self { from, to, ids }
+    }
+
+    pub fn from(&self) -> ProtocolVersion {
+        self.from
+    }
+
+    pub fn to(&self) -> ProtocolVersion {
+        self.to
+    }
+
+    pub fn clientbound<'a>(&self, mut packet: CbPlay<'a>) -> Result<CbPlay<'a>> {
+        if *self.from == *self.to {
+            return Ok(packet);
+        }
+        while packet.id_for_version(self.to).is_none() {
+            packet = self.step_clientbound(packet)?;
+        }
+        self.rewrite_clientbound(&mut packet)?;
+        Ok(packet)
+    }
+
+    pub fn serverbound<'a>(&self, mut packet: SbPlay<'a>) -> Result<SbPlay<'a>> {
+        if *self.from == *self.to {
+            return Ok(packet);
+        }
+        while packet.id_for_version(self.to).is_none() {
+            packet = self.step_serverbound(packet)?;
+        }
+        self.rewrite_serverbound(&mut packet)?;
+        Ok(packet)
+    }
+
+    fn upgrade(&self) -> bool {
+        *self.from < *self.to
+    }
+
+    /// Particles are only translated within the same type, the particle ids
+    /// shift with almost every version.
+    fn no_particles<Q, Q2>(&self) -> impl Fn(Q) -> Result<Q2> + '_ {
+        |_| Err(self.unsupported())
+    }
+
+    fn unsupported(&self) -> TranslateError {
+        TranslateError::Unsupported {
+            from: *self.from,
+            to: *self.to,
+        }
+    }
+
+    /// Converts the packet into the type of the next (or previous) version,
+    /// doesn't touch any ids.
+    fn step_clientbound<'a>(&self, packet: CbPlay<'a>) -> Result<CbPlay<'a>> {
+        use CbPlay::*;
+        Ok(if self.upgrade() {
+            match packet {
+                KeepAlive0(p) => KeepAlive32(cb::KeepAlive32 { id: p.id }),
+                KeepAlive32(p) => KeepAlive339(cb::KeepAlive339 { id: p.id as i64 }),
+                SpawnPosition0(p) => SpawnPosition6(cb::SpawnPosition6 {
+                    x: bits(p.x, 26, "spawn position x")?,
+                    y: bits(p.y, 12, "spawn position y")? as i16,
+                    z: bits(p.z, 26, "spawn position z")?,
+                }),
+                SpawnPosition6(p) => SpawnPosition441(cb::SpawnPosition441 {
+                    location: Position441 {
+                        x: p.x,
+                        z: p.z,
+                        y: p.y,
+                    },
+                }),
+                SpawnPosition441(p) => SpawnPosition755(cb::SpawnPosition755 {
+                    location: p.location,
+                    angle: 0.0,
+                }),
+                BlockChange0(p) => BlockChange6(cb::BlockChange6 {
+                    location: Position6 {
+                        x: bits(p.x, 26, "block change x")?,
+                        y: p.y as i16,
+                        z: bits(p.z, 26, "block change z")?,
+                    },
+                    block_type: p.block_type,
+                    block_data: p.block_data,
+                }),
+                BlockChange6(p) => BlockChange25(cb::BlockChange25 {
+                    location: p.location,
+                    block_id: p.block_type << 4 | (p.block_data & 0b1111) as i32,
+                }),
+                BlockChange25(p) => BlockChange441(cb::BlockChange441 {
+                    location: p.location.into(),
+                    block_id: p.block_id,
+                }),
+                EntityRelativeMove0(p) => EntityRelativeMove7(cb::EntityRelativeMove7 {
+                    entity_id: p.entity_id,
+                    dx: p.dx,
+                    dy: p.dy,
+                    dz: p.dz,
+                }),
+                // the old versions don't tell, most moving entities are on the ground
+                EntityRelativeMove7(p) => EntityRelativeMove22(cb::EntityRelativeMove22 {
+                    entity_id: p.entity_id,
+                    dx: p.dx,
+                    dy: p.dy,
+                    dz: p.dz,
+                    on_ground: true,
+                }),
+                EntityRelativeMove22(p) => EntityRelativeMove100(cb::EntityRelativeMove100 {
+                    entity_id: p.entity_id,
+                    dx: p.dx as f64,
+                    dy: p.dy as f64,
+                    dz: p.dz as f64,
+                    on_ground: p.on_ground,
+                }),
+                EntityVelocity0(p) => EntityVelocity7(cb::EntityVelocity7 {
+                    entity_id: p.entity_id,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                }),
+                UpdateHealth0(p) => UpdateHealth7(cb::UpdateHealth7 {
+                    health: p.health,
+                    food: p.food as i32,
+                    saturation: p.saturation,
+                }),
+                EntityMetadata353(p) => EntityMetadata402(self.metadata(
+                    p,
+                    slot346_to_402,
+                    |p| p,
+                    self.no_particles(),
+                )?),
+                EntityMetadata402(p) => {
+                    EntityMetadata441(self.metadata(p, Ok, Position441::from, Ok)?)
+                }
+                EntityMetadata441(p) => {
+                    EntityMetadata463(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata463(p) => {
+                    EntityMetadata706(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata706(p) => {
+                    EntityMetadata755(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata755(p) => {
+                    EntityMetadata757(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata757(p) => {
+                    EntityMetadata759(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata57(p) => EntityMetadata353(cb::EntityMetadata7 {
+                    entity_id: p.entity_id,
+                    metadata: map_metadata(p.metadata, |v| {
+                        Ok(value57_to_353(value57(v, slot0_to_346)?))
+                    })?,
+                }),
+                SpawnPlayer0(p) => SpawnPlayer5(cb::SpawnPlayer5 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    name: p.name,
+                    properties: Vec::new(),
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    current_item: p.current_item,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer5(p) => SpawnPlayer19(cb::SpawnPlayer19 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid.into_inner().unwrap_or_default(),
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    current_item: p.current_item,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer19(p) => SpawnPlayer49(cb::SpawnPlayer49 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer49(p) => SpawnPlayer57(cb::SpawnPlayer49 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: EntityMetadata {
+                        inner: BTreeMap::new(),
+                    },
+                }),
+                SpawnPlayer57(p) => SpawnPlayer100(cb::SpawnPlayer100 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer100(p) => SpawnPlayer346(spawn_player(p, |v| value57(v, slot0_to_346))?),
+                SpawnPlayer346(p) => SpawnPlayer353(spawn_player(p, |v| Ok(value57_to_353(v)))?),
+                SpawnPlayer353(p) => SpawnPlayer402(spawn_player(p, |v| {
+                    value353(v, slot346_to_402, |p| p, self.no_particles())
+                })?),
+                SpawnPlayer402(p) => {
+                    SpawnPlayer441(spawn_player(p, |v| value353(v, Ok, Position441::from, Ok))?)
+                }
+                SpawnPlayer441(p) => SpawnPlayer463(spawn_player(p, |v| {
+                    value353(v, Ok, |p| p, self.no_particles())
+                })?),
+                // the metadata is sent in its own packet
+                SpawnPlayer463(p) => SpawnPlayer550(cb::SpawnPlayer550 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                }),
+                JoinGame0(p) => JoinGame1(cb::JoinGame1 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: Cow::Borrowed("default"),
+                }),
+                JoinGame1(p) => JoinGame29(cb::JoinGame29 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: false,
+                }),
+                JoinGame29(p) => JoinGame86(cb::JoinGame86 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: gamemode86(p.gamemode),
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame86(p) => JoinGame108(cb::JoinGame108 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: dimension86(p.dimension),
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame108(p) => JoinGame468(cb::JoinGame468 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    view_distance: VIEW_DISTANCE,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame468(p) => JoinGame552(cb::JoinGame552 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    hashed_seed: 0,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    view_distance: p.view_distance,
+                    reduced_debug_info: p.reduced_debug_info,
+                    enable_respawn_screen: true,
+                }),
+                JoinGame748(p) => JoinGame757(cb::JoinGame757 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    dimension_codec: p.dimension_codec,
+                    dimension: p.dimension,
+                    world_name: p.world_name,
+                    hashed_seed: p.hashed_seed,
+                    max_players: p.max_players,
+                    view_distance: p.view_distance,
+                    simulation_distance: p.view_distance,
+                    reduced_debug_info: p.reduced_debug_info,
+                    enable_respawn_screen: p.enable_respawn_screen,
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                }),
+                SetSlot67(p) => SetSlot346(cb::SetSlot346 {
+                    window_id: p.window_id,
+                    slot: p.slot,
+                    item: slot0_to_346(p.item)?,
+                }),
+                SetSlot346(p) => SetSlot402(cb::SetSlot346 {
+                    window_id: p.window_id,
+                    slot: p.slot,
+                    item: slot346_to_402(p.item)?,
+                }),
+                // the server doesn't track a state id, the client resyncs
+                // with the next window items
+                SetSlot402(p) => SetSlot756(cb::SetSlot756 {
+                    window_id: p.window_id,
+                    state_id: 0,
+                    slot: p.slot,
+                    item: p.item,
+                }),
+                WindowItems67(p) => WindowItems346(cb::WindowItems346 {
+                    window_id: p.window_id,
+                    slots: map_slots(p.slots, slot0_to_346)?,
+                }),
+                WindowItems346(p) => WindowItems402(cb::WindowItems346 {
+                    window_id: p.window_id,
+                    slots: map_slots(p.slots, slot346_to_402)?,
+                }),
+                // older servers set the carried item with a set slot to window -1
+                WindowItems402(p) => WindowItems756(cb::WindowItems756 {
+                    window_id: p.window_id,
+                    state_id: 0,
+                    items: p.slots,
+                    carried_item: Slot402::Empty,
+                }),
+                _ => return Err(self.unsupported()),
+            }
+        } else {
+            match packet {
+                KeepAlive32(p) => KeepAlive0(cb::KeepAlive0 { id: p.id }),
+                KeepAlive339(p) => KeepAlive32(cb::KeepAlive32 {
+                    id: p
+                        .id
+                        .try_into()
+                        .map_err(|_| TranslateError::OutOfRange("keep alive id"))?,
+                }),
+                SpawnPosition6(p) => SpawnPosition0(cb::SpawnPosition0 {
+                    x: p.x,
+                    y: p.y as i32,
+                    z: p.z,
+                }),
+                SpawnPosition441(p) => SpawnPosition6(cb::SpawnPosition6 {
+                    x: p.location.x,
+                    y: p.location.y,
+                    z: p.location.z,
+                }),
+                SpawnPosition755(p) => SpawnPosition441(cb::SpawnPosition441 {
+                    location: p.location,
+                }),
+                BlockChange6(p) => BlockChange0(cb::BlockChange0 {
+                    x: p.location.x,
+                    y: p.location
+                        .y
+                        .try_into()
+                        .map_err(|_| TranslateError::OutOfRange("block change y"))?,
+                    z: p.location.z,
+                    block_type: p.block_type,
+                    block_data: p.block_data,
+                }),
+                BlockChange25(p) => BlockChange6(cb::BlockChange6 {
+                    location: p.location,
+                    block_type: p.block_id >> 4,
+                    block_data: (p.block_id & 0b1111) as u8,
+                }),
+                BlockChange441(p) => BlockChange25(cb::BlockChange25 {
+                    location: p.location.into(),
+                    block_id: p.block_id,
+                }),
+                EntityRelativeMove7(p) => EntityRelativeMove0(cb::EntityRelativeMove0 {
+                    entity_id: p.entity_id,
+                    dx: p.dx,
+                    dy: p.dy,
+                    dz: p.dz,
+                }),
+                EntityRelativeMove22(p) => EntityRelativeMove7(cb::EntityRelativeMove7 {
+                    entity_id: p.entity_id,
+                    dx: p.dx,
+                    dy: p.dy,
+                    dz: p.dz,
+                }),
+                EntityRelativeMove100(p) => EntityRelativeMove22(cb::EntityRelativeMove22 {
+                    entity_id: p.entity_id,
+                    dx: relative_move(p.dx)?,
+                    dy: relative_move(p.dy)?,
+                    dz: relative_move(p.dz)?,
+                    on_ground: p.on_ground,
+                }),
+                EntityVelocity7(p) => EntityVelocity0(cb::EntityVelocity0 {
+                    entity_id: p.entity_id,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                }),
+                UpdateHealth7(p) => UpdateHealth0(cb::UpdateHealth0 {
+                    health: p.health,
+                    food: p
+                        .food
+                        .try_into()
+                        .map_err(|_| TranslateError::OutOfRange("food"))?,
+                    saturation: p.saturation,
+                }),
+                EntityMetadata402(p) => EntityMetadata353(self.metadata(
+                    p,
+                    slot402_to_346,
+                    |p| p,
+                    self.no_particles(),
+                )?),
+                EntityMetadata441(p) => {
+                    EntityMetadata402(self.metadata(p, Ok, Position6::from, Ok)?)
+                }
+                EntityMetadata463(p) => {
+                    EntityMetadata441(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata706(p) => {
+                    EntityMetadata463(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata755(p) => {
+                    EntityMetadata706(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata757(p) => {
+                    EntityMetadata755(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata759(p) => {
+                    EntityMetadata757(self.metadata(p, Ok, |p| p, self.no_particles())?)
+                }
+                EntityMetadata353(p) => EntityMetadata57(cb::EntityMetadata7 {
+                    entity_id: p.entity_id,
+                    metadata: map_metadata(p.metadata, |v| {
+                        value57(self.value353_to_57(v)?, slot346_to_0)
+                    })?,
+                }),
+                SpawnPlayer5(p) => SpawnPlayer0(cb::SpawnPlayer0 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    name: p.name,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    current_item: p.current_item,
+                    metadata: p.metadata,
+                }),
+                // the name of the player isn't sent anymore
+                SpawnPlayer19(_) => return Err(self.unsupported()),
+                SpawnPlayer49(p) => SpawnPlayer19(cb::SpawnPlayer19 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    current_item: 0,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer57(p) => SpawnPlayer49(cb::SpawnPlayer49 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: packed_health(),
+                }),
+                SpawnPlayer100(p) => SpawnPlayer57(cb::SpawnPlayer49 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: p.metadata,
+                }),
+                SpawnPlayer346(p) => SpawnPlayer100(spawn_player(p, |v| value57(v, slot346_to_0))?),
+                SpawnPlayer353(p) => SpawnPlayer346(spawn_player(p, |v| self.value353_to_57(v))?),
+                SpawnPlayer402(p) => SpawnPlayer353(spawn_player(p, |v| {
+                    value353(v, slot402_to_346, |p| p, self.no_particles())
+                })?),
+                SpawnPlayer441(p) => {
+                    SpawnPlayer402(spawn_player(p, |v| value353(v, Ok, Position6::from, Ok))?)
+                }
+                SpawnPlayer463(p) => SpawnPlayer441(spawn_player(p, |v| {
+                    value353(v, Ok, |p| p, self.no_particles())
+                })?),
+                SpawnPlayer550(p) => SpawnPlayer463(cb::SpawnPlayer100 {
+                    entity_id: p.entity_id,
+                    player_uuid: p.player_uuid,
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    metadata: EntityMetadata {
+                        inner: BTreeMap::new(),
+                    },
+                }),
+                JoinGame1(p) => JoinGame0(cb::JoinGame0 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                }),
+                JoinGame29(p) => JoinGame1(cb::JoinGame1 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                }),
+                JoinGame86(p) => JoinGame29(cb::JoinGame29 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: gamemode0(p.gamemode)?,
+                    dimension: p.dimension,
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame108(p) => JoinGame86(cb::JoinGame86 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: dimension0(p.dimension),
+                    difficulty: p.difficulty,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                // the difficulty is only sent in its own packet
+                JoinGame468(p) => JoinGame108(cb::JoinGame108 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    difficulty: Difficulty0::Normal,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame552(p) => JoinGame468(cb::JoinGame468 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: p.dimension,
+                    max_players: p.max_players,
+                    level_type: p.level_type,
+                    view_distance: p.view_distance,
+                    reduced_debug_info: p.reduced_debug_info,
+                }),
+                JoinGame730(p) => JoinGame552(cb::JoinGame552 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    dimension: dimension_by_name(&p.dimension)?,
+                    hashed_seed: p.hashed_seed,
+                    max_players: p.max_players,
+                    level_type: level_type(p.is_flat),
+                    view_distance: p.view_distance,
+                    reduced_debug_info: p.reduced_debug_info,
+                    enable_respawn_screen: p.enable_respawn_screen,
+                }),
+                // the registry codec of 1.16 has a different format, skip
+                // right to the versions without it
+                JoinGame748(p) if *self.to < 730 => {
+                    let dimension = p
+                        .dimension
+                        .get("effects")
+                        .and_then(miners_nbt::Value::as_string)
+                        .unwrap_or(&p.world_name);
+                    JoinGame552(cb::JoinGame552 {
+                        entity_id: p.entity_id,
+                        hardcore: p.hardcore,
+                        gamemode: p.gamemode,
+                        dimension: dimension_by_name(dimension)?,
+                        hashed_seed: p.hashed_seed,
+                        max_players: p.max_players.clamp(0, u8::MAX as i32) as u8,
+                        level_type: level_type(p.is_flat),
+                        view_distance: p.view_distance,
+                        reduced_debug_info: p.reduced_debug_info,
+                        enable_respawn_screen: p.enable_respawn_screen,
+                    })
+                }
+                JoinGame757(p) => JoinGame748(cb::JoinGame748 {
+                    entity_id: p.entity_id,
+                    hardcore: p.hardcore,
+                    gamemode: p.gamemode,
+                    previous_gamemode: p.previous_gamemode,
+                    world_names: p.world_names,
+                    dimension_codec: p.dimension_codec,
+                    dimension: p.dimension,
+                    world_name: p.world_name,
+                    hashed_seed: p.hashed_seed,
+                    max_players: p.max_players,
+                    view_distance: p.view_distance,
+                    reduced_debug_info: p.reduced_debug_info,
+                    enable_respawn_screen: p.enable_respawn_screen,
+                    is_debug: p.is_debug,
+                    is_flat: p.is_flat,
+                }),
+                SetSlot346(p) => SetSlot67(cb::SetSlot67 {
+                    window_id: p.window_id,
+                    slot: p.slot,
+                    item: slot346_to_0(p.item)?,
+                }),
+                SetSlot402(p) => SetSlot346(cb::SetSlot346 {
+                    window_id: p.window_id,
+                    slot: p.slot,
+                    item: slot402_to_346(p.item)?,
+                }),
+                SetSlot756(p) => SetSlot402(cb::SetSlot346 {
+                    window_id: p.window_id,
+                    slot: p.slot,
+                    item: p.item,
+                }),
+                WindowItems346(p) => WindowItems67(cb::WindowItems67 {
+                    window_id: p.window_id,
+                    slots: map_slots(p.slots, slot346_to_0)?,
+                }),
+                WindowItems402(p) => WindowItems346(cb::WindowItems346 {
+                    window_id: p.window_id,
+                    slots: map_slots(p.slots, slot402_to_346)?,
+                }),
+                // older clients are told about the carried item by a set slot
+                // to window -1
+                WindowItems756(p) => WindowItems402(cb::WindowItems346 {
+                    window_id: p.window_id,
+                    slots: p.items,
+                }),
+                _ => return Err(self.unsupported()),
+            }
+        })
+    }
+
+    fn step_serverbound<'a>(&self, packet: SbPlay<'a>) -> Result<SbPlay<'a>> {
+        use SbPlay::*;
+        Ok(if self.upgrade() {
+            match packet {
+                KeepAlive0(p) => KeepAlive7(sb::KeepAlive7 { id: p.id }),
+                KeepAlive7(p) => KeepAlive339(sb::KeepAlive339 { id: p.id as i64 }),
+                PlayerPosition0(p) => PlayerPosition10(sb::PlayerPosition10 {
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    on_ground: p.on_ground,
+                }),
+                PlayerPositionAndLook0(p) => PlayerPositionAndLook10(sb::PlayerPositionAndLook10 {
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    on_ground: p.on_ground,
+                }),
+                ClickWindow83(p) => ClickWindow346(sb::ClickWindow83 {
+                    window_id: p.window_id,
+                    action: p.action,
+                    action_id: p.action_id,
+                    clicked_item: slot0_to_346(p.clicked_item)?,
+                }),
+                ClickWindow346(p) => ClickWindow402(sb::ClickWindow83 {
+                    window_id: p.window_id,
+                    action: p.action,
+                    action_id: p.action_id,
+                    clicked_item: slot346_to_402(p.clicked_item)?,
+                }),
+                // the server resyncs the window on the unknown state id
+                ClickWindow755(p) => ClickWindow756(sb::ClickWindow756 {
+                    window_id: p.window_id,
+                    state_id: 0,
+                    action: p.action,
+                    changed_slots: p.changed_slots,
+                    carried_item: p.carried_item,
+                }),
+                CreativeInventoryAction80(p) => {
+                    CreativeInventoryAction346(sb::CreativeInventoryAction80 {
+                        slot: p.slot,
+                        clicked_item: slot0_to_346(p.clicked_item)?,
+                    })
+                }
+                CreativeInventoryAction346(p) => {
+                    CreativeInventoryAction402(sb::CreativeInventoryAction80 {
+                        slot: p.slot,
+                        clicked_item: slot346_to_402(p.clicked_item)?,
+                    })
+                }
+                _ => return Err(self.unsupported()),
+            }
+        } else {
+            match packet {
+                KeepAlive7(p) => KeepAlive0(sb::KeepAlive0 { id: p.id }),
+                KeepAlive339(p) => KeepAlive7(sb::KeepAlive7 {
+                    id: p
+                        .id
+                        .try_into()
+                        .map_err(|_| TranslateError::OutOfRange("keep alive id"))?,
+                }),
+                PlayerPosition10(p) => PlayerPosition0(sb::PlayerPosition0 {
+                    x: p.x,
+                    y: p.y,
+                    stance: p.y + EYE_HEIGHT,
+                    z: p.z,
+                    on_ground: p.on_ground,
+                }),
+                PlayerPositionAndLook10(p) => PlayerPositionAndLook0(sb::PlayerPositionAndLook0 {
+                    x: p.x,
+                    y: p.y,
+                    stance: p.y + EYE_HEIGHT,
+                    z: p.z,
+                    yaw: p.yaw,
+                    pitch: p.pitch,
+                    on_ground: p.on_ground,
+                }),
+                ClickWindow346(p) => ClickWindow83(sb::ClickWindow83 {
+                    window_id: p.window_id,
+                    action: p.action,
+                    action_id: p.action_id,
+                    clicked_item: slot346_to_0(p.clicked_item)?,
+                }),
+                ClickWindow402(p) => ClickWindow346(sb::ClickWindow83 {
+                    window_id: p.window_id,
+                    action: p.action,
+                    action_id: p.action_id,
+                    clicked_item: slot402_to_346(p.clicked_item)?,
+                }),
+                ClickWindow756(p) => ClickWindow755(sb::ClickWindow755 {
+                    window_id: p.window_id,
+                    action: p.action,
+                    changed_slots: p.changed_slots,
+                    carried_item: p.carried_item,
+                }),
+                CreativeInventoryAction346(p) => {
+                    CreativeInventoryAction80(sb::CreativeInventoryAction80 {
+                        slot: p.slot,
+                        clicked_item: slot346_to_0(p.clicked_item)?,
+                    })
+                }
+                CreativeInventoryAction402(p) => {
+                    CreativeInventoryAction346(sb::CreativeInventoryAction80 {
+                        slot: p.slot,
+                        clicked_item: slot402_to_346(p.clicked_item)?,
+                    })
+                }
+                _ => return Err(self.unsupported()),
+            }
+        })
+    }
+
+    /// Translates the ids and metadata indices of a packet already converted
+    /// to the target version.
+    fn rewrite_clientbound(&self, packet: &mut CbPlay) -> Result<()> {
+        use CbPlay::*;
+        match packet {
+            BlockChange0(cb::BlockChange0 {
+                block_type,
+                block_data,
+                ..
+            })
+            | BlockChange6(cb::BlockChange6 {
+                block_type,
+                block_data,
+                ..
+            }) => {
+                let id = self.block_state(*block_type << 4 | (*block_data & 0b1111) as i32)?;
+                *block_type = id >> 4;
+                *block_data = (id & 0b1111) as u8;
+            }
+            BlockChange25(cb::BlockChange25 { block_id, .. })
+            | BlockChange441(cb::BlockChange441 { block_id, .. }) => {
+                *block_id = self.block_state(*block_id)?;
+            }
+            EntityMetadata57(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata353(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata402(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata441(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata463(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata706(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata755(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata757(p) => self.rewrite_metadata(&mut p.metadata)?,
+            EntityMetadata759(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer57(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer100(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer346(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer353(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer402(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer441(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SpawnPlayer463(p) => self.rewrite_metadata(&mut p.metadata)?,
+            SetSlot67(p) => p.item.map_ids(self)?,
+            SetSlot346(p) => p.item.map_ids(self)?,
+            SetSlot402(p) => p.item.map_ids(self)?,
+            SetSlot756(p) => p.item.map_ids(self)?,
+            WindowItems67(p) => self.map_slot_ids(&mut p.slots)?,
+            WindowItems346(p) => self.map_slot_ids(&mut p.slots)?,
+            WindowItems402(p) => self.map_slot_ids(&mut p.slots)?,
+            WindowItems756(p) => {
+                self.map_slot_ids(&mut p.items)?;
+                p.carried_item.map_ids(self)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Translates the item ids of a packet already converted to the target
+    /// version.
+    fn rewrite_serverbound(&self, packet: &mut SbPlay) -> Result<()> {
+        use SbPlay::*;
+        match packet {
+            ClickWindow83(p) => p.clicked_item.map_ids(self)?,
+            ClickWindow346(p) => p.clicked_item.map_ids(self)?,
+            ClickWindow402(p) => p.clicked_item.map_ids(self)?,
+            ClickWindow755(sb::ClickWindow755 {
+                changed_slots,
+                carried_item,
+                ..
+            })
+            | ClickWindow756(sb::ClickWindow756 {
+                changed_slots,
+                carried_item,
+                ..
+            }) => {
+                for changed in changed_slots {
+                    changed.item.map_ids(self)?;
+                }
+                carried_item.map_ids(self)?;
+            }
+            CreativeInventoryAction80(p) => p.clicked_item.map_ids(self)?,
+            CreativeInventoryAction346(p) => p.clicked_item.map_ids(self)?,
+            CreativeInventoryAction402(p) => p.clicked_item.map_ids(self)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn map_slot_ids(&self, slots: &mut [impl MapIds]) -> Result<()> {
+        for slot in slots {
+            slot.map_ids(self)?;
+        }
+        Ok(())
+    }
+
+    fn block_state(&self, id: i32) -> Result<i32> {
+        self.ids
+            .block_state(id, self.from, self.to)
+            .ok_or(TranslateError::UnmappedId {
+                kind: "block state",
+                id,
+            })
+    }
+
+    fn item(&self, id: i32) -> Result<i32> {
+        self.ids
+            .item(id, self.from, self.to)
+            .ok_or(TranslateError::UnmappedId { kind: "item", id })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn metadata<'a, S, P, Q, S2, P2, Q2>(
+        &self,
+        packet: cb::EntityMetadata7<EntityMetadata<Value353<'a, S, P, Q>>>,
+        slot: impl Fn(S) -> Result<S2>,
+        position: impl Fn(P) -> P2,
+        particle: impl Fn(Q) -> Result<Q2>,
+    ) -> Result<cb::EntityMetadata7<EntityMetadata<Value353<'a, S2, P2, Q2>>>> {
+        Ok(cb::EntityMetadata7 {
+            entity_id: packet.entity_id,
+            metadata: map_metadata(packet.metadata, |value| {
+                value353(value, &slot, &position, &particle)
+            })?,
+        })
+    }
+
+    /// The values added after 1.12 can't be sent to older versions.
+    fn value353_to_57<'a, S, Q>(
+        &self,
+        value: Value353<'a, S, Position6, Q>,
+    ) -> Result<Value57<'a, S>> {
+        use Value353::*;
+        Ok(match value {
+            Byte(v) => Value57::Byte(v),
+            VarInt(v) => Value57::VarInt(v),
+            Float(v) => Value57::Float(v),
+            String(v) => Value57::String(v),
+            Chat(v) => Value57::Chat(v),
+            Slot(v) => Value57::Slot(v),
+            Boolean(v) => Value57::Boolean(v),
+            Rotation(v) => Value57::Rotation(v),
+            Position(v) => Value57::Position(v),
+            OptPosition(v) => Value57::OptPosition(v),
+            Direction(v) => Value57::Direction(v),
+            OptUuid(v) => Value57::OptUuid(v),
+            BlockId(v) => Value57::BlockId(v),
+            Nbt(v) => Value57::Nbt(v),
+            _ => return Err(self.unsupported()),
+        })
+    }
+
+    fn rewrite_metadata<V: MapIds>(&self, metadata: &mut EntityMetadata<V>) -> Result<()> {
+        let (from, to) = (*self.from, *self.to);
+        if from.min(to) < METADATA_LAYOUT_PV {
+            if from.max(to) >= METADATA_LAYOUT_PV && !metadata.inner.is_empty() {
+                return Err(self.unsupported());
+            }
+        } else if from < to {
+            for (pv, inserted) in METADATA_INSERTIONS {
+                if from < pv && pv <= to {
+                    shift_indices(metadata, inserted, 1)?;
+                }
+            }
+        } else {
+            for (pv, inserted) in METADATA_INSERTIONS.into_iter().rev() {
+                if to < pv && pv <= from {
+                    metadata.inner.remove(&inserted);
+                    shift_indices(metadata, inserted + 1, -1)?;
+                }
+            }
+        }
+        for value in metadata.inner.values_mut() {
+            value.map_ids(self)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shifts all indices starting at `start` by `by`
+fn shift_indices<V>(metadata: &mut EntityMetadata<V>, start: u8, by: i16) -> Result<()> {
+    metadata.inner = std::mem::take(&mut metadata.inner)
+        .into_iter()
+        .map(|(index, value)| {
+            if index < start {
+                return Ok((index, value));
+            }
+            match u8::try_from(index as i16 + by) {
+                // 0xff terminates the metadata
+                Ok(index) if index != 0xff => Ok((index, value)),
+                _ => Err(TranslateError::OutOfRange("entity metadata index")),
+            }
+        })
+        .collect::<Result<_>>()?;
+    Ok(())
+}
+
+fn map_metadata<V, V2>(
+    metadata: EntityMetadata<V>,
+    value: impl Fn(V) -> Result<V2>,
+) -> Result<EntityMetadata<V2>> {
+    let inner = metadata
+        .inner
+        .into_iter()
+        .map(|(index, v)| Ok((index, value(v)?)))
+        .collect::<Result<_>>()?;
+    Ok(EntityMetadata { inner })
+}
+
+fn spawn_player<V, V2>(
+    packet: cb::SpawnPlayer100<EntityMetadata<V>>,
+    value: impl Fn(V) -> Result<V2>,
+) -> Result<cb::SpawnPlayer100<EntityMetadata<V2>>> {
+    Ok(cb::SpawnPlayer100 {
+        entity_id: packet.entity_id,
+        player_uuid: packet.player_uuid,
+        x: packet.x,
+        y: packet.y,
+        z: packet.z,
+        yaw: packet.yaw,
+        pitch: packet.pitch,
+        metadata: map_metadata(packet.metadata, value)?,
+    })
+}
+
+/// Clients before 1.9 crash on players spawned without metadata, they get
+/// the full health.
+fn packed_health<'a>() -> PackedEntityMetadata0<'a> {
+    PackedEntityMetadata0 {
+        inner: BTreeMap::from([(6, Value0::Float(20.0))]),
+    }
+}
+
+fn gamemode86(gamemode: cb::GameMode0) -> cb::GameMode86 {
+    match gamemode {
+        cb::GameMode0::Survival => cb::GameMode86::Survival,
+        cb::GameMode0::Creative => cb::GameMode86::Creative,
+        cb::GameMode0::Adventure => cb::GameMode86::Adventure,
+    }
+}
+
+fn gamemode0(gamemode: cb::GameMode86) -> Result<cb::GameMode0> {
+    Ok(match gamemode {
+        cb::GameMode86::Survival => cb::GameMode0::Survival,
+        cb::GameMode86::Creative => cb::GameMode0::Creative,
+        cb::GameMode86::Adventure => cb::GameMode0::Adventure,
+        cb::GameMode86::Spectator => return Err(TranslateError::OutOfRange("gamemode")),
+    })
+}
+
+fn dimension86(dimension: cb::Dimension0) -> cb::Dimension86 {
+    match dimension {
+        cb::Dimension0::Nether => cb::Dimension86::Nether,
+        cb::Dimension0::Overworld => cb::Dimension86::Overworld,
+        cb::Dimension0::End => cb::Dimension86::End,
+    }
+}
+
+fn dimension0(dimension: cb::Dimension86) -> cb::Dimension0 {
+    match dimension {
+        cb::Dimension86::Nether => cb::Dimension0::Nether,
+        cb::Dimension86::Overworld => cb::Dimension0::Overworld,
+        cb::Dimension86::End => cb::Dimension0::End,
+    }
+}
+
+/// Only the vanilla dimensions existed before 1.16
+fn dimension_by_name(name: &str) -> Result<cb::Dimension86> {
+    Ok(match name {
+        "minecraft:overworld" => cb::Dimension86::Overworld,
+        "minecraft:the_nether" => cb::Dimension86::Nether,
+        "minecraft:the_end" => cb::Dimension86::End,
+        _ => return Err(TranslateError::OutOfRange("dimension")),
+    })
+}
+
+fn level_type<'a>(is_flat: bool) -> Cow<'a, str> {
+    Cow::Borrowed(if is_flat { "flat" } else { "default" })
+}
+
+fn bits(value: i32, bits: u32, name: &'static str) -> Result<i32> {
+    let max = 1 << (bits - 1);
+    if (-max..max).contains(&value) {
+        Ok(value)
+    } else {
+        Err(TranslateError::OutOfRange(name))
+    }
+}
+
+fn relative_move(delta: f64) -> Result<f32> {
+    if (-4.0..=127.0 / 32.0).contains(&delta) {
+        Ok(delta as f32)
+    } else {
+        Err(TranslateError::OutOfRange("relative move"))
+    }
+}
+
+/// The damage of items moved into their nbt in 1.13
+fn slot0_to_346(slot: Slot0) -> Result<Slot346> {
+    Ok(match slot {
+        Slot0::Empty => Slot346::Empty,
+        Slot0::Item {
+            block_id,
+            count,
+            damage,
+            mut nbt,
+        } => {
+            if damage != 0 {
+                nbt.insert(
+                    Cow::Borrowed("Damage"),
+                    miners_nbt::Value::Int(damage as i32),
+                );
+            }
+            Slot346::Item {
+                id: block_id
+                    .try_into()
+                    .map_err(|_| TranslateError::OutOfRange("item id"))?,
+                count,
+                nbt,
+            }
+        }
+    })
+}
+
+fn slot346_to_0(slot: Slot346) -> Result<Slot0> {
+    Ok(match slot {
+        Slot346::Empty => Slot0::Empty,
+        Slot346::Item { id, count, mut nbt } => {
+            let damage = match nbt.remove("Damage") {
+                Some(miners_nbt::Value::Int(damage)) => damage
+                    .try_into()
+                    .map_err(|_| TranslateError::OutOfRange("item damage"))?,
+                _ => 0,
+            };
+            Slot0::Item {
+                block_id: id
+                    .try_into()
+                    .map_err(|_| TranslateError::OutOfRange("item id"))?,
+                count,
+                damage,
+                nbt,
+            }
+        }
+    })
+}
+
+fn map_slots<S, S2>(slots: Vec<S>, slot: impl Fn(S) -> Result<S2>) -> Result<Vec<S2>> {
+    slots.into_iter().map(slot).collect()
+}
+
+fn slot346_to_402(slot: Slot346) -> Result<Slot402> {
+    Ok(match slot {
+        Slot346::Empty => Slot402::Empty,
+        Slot346::Item { id, count, nbt } => Slot402::Item {
+            id: id as i32,
+            count,
+            nbt,
+        },
+    })
+}
+
+fn slot402_to_346(slot: Slot402) -> Result<Slot346> {
+    Ok(match slot {
+        Slot402::Empty => Slot346::Empty,
+        Slot402::Item { id, count, nbt } => Slot346::Item {
+            id: id
+                .try_into()
+                .map_err(|_| TranslateError::OutOfRange("item id"))?,
+            count,
+            nbt,
+        },
+    })
+}
+
+fn value57<'a, S, S2>(
+    value: Value57<'a, S>,
+    slot: impl Fn(S) -> Result<S2>,
+) -> Result<Value57<'a, S2>> {
+    use Value57::*;
+    Ok(match value {
+        Byte(v) => Byte(v),
+        VarInt(v) => VarInt(v),
+        Float(v) => Float(v),
+        String(v) => String(v),
+        Chat(v) => Chat(v),
+        Slot(v) => Slot(slot(v)?),
+        Boolean(v) => Boolean(v),
+        Rotation(v) => Rotation(v),
+        Position(v) => Position(v),
+        OptPosition(v) => OptPosition(v),
+        Direction(v) => Direction(v),
+        OptUuid(v) => OptUuid(v),
+        BlockId(v) => BlockId(v),
+        Nbt(v) => Nbt(v),
+    })
+}
+
+fn value57_to_353<'a, S, Q>(value: Value57<'a, S>) -> Value353<'a, S, Position6, Q> {
+    use Value57::*;
+    match value {
+        Byte(v) => Value353::Byte(v),
+        VarInt(v) => Value353::VarInt(v),
+        Float(v) => Value353::Float(v),
+        String(v) => Value353::String(v),
+        Chat(v) => Value353::Chat(v),
+        Slot(v) => Value353::Slot(v),
+        Boolean(v) => Value353::Boolean(v),
+        Rotation(v) => Value353::Rotation(v),
+        Position(v) => Value353::Position(v),
+        OptPosition(v) => Value353::OptPosition(v),
+        Direction(v) => Value353::Direction(v),
+        OptUuid(v) => Value353::OptUuid(v),
+        BlockId(v) => Value353::BlockId(v),
+        Nbt(v) => Value353::Nbt(v),
+    }
+}
+
+fn value353<'a, S, P, Q, S2, P2, Q2>(
+    value: Value353<'a, S, P, Q>,
+    slot: impl Fn(S) -> Result<S2>,
+    position: impl Fn(P) -> P2,
+    particle: impl Fn(Q) -> Result<Q2>,
+) -> Result<Value353<'a, S2, P2, Q2>> {
+    use Value353::*;
+    Ok(match value {
+        Byte(v) => Byte(v),
+        VarInt(v) => VarInt(v),
+        Float(v) => Float(v),
+        String(v) => String(v),
+        Chat(v) => Chat(v),
+        OptChat(v) => OptChat(v),
+        Slot(v) => Slot(slot(v)?),
+        Boolean(v) => Boolean(v),
+        Rotation(v) => Rotation(v),
+        Position(v) => Position(position(v)),
+        OptPosition(v) => OptPosition(v.map(position)),
+        Direction(v) => Direction(v),
+        OptUuid(v) => OptUuid(v),
+        BlockId(v) => BlockId(v),
+        Nbt(v) => Nbt(v),
+        Particle(v) => Particle(particle(v)?),
+        VillagerData(v) => VillagerData(v),
+        OptVarInt(v) => OptVarInt(v),
+        Pose(v) => Pose(v),
+        CatVariant(v) => CatVariant(v),
+        FrogVariant(v) => FrogVariant(v),
+        GlobalPos(v) => GlobalPos(self::GlobalPos {
+            dimension: v.dimension,
+            position: position(v.position),
+        }),
+        PaintingVariant(v) => PaintingVariant(v),
+    })
+}
+
+/// Values containing registry ids
+trait MapIds {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()>;
+}
+
+impl<'a> MapIds for Slot0<'a> {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        if let Slot0::Item { block_id, .. } = self {
+            *block_id = translator
+                .item(*block_id as i32)?
+                .try_into()
+                .map_err(|_| TranslateError::OutOfRange("item id"))?;
+        }
+        Ok(())
+    }
+}
+impl<'a> MapIds for Slot346<'a> {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        if let Slot346::Item { id, .. } = self {
+            *id = translator
+                .item(*id as i32)?
+                .try_into()
+                .map_err(|_| TranslateError::OutOfRange("item id"))?;
+        }
+        Ok(())
+    }
+}
+impl<'a> MapIds for Slot402<'a> {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        if let Slot402::Item { id, .. } = self {
+            *id = translator.item(*id)?;
+        }
+        Ok(())
+    }
+}
+impl MapIds for BlockId {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        let id = translator.block_state(self.id << 4 | (self.data & 0b1111) as i32)?;
+        self.id = id >> 4;
+        self.data = (id & 0b1111) as u8;
+        Ok(())
+    }
+}
+impl<'a, S: MapIds> MapIds for Value57<'a, S> {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        match self {
+            Value57::Slot(slot) => slot.map_ids(translator),
+            Value57::BlockId(block_id) => block_id.map_ids(translator),
+            _ => Ok(()),
+        }
+    }
+}
+impl<'a, S: MapIds, P, Q> MapIds for Value353<'a, S, P, Q> {
+    fn map_ids(&mut self, translator: &Translator<impl IdMap>) -> Result<()> {
+        match self {
+            Value353::Slot(slot) => slot.map_ids(translator),
+            Value353::BlockId(block_id) => block_id.map_ids(translator),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+fn pv(pv: i32) -> ProtocolVersion {
+    ProtocolVersion::new(pv).unwrap()
+}
+
+#[test]
+fn keep_alive() {
+    let translator = Translator::new(pv(5), pv(760), &SameIds);
+    let packet = CbPlay::KeepAlive0(cb::KeepAlive0 { id: 42 });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::KeepAlive339(cb::KeepAlive339 { id: 42 }))
+    ));
+
+    let translator = Translator::new(pv(760), pv(47), &SameIds);
+    let packet = SbPlay::KeepAlive339(sb::KeepAlive339 { id: 42 });
+    assert!(matches!(
+        translator.serverbound(packet),
+        Ok(SbPlay::KeepAlive7(sb::KeepAlive7 { id: 42 }))
+    ));
+    let packet = SbPlay::KeepAlive339(sb::KeepAlive339 { id: i64::MAX });
+    assert!(matches!(
+        translator.serverbound(packet),
+        Err(TranslateError::OutOfRange(_))
+    ));
+}
+
+/// Shifts all ids up on upgrades and down on downgrades
+#[cfg(test)]
+struct Offset;
+#[cfg(test)]
+impl IdMap for Offset {
+    fn block_state(&self, id: i32, from: ProtocolVersion, to: ProtocolVersion) -> Option<i32> {
+        Some(if *from < *to { id + 1 } else { id - 1 })
+    }
+    fn item(&self, id: i32, from: ProtocolVersion, to: ProtocolVersion) -> Option<i32> {
+        Some(if *from < *to { id + 1 } else { id - 1 })
+    }
+}
+
+#[test]
+fn block_change() {
+    let translator = Translator::new(pv(340), pv(760), &Offset);
+    let packet = CbPlay::BlockChange25(cb::BlockChange25 {
+        location: Position6 { x: 1, y: 2, z: 3 },
+        block_id: 16,
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::BlockChange441(cb::BlockChange441 {
+            location: Position441 { x: 1, z: 3, y: 2 },
+            block_id: 17,
+        }))
+    ));
+}
+
+#[test]
+fn entity_relative_move() {
+    let translator = Translator::new(pv(760), pv(47), &SameIds);
+    let packet = CbPlay::EntityRelativeMove100(cb::EntityRelativeMove100 {
+        entity_id: 1,
+        dx: 1.5,
+        dy: 0.0,
+        dz: -2.0,
+        on_ground: true,
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::EntityRelativeMove22(cb::EntityRelativeMove22 { dx, on_ground: true, .. })) if dx == 1.5
+    ));
+    // the deltas are sent as a byte in 1/32 blocks
+    for (delta, valid) in [
+        (-4.0, true),
+        (127.0 / 32.0, true),
+        (4.0, false),
+        (7.0, false),
+    ] {
+        let packet = CbPlay::EntityRelativeMove100(cb::EntityRelativeMove100 {
+            entity_id: 1,
+            dx: delta,
+            dy: 0.0,
+            dz: 0.0,
+            on_ground: true,
+        });
+        let translated = translator.clientbound(packet);
+        if valid {
+            assert!(translated.is_ok());
+        } else {
+            assert!(matches!(translated, Err(TranslateError::OutOfRange(_))));
+        }
+    }
+}
+
+#[test]
+fn entity_metadata() {
+    let translator = Translator::new(pv(404), pv(760), &SameIds);
+    let packet = CbPlay::EntityMetadata402(cb::EntityMetadata7 {
+        entity_id: 1,
+        metadata: EntityMetadata {
+            inner: [
+                (0, Value353::Byte(1)),
+                (7, Value353::Position(Position6 { x: 1, y: 2, z: 3 })),
+            ]
+            .into_iter()
+            .collect(),
+        },
+    });
+    let translated = translator.clientbound(packet);
+    let Ok(CbPlay::EntityMetadata759(packet)) = translated else {
+        panic!("unexpected translation")
+    };
+    let indices: Vec<_> = packet.metadata.inner.keys().copied().collect();
+    assert_eq!(indices, [0, 9]);
+    assert!(matches!(
+        packet.metadata.inner[&9],
+        Value353::Position(Position441 { x: 1, z: 3, y: 2 })
+    ));
+
+    let translator = Translator::new(pv(760), pv(404), &SameIds);
+    let translated = translator.clientbound(CbPlay::EntityMetadata759(packet));
+    let Ok(CbPlay::EntityMetadata402(packet)) = translated else {
+        panic!("unexpected translation")
+    };
+    let indices: Vec<_> = packet.metadata.inner.keys().copied().collect();
+    assert_eq!(indices, [0, 7]);
+}
+
+#[test]
+fn join_game() {
+    use miners_nbt::nbt;
+
+    let translator = Translator::new(pv(47), pv(340), &SameIds);
+    let packet = CbPlay::JoinGame29(cb::JoinGame29 {
+        entity_id: 1,
+        hardcore: false,
+        gamemode: cb::GameMode0::Creative,
+        dimension: cb::Dimension0::Nether,
+        difficulty: Difficulty0::Easy,
+        max_players: 20,
+        level_type: Cow::Borrowed("flat"),
+        reduced_debug_info: false,
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::JoinGame108(cb::JoinGame108 {
+            entity_id: 1,
+            gamemode: cb::GameMode86::Creative,
+            dimension: cb::Dimension86::Nether,
+            difficulty: Difficulty0::Easy,
+            level_type,
+            ..
+        })) if level_type == "flat"
+    ));
+
+    let packet = CbPlay::JoinGame757(cb::JoinGame757 {
+        entity_id: 1,
+        hardcore: false,
+        gamemode: cb::GameMode86::Spectator,
+        previous_gamemode: -1,
+        world_names: vec![Cow::Borrowed("minecraft:overworld")],
+        dimension_codec: miners_nbt::Nbt {
+            name: Cow::Borrowed(""),
+            data: miners_nbt::Compound::default(),
+        },
+        dimension: miners_nbt::Nbt {
+            name: Cow::Borrowed(""),
+            data: nbt!({ "effects": "minecraft:the_nether" }),
+        },
+        world_name: Cow::Borrowed("minecraft:overworld"),
+        hashed_seed: 0,
+        max_players: 1000,
+        view_distance: 12,
+        simulation_distance: 8,
+        reduced_debug_info: false,
+        enable_respawn_screen: true,
+        is_debug: false,
+        is_flat: false,
+    });
+    let translator = Translator::new(pv(757), pv(498), &SameIds);
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::JoinGame468(cb::JoinGame468 {
+            gamemode: cb::GameMode86::Spectator,
+            dimension: cb::Dimension86::Nether,
+            max_players: 255,
+            view_distance: 12,
+            ..
+        }))
+    ));
+
+    // the registries can't be made up
+    let translator = Translator::new(pv(578), pv(760), &SameIds);
+    let packet = CbPlay::JoinGame552(cb::JoinGame552 {
+        entity_id: 1,
+        hardcore: false,
+        gamemode: cb::GameMode86::Survival,
+        dimension: cb::Dimension86::Overworld,
+        hashed_seed: 0,
+        max_players: 20,
+        level_type: Cow::Borrowed("default"),
+        view_distance: 10,
+        reduced_debug_info: false,
+        enable_respawn_screen: true,
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Err(TranslateError::Unsupported { .. })
+    ));
+}
+
+#[test]
+fn player_spawn() {
+    use crate::netty::types::angle::Angle;
+    let uuid = uuid::Uuid::from_u128(1);
+
+    let translator = Translator::new(pv(47), pv(760), &SameIds);
+    let packet = CbPlay::SpawnPlayer19(cb::SpawnPlayer19 {
+        entity_id: 1,
+        player_uuid: uuid,
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        yaw: Angle(0),
+        pitch: Angle(0),
+        current_item: 0,
+        metadata: packed_health(),
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::SpawnPlayer550(cb::SpawnPlayer550 { entity_id: 1, player_uuid, y, .. }))
+            if player_uuid == uuid && y == 2.0
+    ));
+
+    let translator = Translator::new(pv(760), pv(47), &SameIds);
+    let packet = CbPlay::SpawnPlayer550(cb::SpawnPlayer550 {
+        entity_id: 1,
+        player_uuid: uuid,
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        yaw: Angle(0),
+        pitch: Angle(0),
+    });
+    let Ok(CbPlay::SpawnPlayer19(packet)) = translator.clientbound(packet) else {
+        panic!("unexpected translation")
+    };
+    assert_eq!(packet.player_uuid, uuid);
+    assert!(!packet.metadata.inner.is_empty());
+}
+
+#[test]
+fn set_slot() {
+    let translator = Translator::new(pv(340), pv(760), &Offset);
+    let packet = CbPlay::SetSlot67(cb::SetSlot67 {
+        window_id: 0,
+        slot: 36,
+        item: Slot0::Item {
+            block_id: 276,
+            count: 1,
+            damage: 5,
+            nbt: Default::default(),
+        },
+    });
+    let Ok(CbPlay::SetSlot756(packet)) = translator.clientbound(packet) else {
+        panic!("unexpected translation")
+    };
+    let Slot402::Item {
+        id: 277,
+        count: 1,
+        nbt,
+    } = &packet.item
+    else {
+        panic!("unexpected slot")
+    };
+    assert_eq!(nbt.get("Damage"), Some(&miners_nbt::Value::Int(5)));
+
+    let translator = Translator::new(pv(760), pv(340), &Offset);
+    assert!(matches!(
+        translator.clientbound(CbPlay::SetSlot756(packet)),
+        Ok(CbPlay::SetSlot67(cb::SetSlot67 {
+            slot: 36,
+            item: Slot0::Item { block_id: 276, damage: 5, nbt, .. },
+            ..
+        })) if nbt.is_empty()
+    ));
+
+    let packet = CbPlay::WindowItems756(cb::WindowItems756 {
+        window_id: 0,
+        state_id: 3,
+        items: vec![Slot402::Empty],
+        carried_item: Slot402::Empty,
+    });
+    assert!(matches!(
+        translator.clientbound(packet),
+        Ok(CbPlay::WindowItems67(cb::WindowItems67 { slots, .. })) if slots == [Slot0::Empty]
+    ));
+}
+
+#[test]
+fn serverbound_items() {
+    let item = |id| sb::CreativeInventoryAction80 {
+        slot: 36,
+        clicked_item: Slot402::Item {
+            id,
+            count: 1,
+            nbt: Default::default(),
+        },
+    };
+    let translator = Translator::new(pv(760), pv(340), &Offset);
+    let packet = SbPlay::CreativeInventoryAction402(item(277));
+    assert!(matches!(
+        translator.serverbound(packet),
+        Ok(SbPlay::CreativeInventoryAction80(
+            sb::CreativeInventoryAction80 {
+                clicked_item: Slot0::Item { block_id: 276, .. },
+                ..
+            }
+        ))
+    ));
+
+    let translator = Translator::new(pv(340), pv(760), &Offset);
+    let packet = SbPlay::CreativeInventoryAction80(sb::CreativeInventoryAction80 {
+        slot: 36,
+        clicked_item: Slot0::Item {
+            block_id: 276,
+            count: 1,
+            damage: 0,
+            nbt: Default::default(),
+        },
+    });
+    assert!(matches!(
+        translator.serverbound(packet),
+        Ok(SbPlay::CreativeInventoryAction402(
+            sb::CreativeInventoryAction80 {
+                clicked_item: Slot402::Item { id: 277, .. },
+                ..
+            }
+        ))
+    ));
+
+    let translator = Translator::new(pv(340), pv(5), &SameIds);
+    let packet = SbPlay::PlayerPosition10(sb::PlayerPosition10 {
+        x: 0.0,
+        y: 64.0,
+        z: 0.0,
+        on_ground: true,
+    });
+    assert!(matches!(
+        translator.serverbound(packet),
+        Ok(SbPlay::PlayerPosition0(sb::PlayerPosition0 { y, stance, .. }))
+            if y == 64.0 && stance == 64.0 + EYE_HEIGHT
+    ));
+}
@@ -1,4 +1,5 @@
 pub mod angle;
+pub mod chunk;
 pub mod entity_metadata;
 pub mod particle;
 pub mod position;
@@ -0,0 +1,193 @@
+use ::miners_encoding::{attrs::Var, decode, encode, Decode, Encode};
+
+use std::borrow::Cow;
+
+/// the length of the block light and sky light arrays of a section
+const LIGHT_LEN: usize = 16 * 16 * 16 / 2;
+/// the length of the biome array of a full chunk
+const BIOMES_LEN: usize = 16 * 16;
+
+fn take<'dec>(cursor: &mut std::io::Cursor<&'dec [u8]>, len: usize) -> decode::Result<&'dec [u8]> {
+    let pos = cursor.position() as usize;
+    let data = cursor
+        .get_ref()
+        .get(pos..pos + len)
+        .ok_or(decode::Error::UnexpectedEndOfSlice)?;
+    cursor.set_position((pos + len) as u64);
+    Ok(data)
+}
+
+/// A 16x16x16 section of the paletted chunk format sent in the chunk data
+/// packet since pv86 (1.9).
+#[derive(ToStatic, Debug, Clone, PartialEq)]
+pub struct ChunkSection86<'a> {
+    /// The index of the section in the column, 0 being the lowest.
+    pub y: u8,
+    pub bits_per_block: u8,
+    /// Maps the values in `data` to global block state ids. Empty if the
+    /// global palette is used directly.
+    pub palette: Vec<i32>,
+    /// The block state values, packed into longs with entries spanning
+    /// across long boundaries.
+    pub data: Vec<u64>,
+    pub block_light: Cow<'a, [u8]>,
+    /// Only sent in dimensions with sky light, i.e. the overworld.
+    pub sky_light: Option<Cow<'a, [u8]>>,
+}
+
+impl<'a> ChunkSection86<'a> {
+    fn decode(
+        cursor: &mut std::io::Cursor<&'a [u8]>,
+        y: u8,
+        sky_light: bool,
+    ) -> decode::Result<Self> {
+        let bits_per_block = u8::decode(cursor)?;
+        if bits_per_block == 0 || bits_per_block > 32 {
+            return Err(decode::Error::Custom("invalid bits per block"));
+        }
+        let palette_len = Var::<u32>::decode(cursor)?.into_inner();
+        let palette = (0..palette_len)
+            .map(|_| Var::<i32>::decode(cursor).map(Var::into_inner))
+            .collect::<decode::Result<_>>()?;
+        let data_len = Var::<u32>::decode(cursor)?.into_inner();
+        let data = (0..data_len)
+            .map(|_| u64::decode(cursor))
+            .collect::<decode::Result<_>>()?;
+        Ok(Self {
+            y,
+            bits_per_block,
+            palette,
+            data,
+            block_light: Cow::Borrowed(take(cursor, LIGHT_LEN)?),
+            sky_light: match sky_light {
+                true => Some(Cow::Borrowed(take(cursor, LIGHT_LEN)?)),
+                false => None,
+            },
+        })
+    }
+
+    /// The global block state id at the given position relative to the
+    /// section, `None` if the position or the data is out of bounds.
+    pub fn block(&self, x: u8, y: u8, z: u8) -> Option<i32> {
+        if x > 15 || y > 15 || z > 15 {
+            return None;
+        }
+        let bits = self.bits_per_block as usize;
+        let index = ((y as usize * 16) + z as usize) * 16 + x as usize;
+        let bit = index * bits;
+        let (long, offset) = (bit / 64, bit % 64);
+        let mut value = self.data.get(long)? >> offset;
+        if offset + bits > 64 {
+            value |= self.data.get(long + 1)? << (64 - offset);
+        }
+        let value = value & ((1u64 << bits) - 1);
+        match self.palette.is_empty() {
+            true => Some(value as i32),
+            false => self.palette.get(value as usize).copied(),
+        }
+    }
+}
+
+impl<'a> Encode for ChunkSection86<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        self.bits_per_block.encode(writer)?;
+        Var::from(self.palette.len() as u32).encode(writer)?;
+        for entry in &self.palette {
+            Var::from(*entry).encode(writer)?;
+        }
+        Var::from(self.data.len() as u32).encode(writer)?;
+        for long in &self.data {
+            long.encode(writer)?;
+        }
+        writer.write_all(&self.block_light)?;
+        if let Some(sky_light) = &self.sky_light {
+            writer.write_all(sky_light)?;
+        }
+        Ok(())
+    }
+}
+
+/// The decoded data of a chunk data packet since pv86 (1.9).
+#[derive(ToStatic, Debug, Clone, PartialEq)]
+pub struct ChunkColumn86<'a> {
+    /// The sent sections ordered from bottom to top.
+    pub sections: Vec<ChunkSection86<'a>>,
+    /// Only sent for full chunks.
+    pub biomes: Option<Cow<'a, [u8]>>,
+}
+
+impl<'a> ChunkColumn86<'a> {
+    /// Decodes the data of a chunk data packet. Whether sky light is sent
+    /// depends on the dimension the chunk is in and is not part of the
+    /// packet.
+    pub fn decode(
+        data: &'a [u8],
+        primary_bitmask: i32,
+        full_chunk: bool,
+        sky_light: bool,
+    ) -> decode::Result<Self> {
+        let mut cursor = std::io::Cursor::new(data);
+        let sections = (0..16u8)
+            .filter(|y| primary_bitmask & (1 << y) != 0)
+            .map(|y| ChunkSection86::decode(&mut cursor, y, sky_light))
+            .collect::<decode::Result<_>>()?;
+        let biomes = match full_chunk {
+            true => Some(Cow::Borrowed(take(&mut cursor, BIOMES_LEN)?)),
+            false => None,
+        };
+        Ok(Self { sections, biomes })
+    }
+
+    /// The bitmask of the sent sections.
+    pub fn primary_bitmask(&self) -> i32 {
+        self.sections
+            .iter()
+            .fold(0, |mask, section| mask | 1 << section.y)
+    }
+}
+
+impl<'a> Encode for ChunkColumn86<'a> {
+    fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+        for section in &self.sections {
+            section.encode(writer)?;
+        }
+        if let Some(biomes) = &self.biomes {
+            writer.write_all(biomes)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn chunk_column86() {
+    // 4 bits per block, so 16 entries per long
+    let mut data = vec![0u64; 256];
+    // x = 1, y = 0, z = 0
+    data[0] |= 1 << 4;
+    // x = 0, y = 15, z = 15 is the first entry in the last long
+    data[255] |= 2;
+    let section = ChunkSection86 {
+        y: 3,
+        bits_per_block: 4,
+        palette: vec![0, 16, 32],
+        data,
+        block_light: Cow::Owned(vec![0xff; LIGHT_LEN]),
+        sky_light: Some(Cow::Owned(vec![0xff; LIGHT_LEN])),
+    };
+    let column = ChunkColumn86 {
+        sections: vec![section],
+        biomes: Some(Cow::Owned(vec![1; BIOMES_LEN])),
+    };
+    let mut bytes = vec![];
+    #[allow(clippy::unwrap_used)]
+    column.encode(&mut bytes).unwrap();
+
+    #[allow(clippy::unwrap_used)]
+    let decoded = ChunkColumn86::decode(&bytes, column.primary_bitmask(), true, true).unwrap();
+    assert_eq!(decoded, column);
+    let section = &decoded.sections[0];
+    assert_eq!(section.block(0, 0, 0), Some(0));
+    assert_eq!(section.block(1, 0, 0), Some(16));
+    assert_eq!(section.block(0, 15, 15), Some(32));
+    assert_eq!(section.block(16, 0, 0), None);
+}
@@ -14,7 +14,7 @@ const DUPLICATE_METADATA_INDEX: &str = "duplicate index in EntityMetadata";
 /// The first EntityMetadata
 #[derive(ToStatic, Debug)]
 pub struct PackedEntityMetadata0<'a> {
-    inner: BTreeMap<u8, Value0<'a>>,
+    pub(crate) inner: BTreeMap<u8, Value0<'a>>,
 }
 /// Chat shifted the ids and packed key and type values are no longer used
 /// both now have their own respective bytes
@@ -128,7 +128,7 @@ impl<'a> Encode for PackedEntityMetadata0<'a> {
 
 #[derive(ToStatic, Debug)]
 pub struct EntityMetadata<Value> {
-    inner: BTreeMap<u8, Value>,
+    pub(crate) inner: BTreeMap<u8, Value>,
 }
 impl<'dec, Value> Decode<'dec> for EntityMetadata<Value>
 where
@@ -161,10 +161,26 @@ where
             index.encode(writer)?;
             value.encode(writer)?;
         }
-        Ok(())
+        0xffu8.encode(writer)
     }
 }
 
+#[test]
+#[allow(clippy::unwrap_used)]
+fn entity_metadata() {
+    let metadata = EntityMetadata57 {
+        inner: BTreeMap::from([(0, Value57::Byte(1)), (2, Value57::Boolean(true))]),
+    };
+    let mut encoded = vec![];
+    metadata.encode(&mut encoded).unwrap();
+    // the entries have to be terminated by 0xff, it used to be missing
+    assert_eq!(encoded, [0, 0, 1, 2, 6, 1, 0xff]);
+    let mut cursor = std::io::Cursor::new(&encoded[..]);
+    let decoded = EntityMetadata57::decode(&mut cursor).unwrap();
+    assert_eq!(cursor.position() as usize, encoded.len());
+    assert_eq!(decoded.inner.len(), 2);
+}
+
 #[derive(Encoding, ToStatic, Debug)]
 pub struct Chat<'a>(Cow<'a, str>);
 
@@ -279,8 +295,8 @@ pub enum Direction {
 // `id << 4 | data`, varint
 // global palette, 0 means absent
 pub struct BlockId {
-    id: i32,
-    data: u8,
+    pub(crate) id: i32,
+    pub(crate) data: u8,
 }
 impl<'dec> Decode<'dec> for BlockId {
     fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
@@ -310,6 +326,6 @@ impl Encode for BlockId {
 
 #[derive(Encoding, ToStatic, Debug)]
 pub struct GlobalPos<'a, Position> {
-    dimension: Cow<'a, str>,
-    position: Position,
+    pub(crate) dimension: Cow<'a, str>,
+    pub(crate) position: Position,
 }
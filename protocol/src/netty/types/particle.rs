@@ -5,6 +5,18 @@ use attrs::*;
 
 use super::position::Position441;
 
+/// Decodes a particle from its id and the data following it, used where the
+/// id is not sent directly in front of the data like in the particle packet.
+pub trait DecodeParticle<'dec>: Sized {
+    fn decode_data(id: i32, cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self>;
+}
+
+/// The encoding counterpart of [`DecodeParticle`].
+pub trait EncodeParticle {
+    fn id(&self) -> i32;
+    fn encode_data(&self, writer: &mut impl std::io::Write) -> encode::Result<()>;
+}
+
 macro_rules! particle_versions {
     ($(
         $(#[$($attr:tt)*])*
@@ -24,12 +36,11 @@ macro_rules! particle_versions {
             id: i32,
             data: Option<ParticleData<'a, Slot>>,
         }
-        impl<'dec: 'a, 'a, Slot> Decode<'dec> for $particle<'a, Slot>
+        impl<'dec: 'a, 'a, Slot> DecodeParticle<'dec> for $particle<'a, Slot>
         where
             Slot: Decode<'dec>,
         {
-            fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
-                let id = Var::decode(cursor)?.into_inner();
+            fn decode_data(id: i32, cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
                 let data = match id {
                     $($block_id => Some(ParticleData::Block(Var::decode(cursor)?.into_inner())),)?
                     $($block_marker_id => Some(ParticleData::BlockMarker(Var::decode(cursor)?.into_inner())),)?
@@ -51,12 +62,15 @@ macro_rules! particle_versions {
             }
         }
 
-        impl<'a, Slot> Encode for $particle<'a, Slot>
+        impl<'a, Slot> EncodeParticle for $particle<'a, Slot>
         where
             Slot: Encode,
         {
-            fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
-                Var::from(self.id).encode(writer)?;
+            fn id(&self) -> i32 {
+                self.id
+            }
+
+            fn encode_data(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
                 #[cfg(debug_assertions)]
                 match self.id {
                     $($block_id if !matches!(self.data, Some(ParticleData::Block(_))) => {
@@ -122,6 +136,26 @@ macro_rules! particle_versions {
                 Ok(())
             }
         }
+
+        impl<'dec: 'a, 'a, Slot> Decode<'dec> for $particle<'a, Slot>
+        where
+            Slot: Decode<'dec>,
+        {
+            fn decode(cursor: &mut std::io::Cursor<&'dec [u8]>) -> decode::Result<Self> {
+                let id = Var::decode(cursor)?.into_inner();
+                Self::decode_data(id, cursor)
+            }
+        }
+
+        impl<'a, Slot> Encode for $particle<'a, Slot>
+        where
+            Slot: Encode,
+        {
+            fn encode(&self, writer: &mut impl std::io::Write) -> encode::Result<()> {
+                Var::from(self.id).encode(writer)?;
+                self.encode_data(writer)
+            }
+        }
     )*};
 }
 
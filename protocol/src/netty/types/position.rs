@@ -1,7 +1,5 @@
 #[derive(Bitfield, ToStatic, Clone, Copy, Debug)]
-// TODO: figure out when position changed, this might be the new version
-// the old one had z and y swapped compared to the current one
-// was changed in pv442
+// see ProtocolVersion::position_layout for which versions use which layout
 pub struct Position6 {
     #[encoding(bits = "26")]
     pub x: i32,
@@ -62,6 +60,16 @@ pub struct Position441 {
     #[encoding(bits = "12")]
     pub y: i16,
 }
+impl From<Position6> for Position441 {
+    fn from(Position6 { x, y, z }: Position6) -> Self {
+        Position441 { x, z, y }
+    }
+}
+impl From<Position441> for Position6 {
+    fn from(Position441 { x, z, y }: Position441) -> Self {
+        Position6 { x, y, z }
+    }
+}
 
 #[test]
 fn position441() {
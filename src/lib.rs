@@ -16,6 +16,8 @@ pub use miners_nbt as nbt;
 pub use miners_net as net;
 #[cfg(feature = "packet")]
 pub use miners_packet as packet;
+#[cfg(feature = "profile")]
+pub use miners_profile as profile;
 #[cfg(feature = "protocol")]
 pub use miners_protocol as protocol;
 #[cfg(feature = "version")]
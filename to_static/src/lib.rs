@@ -2,6 +2,7 @@ mod bool;
 mod btreemap;
 mod cow;
 mod hashmap;
+mod net;
 mod nonzero;
 mod num;
 mod option;
@@ -0,0 +1,11 @@
+use crate::ToStatic;
+
+impl ToStatic for std::net::IpAddr {
+    type Static = std::net::IpAddr;
+    fn to_static(&self) -> Self::Static {
+        *self
+    }
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
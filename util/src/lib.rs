@@ -1 +1,3 @@
 pub mod bufpool;
+#[cfg(feature = "server_hash")]
+pub mod server_hash;
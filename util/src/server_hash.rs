@@ -0,0 +1,48 @@
+//! The hash identifying a connection to the session server, computed by both
+//! the client and the server after the encryption handshake.
+
+use sha1::{Digest, Sha1};
+
+/// Computes the hash sent to the session server as `serverId`.
+///
+/// This is the sha1 of the server id, the shared secret and the public key,
+/// formatted as a signed two's complement number in hex without leading
+/// zeros.
+pub fn server_hash(server_id: &str, secret: &[u8], public_key_der: &[u8]) -> String {
+    let mut hash: [u8; 20] = Sha1::new()
+        .chain_update(server_id)
+        .chain_update(secret)
+        .chain_update(public_key_der)
+        .finalize()
+        .into();
+
+    let negative = hash[0] & 0x80 != 0;
+    if negative {
+        // negate the two's complement number: invert the bits and add one
+        let mut carry = true;
+        for byte in hash.iter_mut().rev() {
+            let (value, overflow) = (!*byte).overflowing_add(carry as u8);
+            *byte = value;
+            carry = overflow;
+        }
+    }
+
+    let hex: String = hash.iter().map(|byte| format!("{byte:02x}")).collect();
+    let hex = match hex.trim_start_matches('0') {
+        "" => "0",
+        hex => hex,
+    };
+    if negative {
+        format!("-{hex}")
+    } else {
+        hex.into()
+    }
+}
+
+#[test]
+fn known_hashes() {
+    let hash = |name: &str| server_hash(name, &[], &[]);
+    assert_eq!(hash("Notch"), "4ed1f46bbe04bc756bcb17c0c7ce3e4632f06a48");
+    assert_eq!(hash("jeb_"), "-7c9d5b0044c130109a5d7b5fb5c317c02b4e28c1");
+    assert_eq!(hash("simon"), "88e16a1019277b15d58faf0541e11910eb756f6");
+}
@@ -0,0 +1,529 @@
+//! The names of the releases and snapshots using each protocol version.
+//!
+//! Taken from the protocol version numbers listed on wiki.vg, the names of a
+//! version are in the order they were released in. Some accepted numbers were
+//! skipped by Mojang and have no names.
+
+use super::SNAPSHOT;
+
+/// All named protocol versions sorted by their number.
+pub(crate) static CATALOGUE: &[(i32, &[&str])] = &[
+    (0, &["13w41a", "13w41b"]),
+    (1, &["13w42a", "13w42b"]),
+    (2, &["13w43a"]),
+    (3, &["1.7-pre", "1.7.1-pre"]),
+    (
+        4,
+        &[
+            "1.7.2",
+            "13w47a",
+            "13w47b",
+            "13w47c",
+            "13w47d",
+            "13w47e",
+            "13w48a",
+            "13w48b",
+            "13w49a",
+            "1.7.3-pre",
+            "1.7.4",
+            "1.7.5",
+        ],
+    ),
+    (
+        5,
+        &[
+            "14w02a",
+            "1.7.6-pre1",
+            "1.7.6-pre2",
+            "1.7.6",
+            "1.7.7",
+            "1.7.8",
+            "1.7.9",
+            "1.7.10-pre1",
+            "1.7.10-pre2",
+            "1.7.10-pre3",
+            "1.7.10-pre4",
+            "1.7.10",
+        ],
+    ),
+    (6, &["14w03a"]),
+    (7, &["14w04a"]),
+    (8, &["14w04b"]),
+    (9, &["14w05a"]),
+    (10, &["14w06a"]),
+    (11, &["14w07a"]),
+    (12, &["14w08a"]),
+    (13, &["14w10a"]),
+    (14, &["14w11a"]),
+    (15, &["14w17a"]),
+    (16, &["14w18b"]),
+    (17, &["14w19a"]),
+    (18, &["14w20a"]),
+    (19, &["14w21a"]),
+    (20, &["14w21b"]),
+    (21, &["14w25a"]),
+    (22, &["14w25b"]),
+    (23, &["14w26a"]),
+    (24, &["14w26b"]),
+    (25, &["14w26c"]),
+    (26, &["14w27a"]),
+    (27, &["14w28a"]),
+    (28, &["14w28b"]),
+    (29, &["14w29a"]),
+    (30, &["14w30a"]),
+    (31, &["14w30c"]),
+    (32, &["14w31a"]),
+    (33, &["14w32a"]),
+    (34, &["14w32b"]),
+    (35, &["14w32c"]),
+    (36, &["14w32d"]),
+    (37, &["14w33a"]),
+    (38, &["14w33b"]),
+    (39, &["14w33c"]),
+    (40, &["14w34a"]),
+    (41, &["14w34b"]),
+    (42, &["14w34c"]),
+    (43, &["14w34d"]),
+    (44, &["1.8-pre1"]),
+    (45, &["1.8-pre2"]),
+    (46, &["1.8-pre3"]),
+    (
+        47,
+        &[
+            "1.8",
+            "1.8.1-pre1",
+            "1.8.1-pre2",
+            "1.8.1-pre3",
+            "1.8.1-pre4",
+            "1.8.1-pre5",
+            "1.8.1",
+            "1.8.2-pre1",
+            "1.8.2-pre2",
+            "1.8.2-pre3",
+            "1.8.2-pre4",
+            "1.8.2-pre5",
+            "1.8.2-pre6",
+            "1.8.2-pre7",
+            "1.8.2",
+            "1.8.3",
+            "1.8.4",
+            "1.8.5",
+            "1.8.6",
+            "1.8.7",
+            "1.8.8",
+            "1.8.9",
+        ],
+    ),
+    (48, &["15w14a"]),
+    (49, &["15w31a"]),
+    (50, &["15w31b"]),
+    (51, &["15w31c"]),
+    (52, &["15w32a"]),
+    (53, &["15w32b"]),
+    (54, &["15w32c"]),
+    (55, &["15w33a"]),
+    (56, &["15w33b"]),
+    (57, &["15w33c"]),
+    (58, &["15w34a"]),
+    (59, &["15w34b"]),
+    (60, &["15w34c"]),
+    (61, &["15w34d"]),
+    (62, &["15w35a"]),
+    (63, &["15w35b"]),
+    (64, &["15w35c"]),
+    (65, &["15w35d"]),
+    (66, &["15w35e"]),
+    (67, &["15w36a"]),
+    (68, &["15w36b"]),
+    (69, &["15w36c"]),
+    (70, &["15w36d"]),
+    (71, &["15w37a"]),
+    (72, &["15w38a"]),
+    (73, &["15w38b"]),
+    (74, &["15w39a", "15w39b", "15w39c"]),
+    (75, &["15w40a"]),
+    (76, &["15w40b"]),
+    (77, &["15w41a"]),
+    (78, &["15w41b"]),
+    (79, &["15w42a"]),
+    (80, &["15w43a"]),
+    (81, &["15w43b"]),
+    (82, &["15w43c"]),
+    (83, &["15w44a"]),
+    (84, &["15w44b"]),
+    (85, &["15w45a"]),
+    (86, &["15w46a"]),
+    (87, &["15w47a"]),
+    (88, &["15w47b"]),
+    (89, &["15w47c"]),
+    (90, &["15w49a"]),
+    (91, &["15w49b"]),
+    (92, &["15w50a"]),
+    (93, &["15w51a"]),
+    (94, &["15w51b"]),
+    (95, &["16w02a"]),
+    (96, &["16w03a"]),
+    (97, &["16w04a"]),
+    (98, &["16w05a"]),
+    (99, &["16w05b"]),
+    (100, &["16w06a"]),
+    (101, &["16w07a"]),
+    (102, &["16w07b"]),
+    (103, &["1.9-pre1"]),
+    (104, &["1.9-pre2"]),
+    (105, &["1.9-pre3"]),
+    (106, &["1.9-pre4"]),
+    (107, &["1.9", "1.9.1-pre1"]),
+    (108, &["1.9.1-pre2", "1.9.1-pre3", "1.9.1", "1.RV-Pre1"]),
+    (109, &["16w14a", "16w15a", "16w15b", "1.9.3-pre1", "1.9.2"]),
+    (110, &["1.9.3-pre2", "1.9.3-pre3", "1.9.3", "1.9.4"]),
+    (201, &["16w20a"]),
+    (202, &["16w21a"]),
+    (203, &["16w21b"]),
+    (204, &["1.10-pre1"]),
+    (205, &["1.10-pre2"]),
+    (210, &["1.10", "1.10.1", "1.10.2"]),
+    (301, &["16w32a"]),
+    (302, &["16w32b"]),
+    (303, &["16w33a"]),
+    (304, &["16w35a"]),
+    (305, &["16w36a"]),
+    (306, &["16w38a"]),
+    (307, &["16w39a"]),
+    (308, &["16w39b"]),
+    (309, &["16w39c"]),
+    (310, &["16w40a"]),
+    (311, &["16w41a"]),
+    (312, &["16w42a"]),
+    (313, &["16w43a", "16w44a"]),
+    (314, &["1.11-pre1"]),
+    (315, &["1.11"]),
+    (316, &["16w50a", "1.11.1", "1.11.2"]),
+    (317, &["17w06a"]),
+    (318, &["17w13a"]),
+    (319, &["17w13b"]),
+    (320, &["17w14a"]),
+    (321, &["17w15a"]),
+    (322, &["17w16a"]),
+    (323, &["17w16b"]),
+    (324, &["17w17a"]),
+    (325, &["17w17b"]),
+    (326, &["17w18a"]),
+    (327, &["17w18b"]),
+    (328, &["1.12-pre1"]),
+    (329, &["1.12-pre2"]),
+    (330, &["1.12-pre3"]),
+    (331, &["1.12-pre4"]),
+    (332, &["1.12-pre5"]),
+    (333, &["1.12-pre6"]),
+    (334, &["1.12-pre7"]),
+    (335, &["1.12"]),
+    (336, &["17w31a"]),
+    (337, &["1.12.1-pre1"]),
+    (338, &["1.12.1"]),
+    (339, &["1.12.2-pre1", "1.12.2-pre2"]),
+    (340, &["1.12.2"]),
+    (341, &["17w43a"]),
+    (342, &["17w43b"]),
+    (343, &["17w45a"]),
+    (344, &["17w45b"]),
+    (345, &["17w46a"]),
+    (346, &["17w47a"]),
+    (347, &["17w47b"]),
+    (348, &["17w48a"]),
+    (349, &["17w49a"]),
+    (350, &["17w49b"]),
+    (351, &["17w50a"]),
+    (352, &["18w01a"]),
+    (353, &["18w02a"]),
+    (354, &["18w03a"]),
+    (355, &["18w03b"]),
+    (356, &["18w05a"]),
+    (357, &["18w06a"]),
+    (358, &["18w07a"]),
+    (359, &["18w07b"]),
+    (360, &["18w07c"]),
+    (361, &["18w08a"]),
+    (362, &["18w08b"]),
+    (363, &["18w09a"]),
+    (364, &["18w10a"]),
+    (365, &["18w10b"]),
+    (366, &["18w10c"]),
+    (367, &["18w10d"]),
+    (368, &["18w11a"]),
+    (369, &["18w14a"]),
+    (370, &["18w14b"]),
+    (371, &["18w15a"]),
+    (372, &["18w16a"]),
+    (373, &["18w19a"]),
+    (374, &["18w19b"]),
+    (375, &["18w20a"]),
+    (376, &["18w20b"]),
+    (377, &["18w20c"]),
+    (378, &["18w21a"]),
+    (379, &["18w21b"]),
+    (380, &["18w22a"]),
+    (381, &["18w22b"]),
+    (382, &["18w22c"]),
+    (383, &["1.13-pre1"]),
+    (384, &["1.13-pre2"]),
+    (385, &["1.13-pre3"]),
+    (386, &["1.13-pre4"]),
+    (387, &["1.13-pre5"]),
+    (388, &["1.13-pre6"]),
+    (389, &["1.13-pre7"]),
+    (390, &["1.13-pre8"]),
+    (391, &["1.13-pre9"]),
+    (392, &["1.13-pre10"]),
+    (393, &["1.13"]),
+    (394, &["18w30a"]),
+    (395, &["18w30b"]),
+    (396, &["18w31a"]),
+    (397, &["18w32a"]),
+    (398, &["18w33a"]),
+    (399, &["1.13.1-pre1"]),
+    (400, &["1.13.1-pre2"]),
+    (401, &["1.13.1"]),
+    (402, &["1.13.2-pre1"]),
+    (403, &["1.13.2-pre2"]),
+    (404, &["1.13.2"]),
+    (441, &["18w43a", "18w43b"]),
+    (442, &["18w43c"]),
+    (443, &["18w44a"]),
+    (444, &["18w45a"]),
+    (445, &["18w46a"]),
+    (446, &["18w47a"]),
+    (447, &["18w47b"]),
+    (448, &["18w48a"]),
+    (449, &["18w48b"]),
+    (450, &["18w49a"]),
+    (451, &["18w50a"]),
+    (452, &["19w02a"]),
+    (453, &["19w03a"]),
+    (454, &["19w03b"]),
+    (455, &["19w03c"]),
+    (456, &["19w04a"]),
+    (457, &["19w04b"]),
+    (458, &["19w05a"]),
+    (459, &["19w06a"]),
+    (460, &["19w07a"]),
+    (461, &["19w08a"]),
+    (462, &["19w08b"]),
+    (463, &["19w09a"]),
+    (464, &["19w11a"]),
+    (465, &["19w11b"]),
+    (466, &["19w12a"]),
+    (467, &["19w12b"]),
+    (468, &["19w13a"]),
+    (469, &["19w13b"]),
+    (470, &["19w14a"]),
+    (471, &["19w14b"]),
+    (472, &["1.14-pre1"]),
+    (473, &["1.14-pre2"]),
+    (474, &["1.14-pre3"]),
+    (475, &["1.14-pre4"]),
+    (476, &["1.14-pre5"]),
+    (477, &["1.14"]),
+    (478, &["1.14.1-pre1"]),
+    (479, &["1.14.1-pre2"]),
+    (480, &["1.14.1"]),
+    (481, &["1.14.2-pre1"]),
+    (482, &["1.14.2-pre2"]),
+    (483, &["1.14.2-pre3"]),
+    (484, &["1.14.2-pre4"]),
+    (485, &["1.14.2"]),
+    (486, &["1.14.3-pre1"]),
+    (487, &["1.14.3-pre2"]),
+    (488, &["1.14.3-pre3"]),
+    (489, &["1.14.3-pre4"]),
+    (490, &["1.14.3"]),
+    (491, &["1.14.4-pre1"]),
+    (492, &["1.14.4-pre2"]),
+    (493, &["1.14.4-pre3"]),
+    (494, &["1.14.4-pre4"]),
+    (495, &["1.14.4-pre5"]),
+    (496, &["1.14.4-pre6"]),
+    (497, &["1.14.4-pre7"]),
+    (498, &["1.14.4"]),
+    (500, &["1.14.3 - Combat Test"]),
+    (550, &["19w34a"]),
+    (551, &["19w35a"]),
+    (552, &["19w36a"]),
+    (553, &["19w37a"]),
+    (554, &["19w38a"]),
+    (555, &["19w38b"]),
+    (556, &["19w39a"]),
+    (557, &["19w40a"]),
+    (558, &["19w41a"]),
+    (559, &["19w42a"]),
+    (560, &["19w44a"]),
+    (561, &["19w45a"]),
+    (562, &["19w45b"]),
+    (563, &["19w46a"]),
+    (564, &["19w46b"]),
+    (565, &["1.15-pre1"]),
+    (566, &["1.15-pre2"]),
+    (567, &["1.15-pre3"]),
+    (569, &["1.15-pre4"]),
+    (570, &["1.15-pre5"]),
+    (571, &["1.15-pre6"]),
+    (572, &["1.15-pre7"]),
+    (573, &["1.15"]),
+    (574, &["1.15.1-pre1"]),
+    (575, &["1.15.1"]),
+    (576, &["1.15.2-pre1"]),
+    (577, &["1.15.2-pre2"]),
+    (578, &["1.15.2"]),
+    (701, &["20w06a"]),
+    (702, &["20w07a"]),
+    (703, &["20w08a"]),
+    (704, &["20w09a"]),
+    (705, &["20w10a"]),
+    (706, &["20w11a"]),
+    (707, &["20w12a"]),
+    (708, &["20w13a"]),
+    (709, &["20w13b"]),
+    (710, &["20w14a"]),
+    (711, &["20w15a"]),
+    (712, &["20w16a"]),
+    (713, &["20w17a"]),
+    (714, &["20w18a"]),
+    (715, &["20w19a"]),
+    (716, &["20w20a"]),
+    (717, &["20w20b"]),
+    (718, &["20w21a"]),
+    (719, &["20w22a"]),
+    (721, &["1.16-pre1"]),
+    (722, &["1.16-pre2"]),
+    (725, &["1.16-pre3"]),
+    (727, &["1.16-pre4"]),
+    (729, &["1.16-pre5"]),
+    (730, &["1.16-pre6"]),
+    (732, &["1.16-pre7"]),
+    (733, &["1.16-pre8"]),
+    (734, &["1.16-rc1"]),
+    (735, &["1.16"]),
+    (736, &["1.16.1"]),
+    (738, &["20w27a"]),
+    (740, &["20w28a"]),
+    (741, &["20w29a"]),
+    (743, &["20w30a"]),
+    (744, &["1.16.2-pre1"]),
+    (746, &["1.16.2-pre2"]),
+    (748, &["1.16.2-pre3"]),
+    (749, &["1.16.2-rc1"]),
+    (750, &["1.16.2-rc2"]),
+    (751, &["1.16.2"]),
+    (752, &["1.16.3-rc1"]),
+    (753, &["1.16.3"]),
+    (754, &["1.16.4", "1.16.5"]),
+    (755, &["1.17"]),
+    (756, &["1.17.1"]),
+    (757, &["1.18", "1.18.1"]),
+    (758, &["1.18.2"]),
+    (759, &["1.19"]),
+    (760, &["1.19.1", "1.19.2"]),
+    (761, &["1.19.3"]),
+    (762, &["1.19.4"]),
+    (763, &["1.20", "1.20.1"]),
+    (764, &["1.20.2"]),
+    (SNAPSHOT | 1, &["1.16.4-pre1"]),
+    (SNAPSHOT | 2, &["1.16.4-pre2"]),
+    (SNAPSHOT | 3, &["1.16.4-rc1"]),
+    (SNAPSHOT | 5, &["20w45a"]),
+    (SNAPSHOT | 6, &["20w46a"]),
+    (SNAPSHOT | 7, &["20w48a"]),
+    (SNAPSHOT | 8, &["20w49a"]),
+    (SNAPSHOT | 9, &["20w51a"]),
+    (SNAPSHOT | 10, &["21w03a"]),
+    (SNAPSHOT | 11, &["21w05a"]),
+    (SNAPSHOT | 12, &["21w05b"]),
+    (SNAPSHOT | 13, &["21w06a"]),
+    (SNAPSHOT | 14, &["21w07a"]),
+    (SNAPSHOT | 15, &["21w08a"]),
+    (SNAPSHOT | 16, &["21w08b"]),
+    (SNAPSHOT | 17, &["21w10a"]),
+    (SNAPSHOT | 18, &["21w11a"]),
+    (SNAPSHOT | 19, &["21w13a"]),
+    (SNAPSHOT | 20, &["21w14a"]),
+    (SNAPSHOT | 21, &["21w15a"]),
+    (SNAPSHOT | 22, &["21w16a"]),
+    (SNAPSHOT | 23, &["21w17a"]),
+    (SNAPSHOT | 25, &["21w18a"]),
+    (SNAPSHOT | 26, &["21w19a"]),
+    (SNAPSHOT | 27, &["21w20a"]),
+    (SNAPSHOT | 28, &["1.17-pre1"]),
+    (SNAPSHOT | 29, &["1.17-pre2"]),
+    (SNAPSHOT | 30, &["1.17-pre3"]),
+    (SNAPSHOT | 31, &["1.17-pre4"]),
+    (SNAPSHOT | 32, &["1.17-pre5"]),
+    (SNAPSHOT | 33, &["1.17-rc1"]),
+    (SNAPSHOT | 34, &["1.17-rc2"]),
+    (SNAPSHOT | 35, &["1.17.1-pre1"]),
+    (SNAPSHOT | 36, &["1.17.1-pre2"]),
+    (SNAPSHOT | 37, &["1.17.1-pre3"]),
+    (SNAPSHOT | 38, &["1.17.1-rc1"]),
+    (SNAPSHOT | 39, &["1.17.1-rc2"]),
+    (SNAPSHOT | 40, &["21w37a"]),
+    (SNAPSHOT | 41, &["21w38a"]),
+    (SNAPSHOT | 42, &["21w39a"]),
+    (SNAPSHOT | 43, &["21w40a"]),
+    (SNAPSHOT | 44, &["21w41a"]),
+    (SNAPSHOT | 45, &["21w42a"]),
+    (SNAPSHOT | 46, &["21w43a"]),
+    (SNAPSHOT | 47, &["21w44a"]),
+    (SNAPSHOT | 48, &["1.18-pre1"]),
+    (SNAPSHOT | 49, &["1.18-pre2"]),
+    (SNAPSHOT | 50, &["1.18-pre3"]),
+    (SNAPSHOT | 51, &["1.18-pre4"]),
+    (SNAPSHOT | 52, &["1.18-pre5"]),
+    (SNAPSHOT | 53, &["1.18-pre6"]),
+    (SNAPSHOT | 54, &["1.18-pre7"]),
+    (SNAPSHOT | 55, &["1.18-pre8"]),
+    (SNAPSHOT | 56, &["1.18-rc1"]),
+    (SNAPSHOT | 57, &["1.18-rc2"]),
+    (SNAPSHOT | 58, &["1.18-rc3"]),
+    (SNAPSHOT | 59, &["1.18-rc4"]),
+    (SNAPSHOT | 60, &["1.18.1-pre1"]),
+    (SNAPSHOT | 61, &["1.18.1-rc1"]),
+    (SNAPSHOT | 62, &["1.18.1-rc2"]),
+    (SNAPSHOT | 63, &["1.18.1-rc3"]),
+    (SNAPSHOT | 64, &["22w03a"]),
+    (SNAPSHOT | 65, &["22w05a"]),
+    (SNAPSHOT | 66, &["22w06a"]),
+    (SNAPSHOT | 67, &["22w07a"]),
+    (SNAPSHOT | 68, &["1.18.2-pre1"]),
+    (SNAPSHOT | 69, &["1.18.2-pre2"]),
+    (SNAPSHOT | 70, &["1.18.2-pre3"]),
+    (SNAPSHOT | 71, &["1.18.2-rc1"]),
+    (SNAPSHOT | 72, &["22w11a"]),
+    (SNAPSHOT | 73, &["22w12a"]),
+    (SNAPSHOT | 74, &["22w13a"]),
+    (SNAPSHOT | 75, &["22w14a"]),
+    (SNAPSHOT | 76, &["22w15a"]),
+    (SNAPSHOT | 77, &["22w16a"]),
+    (SNAPSHOT | 78, &["22w16b"]),
+    (SNAPSHOT | 79, &["22w17a"]),
+    (SNAPSHOT | 80, &["22w18a"]),
+    (SNAPSHOT | 81, &["22w19a"]),
+    (SNAPSHOT | 82, &["1.19-pre1"]),
+    (SNAPSHOT | 83, &["1.19-pre2"]),
+    (SNAPSHOT | 84, &["1.19-pre3"]),
+    (SNAPSHOT | 85, &["1.19-pre4"]),
+    (SNAPSHOT | 86, &["1.19-pre5"]),
+    (SNAPSHOT | 87, &["1.19-rc1"]),
+    (SNAPSHOT | 88, &["1.19-rc2"]),
+    (SNAPSHOT | 89, &["22w24a"]),
+    (SNAPSHOT | 90, &["1.19.1-pre1"]),
+    (SNAPSHOT | 91, &["1.19.1-rc1"]),
+    (SNAPSHOT | 92, &["1.19.1-pre2"]),
+    (SNAPSHOT | 93, &["1.19.1-pre3"]),
+    (SNAPSHOT | 94, &["1.19.1-pre4"]),
+    (SNAPSHOT | 95, &["1.19.1-pre5"]),
+    (SNAPSHOT | 96, &["1.19.1-pre6"]),
+    (SNAPSHOT | 97, &["1.19.1-rc2"]),
+    (SNAPSHOT | 98, &["1.19.1-rc3"]),
+    (SNAPSHOT | 99, &["1.19.2-rc1"]),
+    (SNAPSHOT | 100, &["1.19.2-rc2"]),
+    (SNAPSHOT | 101, &["22w42a"]),
+];
@@ -0,0 +1,131 @@
+//! What changed between protocol versions.
+//!
+//! Instead of comparing against magic numbers, code that behaves differently
+//! depending on the version should ask the [`ProtocolVersion`] here. Snapshots
+//! numbered with the snapshot bit are compared using the ordering of
+//! [`ProtocolVersion`], so they only get the features of the releases before
+//! them.
+
+use super::ProtocolVersion;
+
+/// Changes to the protocol that are either present in a version or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// Entity ids are sent as varints instead of i32s (14w04a)
+    VarIntEntityIds,
+    /// Byte arrays in the login state are prefixed with a varint instead of a
+    /// u16, like in the encryption request (14w21a)
+    VarIntByteArrays,
+    /// Packets can be compressed after the server sent a set compression
+    /// packet (14w28a)
+    Compression,
+    /// The brand is sent as a varint prefixed string instead of the raw utf-8
+    /// bytes (1.8)
+    PrefixedBrand,
+    /// Command block edits tell whether the output of the command block is
+    /// tracked (1.8)
+    TrackOutput,
+    /// The server can send login plugin requests before login success
+    /// (1.13-pre3)
+    LoginPlugin,
+    /// Plugin channels are named like `minecraft:brand` instead of `MC|Brand`
+    /// (1.13)
+    NamespacedChannels,
+    /// Chat messages are signed by the player (1.19)
+    SignedChat,
+    /// The connection goes through the configuration state between login and
+    /// play (1.20.2)
+    Configuration,
+}
+
+impl Feature {
+    /// The first version with this feature.
+    pub fn since(self) -> ProtocolVersion {
+        ProtocolVersion(match self {
+            Self::VarIntEntityIds => 7,
+            Self::VarIntByteArrays => 19,
+            Self::Compression => 27,
+            Self::PrefixedBrand => 47,
+            Self::TrackOutput => 47,
+            Self::LoginPlugin => 385,
+            Self::NamespacedChannels => 393,
+            Self::SignedChat => 759,
+            Self::Configuration => 764,
+        })
+    }
+}
+
+/// How a block position is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionLayout {
+    /// Three separate i32s
+    Separate,
+    /// Packed into a u64 as 26 bits x, 12 bits y and 26 bits z
+    Xyz,
+    /// Packed into a u64 as 26 bits x, 26 bits z and 12 bits y
+    Xzy,
+}
+
+/// How an item stack is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlotFormat {
+    /// i16 item id, followed by the count, damage and nbt
+    Damage,
+    /// The damage became part of the nbt (17w47a)
+    Flattened,
+    /// bool whether the slot is present, followed by a varint item id, the
+    /// count and nbt (1.13.2-pre1)
+    Present,
+}
+
+impl ProtocolVersion {
+    /// Returns whether this version has `feature`.
+    pub fn supports(&self, feature: Feature) -> bool {
+        *self >= feature.since()
+    }
+
+    pub fn position_layout(&self) -> PositionLayout {
+        if *self >= ProtocolVersion(441) {
+            PositionLayout::Xzy
+        } else if *self >= ProtocolVersion(6) {
+            PositionLayout::Xyz
+        } else {
+            PositionLayout::Separate
+        }
+    }
+
+    pub fn slot_format(&self) -> SlotFormat {
+        if *self >= ProtocolVersion(402) {
+            SlotFormat::Present
+        } else if *self >= ProtocolVersion(346) {
+            SlotFormat::Flattened
+        } else {
+            SlotFormat::Damage
+        }
+    }
+
+    /// Returns whether plugin channels are named like `minecraft:brand`.
+    pub fn uses_namespaced_channels(&self) -> bool {
+        self.supports(Feature::NamespacedChannels)
+    }
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn features() {
+    let pv = |pv| ProtocolVersion::new(pv).unwrap();
+    assert!(!pv(26).supports(Feature::Compression));
+    assert!(pv(47).supports(Feature::Compression));
+    assert!(!pv(340).uses_namespaced_channels());
+    assert!(pv(393).uses_namespaced_channels());
+    assert_eq!(pv(5).position_layout(), PositionLayout::Separate);
+    assert_eq!(pv(404).position_layout(), PositionLayout::Xyz);
+    assert_eq!(pv(441).position_layout(), PositionLayout::Xzy);
+    assert_eq!(pv(340).slot_format(), SlotFormat::Damage);
+    assert_eq!(pv(401).slot_format(), SlotFormat::Flattened);
+    assert_eq!(pv(760).slot_format(), SlotFormat::Present);
+    // snapshots only get the features of the releases before them
+    assert!(pv(super::SNAPSHOT | 89).supports(Feature::SignedChat));
+    assert!(!pv(super::SNAPSHOT | 82).supports(Feature::SignedChat));
+}
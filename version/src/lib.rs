@@ -1,7 +1,30 @@
-use std::{fmt::Display, ops::Deref};
+use std::{cmp::Ordering, fmt::Display, hash::Hash, ops::Deref, str::FromStr};
+
+mod catalogue;
+mod features;
+
+use catalogue::CATALOGUE;
+pub use features::{Feature, PositionLayout, SlotFormat};
 
 const SNAPSHOT: i32 = 0x40000000;
 
+/// The highest accepted version that isn't a snapshot.
+const LATEST: i32 = 764;
+
+/// Snapshots after 1.16.3 set the [`SNAPSHOT`] bit, `(n, pv)` means that
+/// snapshots up to `SNAPSHOT | n` came before the release with the protocol
+/// version `pv`.
+const SNAPSHOT_RELEASES: [(i32, i32); 8] = [
+    (3, 754),
+    (34, 755),
+    (39, 756),
+    (59, 757),
+    (71, 758),
+    (88, 759),
+    (98, 760),
+    (101, 761),
+];
+
 #[derive(thiserror::Error, Debug)]
 #[error("invalid protocol version {0}")]
 pub struct InvalidVersion(i32);
@@ -12,8 +35,18 @@ impl InvalidVersion {
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+#[error("unknown minecraft version {0}")]
+pub struct UnknownVersion(String);
+impl UnknownVersion {
+    /// Returns the name that couldn't be found.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ProtocolVersion(i32);
 
 impl Display for ProtocolVersion {
@@ -38,6 +71,37 @@ impl TryFrom<i32> for ProtocolVersion {
     }
 }
 
+impl FromStr for ProtocolVersion {
+    type Err = UnknownVersion;
+
+    /// Parses the name of a release or snapshot like `1.12.2` or `20w14a`,
+    /// plain protocol versions like `340` are accepted as well.
+    fn from_str(s: &str) -> Result<Self, UnknownVersion> {
+        if let Ok(version) = s.parse() {
+            return Self::new(version).map_err(|_| UnknownVersion(s.into()));
+        }
+        CATALOGUE
+            .iter()
+            .find(|(_, names)| names.contains(&s))
+            .map(|&(version, _)| Self(version))
+            .ok_or_else(|| UnknownVersion(s.into()))
+    }
+}
+
+impl PartialOrd for ProtocolVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders versions by their release, snapshots with the [`SNAPSHOT`] bit set
+/// are placed between the releases they came out between.
+impl Ord for ProtocolVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
 impl From<ProtocolVersion> for i32 {
     fn from(val: ProtocolVersion) -> Self {
         *val
@@ -49,13 +113,13 @@ impl ProtocolVersion {
     /// # Errors
     /// If the supplied version is invalid, an `InvalidVersion` error is returned.
     pub fn new(version: i32) -> Result<Self, InvalidVersion> {
-        if ((0..=760).contains(&version)
+        if ((0..=LATEST).contains(&version)
             && !((111..201).contains(&version))
             && !((211..301).contains(&version))
             && !((405..441).contains(&version))
             && version != 499
             && !((501..550).contains(&version))
-            && !((578..701).contains(&version))
+            && !((579..701).contains(&version))
             && version != 720
             && !((723..725).contains(&version))
             && version != 726
@@ -66,15 +130,70 @@ impl ProtocolVersion {
             && version != 742
             && version != 745
             && version != 747)
-            || ((SNAPSHOT & 1..=SNAPSHOT & 101).contains(&version)
-                && !((SNAPSHOT & 71..SNAPSHOT & 704).contains(&version))
-                && version != SNAPSHOT & 24
-                && version != SNAPSHOT & 4)
+            || ((SNAPSHOT | 1..=SNAPSHOT | 101).contains(&version)
+                && version != SNAPSHOT | 24
+                && version != SNAPSHOT | 4)
         {
             Ok(Self(version))
         } else {
-            Err(InvalidVersion(0))
+            Err(InvalidVersion(version))
+        }
+    }
+
+    /// Returns the newest valid version.
+    pub fn latest() -> Self {
+        Self(LATEST)
+    }
+
+    /// Returns all valid versions from oldest to newest.
+    pub fn all() -> impl Iterator<Item = Self> {
+        let mut versions: Vec<_> = (0..=LATEST)
+            .chain(SNAPSHOT | 1..=SNAPSHOT | 101)
+            .filter_map(|version| Self::new(version).ok())
+            .collect();
+        versions.sort();
+        versions.into_iter()
+    }
+
+    /// Returns the names of all releases and snapshots using this version in
+    /// the order they were released in, the list is empty for versions that
+    /// were skipped.
+    pub fn names(&self) -> &'static [&'static str] {
+        CATALOGUE
+            .binary_search_by_key(&self.0, |&(version, _)| version)
+            .map_or(&[], |i| CATALOGUE[i].1)
+    }
+
+    /// Returns the name of the last release using this version, like `1.8.9`
+    /// for 47, or `None` if only snapshots used it.
+    pub fn release(&self) -> Option<&'static str> {
+        self.names()
+            .iter()
+            .rev()
+            .copied()
+            .find(|name| is_release(name))
+    }
+
+    /// Returns the name of the last release or snapshot using this version.
+    pub fn name(&self) -> Option<&'static str> {
+        self.release().or_else(|| self.names().last().copied())
+    }
+
+    /// Returns whether no release uses this version.
+    pub fn is_snapshot(&self) -> bool {
+        self.release().is_none()
+    }
+
+    fn sort_key(&self) -> (i32, bool, i32) {
+        if self.0 & SNAPSHOT == 0 {
+            return (self.0, true, 0);
         }
+        let snapshot = self.0 & !SNAPSHOT;
+        let release = SNAPSHOT_RELEASES
+            .iter()
+            .find(|&&(last, _)| snapshot <= last)
+            .map_or(LATEST + 1, |&(_, release)| release);
+        (release, false, snapshot)
     }
 
     /// Constructs a new `ProtocolVersion` without checking if the provided version is valid
@@ -84,3 +203,82 @@ impl ProtocolVersion {
         Self(version)
     }
 }
+
+/// Releases are named like `1.8` or `1.8.9`.
+fn is_release(name: &str) -> bool {
+    name.split('.')
+        .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+fn pv(pv: i32) -> ProtocolVersion {
+    ProtocolVersion::new(pv).unwrap()
+}
+
+#[test]
+fn catalogue() {
+    assert!(CATALOGUE.windows(2).all(|w| w[0].0 < w[1].0));
+    for &(version, _) in CATALOGUE {
+        assert!(ProtocolVersion::new(version).is_ok(), "{version}");
+    }
+    assert_eq!(pv(47).release(), Some("1.8.9"));
+    assert_eq!(pv(340).name(), Some("1.12.2"));
+    assert_eq!(pv(SNAPSHOT | 5).name(), Some("20w45a"));
+    assert!(pv(SNAPSHOT | 5).is_snapshot());
+    assert!(pv(46).is_snapshot());
+    assert!(!pv(754).is_snapshot());
+    assert!(pv(206).names().is_empty());
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn from_str() {
+    assert_eq!("1.8.9".parse::<ProtocolVersion>().unwrap(), pv(47));
+    assert_eq!("1.12.2".parse::<ProtocolVersion>().unwrap(), pv(340));
+    assert_eq!("1.16.5".parse::<ProtocolVersion>().unwrap(), pv(754));
+    assert_eq!(
+        "21w07a".parse::<ProtocolVersion>().unwrap(),
+        pv(SNAPSHOT | 14)
+    );
+    assert_eq!("578".parse::<ProtocolVersion>().unwrap(), pv(578));
+    assert!("1.12.3".parse::<ProtocolVersion>().is_err());
+    assert!("499".parse::<ProtocolVersion>().is_err());
+}
+
+#[test]
+fn ordering() {
+    assert!(pv(753) < pv(SNAPSHOT | 1));
+    assert!(pv(SNAPSHOT | 3) < pv(754));
+    assert!(pv(754) < pv(SNAPSHOT | 5));
+    assert!(pv(SNAPSHOT | 34) < pv(755));
+    assert!(pv(760) < pv(SNAPSHOT | 99));
+    assert!(pv(SNAPSHOT | 101) < pv(761));
+
+    let all: Vec<_> = ProtocolVersion::all().collect();
+    assert!(all.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(all.first().map(|v| **v), Some(0));
+    assert_eq!(all.last().map(|v| **v), Some(764));
+    assert!(all.iter().all(|v| ProtocolVersion::new(**v).is_ok()));
+}
+
+#[test]
+fn new() {
+    // 1.15.2
+    assert!(ProtocolVersion::new(578).is_ok());
+    assert!(ProtocolVersion::new(579).is_err());
+    // snapshots set the bit instead of being masked with it, 20w45a and
+    // 22w42a
+    assert!(ProtocolVersion::new(SNAPSHOT | 5).is_ok());
+    assert!(ProtocolVersion::new(SNAPSHOT | 101).is_ok());
+    assert!(ProtocolVersion::new(SNAPSHOT | 4).is_err());
+    assert!(ProtocolVersion::new(SNAPSHOT | 102).is_err());
+    // every accepted snapshot comes before an accepted release
+    for (_, release) in SNAPSHOT_RELEASES {
+        assert!(ProtocolVersion::new(release).is_ok(), "{release}");
+    }
+    assert_eq!(
+        ProtocolVersion::new(499).map_err(|e| e.inner()).err(),
+        Some(499)
+    );
+}